@@ -0,0 +1,184 @@
+use crate::output_style::OutputStyle;
+use std::collections::HashMap;
+use std::env;
+use std::time::Duration;
+use tracing::warn;
+
+/// Server-level settings read from the environment at startup.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    /// Register tools that modify Sentry data (`SENTRY_MCP_ALLOW_WRITES`).
+    pub allow_writes: bool,
+    /// Register tools that permanently delete Sentry data (`SENTRY_MCP_ALLOW_DESTRUCTIVE`).
+    pub allow_destructive: bool,
+    /// Path prefixes or module patterns whose stack frames count as in-app even
+    /// when the SDK marked them otherwise (`SENTRY_MCP_IN_APP_INCLUDE`, comma-separated).
+    pub in_app_include: Vec<String>,
+    /// Per-tool request timeout and retry overrides (`SENTRY_MCP_TOOL_POLICY`).
+    pub tool_policies: ToolPolicies,
+    /// Organization whose projects and environments are loaded in the background
+    /// at startup (`SENTRY_MCP_PREFETCH_ORG`).
+    pub prefetch_org: Option<String>,
+    /// How tool output is rendered (`SENTRY_MCP_OUTPUT_STYLE`).
+    pub output_style: OutputStyle,
+    /// Replace symbols and box drawing in tool output with ASCII
+    /// (`SENTRY_MCP_ASCII`); tools override it with their `ascii` argument.
+    pub ascii_only: bool,
+}
+
+impl Config {
+    pub fn from_env() -> Self {
+        Self {
+            allow_writes: env_flag("SENTRY_MCP_ALLOW_WRITES"),
+            allow_destructive: env_flag("SENTRY_MCP_ALLOW_DESTRUCTIVE"),
+            in_app_include: env::var("SENTRY_MCP_IN_APP_INCLUDE")
+                .map(|v| parse_list(&v))
+                .unwrap_or_default(),
+            tool_policies: env::var("SENTRY_MCP_TOOL_POLICY")
+                .map(|v| ToolPolicies::parse(&v))
+                .unwrap_or_default(),
+            prefetch_org: env::var("SENTRY_MCP_PREFETCH_ORG")
+                .ok()
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty()),
+            output_style: env::var("SENTRY_MCP_OUTPUT_STYLE")
+                .map(|v| parse_output_style(&v))
+                .unwrap_or_default(),
+            ascii_only: env_flag("SENTRY_MCP_ASCII"),
+        }
+    }
+}
+
+/// Parses an output style, falling back to markdown for unknown values.
+pub fn parse_output_style(value: &str) -> OutputStyle {
+    OutputStyle::parse(value).unwrap_or_else(|| {
+        warn!(
+            "Unknown SENTRY_MCP_OUTPUT_STYLE '{}', using markdown",
+            value
+        );
+        OutputStyle::default()
+    })
+}
+
+/// Interprets common truthy spellings: `1`, `true`, `yes`, `on`.
+pub fn parse_flag(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "1" | "true" | "yes" | "on"
+    )
+}
+
+/// Splits a comma-separated value, dropping blank entries.
+pub fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn env_flag(name: &str) -> bool {
+    env::var(name).map(|v| parse_flag(&v)).unwrap_or(false)
+}
+
+/// Timeout and retry behaviour for the Sentry API requests a tool makes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ToolPolicy {
+    /// Per-request timeout; `None` waits indefinitely.
+    pub timeout: Option<Duration>,
+    /// Extra attempts for GET requests that fail with a connection error,
+    /// timeout, 429 or 5xx. Writes are never retried.
+    pub retries: u32,
+}
+
+/// Policies keyed by tool name, with `*` as the fallback for unlisted tools.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ToolPolicies {
+    default: ToolPolicy,
+    by_tool: HashMap<String, ToolPolicy>,
+}
+
+impl ToolPolicies {
+    /// Parses `tool=timeout[/retries]` entries separated by commas, e.g.
+    /// `*=30s,get_trace_details=60s/1,get_issue_details=10s/3`. Timeouts take
+    /// `ms`, `s` or `m` suffixes (bare numbers are seconds); leave the timeout
+    /// empty (`tool=/2`) to only set retries. Malformed entries are skipped.
+    pub fn parse(value: &str) -> Self {
+        let mut policies = Self::default();
+        for entry in parse_list(value) {
+            match parse_policy_entry(&entry) {
+                Some((tool, policy)) if tool == "*" => policies.default = policy,
+                Some((tool, policy)) => {
+                    policies.by_tool.insert(tool, policy);
+                }
+                None => warn!(
+                    "Ignoring malformed SENTRY_MCP_TOOL_POLICY entry '{}'",
+                    entry
+                ),
+            }
+        }
+        policies
+    }
+
+    pub fn get(&self, tool: &str) -> ToolPolicy {
+        self.by_tool.get(tool).copied().unwrap_or(self.default)
+    }
+}
+
+fn parse_policy_entry(entry: &str) -> Option<(String, ToolPolicy)> {
+    let (tool, spec) = entry.split_once('=')?;
+    let tool = tool.trim();
+    if tool.is_empty() {
+        return None;
+    }
+    let (timeout, retries) = match spec.split_once('/') {
+        Some((timeout, retries)) => (timeout, retries.trim().parse().ok()?),
+        None => (spec, 0),
+    };
+    let timeout = match timeout.trim() {
+        "" => None,
+        t => Some(parse_duration(t)?),
+    };
+    Some((tool.to_string(), ToolPolicy { timeout, retries }))
+}
+
+/// Parses `500ms`, `10s`, `2m` or a bare number of seconds.
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (number, unit_ms) = if let Some(n) = value.strip_suffix("ms") {
+        (n, 1)
+    } else if let Some(n) = value.strip_suffix('s') {
+        (n, 1000)
+    } else if let Some(n) = value.strip_suffix('m') {
+        (n, 60_000)
+    } else {
+        (value, 1000)
+    };
+    let number: u64 = number.trim().parse().ok()?;
+    (number > 0).then(|| Duration::from_millis(number * unit_ms))
+}
+
+/// URL layout used for issue endpoints (`SENTRY_MCP_API_COMPAT`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ApiCompat {
+    /// Organization-scoped routes; an issue lookup that 404s there is retried on
+    /// the unscoped route, for that call only.
+    #[default]
+    Auto,
+    /// Organization-scoped routes only (current Sentry).
+    Modern,
+    /// Unscoped `/issues/{id}/` routes with numeric IDs (self-hosted 9.x/20.x).
+    Legacy,
+}
+
+impl ApiCompat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "auto" => Some(Self::Auto),
+            "modern" | "off" => Some(Self::Modern),
+            "legacy" | "on" => Some(Self::Legacy),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt::Display;
+
+/// Default output budget of tools that cap their output, in estimated tokens.
+pub const DEFAULT_MAX_TOKENS: usize = 8000;
+
+/// Rough token estimate (~4 characters per token for code and English text).
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// Cuts `text` to `max_chars` characters, ending with `...` when it was cut.
+pub fn truncate_chars(text: &str, max_chars: usize) -> String {
+    if text.chars().count() > max_chars {
+        let kept: String = text.chars().take(max_chars.saturating_sub(3)).collect();
+        format!("{}...", kept)
+    } else {
+        text.to_string()
+    }
+}
+
+pub fn format_duration(ms: f64) -> String {
+    if ms >= 1000.0 {
+        format!("{:.2}s", ms / 1000.0)
+    } else {
+        format!("{:.2}ms", ms)
+    }
+}
+
+/// A fraction between 0 and 1 as a percentage, e.g. `99.53%`.
+pub fn format_rate(rate: f64) -> String {
+    format!("{:.2}%", rate * 100.0)
+}
+
+/// `count`, or `{cap}+` once it reaches `cap`, for results Sentry stops at.
+pub fn count_label(count: usize, cap: usize) -> String {
+    if count >= cap {
+        format!("{}+", cap)
+    } else {
+        count.to_string()
+    }
+}
+
+/// Text made safe for a markdown table cell: pipes escaped, newlines flattened.
+pub fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+/// `key=value` pairs joined with commas, as tags and attributes are shown inline.
+pub fn key_values<K: Display, V: Display>(pairs: impl IntoIterator<Item = (K, V)>) -> String {
+    pairs
+        .into_iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A `**Label:** value` line.
+pub fn field(output: &mut String, label: &str, value: impl Display) {
+    output.push_str(&format!("**{}:** {}\n", label, value));
+}
+
+/// A `**Label:** value` line when there is a non-empty value.
+pub fn opt_field(output: &mut String, label: &str, value: Option<impl Display>) {
+    if let Some(value) = value.map(|v| v.to_string()).filter(|v| !v.is_empty()) {
+        field(output, label, value);
+    }
+}
+
+/// Output with a token budget. Text pushed directly is always kept; sections
+/// are kept while they fit, and [`Writer::finish`] notes how many did not.
+pub struct Writer {
+    output: String,
+    chars: usize,
+    max_tokens: usize,
+    omitted: usize,
+}
+
+impl Writer {
+    pub fn new(max_tokens: usize) -> Self {
+        Self {
+            output: String::new(),
+            chars: 0,
+            max_tokens,
+            omitted: 0,
+        }
+    }
+
+    pub fn push_str(&mut self, text: &str) {
+        self.chars += text.chars().count();
+        self.output.push_str(text);
+    }
+
+    pub fn field(&mut self, label: &str, value: impl Display) {
+        self.push_str(&format!("**{}:** {}\n", label, value));
+    }
+
+    pub fn opt_field(&mut self, label: &str, value: Option<impl Display>) {
+        if let Some(value) = value.map(|v| v.to_string()).filter(|v| !v.is_empty()) {
+            self.field(label, value);
+        }
+    }
+
+    pub fn tokens(&self) -> usize {
+        self.chars.div_ceil(4)
+    }
+
+    /// Appends `section` if it fits the remaining budget. Once a section is
+    /// left out, later ones are too, so the output stays a prefix.
+    pub fn section(&mut self, section: &str) -> bool {
+        let fits = (self.chars + section.chars().count()).div_ceil(4) <= self.max_tokens;
+        if self.omitted > 0 || !fits {
+            self.omitted += 1;
+            return false;
+        }
+        self.push_str(section);
+        true
+    }
+
+    /// Sections left out so far.
+    pub fn omitted(&self) -> usize {
+        self.omitted
+    }
+
+    /// The output, with a note naming how many `what` were left out.
+    pub fn finish(mut self, what: &str) -> String {
+        if self.omitted > 0 {
+            let note = format!(
+                "\n_{} more {} omitted to fit ~{} tokens._\n",
+                self.omitted, what, self.max_tokens
+            );
+            self.output.push_str(&note);
+        }
+        self.output
+    }
+}
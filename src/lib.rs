@@ -1,3 +1,8 @@
 pub mod api_client;
+pub mod config;
+pub mod format;
 pub mod json_ext;
+pub mod logging;
+pub mod minidump;
+pub mod output_style;
 pub mod tools;
@@ -1,27 +1,22 @@
-mod api_client;
-mod json_ext;
-mod tools;
-
 use rmcp::{ServiceExt, transport::stdio};
-use tools::SentryTools;
-use tracing::info;
+use sentry_mcp::logging::{self, LogFormat};
+use sentry_mcp::tools::SentryTools;
+use tracing::{info, warn};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::from_default_env()
-                .add_directive("sentry_mcp=info".parse()?)
-                .add_directive("reqwest::connect=debug".parse()?)
-                .add_directive("rmcp=warn".parse()?)
-                .add_directive("hyper=warn".parse()?)
-                .add_directive("hyper_util=warn".parse()?),
-        )
-        .with_writer(std::io::stderr)
-        .with_ansi(false)
-        .init();
+    let requested_format = std::env::var("SENTRY_MCP_LOG_FORMAT").unwrap_or_default();
+    let log_format = LogFormat::parse(&requested_format);
+    logging::init(log_format.unwrap_or_default())?;
+    if log_format.is_none() {
+        warn!(
+            "Unknown SENTRY_MCP_LOG_FORMAT '{}', using text",
+            requested_format
+        );
+    }
     info!("Starting sentry-mcp MCP server");
     let tools = SentryTools::new();
+    tools.spawn_prefetch();
     let service = tools.serve(stdio()).await?;
     service.waiting().await?;
     Ok(())
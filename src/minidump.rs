@@ -0,0 +1,254 @@
+//! Minimal reader for the Windows/Breakpad minidump container.
+//!
+//! Only the streams needed for a crash summary are decoded: system info, the
+//! exception record (plus the crashing thread's instruction pointer on x86/amd64)
+//! and the module list. Nothing is symbolicated.
+
+const SIGNATURE: u32 = 0x504d_444d; // "MDMP"
+const HEADER_SIZE: usize = 32;
+const DIRECTORY_ENTRY_SIZE: usize = 12;
+const MODULE_SIZE: usize = 108;
+
+const MODULE_LIST_STREAM: u32 = 4;
+const EXCEPTION_STREAM: u32 = 6;
+const SYSTEM_INFO_STREAM: u32 = 7;
+
+const ARCH_X86: u16 = 0;
+const ARCH_AMD64: u16 = 9;
+const X86_EIP_OFFSET: usize = 0xb8;
+const AMD64_RIP_OFFSET: usize = 0xf8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Module {
+    pub base: u64,
+    pub size: u32,
+    pub name: String,
+}
+
+impl Module {
+    pub fn contains(&self, address: u64) -> bool {
+        address >= self.base && address - self.base < u64::from(self.size)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemInfo {
+    pub processor_architecture: u16,
+    pub processor_count: u8,
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub build_number: u32,
+    pub platform_id: u32,
+    pub csd_version: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Exception {
+    pub thread_id: u32,
+    pub code: u32,
+    pub address: u64,
+    /// Instruction pointer from the crashing thread's context, when decodable.
+    pub instruction_pointer: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Minidump {
+    pub timestamp: u32,
+    pub system_info: Option<SystemInfo>,
+    pub exception: Option<Exception>,
+    pub modules: Vec<Module>,
+}
+
+impl Minidump {
+    /// Address the crash happened at: the thread's instruction pointer if known,
+    /// otherwise the exception address.
+    pub fn crash_address(&self) -> Option<u64> {
+        let exception = self.exception.as_ref()?;
+        Some(exception.instruction_pointer.unwrap_or(exception.address))
+    }
+
+    pub fn module_at(&self, address: u64) -> Option<&Module> {
+        self.modules.iter().find(|m| m.contains(address))
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&self, offset: usize, len: usize) -> anyhow::Result<&'a [u8]> {
+        offset
+            .checked_add(len)
+            .and_then(|end| self.data.get(offset..end))
+            .ok_or_else(|| anyhow::anyhow!("Minidump truncated at offset {:#x}", offset))
+    }
+    fn u8(&self, offset: usize) -> anyhow::Result<u8> {
+        Ok(self.bytes(offset, 1)?[0])
+    }
+    fn u16(&self, offset: usize) -> anyhow::Result<u16> {
+        Ok(u16::from_le_bytes(self.bytes(offset, 2)?.try_into()?))
+    }
+    fn u32(&self, offset: usize) -> anyhow::Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes(offset, 4)?.try_into()?))
+    }
+    fn u64(&self, offset: usize) -> anyhow::Result<u64> {
+        Ok(u64::from_le_bytes(self.bytes(offset, 8)?.try_into()?))
+    }
+    /// Reads a MINIDUMP_STRING: a byte length followed by UTF-16LE code units.
+    fn string(&self, rva: u32) -> anyhow::Result<String> {
+        let offset = rva as usize;
+        let len = self.u32(offset)? as usize;
+        let units: Vec<u16> = self
+            .bytes(offset + 4, len)?
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        Ok(String::from_utf16_lossy(&units))
+    }
+}
+
+pub fn parse(data: &[u8]) -> anyhow::Result<Minidump> {
+    let reader = Reader { data };
+    if data.len() < HEADER_SIZE || reader.u32(0)? != SIGNATURE {
+        anyhow::bail!("Not a minidump (missing MDMP signature)");
+    }
+    let stream_count = reader.u32(8)? as usize;
+    let directory = reader.u32(12)? as usize;
+    let mut dump = Minidump {
+        timestamp: reader.u32(20)?,
+        ..Default::default()
+    };
+    let mut context = None;
+    for i in 0..stream_count {
+        let entry = directory + i * DIRECTORY_ENTRY_SIZE;
+        let stream_type = reader.u32(entry)?;
+        let rva = reader.u32(entry + 8)? as usize;
+        match stream_type {
+            SYSTEM_INFO_STREAM => dump.system_info = Some(parse_system_info(&reader, rva)?),
+            MODULE_LIST_STREAM => dump.modules = parse_modules(&reader, rva)?,
+            EXCEPTION_STREAM => {
+                dump.exception = Some(parse_exception(&reader, rva)?);
+                context = Some((
+                    reader.u32(rva + 160)? as usize,
+                    reader.u32(rva + 164)? as usize,
+                ));
+            }
+            _ => {}
+        }
+    }
+    if let (Some(exception), Some(info), Some((size, rva))) =
+        (&mut dump.exception, &dump.system_info, context)
+    {
+        exception.instruction_pointer = match info.processor_architecture {
+            ARCH_AMD64 if AMD64_RIP_OFFSET + 8 <= size => reader.u64(rva + AMD64_RIP_OFFSET).ok(),
+            ARCH_X86 if X86_EIP_OFFSET + 4 <= size => {
+                reader.u32(rva + X86_EIP_OFFSET).ok().map(u64::from)
+            }
+            _ => None,
+        };
+    }
+    Ok(dump)
+}
+
+fn parse_system_info(reader: &Reader, rva: usize) -> anyhow::Result<SystemInfo> {
+    let csd_rva = reader.u32(rva + 24)?;
+    Ok(SystemInfo {
+        processor_architecture: reader.u16(rva)?,
+        processor_count: reader.u8(rva + 6)?,
+        major_version: reader.u32(rva + 8)?,
+        minor_version: reader.u32(rva + 12)?,
+        build_number: reader.u32(rva + 16)?,
+        platform_id: reader.u32(rva + 20)?,
+        csd_version: (csd_rva != 0)
+            .then(|| reader.string(csd_rva).ok())
+            .flatten()
+            .filter(|s| !s.is_empty()),
+    })
+}
+
+fn parse_modules(reader: &Reader, rva: usize) -> anyhow::Result<Vec<Module>> {
+    let count = reader.u32(rva)? as usize;
+    (0..count)
+        .map(|i| {
+            let at = rva + 4 + i * MODULE_SIZE;
+            Ok(Module {
+                base: reader.u64(at)?,
+                size: reader.u32(at + 8)?,
+                name: reader.string(reader.u32(at + 20)?)?,
+            })
+        })
+        .collect()
+}
+
+fn parse_exception(reader: &Reader, rva: usize) -> anyhow::Result<Exception> {
+    Ok(Exception {
+        thread_id: reader.u32(rva)?,
+        code: reader.u32(rva + 8)?,
+        address: reader.u64(rva + 24)?,
+        instruction_pointer: None,
+    })
+}
+
+pub fn architecture_name(arch: u16) -> &'static str {
+    match arch {
+        0 => "x86",
+        5 => "arm",
+        9 => "amd64",
+        12 | 0x8003 => "arm64",
+        0x8001 => "sparc",
+        0x8002 => "ppc",
+        _ => "unknown",
+    }
+}
+
+pub fn platform_name(platform_id: u32) -> &'static str {
+    match platform_id {
+        0..=2 => "Windows",
+        0x8000 => "Unix",
+        0x8101 => "macOS",
+        0x8102 => "iOS",
+        0x8201 => "Linux",
+        0x8202 => "Solaris",
+        0x8203 => "Android",
+        0x8204 => "PS3",
+        0x8205 => "NaCl",
+        _ => "Unknown OS",
+    }
+}
+
+/// Human-readable crash reason; Breakpad stores POSIX signals or Mach exceptions as the code.
+pub fn exception_name(platform_id: u32, code: u32) -> Option<&'static str> {
+    let name = match platform_id {
+        0..=2 => match code {
+            0xc000_0005 => "EXCEPTION_ACCESS_VIOLATION",
+            0x8000_0003 => "EXCEPTION_BREAKPOINT",
+            0xc000_001d => "EXCEPTION_ILLEGAL_INSTRUCTION",
+            0xc000_0094 => "EXCEPTION_INT_DIVIDE_BY_ZERO",
+            0xc000_00fd => "EXCEPTION_STACK_OVERFLOW",
+            0xc000_0409 => "STATUS_STACK_BUFFER_OVERRUN",
+            0xc000_0374 => "STATUS_HEAP_CORRUPTION",
+            0xe06d_7363 => "Unhandled C++ exception",
+            _ => return None,
+        },
+        0x8101 | 0x8102 => match code {
+            1 => "EXC_BAD_ACCESS",
+            2 => "EXC_BAD_INSTRUCTION",
+            3 => "EXC_ARITHMETIC",
+            5 => "EXC_SOFTWARE",
+            6 => "EXC_BREAKPOINT",
+            10 => "EXC_CRASH",
+            _ => return None,
+        },
+        _ => match code {
+            4 => "SIGILL",
+            5 => "SIGTRAP",
+            6 => "SIGABRT",
+            7 => "SIGBUS",
+            8 => "SIGFPE",
+            11 => "SIGSEGV",
+            _ => return None,
+        },
+    };
+    Some(name)
+}
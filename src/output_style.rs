@@ -0,0 +1,226 @@
+use regex::Regex;
+use rmcp::model::{CallToolResult, RawContent};
+use std::sync::LazyLock;
+
+/// How tool output is rendered (`SENTRY_MCP_OUTPUT_STYLE`). Tools write
+/// markdown; the other styles rewrite it for clients that show raw text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputStyle {
+    #[default]
+    Markdown,
+    /// Plain text: no markdown syntax, aligned table columns, underlined
+    /// top-level headings, ASCII instead of symbols and box drawing.
+    Plain,
+    /// Like `Plain`, but as compact as possible: no underlines, unpadded
+    /// table cells, no rules and no repeated blank lines.
+    Minimal,
+}
+
+/// Replacements for the non-ASCII characters tools use as decoration.
+const SYMBOLS: [(char, &str); 23] = [
+    ('─', "-"),
+    ('│', "|"),
+    ('├', "|"),
+    ('└', "`"),
+    ('→', "->"),
+    ('⚠', "!"),
+    ('\u{fe0f}', ""),
+    ('×', "x"),
+    ('—', "-"),
+    ('–', "-"),
+    ('…', "..."),
+    ('✅', "[ok]"),
+    ('❌', "[x]"),
+    ('✓', "ok"),
+    ('✗', "x"),
+    ('▁', "_"),
+    ('▂', "."),
+    ('▃', ":"),
+    ('▄', "-"),
+    ('▅', "="),
+    ('▆', "+"),
+    ('▇', "*"),
+    ('█', "#"),
+];
+
+static BOLD_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\*\*(.+?)\*\*").unwrap());
+static CODE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"`([^`]+)`").unwrap());
+static LINK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[([^\]]+)\]\(([^)\s]+)\)").unwrap());
+static HEADING_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(#{1,6})\s+(.*)$").unwrap());
+static SEPARATOR_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^:?-{3,}:?$").unwrap());
+
+impl OutputStyle {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "markdown" | "md" | "rich" => Some(Self::Markdown),
+            "plain" | "text" => Some(Self::Plain),
+            "minimal" => Some(Self::Minimal),
+            _ => None,
+        }
+    }
+
+    /// Rewrites a tool's markdown output. Code blocks keep their markdown and
+    /// layout; only their symbols are replaced.
+    pub fn render(self, text: &str) -> String {
+        if self == Self::Markdown {
+            return text.to_string();
+        }
+        let mut lines: Vec<String> = Vec::new();
+        let mut table: Vec<&str> = Vec::new();
+        let mut in_code = false;
+        for line in text.lines() {
+            if line.trim_start().starts_with("```") {
+                self.flush_table(&mut lines, &mut table);
+                in_code = !in_code;
+                continue;
+            }
+            if in_code {
+                lines.push(match self {
+                    Self::Plain => format!("    {}", ascii(line)),
+                    _ => ascii(line),
+                });
+                continue;
+            }
+            if line.trim_start().starts_with('|') {
+                table.push(line);
+                continue;
+            }
+            self.flush_table(&mut lines, &mut table);
+            self.render_line(&mut lines, line);
+        }
+        self.flush_table(&mut lines, &mut table);
+        if self == Self::Minimal {
+            lines.dedup_by(|a, b| a.is_empty() && b.is_empty());
+        }
+        let mut output = lines.join("\n");
+        if text.ends_with('\n') {
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Renders the text contents of a tool result in this style, in ASCII
+    /// only when `ascii_only` is set.
+    pub fn apply(self, result: &mut CallToolResult, ascii_only: bool) {
+        if self == Self::Markdown && !ascii_only {
+            return;
+        }
+        for content in &mut result.content {
+            if let RawContent::Text(text) = &mut content.raw {
+                text.text = match self {
+                    Self::Markdown => ascii(&text.text),
+                    _ => self.render(&text.text),
+                };
+            }
+        }
+    }
+
+    fn render_line(self, lines: &mut Vec<String>, line: &str) {
+        if let Some(caps) = HEADING_RE.captures(line) {
+            let heading = inline(&caps[2]);
+            let underline = match caps[1].len() {
+                1 => Some('='),
+                2 => Some('-'),
+                _ => None,
+            };
+            let width = heading.chars().count();
+            lines.push(heading);
+            if let (Self::Plain, Some(c)) = (self, underline) {
+                lines.push(c.to_string().repeat(width));
+            }
+            return;
+        }
+        let trimmed = line.trim();
+        if !trimmed.is_empty()
+            && trimmed.chars().all(|c| c == '─' || c == '-')
+            && trimmed.len() >= 3
+        {
+            if self == Self::Plain {
+                lines.push("-".repeat(trimmed.chars().count()));
+            }
+            return;
+        }
+        lines.push(inline(line));
+    }
+
+    fn flush_table(self, lines: &mut Vec<String>, table: &mut Vec<&str>) {
+        if table.is_empty() {
+            return;
+        }
+        let rows: Vec<Vec<String>> = table
+            .drain(..)
+            .map(table_cells)
+            .filter(|cells| !cells.iter().all(|c| SEPARATOR_RE.is_match(c)))
+            .map(|cells| cells.iter().map(|c| inline(c)).collect())
+            .collect();
+        if self == Self::Minimal {
+            lines.extend(rows.iter().map(|row| row.join(" | ")));
+            return;
+        }
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|i| {
+                rows.iter()
+                    .filter_map(|row| row.get(i))
+                    .map(|c| c.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        for row in rows {
+            let cells: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(i, c)| format!("{:width$}", c, width = widths[i]))
+                .collect();
+            lines.push(cells.join("  ").trim_end().to_string());
+        }
+    }
+}
+
+/// Cells of a markdown table row, with escaped pipes restored.
+fn table_cells(row: &str) -> Vec<String> {
+    let row = row.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = row.strip_suffix('|').unwrap_or(row);
+    let mut cells = vec![String::new()];
+    let mut chars = row.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                chars.next();
+                cells.last_mut().unwrap().push('|');
+            }
+            '|' => cells.push(String::new()),
+            c => cells.last_mut().unwrap().push(c),
+        }
+    }
+    cells.iter().map(|c| c.trim().to_string()).collect()
+}
+
+/// Strips inline markdown and replaces decorative symbols.
+fn inline(text: &str) -> String {
+    let text = BOLD_RE.replace_all(text, "$1");
+    let text = LINK_RE.replace_all(&text, |caps: &regex::Captures| {
+        if caps[1] == caps[2] {
+            caps[2].to_string()
+        } else {
+            format!("{} ({})", &caps[1], &caps[2])
+        }
+    });
+    ascii(&CODE_RE.replace_all(&text, "$1"))
+}
+
+/// Replaces the symbols and box drawing tools use with ASCII, leaving
+/// markdown as is.
+pub fn ascii(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for c in text.chars() {
+        match SYMBOLS.iter().find(|(symbol, _)| *symbol == c) {
+            Some((_, replacement)) => output.push_str(replacement),
+            None => output.push(c),
+        }
+    }
+    output
+}
@@ -0,0 +1,67 @@
+use crate::api_client::{IssueComment, NewComment, SentryApi};
+use crate::tools::list_issue_comments::comment_author;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Longest note accepted, in characters.
+pub const MAX_NOTE_CHARS: usize = 10_000;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct AddIssueNoteInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+    #[schemars(
+        description = "Note text (Markdown), e.g. a root-cause summary or a link to the fix PR. Mention people with @username"
+    )]
+    pub text: String,
+}
+
+/// Validates the input and builds the POST body.
+pub fn build_note(input: &AddIssueNoteInput) -> Result<NewComment, McpError> {
+    let text = input.text.trim();
+    if text.is_empty() {
+        return Err(McpError::invalid_params(
+            "text must not be empty".to_string(),
+            None,
+        ));
+    }
+    if text.chars().count() > MAX_NOTE_CHARS {
+        return Err(McpError::invalid_params(
+            format!("text must be at most {} characters", MAX_NOTE_CHARS),
+            None,
+        ));
+    }
+    Ok(NewComment {
+        text: text.to_string(),
+    })
+}
+
+pub fn format_note_output(issue_id: &str, comment: &IssueComment) -> String {
+    let mut output = String::new();
+    output.push_str("# Note Added\n\n");
+    output.push_str(&format!("**Issue:** {}\n", issue_id));
+    output.push_str(&format!("**Comment ID:** {}\n", comment.id));
+    output.push_str(&format!("**Author:** {}\n", comment_author(comment)));
+    if let Some(date) = &comment.date_created {
+        output.push_str(&format!("**Created:** {}\n", date));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: AddIssueNoteInput,
+) -> Result<CallToolResult, McpError> {
+    let note = build_note(&input)?;
+    let comment = client
+        .add_issue_comment(&input.organization_slug, &input.issue_id, &note)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_note_output(&input.issue_id, &comment);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
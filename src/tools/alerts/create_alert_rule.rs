@@ -0,0 +1,70 @@
+use super::{
+    ActionInput, ConditionInput, DEFAULT_FREQUENCY, FilterInput, build_actions, build_conditions,
+    build_filters, format_alert_rule_output, validate_action_match, validate_filter_match,
+    validate_frequency, validate_name, validate_owner,
+};
+use crate::api_client::{IssueAlertRuleBody, SentryApi};
+use crate::tools::list_environments::resolve_environment;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateAlertRuleInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug the rule belongs to")]
+    pub project_slug: String,
+    #[schemars(description = "Rule name shown in Sentry's alert list")]
+    pub name: String,
+    #[schemars(description = "Triggers; at least one is required")]
+    pub conditions: Vec<ConditionInput>,
+    #[schemars(description = "Optional filters that narrow which events can fire the rule")]
+    pub filters: Option<Vec<FilterInput>>,
+    #[schemars(description = "Notifications to send; at least one is required")]
+    pub actions: Vec<ActionInput>,
+    #[schemars(description = "'all' (default) or 'any' of the conditions must match")]
+    pub action_match: Option<String>,
+    #[schemars(description = "'all' (default), 'any' or 'none' of the filters must match")]
+    pub filter_match: Option<String>,
+    #[schemars(
+        description = "Minimum minutes between actions for the same issue, 5 to 43200 (default: 30)"
+    )]
+    pub frequency_minutes: Option<u32>,
+    #[schemars(description = "Only fire for events in this environment (default: all)")]
+    pub environment: Option<String>,
+    #[schemars(description = "Rule owner, 'team:<id>' or 'user:<id>'")]
+    pub owner: Option<String>,
+}
+
+/// Validates the input and builds the POST body; the environment is resolved separately.
+pub fn build_alert_rule(input: &CreateAlertRuleInput) -> Result<IssueAlertRuleBody, McpError> {
+    Ok(IssueAlertRuleBody {
+        name: validate_name(&input.name)?,
+        action_match: validate_action_match(input.action_match.as_deref().unwrap_or("all"))?,
+        filter_match: validate_filter_match(input.filter_match.as_deref().unwrap_or("all"))?,
+        frequency: validate_frequency(input.frequency_minutes.unwrap_or(DEFAULT_FREQUENCY))?,
+        environment: None,
+        conditions: build_conditions(&input.conditions)?,
+        filters: build_filters(input.filters.as_deref().unwrap_or_default())?,
+        actions: build_actions(&input.actions)?,
+        owner: input.owner.as_deref().map(validate_owner).transpose()?,
+    })
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: CreateAlertRuleInput,
+) -> Result<CallToolResult, McpError> {
+    let mut rule = build_alert_rule(&input)?;
+    let org = &input.organization_slug;
+    rule.environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let created = client
+        .create_issue_alert_rule(org, &input.project_slug, &rule)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_alert_rule_output("Alert Rule Created", &input.project_slug, &created);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,429 @@
+pub mod create_alert_rule;
+pub mod update_alert_rule;
+
+use crate::api_client::{IssueAlertRule, RuleComponent};
+use rmcp::ErrorData as McpError;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+
+const FIRST_SEEN: &str = "sentry.rules.conditions.first_seen_event.FirstSeenEventCondition";
+const REGRESSION: &str = "sentry.rules.conditions.regression_event.RegressionEventCondition";
+const REAPPEARED: &str = "sentry.rules.conditions.reappeared_event.ReappearedEventCondition";
+const EVENT_FREQUENCY: &str = "sentry.rules.conditions.event_frequency.EventFrequencyCondition";
+const USER_FREQUENCY: &str =
+    "sentry.rules.conditions.event_frequency.EventUniqueUserFrequencyCondition";
+const TAGGED_EVENT: &str = "sentry.rules.filters.tagged_event.TaggedEventFilter";
+const LEVEL: &str = "sentry.rules.filters.level.LevelFilter";
+const ISSUE_OCCURRENCES: &str = "sentry.rules.filters.issue_occurrences.IssueOccurrencesFilter";
+const EMAIL_ACTION: &str = "sentry.mail.actions.NotifyEmailAction";
+const SLACK_ACTION: &str = "sentry.integrations.slack.notify_action.SlackNotifyAction";
+
+/// Windows accepted by the frequency conditions.
+pub const INTERVALS: &[&str] = &["1m", "5m", "15m", "1h", "1d", "1w", "30d"];
+/// Tag filter operators and the codes Sentry stores for them.
+pub const TAG_OPERATORS: &[(&str, &str)] = &[
+    ("equals", "eq"),
+    ("not_equals", "ne"),
+    ("starts_with", "sw"),
+    ("not_starts_with", "nsw"),
+    ("ends_with", "ew"),
+    ("not_ends_with", "new"),
+    ("contains", "co"),
+    ("not_contains", "nc"),
+    ("is_set", "is"),
+    ("not_set", "ns"),
+];
+/// Event levels and their numeric values in level filters.
+pub const LEVELS: &[(&str, u32)] = &[
+    ("fatal", 50),
+    ("error", 40),
+    ("warning", 30),
+    ("info", 20),
+    ("debug", 10),
+];
+pub const ACTION_MATCHES: &[&str] = &["all", "any"];
+pub const FILTER_MATCHES: &[&str] = &["all", "any", "none"];
+/// Bounds on minutes between actions for the same issue.
+pub const MIN_FREQUENCY: u32 = 5;
+pub const MAX_FREQUENCY: u32 = 43200;
+pub const DEFAULT_FREQUENCY: u32 = 30;
+const MAX_NAME_LEN: usize = 256;
+
+/// When the rule fires.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConditionInput {
+    /// A new issue is created
+    FirstSeen,
+    /// A resolved issue gets a new event
+    Regression,
+    /// An archived issue becomes unarchived
+    Reappeared,
+    /// An issue sees more than `value` events within `interval`
+    EventFrequency { value: u64, interval: String },
+    /// An issue is seen by more than `value` users within `interval`
+    UserFrequency { value: u64, interval: String },
+}
+
+/// Narrows which events can fire the rule.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FilterInput {
+    /// The event's tag `key` matches `value` using `operator` (equals, not_equals,
+    /// starts_with, not_starts_with, ends_with, not_ends_with, contains, not_contains,
+    /// is_set, not_set)
+    TaggedEvent {
+        key: String,
+        operator: String,
+        value: Option<String>,
+    },
+    /// The event's level compared to `level` (fatal, error, warning, info, debug) using
+    /// `operator` (equals, at_least, at_most)
+    Level { operator: String, level: String },
+    /// The issue has happened at least `value` times
+    IssueOccurrences { value: u64 },
+}
+
+/// What the rule does when it fires.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ActionInput {
+    /// Email `target`: issue_owners (default), team or member. Teams and members need
+    /// `target_id`; issue owners fall through to `fallthrough` (active_members,
+    /// all_members, no_one) when there are none
+    Email {
+        target: Option<String>,
+        target_id: Option<String>,
+        fallthrough: Option<String>,
+    },
+    /// Post to a Slack `channel` (e.g. '#alerts') through the Slack integration whose
+    /// ID is `workspace`, optionally showing the listed comma-separated `tags`
+    Slack {
+        workspace: String,
+        channel: String,
+        tags: Option<String>,
+    },
+}
+
+fn invalid(message: String) -> McpError {
+    McpError::invalid_params(message, None)
+}
+
+fn component(id: &str, settings: Value) -> RuleComponent {
+    let settings = match settings {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    };
+    RuleComponent {
+        id: id.to_string(),
+        name: None,
+        settings,
+    }
+}
+
+fn one_of<'a>(what: &str, raw: &str, allowed: &[&'a str]) -> Result<&'a str, McpError> {
+    let value = raw.trim().to_ascii_lowercase();
+    allowed
+        .iter()
+        .find(|a| **a == value)
+        .copied()
+        .ok_or_else(|| {
+            invalid(format!(
+                "Invalid {} '{}': expected one of {}",
+                what,
+                raw,
+                allowed.join(", ")
+            ))
+        })
+}
+
+pub fn validate_name(raw: &str) -> Result<String, McpError> {
+    let name = raw.trim();
+    if name.is_empty() {
+        return Err(invalid("name must not be empty".to_string()));
+    }
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(invalid(format!(
+            "name must be at most {} characters",
+            MAX_NAME_LEN
+        )));
+    }
+    Ok(name.to_string())
+}
+
+pub fn validate_frequency(minutes: u32) -> Result<u32, McpError> {
+    if !(MIN_FREQUENCY..=MAX_FREQUENCY).contains(&minutes) {
+        return Err(invalid(format!(
+            "frequency_minutes must be between {} and {}",
+            MIN_FREQUENCY, MAX_FREQUENCY
+        )));
+    }
+    Ok(minutes)
+}
+
+pub fn validate_action_match(raw: &str) -> Result<String, McpError> {
+    one_of("action_match", raw, ACTION_MATCHES).map(str::to_string)
+}
+
+pub fn validate_filter_match(raw: &str) -> Result<String, McpError> {
+    one_of("filter_match", raw, FILTER_MATCHES).map(str::to_string)
+}
+
+/// Accepts `team:<id>` or `user:<id>`.
+pub fn validate_owner(raw: &str) -> Result<String, McpError> {
+    let owner = raw.trim();
+    let valid = owner.split_once(':').is_some_and(|(kind, id)| {
+        matches!(kind, "team" | "user") && !id.is_empty() && id.chars().all(|c| c.is_ascii_digit())
+    });
+    if !valid {
+        return Err(invalid(format!(
+            "Invalid owner '{}': expected 'team:<id>' or 'user:<id>'",
+            raw
+        )));
+    }
+    Ok(owner.to_string())
+}
+
+pub fn build_conditions(conditions: &[ConditionInput]) -> Result<Vec<RuleComponent>, McpError> {
+    if conditions.is_empty() {
+        return Err(invalid("at least one condition is required".to_string()));
+    }
+    conditions.iter().map(build_condition).collect()
+}
+
+fn build_condition(condition: &ConditionInput) -> Result<RuleComponent, McpError> {
+    let frequency = |id: &str, value: u64, interval: &str| {
+        if value == 0 {
+            return Err(invalid(
+                "frequency condition value must be at least 1".to_string(),
+            ));
+        }
+        let interval = one_of("interval", interval, INTERVALS)?;
+        Ok(component(id, json!({"value": value, "interval": interval})))
+    };
+    match condition {
+        ConditionInput::FirstSeen => Ok(component(FIRST_SEEN, json!({}))),
+        ConditionInput::Regression => Ok(component(REGRESSION, json!({}))),
+        ConditionInput::Reappeared => Ok(component(REAPPEARED, json!({}))),
+        ConditionInput::EventFrequency { value, interval } => {
+            frequency(EVENT_FREQUENCY, *value, interval)
+        }
+        ConditionInput::UserFrequency { value, interval } => {
+            frequency(USER_FREQUENCY, *value, interval)
+        }
+    }
+}
+
+pub fn build_filters(filters: &[FilterInput]) -> Result<Vec<RuleComponent>, McpError> {
+    filters.iter().map(build_filter).collect()
+}
+
+fn build_filter(filter: &FilterInput) -> Result<RuleComponent, McpError> {
+    match filter {
+        FilterInput::TaggedEvent {
+            key,
+            operator,
+            value,
+        } => {
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(invalid("tagged_event filter needs a key".to_string()));
+            }
+            let names: Vec<&str> = TAG_OPERATORS.iter().map(|(name, _)| *name).collect();
+            let name = one_of("tag operator", operator, &names)?;
+            let code = TAG_OPERATORS
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, code)| *code)
+                .unwrap_or("eq");
+            let mut settings = json!({"key": key, "match": code});
+            if !matches!(code, "is" | "ns") {
+                let value = value.as_deref().map(str::trim).unwrap_or_default();
+                if value.is_empty() {
+                    return Err(invalid(format!(
+                        "tagged_event filter with operator '{}' needs a value",
+                        name
+                    )));
+                }
+                settings["value"] = value.into();
+            }
+            Ok(component(TAGGED_EVENT, settings))
+        }
+        FilterInput::Level { operator, level } => {
+            let code = match one_of(
+                "level operator",
+                operator,
+                &["equals", "at_least", "at_most"],
+            )? {
+                "at_least" => "gte",
+                "at_most" => "lte",
+                _ => "eq",
+            };
+            let names: Vec<&str> = LEVELS.iter().map(|(name, _)| *name).collect();
+            let name = one_of("level", level, &names)?;
+            let value = LEVELS
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, v)| *v)
+                .unwrap_or_default();
+            Ok(component(
+                LEVEL,
+                json!({"match": code, "level": value.to_string()}),
+            ))
+        }
+        FilterInput::IssueOccurrences { value } => {
+            if *value == 0 {
+                return Err(invalid(
+                    "issue_occurrences filter value must be at least 1".to_string(),
+                ));
+            }
+            Ok(component(ISSUE_OCCURRENCES, json!({"value": value})))
+        }
+    }
+}
+
+pub fn build_actions(actions: &[ActionInput]) -> Result<Vec<RuleComponent>, McpError> {
+    if actions.is_empty() {
+        return Err(invalid("at least one action is required".to_string()));
+    }
+    actions.iter().map(build_action).collect()
+}
+
+fn build_action(action: &ActionInput) -> Result<RuleComponent, McpError> {
+    match action {
+        ActionInput::Email {
+            target,
+            target_id,
+            fallthrough,
+        } => {
+            let target = one_of(
+                "email target",
+                target.as_deref().unwrap_or("issue_owners"),
+                &["issue_owners", "team", "member"],
+            )?;
+            let target_id = target_id.as_deref().map(str::trim).unwrap_or_default();
+            let mut settings = match target {
+                "issue_owners" => json!({"targetType": "IssueOwners"}),
+                _ => {
+                    if target_id.is_empty() || !target_id.chars().all(|c| c.is_ascii_digit()) {
+                        return Err(invalid(format!(
+                            "email action to a {} needs a numeric target_id",
+                            target
+                        )));
+                    }
+                    let target_type = if target == "team" { "Team" } else { "Member" };
+                    json!({"targetType": target_type, "targetIdentifier": target_id})
+                }
+            };
+            if let Some(raw) = fallthrough {
+                if target != "issue_owners" {
+                    return Err(invalid(
+                        "fallthrough only applies to email actions for issue_owners".to_string(),
+                    ));
+                }
+                settings["fallthroughType"] = match one_of(
+                    "fallthrough",
+                    raw,
+                    &["active_members", "all_members", "no_one"],
+                )? {
+                    "all_members" => "AllMembers",
+                    "no_one" => "NoOne",
+                    _ => "ActiveMembers",
+                }
+                .into();
+            }
+            Ok(component(EMAIL_ACTION, settings))
+        }
+        ActionInput::Slack {
+            workspace,
+            channel,
+            tags,
+        } => {
+            let workspace = workspace.trim();
+            if workspace.is_empty() {
+                return Err(invalid(
+                    "slack action needs the Slack integration ID as workspace".to_string(),
+                ));
+            }
+            let channel = channel.trim();
+            if channel.trim_start_matches(['#', '@']).is_empty() {
+                return Err(invalid("slack action needs a channel".to_string()));
+            }
+            let channel = if channel.starts_with(['#', '@']) {
+                channel.to_string()
+            } else {
+                format!("#{}", channel)
+            };
+            let mut settings = json!({"workspace": workspace, "channel": channel});
+            if let Some(tags) = tags.as_deref().map(str::trim).filter(|t| !t.is_empty()) {
+                settings["tags"] = tags.into();
+            }
+            Ok(component(SLACK_ACTION, settings))
+        }
+    }
+}
+
+/// Uses Sentry's rendering when present, otherwise the class name plus settings.
+pub fn describe_component(component: &RuleComponent) -> String {
+    if let Some(name) = component.name.as_deref().filter(|n| !n.is_empty()) {
+        return name.to_string();
+    }
+    let class = component.id.rsplit('.').next().unwrap_or(&component.id);
+    if component.settings.is_empty() {
+        return class.to_string();
+    }
+    let settings: Vec<String> = component
+        .settings
+        .iter()
+        .map(|(key, value)| match value {
+            Value::String(s) => format!("{}={}", key, s),
+            other => format!("{}={}", key, other),
+        })
+        .collect();
+    format!("{} ({})", class, settings.join(", "))
+}
+
+fn format_components(output: &mut String, title: &str, components: &[RuleComponent]) {
+    if components.is_empty() {
+        return;
+    }
+    output.push_str(&format!("\n## {}\n\n", title));
+    for component in components {
+        output.push_str(&format!("- {}\n", describe_component(component)));
+    }
+}
+
+pub fn format_alert_rule_output(
+    heading: &str,
+    project_slug: &str,
+    rule: &IssueAlertRule,
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# {}\n\n", heading));
+    output.push_str(&format!("**ID:** {}\n", rule.id));
+    output.push_str(&format!("**Name:** {}\n", rule.name));
+    output.push_str(&format!("**Project:** {}\n", project_slug));
+    output.push_str(&format!(
+        "**Environment:** {}\n",
+        rule.environment.as_deref().unwrap_or("all")
+    ));
+    if let Some(frequency) = rule.frequency {
+        output.push_str(&format!("**Action Interval:** {} min\n", frequency));
+    }
+    if let Some(action_match) = &rule.action_match {
+        output.push_str(&format!("**Conditions Match:** {}\n", action_match));
+    }
+    if !rule.filters.is_empty()
+        && let Some(filter_match) = &rule.filter_match
+    {
+        output.push_str(&format!("**Filters Match:** {}\n", filter_match));
+    }
+    if let Some(owner) = &rule.owner {
+        output.push_str(&format!("**Owner:** {}\n", owner));
+    }
+    format_components(&mut output, "Conditions", &rule.conditions);
+    format_components(&mut output, "Filters", &rule.filters);
+    format_components(&mut output, "Actions", &rule.actions);
+    output
+}
@@ -0,0 +1,134 @@
+use super::{
+    ActionInput, ConditionInput, DEFAULT_FREQUENCY, FilterInput, build_actions, build_conditions,
+    build_filters, format_alert_rule_output, validate_action_match, validate_filter_match,
+    validate_frequency, validate_name, validate_owner,
+};
+use crate::api_client::{IssueAlertRule, IssueAlertRuleBody, SentryApi};
+use crate::tools::list_environments::resolve_environment;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateAlertRuleInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug the rule belongs to")]
+    pub project_slug: String,
+    #[schemars(description = "ID of the issue alert rule to modify")]
+    pub rule_id: String,
+    #[schemars(description = "New rule name")]
+    pub name: Option<String>,
+    #[schemars(description = "Replace all conditions")]
+    pub conditions: Option<Vec<ConditionInput>>,
+    #[schemars(description = "Replace all filters; an empty list removes them")]
+    pub filters: Option<Vec<FilterInput>>,
+    #[schemars(description = "Replace all actions")]
+    pub actions: Option<Vec<ActionInput>>,
+    #[schemars(description = "'all' or 'any' of the conditions must match")]
+    pub action_match: Option<String>,
+    #[schemars(description = "'all', 'any' or 'none' of the filters must match")]
+    pub filter_match: Option<String>,
+    #[schemars(description = "Minimum minutes between actions for the same issue, 5 to 43200")]
+    pub frequency_minutes: Option<u32>,
+    #[schemars(description = "Only fire for events in this environment; empty string for all")]
+    pub environment: Option<String>,
+    #[schemars(description = "Rule owner, 'team:<id>' or 'user:<id>'")]
+    pub owner: Option<String>,
+}
+
+impl UpdateAlertRuleInput {
+    fn has_changes(&self) -> bool {
+        self.name.is_some()
+            || self.conditions.is_some()
+            || self.filters.is_some()
+            || self.actions.is_some()
+            || self.action_match.is_some()
+            || self.filter_match.is_some()
+            || self.frequency_minutes.is_some()
+            || self.environment.is_some()
+            || self.owner.is_some()
+    }
+}
+
+/// Applies the requested changes to the current rule and validates the result. Sentry
+/// replaces the whole rule on PUT, so unchanged settings are carried over. The
+/// environment is left as it was; the caller resolves a new one.
+pub fn build_updated_rule(
+    existing: &IssueAlertRule,
+    input: &UpdateAlertRuleInput,
+) -> Result<IssueAlertRuleBody, McpError> {
+    if !input.has_changes() {
+        return Err(McpError::invalid_params(
+            "Nothing to update: set at least one field to change".to_string(),
+            None,
+        ));
+    }
+    Ok(IssueAlertRuleBody {
+        name: validate_name(input.name.as_deref().unwrap_or(&existing.name))?,
+        action_match: validate_action_match(
+            input
+                .action_match
+                .as_deref()
+                .or(existing.action_match.as_deref())
+                .unwrap_or("all"),
+        )?,
+        filter_match: validate_filter_match(
+            input
+                .filter_match
+                .as_deref()
+                .or(existing.filter_match.as_deref())
+                .unwrap_or("all"),
+        )?,
+        frequency: validate_frequency(
+            input
+                .frequency_minutes
+                .or(existing.frequency)
+                .unwrap_or(DEFAULT_FREQUENCY),
+        )?,
+        environment: existing.environment.clone(),
+        conditions: match &input.conditions {
+            Some(conditions) => build_conditions(conditions)?,
+            None => existing.conditions.clone(),
+        },
+        filters: match &input.filters {
+            Some(filters) => build_filters(filters)?,
+            None => existing.filters.clone(),
+        },
+        actions: match &input.actions {
+            Some(actions) => build_actions(actions)?,
+            None => existing.actions.clone(),
+        },
+        owner: match &input.owner {
+            Some(owner) => Some(validate_owner(owner)?),
+            None => existing.owner.clone(),
+        },
+    })
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: UpdateAlertRuleInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let existing = client
+        .get_issue_alert_rule(org, &input.project_slug, &input.rule_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let mut rule = build_updated_rule(&existing, &input)?;
+    match input.environment.as_deref().map(str::trim) {
+        Some("") => rule.environment = None,
+        Some(environment) => {
+            rule.environment = resolve_environment(client, org, Some(environment)).await?;
+        }
+        None => {}
+    }
+    let updated = client
+        .update_issue_alert_rule(org, &input.project_slug, &input.rule_id, &rule)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_alert_rule_output("Alert Rule Updated", &input.project_slug, &updated);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
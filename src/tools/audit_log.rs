@@ -0,0 +1,179 @@
+use crate::api_client::{AuditLog, AuditLogEntry, OrgMember, SentryApi};
+use crate::format::escape_cell;
+use crate::tools::whats_new::parse_since;
+use chrono::{DateTime, Utc};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct AuditLogInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Only changes by this user: user ID, email or full name")]
+    pub actor: Option<String>,
+    #[schemars(
+        description = "Only this event type, e.g. 'rule.edit', 'alertrule.edit', 'member.invite', 'project.edit'"
+    )]
+    pub event: Option<String>,
+    #[schemars(
+        description = "Only changes since: ISO 8601 timestamp ('2024-01-15T09:00:00Z', \
+        '2024-01-15') or a relative period ('24h', '7d', '1w')"
+    )]
+    pub since: Option<String>,
+    #[schemars(description = "Maximum number of entries to fetch (default: 25, max: 100)")]
+    pub limit: Option<i32>,
+}
+
+/// The user ID the audit log filters `actor` by. Numeric input is taken as
+/// the ID; otherwise exactly one member must have that email or name.
+pub fn resolve_actor(members: &[OrgMember], actor: &str) -> Result<String, String> {
+    let actor = actor.trim();
+    if !actor.is_empty() && actor.chars().all(|c| c.is_ascii_digit()) {
+        return Ok(actor.to_string());
+    }
+    let matches: Vec<&OrgMember> = members
+        .iter()
+        .filter(|m| m.user.is_some())
+        .filter(|m| {
+            m.email.eq_ignore_ascii_case(actor)
+                || m.name
+                    .as_deref()
+                    .is_some_and(|n| n.eq_ignore_ascii_case(actor))
+        })
+        .collect();
+    match matches.as_slice() {
+        [member] => Ok(member
+            .user
+            .as_ref()
+            .map(|u| u.id.clone())
+            .unwrap_or_default()),
+        [] => Err(format!("No organization member matches actor '{}'", actor)),
+        _ => Err(format!(
+            "Actor '{}' matches several members: {}; use an email",
+            actor,
+            matches
+                .iter()
+                .map(|m| m.email.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Entries created at or after `since`; entries with an unparsable date are kept.
+pub fn entries_since(entries: Vec<AuditLogEntry>, since: &DateTime<Utc>) -> Vec<AuditLogEntry> {
+    entries
+        .into_iter()
+        .filter(|e| DateTime::parse_from_rfc3339(&e.date_created).map_or(true, |d| d >= *since))
+        .collect()
+}
+
+fn actor_cell(entry: &AuditLogEntry) -> String {
+    let Some(actor) = &entry.actor else {
+        return "Sentry".to_string();
+    };
+    match (actor.name.as_deref(), actor.email.as_deref()) {
+        (Some(name), Some(email)) if name != email => format!("{} ({})", name, email),
+        (Some(name), _) => name.to_string(),
+        (None, Some(email)) => email.to_string(),
+        (None, None) => actor.id.clone().unwrap_or_else(|| "-".to_string()),
+    }
+}
+
+pub fn format_audit_log(
+    org: &str,
+    filters: &[String],
+    event: Option<&str>,
+    log: &AuditLog,
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Audit Log: {}\n\n", org));
+    if !filters.is_empty() {
+        output.push_str(&format!("**Filters:** {}\n", filters.join(", ")));
+    }
+    output.push_str(&format!("**Entries:** {}\n\n", log.rows.len()));
+    if log.rows.is_empty() {
+        output.push_str("No audit log entries match.\n");
+        if let Some(event) =
+            event.filter(|e| !log.options.is_empty() && !log.options.iter().any(|o| o == e))
+        {
+            output.push_str(&format!(
+                "\n'{}' is not an audit log event type. Event types: {}\n",
+                event,
+                log.options.join(", ")
+            ));
+        }
+        return output;
+    }
+    output.push_str("| Time | Actor | Event | Target | Description | IP |\n");
+    output.push_str("|---|---|---|---|---|---|\n");
+    for entry in &log.rows {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            entry.date_created,
+            escape_cell(&actor_cell(entry)),
+            entry.event,
+            entry.target_object.as_deref().unwrap_or("-"),
+            escape_cell(entry.note.as_deref().unwrap_or("-")),
+            entry.ip_address.as_deref().unwrap_or("-")
+        ));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: AuditLogInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let limit = input.limit.unwrap_or(25).clamp(1, 100);
+    let since = match input.since.as_deref() {
+        Some(since) => Some(parse_since(since, Utc::now()).ok_or_else(|| {
+            McpError::invalid_params(
+                "Invalid 'since': expected ISO 8601 timestamp or relative period like '7d'",
+                None,
+            )
+        })?),
+        None => None,
+    };
+    let actor = match input.actor.as_deref().map(str::trim) {
+        Some(actor) if !actor.is_empty() => {
+            let members = client
+                .list_org_members(org)
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            Some(resolve_actor(&members, actor).map_err(|e| McpError::invalid_params(e, None))?)
+        }
+        _ => None,
+    };
+    let event = input
+        .event
+        .as_deref()
+        .map(str::trim)
+        .filter(|e| !e.is_empty());
+    let mut log = client
+        .list_audit_log(org, actor.as_deref(), event, limit)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    if let Some(since) = &since {
+        log.rows = entries_since(log.rows, since);
+    }
+    let mut filters = Vec::new();
+    if actor.is_some() {
+        filters.push(format!(
+            "actor {}",
+            input.actor.as_deref().unwrap_or("").trim()
+        ));
+    }
+    if let Some(event) = event {
+        filters.push(format!("event {}", event));
+    }
+    if let Some(since) = &input.since {
+        filters.push(format!("since {}", since.trim()));
+    }
+    let output = format_audit_log(org, &filters, event, &log);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
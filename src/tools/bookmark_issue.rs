@@ -0,0 +1,56 @@
+use crate::api_client::{Issue, IssueUpdate, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BookmarkIssueInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+    #[schemars(
+        description = "true to bookmark the issue, false to remove the bookmark (default: true)"
+    )]
+    pub bookmarked: Option<bool>,
+}
+
+pub fn format_bookmark_output(issue: &Issue, bookmarked: bool) -> String {
+    let mut output = String::new();
+    output.push_str(if bookmarked {
+        "# Issue Bookmarked\n\n"
+    } else {
+        "# Issue Bookmark Removed\n\n"
+    });
+    output.push_str(&format!("**ID:** {}\n", issue.short_id));
+    output.push_str(&format!("**Title:** {}\n", issue.title));
+    output.push_str(&format!("**Status:** {}\n", issue.status));
+    if let Some(permalink) = &issue.permalink {
+        output.push_str(&format!("**Link:** {}\n", permalink));
+    }
+    if issue.is_bookmarked.is_some_and(|b| b != bookmarked) {
+        output.push_str(
+            "\n**Note:** Sentry did not apply the change; bookmarks belong to the user that owns the auth token.\n",
+        );
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: BookmarkIssueInput,
+) -> Result<CallToolResult, McpError> {
+    let bookmarked = input.bookmarked.unwrap_or(true);
+    let update = IssueUpdate {
+        is_bookmarked: Some(bookmarked),
+        ..Default::default()
+    };
+    let issue = client
+        .update_issue(&input.organization_slug, &input.issue_id, &update)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_bookmark_output(&issue, bookmarked);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,225 @@
+use crate::api_client::{Event, EventsQuery, SentryApi};
+use crate::json_ext::ValueExt;
+use crate::tools::get_issue_details::is_in_app;
+use crate::tools::search_query::{QueryTarget, check_query, push_query_warnings};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Sample event IDs listed per failure path.
+const MAX_SAMPLE_EVENTS: usize = 3;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ClusterIssueEventsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+    #[schemars(description = "Number of recent events to compare (default: 50, max: 100)")]
+    pub limit: Option<i32>,
+    #[schemars(
+        description = "In-app frames, innermost first, that must match for two events to share a failure path (default: 3)"
+    )]
+    pub depth: Option<usize>,
+    #[schemars(description = "Only compare events matching this search query (optional)")]
+    pub query: Option<String>,
+}
+
+/// What distinguishes one failure path from another: the exception type and the
+/// innermost in-app frames, without line numbers so releases still line up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailurePath {
+    pub exception_type: String,
+    pub frames: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct EventCluster<'a> {
+    pub path: FailurePath,
+    pub events: Vec<&'a Event>,
+}
+
+/// The exception that was raised last, which Sentry lists last and shows first.
+pub fn primary_exception(event: &Event) -> Option<&Value> {
+    last_exception(
+        event
+            .entries
+            .iter()
+            .map(|entry| (entry.entry_type.as_str(), &entry.data)),
+    )
+}
+
+/// [`primary_exception`] of an event given as raw JSON.
+pub fn primary_exception_json(event: &Value) -> Option<&Value> {
+    let entries = event.array_field("entries")?;
+    last_exception(
+        entries
+            .iter()
+            .filter_map(|entry| Some((entry.str_field("type")?, entry.get("data")?))),
+    )
+}
+
+fn last_exception<'a>(
+    mut entries: impl Iterator<Item = (&'a str, &'a Value)>,
+) -> Option<&'a Value> {
+    entries
+        .find(|(entry_type, _)| *entry_type == "exception")?
+        .1
+        .array_field("values")?
+        .last()
+}
+
+/// A frame identified by function and module or file, e.g. `charge (billing/stripe.py)`.
+pub fn frame_key(frame: &Value) -> String {
+    let function = frame.str_field("function").unwrap_or("?");
+    match frame
+        .str_field("module")
+        .or_else(|| frame.str_field("filename"))
+        .or_else(|| frame.str_field("absPath"))
+    {
+        Some(location) => format!("{} ({})", function, location),
+        None => function.to_string(),
+    }
+}
+
+/// The failure path of an event, looking at up to `depth` in-app frames.
+pub fn failure_path(event: &Event, depth: usize, in_app_include: &[String]) -> FailurePath {
+    let exception = primary_exception(event);
+    let frames = exception
+        .and_then(|exc| exc.get("stacktrace"))
+        .and_then(|st| st.array_field("frames"))
+        .map(|frames| {
+            frames
+                .iter()
+                .rev()
+                .filter(|f| is_in_app(f, in_app_include))
+                .take(depth)
+                .map(frame_key)
+                .collect()
+        })
+        .unwrap_or_default();
+    FailurePath {
+        exception_type: exception
+            .and_then(|exc| exc.str_field("type"))
+            .unwrap_or("(no exception)")
+            .to_string(),
+        frames,
+    }
+}
+
+/// Groups events by failure path, largest cluster first.
+pub fn cluster_events<'a>(
+    events: &'a [Event],
+    depth: usize,
+    in_app_include: &[String],
+) -> Vec<EventCluster<'a>> {
+    let mut clusters: Vec<EventCluster> = Vec::new();
+    for event in events {
+        let path = failure_path(event, depth, in_app_include);
+        match clusters.iter_mut().find(|c| c.path == path) {
+            Some(cluster) => cluster.events.push(event),
+            None => clusters.push(EventCluster {
+                path,
+                events: vec![event],
+            }),
+        }
+    }
+    clusters.sort_by_key(|c| std::cmp::Reverse(c.events.len()));
+    clusters
+}
+
+fn format_cluster(output: &mut String, index: usize, cluster: &EventCluster, total: usize) {
+    let count = cluster.events.len();
+    output.push_str(&format!(
+        "## Path {}: {} ({} events, {:.0}%)\n\n",
+        index + 1,
+        cluster.path.exception_type,
+        count,
+        count as f64 * 100.0 / total as f64
+    ));
+    if let Some(value) = cluster
+        .events
+        .iter()
+        .find_map(|e| primary_exception(e).and_then(|exc| exc.str_field("value")))
+        .filter(|v| !v.is_empty())
+    {
+        output.push_str(&format!("**Message:** {}\n", value));
+    }
+    let dates: Vec<&str> = cluster
+        .events
+        .iter()
+        .filter_map(|e| e.date_created.as_deref())
+        .collect();
+    if let (Some(first), Some(last)) = (dates.iter().min(), dates.iter().max()) {
+        output.push_str(&format!("**Seen:** {} to {}\n", first, last));
+    }
+    let samples: Vec<&str> = cluster
+        .events
+        .iter()
+        .take(MAX_SAMPLE_EVENTS)
+        .map(|e| e.event_id.as_str())
+        .collect();
+    output.push_str(&format!("**Sample events:** {}\n\n", samples.join(", ")));
+    if cluster.path.frames.is_empty() {
+        output.push_str("No in-app frames.\n\n");
+        return;
+    }
+    output.push_str("In-app frames, innermost first:\n");
+    for frame in &cluster.path.frames {
+        output.push_str(&format!("- `{}`\n", frame));
+    }
+    output.push('\n');
+}
+
+pub fn format_clusters_output(
+    issue_id: &str,
+    events: &[Event],
+    clusters: &[EventCluster],
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Failure Paths in {}\n\n", issue_id));
+    output.push_str(&format!("**Events analyzed:** {}\n", events.len()));
+    output.push_str(&format!("**Distinct paths:** {}\n\n", clusters.len()));
+    if events.is_empty() {
+        output.push_str("No events found.\n");
+        return output;
+    }
+    if clusters.len() == 1 {
+        output.push_str("All analyzed events share one failure path.\n\n");
+    } else {
+        output.push_str(
+            "The issue's events fail along different code paths. Compare the paths below; \
+            if they are separate bugs, the issue is over-grouped.\n\n",
+        );
+    }
+    for (i, cluster) in clusters.iter().enumerate() {
+        format_cluster(&mut output, i, cluster, events.len());
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ClusterIssueEventsInput,
+    in_app_include: &[String],
+) -> Result<CallToolResult, McpError> {
+    let warnings = check_query(input.query.as_deref(), QueryTarget::Events)?;
+    let query = EventsQuery {
+        query: input.query.clone().filter(|q| !q.trim().is_empty()),
+        limit: Some(input.limit.unwrap_or(50).clamp(1, 100)),
+        sort: None,
+        full: Some(true),
+    };
+    let events = client
+        .list_events_for_issue(&input.organization_slug, &input.issue_id, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let depth = input.depth.unwrap_or(3).max(1);
+    let clusters = cluster_events(&events, depth, in_app_include);
+    let mut output = format_clusters_output(&input.issue_id, &events, &clusters);
+    push_query_warnings(&mut output, &warnings);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
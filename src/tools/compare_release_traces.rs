@@ -0,0 +1,359 @@
+use crate::api_client::{DiscoverQuery, DiscoverResult, SentryApi, TraceSpan};
+use crate::format::format_duration;
+use crate::tools::get_trace_details::collect_operations;
+use crate::tools::list_environments::resolve_environment;
+use crate::tools::slo_report::{aggregate, build_query, quote_filter};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+const P50: &str = "p50(transaction.duration)";
+const COUNT: &str = "count()";
+/// Operations listed in the diff, biggest change first.
+const MAX_DIFF_OPS: usize = 15;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CompareReleaseTracesInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(description = "Transaction name, e.g. 'POST /api/checkout'")]
+    pub transaction: String,
+    #[schemars(description = "Release suspected of the regression")]
+    pub release: String,
+    #[schemars(
+        description = "Release to compare against (default: every other release in the period)"
+    )]
+    pub baseline_release: Option<String>,
+    #[schemars(description = "Time window, e.g. '24h', '7d' (default: 14d)")]
+    pub stats_period: Option<String>,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+}
+
+/// The trace picked to stand for one side of the comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampleTrace {
+    pub event_id: String,
+    pub trace_id: String,
+    pub duration_ms: f64,
+}
+
+/// One side of the comparison: which events it covers and its sample.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReleaseSide {
+    /// `release 1.2.0` or `other releases`.
+    pub label: String,
+    pub events: f64,
+    pub p50_ms: f64,
+    pub sample: Option<SampleTrace>,
+}
+
+/// Count and total duration of one span operation in both traces.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpDiff {
+    pub op: String,
+    pub before: (i32, f64),
+    pub after: (i32, f64),
+}
+
+impl OpDiff {
+    pub fn delta_ms(&self) -> f64 {
+        self.after.1 - self.before.1
+    }
+}
+
+/// Search query for the transaction's events in (or, negated, outside) `release`.
+pub fn side_query(transaction: &str, release: &str, negate: bool) -> String {
+    format!(
+        "{} {}release:{}",
+        build_query("transaction", Some(transaction)),
+        if negate { "!" } else { "" },
+        quote_filter(release)
+    )
+}
+
+/// The first event at or above the median: a typical trace, not an outlier.
+pub fn representative_query(query: &str, p50_ms: f64) -> String {
+    format!("{} transaction.duration:>={}ms", query, p50_ms.floor())
+}
+
+fn parse_sample(result: &DiscoverResult) -> Option<SampleTrace> {
+    let row = result.data.first()?;
+    Some(SampleTrace {
+        event_id: row.get("id")?.as_str()?.to_string(),
+        trace_id: row.get("trace")?.as_str()?.to_string(),
+        duration_ms: row
+            .get("transaction.duration")
+            .and_then(Value::as_f64)
+            .unwrap_or(0.0),
+    })
+}
+
+fn find_transaction<'a>(spans: &'a [TraceSpan], event_id: &str) -> Option<&'a TraceSpan> {
+    spans.iter().find_map(|span| {
+        let id = span.transaction_id.as_deref().unwrap_or(&span.event_id);
+        if span.is_transaction && id == event_id {
+            Some(span)
+        } else {
+            find_transaction(&span.children, event_id)
+        }
+    })
+}
+
+/// Operations under the sampled transaction, or the whole trace if it is not found.
+pub fn sample_operations(spans: &[TraceSpan], event_id: &str) -> HashMap<String, (i32, f64)> {
+    let mut ops = HashMap::new();
+    match find_transaction(spans, event_id) {
+        Some(span) => collect_operations(span, &mut ops),
+        None => spans.iter().for_each(|s| collect_operations(s, &mut ops)),
+    }
+    ops
+}
+
+/// Per-operation changes, the biggest absolute change in total time first.
+pub fn diff_operations(
+    before: &HashMap<String, (i32, f64)>,
+    after: &HashMap<String, (i32, f64)>,
+) -> Vec<OpDiff> {
+    let mut ops: Vec<&String> = before.keys().chain(after.keys()).collect();
+    ops.sort();
+    ops.dedup();
+    let mut diffs: Vec<OpDiff> = ops
+        .into_iter()
+        .map(|op| OpDiff {
+            op: op.clone(),
+            before: before.get(op).copied().unwrap_or((0, 0.0)),
+            after: after.get(op).copied().unwrap_or((0, 0.0)),
+        })
+        .collect();
+    diffs.sort_by(|a, b| {
+        b.delta_ms()
+            .abs()
+            .partial_cmp(&a.delta_ms().abs())
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.op.cmp(&b.op))
+    });
+    diffs
+}
+
+fn format_delta(ms: f64) -> String {
+    if ms < 0.0 {
+        format!("-{}", format_duration(-ms))
+    } else {
+        format!("+{}", format_duration(ms))
+    }
+}
+
+fn format_side(output: &mut String, heading: &str, side: &ReleaseSide) {
+    output.push_str(&format!("**{}:** {}", heading, side.label));
+    if side.events <= 0.0 {
+        output.push_str(" - no events\n");
+        return;
+    }
+    output.push_str(&format!(
+        " - {:.0} events, p50 {}",
+        side.events,
+        format_duration(side.p50_ms)
+    ));
+    if let Some(sample) = &side.sample {
+        output.push_str(&format!(
+            ", sample trace {} ({})",
+            sample.trace_id,
+            format_duration(sample.duration_ms)
+        ));
+    }
+    output.push('\n');
+}
+
+fn format_op_cell((count, total): (i32, f64)) -> String {
+    if count == 0 {
+        "-".to_string()
+    } else {
+        format!("{} × {}", count, format_duration(total))
+    }
+}
+
+pub fn format_release_comparison(
+    transaction: &str,
+    before: &ReleaseSide,
+    after: &ReleaseSide,
+    diffs: &[OpDiff],
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Release Trace Comparison: {}\n\n", transaction));
+    format_side(&mut output, "Before", before);
+    format_side(&mut output, "After", after);
+    let (Some(before_sample), Some(after_sample)) = (&before.sample, &after.sample) else {
+        output.push_str(
+            "\nNo trace to compare on both sides; widen stats_period or pick another baseline_release.\n",
+        );
+        return output;
+    };
+    if before.p50_ms > 0.0 {
+        output.push_str(&format!(
+            "**p50 Change:** {} ({:+.1}%)\n",
+            format_delta(after.p50_ms - before.p50_ms),
+            (after.p50_ms - before.p50_ms) / before.p50_ms * 100.0
+        ));
+    }
+    output.push_str("\n## Operations\n\n");
+    if diffs.is_empty() {
+        output.push_str("Neither trace has spans with an operation.\n");
+        return output;
+    }
+    output.push_str("| Op | Before | After | Change |\n");
+    output.push_str("|---|---|---|---|\n");
+    for diff in diffs.iter().take(MAX_DIFF_OPS) {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            diff.op,
+            format_op_cell(diff.before),
+            format_op_cell(diff.after),
+            format_delta(diff.delta_ms())
+        ));
+    }
+    if diffs.len() > MAX_DIFF_OPS {
+        output.push_str(&format!(
+            "\n{} more operations changed less.\n",
+            diffs.len() - MAX_DIFF_OPS
+        ));
+    }
+    output.push_str(&format!(
+        "\nUse get_trace_details with {} (before) or {} (after) to see the span trees.\n",
+        before_sample.trace_id, after_sample.trace_id
+    ));
+    output
+}
+
+async fn query_side(
+    client: &impl SentryApi,
+    org: &str,
+    base: &DiscoverQuery,
+    label: String,
+    query: String,
+) -> Result<ReleaseSide, McpError> {
+    let aggregates = client
+        .query_events(
+            org,
+            &DiscoverQuery {
+                fields: vec![P50.to_string(), COUNT.to_string()],
+                query: Some(query.clone()),
+                per_page: Some(1),
+                ..base.clone()
+            },
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let events = aggregate(&aggregates, COUNT);
+    let p50_ms = aggregate(&aggregates, P50);
+    if events <= 0.0 {
+        return Ok(ReleaseSide {
+            label,
+            events,
+            p50_ms,
+            sample: None,
+        });
+    }
+    let samples = client
+        .query_events(
+            org,
+            &DiscoverQuery {
+                fields: ["id", "trace", "transaction.duration"]
+                    .iter()
+                    .map(|f| f.to_string())
+                    .collect(),
+                query: Some(representative_query(&query, p50_ms)),
+                sort: Some("transaction.duration".to_string()),
+                per_page: Some(1),
+                ..base.clone()
+            },
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    Ok(ReleaseSide {
+        label,
+        events,
+        p50_ms,
+        sample: parse_sample(&samples),
+    })
+}
+
+async fn sample_ops(
+    client: &impl SentryApi,
+    org: &str,
+    sample: &SampleTrace,
+) -> Result<HashMap<String, (i32, f64)>, McpError> {
+    let trace = client
+        .get_trace(org, &sample.trace_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    Ok(sample_operations(&trace, &sample.event_id))
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: CompareReleaseTracesInput,
+) -> Result<CallToolResult, McpError> {
+    let transaction = input.transaction.trim();
+    let release = input.release.trim();
+    if transaction.is_empty() || release.is_empty() {
+        return Err(McpError::invalid_params(
+            "transaction and release must not be empty".to_string(),
+            None,
+        ));
+    }
+    let org = &input.organization_slug;
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let project = client
+        .get_project(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let base = DiscoverQuery {
+        stats_period: Some(input.stats_period.as_deref().unwrap_or("14d").to_string()),
+        projects: vec![project.id],
+        environments: environment.into_iter().collect(),
+        dataset: Some("transactions".to_string()),
+        ..Default::default()
+    };
+    let baseline = input
+        .baseline_release
+        .as_deref()
+        .map(str::trim)
+        .filter(|b| !b.is_empty());
+    let (before_label, before_query) = match baseline {
+        Some(baseline) => (
+            format!("release {}", baseline),
+            side_query(transaction, baseline, false),
+        ),
+        None => (
+            "other releases".to_string(),
+            side_query(transaction, release, true),
+        ),
+    };
+    let before = query_side(client, org, &base, before_label, before_query).await?;
+    let after = query_side(
+        client,
+        org,
+        &base,
+        format!("release {}", release),
+        side_query(transaction, release, false),
+    )
+    .await?;
+    let diffs = match (&before.sample, &after.sample) {
+        (Some(before_sample), Some(after_sample)) => diff_operations(
+            &sample_ops(client, org, before_sample).await?,
+            &sample_ops(client, org, after_sample).await?,
+        ),
+        _ => Vec::new(),
+    };
+    let output = format_release_comparison(transaction, &before, &after, &diffs);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
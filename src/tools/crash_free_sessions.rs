@@ -0,0 +1,210 @@
+use crate::api_client::{SentryApi, SessionStats, SessionsQuery};
+use crate::format::format_rate;
+use crate::tools::list_environments::resolve_environment;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+const SESSIONS: &str = "sum(session)";
+const USERS: &str = "count_unique(user)";
+const CRASH_FREE_SESSIONS: &str = "crash_free_rate(session)";
+const CRASH_FREE_USERS: &str = "crash_free_rate(user)";
+const GROUP_KEYS: [&str; 2] = ["release", "environment"];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CrashFreeSessionsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug (default: all projects)")]
+    pub project_slug: Option<String>,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+    #[schemars(description = "Only this release version")]
+    pub release: Option<String>,
+    #[schemars(
+        description = "Break down by 'release' and/or 'environment' (default: ['release'])"
+    )]
+    pub group_by: Option<Vec<String>>,
+    #[schemars(description = "Time window, e.g. '24h', '14d' (default: 14d)")]
+    pub stats_period: Option<String>,
+    #[schemars(description = "Maximum number of rows, most sessions first (default: 10, max: 50)")]
+    pub limit: Option<i32>,
+}
+
+/// Session health of one group, e.g. one release.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthRow {
+    /// The group's value for each `group_by` key, in order.
+    pub keys: Vec<String>,
+    pub sessions: f64,
+    pub users: Option<f64>,
+    /// Fractions between 0 and 1; `None` without sessions.
+    pub crash_free_sessions: Option<f64>,
+    pub crash_free_users: Option<f64>,
+}
+
+/// Lowercased, deduplicated `group_by` keys; unknown keys are an error.
+pub fn parse_group_by(group_by: Option<&[String]>) -> Result<Vec<String>, String> {
+    let Some(group_by) = group_by.filter(|g| !g.is_empty()) else {
+        return Ok(vec!["release".to_string()]);
+    };
+    let mut keys: Vec<String> = Vec::new();
+    for key in group_by.iter().map(|k| k.trim().to_ascii_lowercase()) {
+        if !GROUP_KEYS.contains(&key.as_str()) {
+            return Err(format!(
+                "Unknown group_by '{}': expected {}",
+                key,
+                GROUP_KEYS.join(" or ")
+            ));
+        }
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    Ok(keys)
+}
+
+fn by_value(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(s)) if !s.is_empty() => s.clone(),
+        Some(Value::Number(n)) => n.to_string(),
+        _ => "-".to_string(),
+    }
+}
+
+/// One row per session group, in the order the API returned them.
+pub fn health_rows(stats: &SessionStats, group_by: &[String]) -> Vec<HealthRow> {
+    stats
+        .groups
+        .iter()
+        .map(|group| {
+            let total = |field: &str| group.totals.get(field).copied().flatten();
+            HealthRow {
+                keys: group_by.iter().map(|k| by_value(group.by.get(k))).collect(),
+                sessions: total(SESSIONS).unwrap_or(0.0),
+                users: total(USERS),
+                crash_free_sessions: total(CRASH_FREE_SESSIONS),
+                crash_free_users: total(CRASH_FREE_USERS),
+            }
+        })
+        .collect()
+}
+
+fn capitalize(key: &str) -> String {
+    let mut chars = key.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+pub fn format_crash_free_sessions(
+    scope: &str,
+    period: &str,
+    filters: &[String],
+    group_by: &[String],
+    rows: &[HealthRow],
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Crash-Free Sessions: {}\n\n", scope));
+    output.push_str(&format!("**Period:** {}\n", period));
+    if !filters.is_empty() {
+        output.push_str(&format!("**Filters:** {}\n", filters.join(", ")));
+    }
+    output.push('\n');
+    if rows.is_empty() {
+        output.push_str(
+            "No session data; release health needs an SDK with session tracking enabled.\n",
+        );
+        return output;
+    }
+    let columns: Vec<String> = group_by.iter().map(|k| capitalize(k)).collect();
+    output.push_str(&format!(
+        "| {} | Sessions | Users | Crash-Free Sessions | Crash-Free Users |\n",
+        columns.join(" | ")
+    ));
+    output.push_str(&format!("|{}\n", "---|".repeat(columns.len() + 4)));
+    for row in rows {
+        output.push_str(&format!(
+            "| {} | {:.0} | {} | {} | {} |\n",
+            row.keys.join(" | "),
+            row.sessions,
+            row.users
+                .map_or_else(|| "-".to_string(), |u| format!("{:.0}", u)),
+            row.crash_free_sessions
+                .map_or_else(|| "-".to_string(), format_rate),
+            row.crash_free_users
+                .map_or_else(|| "-".to_string(), format_rate)
+        ));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: CrashFreeSessionsInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let group_by =
+        parse_group_by(input.group_by.as_deref()).map_err(|e| McpError::invalid_params(e, None))?;
+    let limit = input.limit.unwrap_or(10).clamp(1, 50);
+    let period = input.stats_period.as_deref().unwrap_or("14d");
+    let project_slug = input
+        .project_slug
+        .as_deref()
+        .map(str::trim)
+        .filter(|p| !p.is_empty());
+    let projects = match project_slug {
+        Some(slug) => vec![
+            client
+                .get_project(org, slug)
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?
+                .id,
+        ],
+        None => Vec::new(),
+    };
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let release = input
+        .release
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty());
+    let query = SessionsQuery {
+        fields: [SESSIONS, USERS, CRASH_FREE_SESSIONS, CRASH_FREE_USERS]
+            .map(String::from)
+            .to_vec(),
+        projects,
+        environment: environment.clone(),
+        stats_period: Some(period.to_string()),
+        interval: Some("1d".to_string()),
+        group_by: group_by.clone(),
+        query: release.map(|r| format!("release:\"{}\"", r)),
+        order_by: Some(format!("-{}", SESSIONS)),
+        per_page: Some(limit),
+    };
+    let stats = client
+        .get_session_stats(org, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let mut filters = Vec::new();
+    if let Some(environment) = &environment {
+        filters.push(format!("environment {}", environment));
+    }
+    if let Some(release) = release {
+        filters.push(format!("release {}", release));
+    }
+    let output = format_crash_free_sessions(
+        project_slug.unwrap_or(org),
+        period,
+        &filters,
+        &group_by,
+        &health_rows(&stats, &group_by),
+    );
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
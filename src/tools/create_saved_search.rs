@@ -0,0 +1,107 @@
+use crate::api_client::{NewSavedSearch, SavedSearch, SentryApi};
+use crate::tools::list_issues::SORTS;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+const VISIBILITIES: [&str; 2] = ["organization", "owner"];
+/// Saved search type for the issue stream.
+const ISSUE_SEARCH_TYPE: i32 = 0;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateSavedSearchInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Name shown in Sentry's saved search list")]
+    pub name: String,
+    #[schemars(description = "Issue search query, e.g. 'is:unresolved level:error release:1.2.0'")]
+    pub query: String,
+    #[schemars(
+        description = "Sort order: 'date' (default), 'new', 'freq', 'user', 'trends', 'inbox'"
+    )]
+    pub sort: Option<String>,
+    #[schemars(
+        description = "'organization' (everyone, default; needs manager role) or 'owner' (only you)"
+    )]
+    pub visibility: Option<String>,
+}
+
+fn invalid(message: String) -> McpError {
+    McpError::invalid_params(message, None)
+}
+
+/// Validates the input and builds the POST body.
+pub fn build_saved_search(input: &CreateSavedSearchInput) -> Result<NewSavedSearch, McpError> {
+    let name = input.name.trim();
+    if name.is_empty() {
+        return Err(invalid("name must not be empty".to_string()));
+    }
+    let query = input.query.trim();
+    if query.is_empty() {
+        return Err(invalid("query must not be empty".to_string()));
+    }
+    let sort = match &input.sort {
+        Some(raw) => {
+            let sort = raw.trim().to_ascii_lowercase();
+            if !SORTS.contains(&sort.as_str()) {
+                return Err(invalid(format!(
+                    "Invalid sort '{}': expected one of {}",
+                    raw,
+                    SORTS.join(", ")
+                )));
+            }
+            Some(sort)
+        }
+        None => None,
+    };
+    let visibility = input
+        .visibility
+        .as_deref()
+        .unwrap_or("organization")
+        .trim()
+        .to_ascii_lowercase();
+    if !VISIBILITIES.contains(&visibility.as_str()) {
+        return Err(invalid(format!(
+            "Invalid visibility '{}': expected one of {}",
+            visibility,
+            VISIBILITIES.join(", ")
+        )));
+    }
+    Ok(NewSavedSearch {
+        search_type: ISSUE_SEARCH_TYPE,
+        name: name.to_string(),
+        query: query.to_string(),
+        sort,
+        visibility,
+    })
+}
+
+pub fn format_saved_search_output(search: &SavedSearch) -> String {
+    let mut output = String::new();
+    output.push_str("# Saved Search Created\n\n");
+    output.push_str(&format!("**ID:** {}\n", search.id));
+    output.push_str(&format!("**Name:** {}\n", search.name));
+    output.push_str(&format!("**Query:** {}\n", search.query));
+    if let Some(sort) = &search.sort {
+        output.push_str(&format!("**Sort:** {}\n", sort));
+    }
+    if let Some(visibility) = &search.visibility {
+        output.push_str(&format!("**Visibility:** {}\n", visibility));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: CreateSavedSearchInput,
+) -> Result<CallToolResult, McpError> {
+    let search = build_saved_search(&input)?;
+    let saved = client
+        .create_saved_search(&input.organization_slug, &search)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_saved_search_output(&saved);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
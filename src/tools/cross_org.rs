@@ -0,0 +1,65 @@
+use crate::api_client::SentryApi;
+use rmcp::ErrorData as McpError;
+use std::future::Future;
+
+/// Pushes every Markdown heading in `text` two levels down so it nests under a `##` section.
+pub fn demote_headings(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.starts_with('#') {
+                format!("##{}", line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Joins per-organization results into one document; failed orgs get an error note.
+pub fn format_org_sections(sections: &[(String, Result<String, McpError>)]) -> String {
+    let mut output = String::new();
+    output.push_str("# Cross-Organization Results\n\n");
+    output.push_str(&format!("**Organizations:** {}\n", sections.len()));
+    let failed = sections.iter().filter(|(_, r)| r.is_err()).count();
+    if failed > 0 {
+        output.push_str(&format!("**Failed:** {}\n", failed));
+    }
+    if sections.is_empty() {
+        output.push_str("\nThe token has no accessible organizations.\n");
+    }
+    for (slug, result) in sections {
+        output.push_str(&format!("\n## Organization: {}\n\n", slug));
+        match result {
+            Ok(text) => {
+                output.push_str(&demote_headings(text.trim_end()));
+                output.push('\n');
+            }
+            Err(e) => output.push_str(&format!("*Error: {}*\n", e.message)),
+        }
+    }
+    output
+}
+
+/// Runs `run` sequentially for every organization the token is a member of.
+///
+/// A failure in one organization is reported in its section and does not stop the rest.
+pub async fn for_each_organization<F, Fut>(
+    client: &impl SentryApi,
+    mut run: F,
+) -> Result<String, McpError>
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<String, McpError>>,
+{
+    let orgs = client
+        .list_organizations()
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let mut sections = Vec::with_capacity(orgs.len());
+    for org in orgs {
+        let result = run(org.slug.clone()).await;
+        sections.push((org.slug, result));
+    }
+    Ok(format_org_sections(&sections))
+}
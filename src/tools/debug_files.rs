@@ -0,0 +1,311 @@
+use crate::api_client::{ArtifactBundle, DebugFile, ReleaseFile, SentryApi};
+use crate::json_ext::ValueExt;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DebugFilesInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(description = "Release to check source map artifacts for (optional)")]
+    pub release: Option<String>,
+    #[schemars(description = "Distribution of the release, e.g. a build number (optional)")]
+    pub dist: Option<String>,
+    #[schemars(
+        description = "Event ID whose release and dist to check, e.g. of an event with a minified stack trace (optional)"
+    )]
+    pub event_id: Option<String>,
+    #[schemars(description = "Only debug files with this debug ID or file name (optional)")]
+    pub debug_id: Option<String>,
+    #[schemars(description = "Maximum entries per list (default: 25, max: 100)")]
+    pub limit: Option<i32>,
+}
+
+/// The release (and dist) whose source maps are checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseTarget {
+    pub version: String,
+    pub dist: Option<String>,
+    /// The event the release was read from.
+    pub event_id: Option<String>,
+}
+
+/// Whether minified stack traces of a release can be unminified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceMapStatus {
+    Uploaded,
+    /// Artifacts exist for the release, but only for other dists.
+    OtherDist,
+    /// Release files were uploaded, but none is a source map.
+    NoMaps,
+    Missing,
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.filter(|v| !v.is_empty())
+}
+
+/// Whether the bundle was uploaded for `release` and exactly `dist`.
+pub fn bundle_matches(bundle: &ArtifactBundle, release: &str, dist: Option<&str>) -> bool {
+    bundle
+        .associations
+        .iter()
+        .any(|a| a.release == release && non_empty(a.dist.as_deref()) == non_empty(dist))
+}
+
+fn bundle_for_release(bundle: &ArtifactBundle, release: &str) -> bool {
+    bundle.associations.iter().any(|a| a.release == release)
+}
+
+pub fn source_map_status(
+    target: &ReleaseTarget,
+    bundles: &[ArtifactBundle],
+    files: &[ReleaseFile],
+) -> SourceMapStatus {
+    let dist = target.dist.as_deref();
+    let files_for_dist: Vec<&ReleaseFile> = files
+        .iter()
+        .filter(|f| non_empty(f.dist.as_deref()) == non_empty(dist))
+        .collect();
+    if bundles
+        .iter()
+        .any(|b| bundle_matches(b, &target.version, dist))
+        || files_for_dist.iter().any(|f| f.name.ends_with(".map"))
+    {
+        return SourceMapStatus::Uploaded;
+    }
+    if !files_for_dist.is_empty() {
+        return SourceMapStatus::NoMaps;
+    }
+    if !files.is_empty()
+        || bundles
+            .iter()
+            .any(|b| bundle_for_release(b, &target.version))
+    {
+        return SourceMapStatus::OtherDist;
+    }
+    SourceMapStatus::Missing
+}
+
+fn upload_command(target: &ReleaseTarget) -> String {
+    match &target.dist {
+        Some(dist) => format!(
+            "sentry-cli sourcemaps upload --release {} --dist {} <build dir>",
+            target.version, dist
+        ),
+        None => format!(
+            "sentry-cli sourcemaps upload --release {} <build dir>",
+            target.version
+        ),
+    }
+}
+
+fn format_verdict(output: &mut String, target: &ReleaseTarget, status: SourceMapStatus) {
+    let dist = target.dist.as_deref().unwrap_or("(none)");
+    let verdict = match status {
+        SourceMapStatus::Uploaded => "✅ Source maps were uploaded for this release. If stack traces are still minified, check that artifact names (e.g. `~/static/js/app.js`) match the frames' paths and that minified files reference their maps (`sourceMappingURL`) or carry debug IDs.".to_string(),
+        SourceMapStatus::OtherDist => format!(
+            "⚠️ Artifacts exist for release {} but not for dist {}; events only use artifacts of their own dist. Upload with `{}`.",
+            target.version, dist, upload_command(target)
+        ),
+        SourceMapStatus::NoMaps => format!(
+            "⚠️ Files were uploaded for release {} but none is a source map (`.map`), so minified frames cannot be mapped back. Upload with `{}`.",
+            target.version, upload_command(target)
+        ),
+        SourceMapStatus::Missing => format!(
+            "⚠️ No source map artifacts were uploaded for release {}, so its stack traces stay minified. Upload with `{}`.",
+            target.version, upload_command(target)
+        ),
+    };
+    output.push_str(&format!("{}\n", verdict));
+}
+
+fn format_bundles(output: &mut String, bundles: &[&ArtifactBundle]) {
+    output.push_str("| Bundle ID | Releases | Files | Uploaded |\n");
+    output.push_str("|---|---|---|---|\n");
+    for bundle in bundles {
+        let releases: Vec<String> = bundle
+            .associations
+            .iter()
+            .map(|a| match non_empty(a.dist.as_deref()) {
+                Some(dist) => format!("{} ({})", a.release, dist),
+                None => a.release.clone(),
+            })
+            .collect();
+        output.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            bundle.bundle_id,
+            releases.join(", "),
+            bundle.file_count,
+            bundle.date.as_deref().unwrap_or("")
+        ));
+    }
+}
+
+fn format_source_maps(
+    output: &mut String,
+    target: Option<&ReleaseTarget>,
+    bundles: &[ArtifactBundle],
+    files: &[ReleaseFile],
+    limit: usize,
+) {
+    output.push_str("\n## Source Maps\n\n");
+    let shown: Vec<&ArtifactBundle> = match target {
+        Some(target) => bundles
+            .iter()
+            .filter(|b| bundle_for_release(b, &target.version))
+            .collect(),
+        None => bundles.iter().collect(),
+    };
+    if let Some(target) = target {
+        format_verdict(output, target, source_map_status(target, bundles, files));
+        output.push('\n');
+    }
+    output.push_str(&format!("**Artifact Bundles:** {}\n\n", shown.len()));
+    if !shown.is_empty() {
+        format_bundles(output, &shown[..shown.len().min(limit)]);
+        output.push('\n');
+    }
+    if target.is_none() {
+        return;
+    }
+    output.push_str(&format!("**Release Files:** {}\n", files.len()));
+    if files.is_empty() {
+        return;
+    }
+    output.push_str("\n| Name | Dist | Size |\n|---|---|---|\n");
+    for file in files.iter().take(limit) {
+        output.push_str(&format!(
+            "| {} | {} | {} |\n",
+            file.name,
+            file.dist.as_deref().unwrap_or(""),
+            file.size
+        ));
+    }
+}
+
+fn format_debug_files(output: &mut String, files: &[DebugFile], limit: usize) {
+    output.push_str(&format!(
+        "\n## Debug Files\n\n**Found:** {}\n\n",
+        files.len()
+    ));
+    if files.is_empty() {
+        output.push_str("No debug information files uploaded.\n");
+        return;
+    }
+    output.push_str("| Debug ID | Object | Type | Arch | Features | Size | Uploaded |\n");
+    output.push_str("|---|---|---|---|---|---|---|\n");
+    for file in files.iter().take(limit) {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} |\n",
+            file.debug_id.as_deref().unwrap_or(""),
+            file.object_name.as_deref().unwrap_or(""),
+            file.symbol_type.as_deref().unwrap_or(""),
+            file.cpu_name.as_deref().unwrap_or(""),
+            file.data.features.join(", "),
+            file.size,
+            file.date_created.as_deref().unwrap_or("")
+        ));
+    }
+}
+
+pub fn format_debug_files_output(
+    project: &str,
+    target: Option<&ReleaseTarget>,
+    debug_files: &[DebugFile],
+    bundles: &[ArtifactBundle],
+    release_files: &[ReleaseFile],
+    limit: usize,
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Debug Files and Source Maps: {}\n\n", project));
+    if let Some(target) = target {
+        output.push_str(&format!("**Release:** {}\n", target.version));
+        if let Some(dist) = &target.dist {
+            output.push_str(&format!("**Dist:** {}\n", dist));
+        }
+        if let Some(event_id) = &target.event_id {
+            output.push_str(&format!("**From Event:** {}\n", event_id));
+        }
+    }
+    format_source_maps(&mut output, target, bundles, release_files, limit);
+    format_debug_files(&mut output, debug_files, limit);
+    output
+}
+
+/// The release to check: the one given, or the release of the event.
+async fn resolve_target(
+    client: &impl SentryApi,
+    input: &DebugFilesInput,
+) -> Result<Option<ReleaseTarget>, McpError> {
+    let dist = non_empty(input.dist.as_deref().map(str::trim)).map(str::to_string);
+    if let Some(version) = non_empty(input.release.as_deref().map(str::trim)) {
+        return Ok(Some(ReleaseTarget {
+            version: version.to_string(),
+            dist,
+            event_id: None,
+        }));
+    }
+    let Some(event_id) = input.event_id.as_deref().map(str::trim) else {
+        return Ok(None);
+    };
+    let event = client
+        .get_event_json(&input.organization_slug, &input.project_slug, event_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let version = non_empty(event.str_field("release")).ok_or_else(|| {
+        McpError::invalid_params(
+            format!(
+                "Event {} has no release, so its source maps cannot be looked up",
+                event_id
+            ),
+            None,
+        )
+    })?;
+    Ok(Some(ReleaseTarget {
+        version: version.to_string(),
+        dist: dist.or_else(|| non_empty(event.str_field("dist")).map(str::to_string)),
+        event_id: Some(event_id.to_string()),
+    }))
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: DebugFilesInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let project = &input.project_slug;
+    let limit = input.limit.unwrap_or(25).clamp(1, 100) as usize;
+    let target = resolve_target(client, &input).await?;
+    let release = target.as_ref().map(|t| t.version.as_str());
+    let bundles = client
+        .list_artifact_bundles(org, project, release)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let release_files = match release {
+        Some(version) => client
+            .list_release_files(org, project, version)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        None => Vec::new(),
+    };
+    let debug_id = non_empty(input.debug_id.as_deref().map(str::trim));
+    let debug_files = client
+        .list_debug_files(org, project, debug_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_debug_files_output(
+        project,
+        target.as_ref(),
+        &debug_files,
+        &bundles,
+        &release_files,
+        limit,
+    );
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
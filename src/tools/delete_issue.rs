@@ -0,0 +1,68 @@
+use crate::api_client::{Issue, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DeleteIssueInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+    #[schemars(
+        description = "The issue's short ID (e.g. 'PROJECT-123') repeated to confirm. Ask the user before deleting"
+    )]
+    pub confirm_short_id: String,
+}
+
+/// Refuses the deletion unless `confirm` names the issue that was looked up, so a
+/// mistyped or stale ID cannot delete the wrong issue.
+pub fn check_confirmation(issue: &Issue, confirm: &str) -> Result<(), McpError> {
+    if confirm.trim().eq_ignore_ascii_case(&issue.short_id) {
+        return Ok(());
+    }
+    Err(McpError::invalid_params(
+        format!(
+            "confirm_short_id must be {} to delete \"{}\" ({} events); nothing was deleted",
+            issue.short_id, issue.title, issue.count
+        ),
+        None,
+    ))
+}
+
+pub fn format_delete_output(issue: &Issue) -> String {
+    let mut output = String::new();
+    output.push_str("# Issue Deleted\n\n");
+    output.push_str(&format!(
+        "**Issue:** {} - {}\n",
+        issue.short_id, issue.title
+    ));
+    output.push_str(&format!("**Project:** {}\n", issue.project.slug));
+    output.push_str(&format!("**Events:** {}\n\n", issue.count));
+    output.push_str(
+        "Sentry removes the issue and its events in the background; this cannot be undone. \
+        New events with the same grouping create a new issue (use discard_issue to drop them \
+        instead).\n",
+    );
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: DeleteIssueInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let issue = client
+        .get_issue(org, &input.issue_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    check_confirmation(&issue, &input.confirm_short_id)?;
+    client
+        .delete_issue(org, &issue.id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_delete_output(&issue);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,49 @@
+use crate::api_client::{Issue, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DiscardIssueInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+}
+
+pub fn format_discard_output(issue: &Issue) -> String {
+    let mut output = String::new();
+    output.push_str("# Issue Discarded\n\n");
+    output.push_str(&format!(
+        "**Issue:** {} - {}\n",
+        issue.short_id, issue.title
+    ));
+    output.push_str(&format!("**Project:** {}\n", issue.project.slug));
+    output.push_str(&format!("**Events deleted:** {}\n\n", issue.count));
+    output.push_str(
+        "The issue and its events were deleted. New events with the same grouping \
+        hash are dropped before ingestion and do not count toward quota. It is listed \
+        by list_discarded_issues and can be restored from the project's Discarded \
+        Issues settings.\n",
+    );
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: DiscardIssueInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let issue = client
+        .get_issue(org, &input.issue_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    client
+        .discard_issue(org, &issue.project.slug, &issue.id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_discard_output(&issue);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
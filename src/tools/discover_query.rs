@@ -0,0 +1,206 @@
+use crate::api_client::{DiscoverQuery, DiscoverResult, SentryApi};
+use crate::format::escape_cell;
+use crate::tools::discover_saved_queries::format_cell;
+use crate::tools::list_environments::resolve_environment;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Most columns selected in one query.
+pub const MAX_FIELDS: usize = 20;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DiscoverQueryInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(
+        description = "Columns and aggregates to select, e.g. ['transaction', 'count()', 'p95(transaction.duration)']. Rows are grouped by the non-aggregate columns"
+    )]
+    pub fields: Vec<String>,
+    #[schemars(
+        description = "Search query, e.g. 'event.type:transaction http.method:POST' (optional)"
+    )]
+    pub query: Option<String>,
+    #[schemars(
+        description = "Column to sort by, prefixed with '-' for descending, e.g. '-count()'. Must be one of the selected fields"
+    )]
+    pub orderby: Option<String>,
+    #[schemars(description = "Relative time window, e.g. '1h', '24h', '14d' (default: 14d)")]
+    pub stats_period: Option<String>,
+    #[schemars(description = "Absolute start (ISO 8601); use with end instead of stats_period")]
+    pub start: Option<String>,
+    #[schemars(description = "Absolute end (ISO 8601)")]
+    pub end: Option<String>,
+    #[schemars(description = "Only query these project slugs (optional)")]
+    pub project_slugs: Option<Vec<String>>,
+    #[schemars(description = "Environment filter (optional)")]
+    pub environment: Option<String>,
+    #[schemars(
+        description = "Dataset: 'errors', 'transactions', 'spans' or 'discover' (default: discover)"
+    )]
+    pub dataset: Option<String>,
+    #[schemars(description = "Maximum number of rows (default: 50, max: 100)")]
+    pub limit: Option<i32>,
+}
+
+/// Checks the selected fields and that `orderby` sorts by one of them, as the
+/// events endpoint requires.
+pub fn validate_discover_input(input: &DiscoverQueryInput) -> Result<Vec<String>, McpError> {
+    let fields: Vec<String> = input
+        .fields
+        .iter()
+        .map(|f| f.trim().to_string())
+        .filter(|f| !f.is_empty())
+        .collect();
+    if fields.is_empty() {
+        return Err(McpError::invalid_params(
+            "fields must select at least one column".to_string(),
+            None,
+        ));
+    }
+    if fields.len() > MAX_FIELDS {
+        return Err(McpError::invalid_params(
+            format!("At most {} fields can be selected", MAX_FIELDS),
+            None,
+        ));
+    }
+    if let Some(orderby) = input.orderby.as_deref().map(str::trim)
+        && !orderby.is_empty()
+        && !fields.iter().any(|f| f == orderby.trim_start_matches('-'))
+    {
+        return Err(McpError::invalid_params(
+            format!(
+                "orderby '{}' must sort by a selected field: {}",
+                orderby,
+                fields.join(", ")
+            ),
+            None,
+        ));
+    }
+    if input.start.is_some() != input.end.is_some() {
+        return Err(McpError::invalid_params(
+            "start and end must be given together".to_string(),
+            None,
+        ));
+    }
+    Ok(fields)
+}
+
+fn format_duration_ms(ms: f64) -> String {
+    if ms >= 1000.0 {
+        format!("{:.2}s", ms / 1000.0)
+    } else {
+        format!("{:.1}ms", ms)
+    }
+}
+
+/// Formats a value using the column type and unit from the result's `meta`.
+pub fn format_typed_cell(value: &Value, field_type: Option<&str>, unit: Option<&str>) -> String {
+    let Some(number) = value.as_f64() else {
+        return format_cell(value);
+    };
+    match (field_type, unit) {
+        (Some("duration"), Some("nanosecond")) => format_duration_ms(number / 1_000_000.0),
+        (Some("duration"), Some("microsecond")) => format_duration_ms(number / 1000.0),
+        (Some("duration"), Some("second")) => format_duration_ms(number * 1000.0),
+        (Some("duration"), _) => format_duration_ms(number),
+        (Some("percentage"), _) => format!("{:.1}%", number * 100.0),
+        _ => format_cell(value),
+    }
+}
+
+pub fn format_discover_query_output(
+    fields: &[String],
+    input: &DiscoverQueryInput,
+    result: &DiscoverResult,
+) -> String {
+    let mut output = String::new();
+    output.push_str("# Discover Query\n\n");
+    if let Some(q) = input.query.as_deref().filter(|q| !q.is_empty()) {
+        output.push_str(&format!("**Query:** {}\n", q));
+    }
+    if let Some(orderby) = input.orderby.as_deref().filter(|o| !o.is_empty()) {
+        output.push_str(&format!("**Sort:** {}\n", orderby));
+    }
+    match (&input.start, &input.end) {
+        (Some(start), Some(end)) => output.push_str(&format!("**Period:** {} to {}\n", start, end)),
+        _ => output.push_str(&format!(
+            "**Period:** {}\n",
+            input.stats_period.as_deref().unwrap_or("14d")
+        )),
+    }
+    output.push_str(&format!("**Rows:** {}\n\n", result.data.len()));
+    if result.data.is_empty() {
+        output.push_str("No results.\n");
+        return output;
+    }
+    output.push_str(&format!(
+        "| {} |\n",
+        fields
+            .iter()
+            .map(|f| escape_cell(f))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    ));
+    output.push_str(&format!("|{}\n", "---|".repeat(fields.len())));
+    for row in &result.data {
+        let cells: Vec<String> = fields
+            .iter()
+            .map(|f| {
+                row.get(f)
+                    .map(|v| format_typed_cell(v, result.field_type(f), result.field_unit(f)))
+                    .unwrap_or_default()
+            })
+            .collect();
+        output.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: DiscoverQueryInput,
+) -> Result<CallToolResult, McpError> {
+    let fields = validate_discover_input(&input)?;
+    let org = &input.organization_slug;
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let mut projects = Vec::new();
+    for slug in input.project_slugs.iter().flatten() {
+        let project = client
+            .get_project(org, slug)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        projects.push(project.id);
+    }
+    let has_range = input.start.is_some() && input.end.is_some();
+    let query = DiscoverQuery {
+        fields: fields.clone(),
+        query: input.query.clone().filter(|q| !q.trim().is_empty()),
+        sort: input.orderby.clone().filter(|o| !o.trim().is_empty()),
+        stats_period: if has_range {
+            None
+        } else {
+            Some(
+                input
+                    .stats_period
+                    .clone()
+                    .unwrap_or_else(|| "14d".to_string()),
+            )
+        },
+        start: input.start.clone(),
+        end: input.end.clone(),
+        projects,
+        environments: environment.into_iter().collect(),
+        dataset: input.dataset.clone().filter(|d| !d.is_empty()),
+        per_page: Some(input.limit.unwrap_or(50).clamp(1, 100)),
+    };
+    let result = client
+        .query_events(org, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_discover_query_output(&fields, &input, &result);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,174 @@
+use crate::api_client::{DiscoverQuery, DiscoverResult, SavedQuery, SentryApi};
+use crate::format::escape_cell;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DiscoverSavedQueriesInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Saved query ID to execute. Omit to list saved queries.")]
+    pub query_id: Option<String>,
+    #[schemars(description = "Maximum number of result rows (default: 50, max: 100)")]
+    pub limit: Option<i32>,
+}
+
+pub fn format_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => escape_cell(s),
+        Value::Number(n) => match n.as_f64() {
+            Some(f) if n.is_f64() && f.fract() != 0.0 => format!("{:.2}", f),
+            _ => n.to_string(),
+        },
+        Value::Array(items) => escape_cell(
+            &items
+                .iter()
+                .map(|v| match v {
+                    Value::String(s) => s.clone(),
+                    _ => v.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        _ => escape_cell(&value.to_string()),
+    }
+}
+
+/// Renders Discover rows as a Markdown table with columns in `fields` order.
+pub fn format_discover_table(output: &mut String, fields: &[String], result: &DiscoverResult) {
+    if result.data.is_empty() {
+        output.push_str("No results.\n");
+        return;
+    }
+    output.push_str(&format!(
+        "| {} |\n",
+        fields
+            .iter()
+            .map(|f| escape_cell(f))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    ));
+    output.push_str(&format!("|{}\n", "---|".repeat(fields.len())));
+    for row in &result.data {
+        let cells: Vec<String> = fields
+            .iter()
+            .map(|f| row.get(f).map(format_cell).unwrap_or_default())
+            .collect();
+        output.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+}
+
+/// Maps a saved query's `queryDataset` to the events endpoint `dataset` parameter.
+pub fn dataset_param(query_dataset: Option<&str>) -> Option<String> {
+    match query_dataset? {
+        "error-events" => Some("errors".to_string()),
+        "transaction-like" => Some("transactions".to_string()),
+        _ => None,
+    }
+}
+
+/// Builds the events query a saved query describes.
+pub fn saved_to_discover(saved: &SavedQuery, limit: i32) -> DiscoverQuery {
+    let has_range = saved.start.is_some() && saved.end.is_some();
+    DiscoverQuery {
+        fields: saved.fields.clone(),
+        query: saved.query.clone().filter(|q| !q.is_empty()),
+        sort: saved.orderby.clone().filter(|o| !o.is_empty()),
+        stats_period: if has_range {
+            None
+        } else {
+            Some(saved.range.clone().unwrap_or_else(|| "14d".to_string()))
+        },
+        start: saved.start.clone().filter(|_| has_range),
+        end: saved.end.clone().filter(|_| has_range),
+        projects: saved.projects.iter().map(|p| p.to_string()).collect(),
+        environments: saved.environment.clone(),
+        dataset: dataset_param(saved.query_dataset.as_deref()),
+        per_page: Some(limit),
+    }
+}
+
+pub fn format_saved_queries_list(queries: &[SavedQuery]) -> String {
+    let mut output = String::new();
+    output.push_str("# Saved Discover Queries\n\n");
+    output.push_str(&format!("**Found:** {} queries\n\n", queries.len()));
+    if queries.is_empty() {
+        output.push_str("No saved queries found.\n");
+        return output;
+    }
+    output.push_str("| ID | Name | Dataset | Query | Fields |\n");
+    output.push_str("|---|---|---|---|---|\n");
+    for q in queries {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            q.id,
+            escape_cell(&q.name),
+            q.query_dataset.as_deref().unwrap_or("discover"),
+            escape_cell(q.query.as_deref().unwrap_or("")),
+            escape_cell(&q.fields.join(", "))
+        ));
+    }
+    output
+}
+
+pub fn format_saved_query_result(saved: &SavedQuery, result: &DiscoverResult) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Saved Query: {}\n\n", saved.name));
+    output.push_str(&format!("**ID:** {}\n", saved.id));
+    if let Some(q) = saved.query.as_deref().filter(|q| !q.is_empty()) {
+        output.push_str(&format!("**Query:** {}\n", q));
+    }
+    if let Some(orderby) = saved.orderby.as_deref().filter(|o| !o.is_empty()) {
+        output.push_str(&format!("**Sort:** {}\n", orderby));
+    }
+    match (&saved.start, &saved.end, &saved.range) {
+        (Some(start), Some(end), _) => {
+            output.push_str(&format!("**Period:** {} to {}\n", start, end))
+        }
+        (_, _, Some(range)) => output.push_str(&format!("**Period:** {}\n", range)),
+        _ => {}
+    }
+    output.push_str(&format!("**Rows:** {}\n\n", result.data.len()));
+    format_discover_table(&mut output, &saved.fields, result);
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: DiscoverSavedQueriesInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let output = match &input.query_id {
+        None => {
+            let queries = client
+                .list_saved_queries(org)
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            format_saved_queries_list(&queries)
+        }
+        Some(query_id) => {
+            let saved = client
+                .get_saved_query(org, query_id)
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            if saved.fields.is_empty() {
+                return Err(McpError::invalid_params(
+                    format!("Saved query {} has no fields to select", query_id),
+                    None,
+                ));
+            }
+            let limit = input.limit.unwrap_or(50).clamp(1, 100);
+            let result = client
+                .query_events(org, &saved_to_discover(&saved, limit))
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            format_saved_query_result(&saved, &result)
+        }
+    };
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,152 @@
+use crate::api_client::{Issue, IssuesQuery, SentryApi};
+use crate::format::escape_cell;
+use crate::json_ext::ValueExt;
+use crate::tools::cross_org::for_each_organization;
+use crate::tools::list_environments::resolve_environment;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct EscalatingIssuesInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug (omit to report across the organization)")]
+    pub project_slug: Option<String>,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+    #[schemars(description = "Maximum number of issues to return (default: 25, max: 100)")]
+    pub limit: Option<i32>,
+    #[schemars(
+        description = "Report across every organization the token can access, one section per organization (organization_slug is ignored; project_slug applies to each organization)"
+    )]
+    pub all_organizations: Option<bool>,
+}
+
+/// Sums the hourly buckets of the issue's 24h stats series.
+pub fn events_last_24h(issue: &Issue) -> Option<i64> {
+    let buckets = issue.stats.array_field("24h")?;
+    Some(
+        buckets
+            .iter()
+            .filter_map(|b| b.as_array().and_then(|pair| pair.get(1)?.as_i64()))
+            .sum(),
+    )
+}
+
+fn parse_forecast_date(value: &Value) -> Option<DateTime<Utc>> {
+    if let Some(ts) = value.as_f64() {
+        return DateTime::from_timestamp(ts as i64, 0);
+    }
+    let s = value.as_str()?;
+    DateTime::parse_from_rfc3339(s)
+        .map(|d| d.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+                .ok()
+                .map(|d| d.and_utc())
+        })
+}
+
+/// Returns the daily escalation threshold Sentry forecast for the day containing `now`.
+///
+/// Forecasts are a list of daily values starting at `date_added`; days past the end
+/// of the list reuse the last value.
+pub fn forecast_for_day(issue: &Issue, now: DateTime<Utc>) -> Option<i64> {
+    let forecast = issue.forecast.as_ref()?;
+    let data = forecast.array_field("data")?;
+    let added = forecast.get("date_added").and_then(parse_forecast_date)?;
+    let day = (now.date_naive() - added.date_naive()).num_days().max(0) as usize;
+    data.get(day.min(data.len().checked_sub(1)?))?.as_i64()
+}
+
+pub fn format_escalating_output(scope: &str, issues: &[Issue], now: DateTime<Utc>) -> String {
+    let mut output = String::new();
+    output.push_str("# Escalating Issues\n\n");
+    output.push_str(&format!("**Scope:** {}\n", scope));
+    output.push_str(&format!("**Found:** {} issues\n\n", issues.len()));
+    if issues.is_empty() {
+        output.push_str("No issues are currently escalating.\n");
+        return output;
+    }
+    output.push_str("| Issue | Title | Last 24h | Forecast Today | Users | Last Seen |\n");
+    output.push_str("|---|---|---|---|---|---|\n");
+    for issue in issues {
+        let recent = events_last_24h(issue)
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| "?".to_string());
+        let forecast = forecast_for_day(issue, now)
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            issue.short_id,
+            escape_cell(&issue.title),
+            recent,
+            forecast,
+            issue.user_count,
+            issue.last_seen.as_deref().unwrap_or("-")
+        ));
+    }
+    output.push_str(
+        "\nAn issue escalates when its daily event count exceeds the forecast threshold.\n",
+    );
+    output
+}
+
+async fn escalating_in_org(
+    client: &impl SentryApi,
+    org_slug: &str,
+    input: &EscalatingIssuesInput,
+) -> Result<String, McpError> {
+    let limit = input.limit.unwrap_or(25).min(100);
+    let project_id = match &input.project_slug {
+        Some(slug) => Some(
+            client
+                .get_project(org_slug, slug)
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?
+                .id,
+        ),
+        None => None,
+    };
+    let environment = resolve_environment(client, org_slug, input.environment.as_deref()).await?;
+    let query = IssuesQuery {
+        query: Some("is:escalating".to_string()),
+        project: project_id,
+        environment,
+        sort: Some("freq".to_string()),
+        limit: Some(limit),
+        expand: Some("forecast".to_string()),
+        ..Default::default()
+    };
+    let issues = client
+        .list_issues(org_slug, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let scope = input.project_slug.as_deref().unwrap_or(org_slug);
+    Ok(format_escalating_output(scope, &issues, Utc::now()))
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: EscalatingIssuesInput,
+) -> Result<CallToolResult, McpError> {
+    let output = if input.all_organizations.unwrap_or(false) {
+        let input = &input;
+        for_each_organization(client, |org| async move {
+            escalating_in_org(client, &org, input).await
+        })
+        .await?
+    } else {
+        escalating_in_org(client, &input.organization_slug, &input).await?
+    };
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
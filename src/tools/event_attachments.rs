@@ -0,0 +1,291 @@
+use crate::api_client::{EventAttachment, SentryApi};
+use base64::Engine;
+use rmcp::{
+    ErrorData as McpError,
+    model::{CallToolResult, Content, RawResource, ReadResourceResult, ResourceContents},
+};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Scheme of the MCP resources attachments are exposed as.
+pub const ATTACHMENT_SCHEME: &str = "sentry-attachment";
+/// Text attachments up to this size are shown inline when downloading.
+const MAX_INLINE_BYTES: u64 = 64 * 1024;
+/// Attachments larger than this are not served as resources either.
+const MAX_RESOURCE_BYTES: u64 = 10 * 1024 * 1024;
+/// Leading bytes checked for NUL when telling text from binary.
+const BINARY_SNIFF_BYTES: usize = 8192;
+const TEXT_EXTENSIONS: [&str; 10] = [
+    "txt", "log", "json", "xml", "csv", "yaml", "yml", "md", "ini", "html",
+];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct EventAttachmentsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(description = "Event ID the attachments belong to")]
+    pub event_id: String,
+    #[schemars(
+        description = "Show text attachments up to 64 KB inline (default: false); larger or binary ones are returned as resource links"
+    )]
+    pub download: Option<bool>,
+    #[schemars(description = "Only this attachment (optional)")]
+    pub attachment_id: Option<String>,
+}
+
+/// Where an attachment lives, as encoded in its resource URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachmentRef {
+    pub organization_slug: String,
+    pub project_slug: String,
+    pub event_id: String,
+    pub attachment_id: String,
+}
+
+/// `sentry-attachment://{org}/{project}/{event_id}/{attachment_id}`.
+pub fn attachment_uri(org: &str, project: &str, event_id: &str, attachment_id: &str) -> String {
+    format!(
+        "{}://{}/{}/{}/{}",
+        ATTACHMENT_SCHEME, org, project, event_id, attachment_id
+    )
+}
+
+pub fn parse_attachment_uri(uri: &str) -> Option<AttachmentRef> {
+    let rest = uri.strip_prefix(ATTACHMENT_SCHEME)?.strip_prefix("://")?;
+    let parts: Vec<&str> = rest.trim_end_matches('/').split('/').collect();
+    match parts.as_slice() {
+        [org, project, event_id, attachment_id] if parts.iter().all(|p| !p.is_empty()) => {
+            Some(AttachmentRef {
+                organization_slug: org.to_string(),
+                project_slug: project.to_string(),
+                event_id: event_id.to_string(),
+                attachment_id: attachment_id.to_string(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Whether the attachment is text by its MIME type or, failing that, its extension.
+pub fn is_text_attachment(attachment: &EventAttachment) -> bool {
+    if attachment.attachment_type == "event.minidump" {
+        return false;
+    }
+    if let Some(mime) = attachment.mimetype.as_deref().map(str::to_ascii_lowercase) {
+        if mime.starts_with("text/")
+            || mime.ends_with("json")
+            || mime.ends_with("xml")
+            || mime.ends_with("yaml")
+        {
+            return true;
+        }
+        if mime != "application/octet-stream" {
+            return false;
+        }
+    }
+    attachment
+        .name
+        .rsplit_once('.')
+        .is_some_and(|(_, ext)| TEXT_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
+/// A NUL byte near the start means the content is not text.
+pub fn looks_binary(bytes: &[u8]) -> bool {
+    bytes.iter().take(BINARY_SNIFF_BYTES).any(|b| *b == 0)
+}
+
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        b if b < 1024 => format!("{} B", b),
+        b if b < 1024 * 1024 => format!("{:.1} KB", b as f64 / 1024.0),
+        b => format!("{:.1} MB", b as f64 / (1024.0 * 1024.0)),
+    }
+}
+
+/// Fence that cannot be closed by a run of backticks inside `text`.
+fn fence_for(text: &str) -> String {
+    let longest = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    "`".repeat(longest.max(2) + 1)
+}
+
+pub fn format_attachments_output(
+    event_id: &str,
+    attachments: &[EventAttachment],
+    inlined: &[(&EventAttachment, String)],
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Attachments of Event {}\n\n", event_id));
+    output.push_str(&format!("**Found:** {} attachments\n\n", attachments.len()));
+    if attachments.is_empty() {
+        output.push_str("No attachments on this event.\n");
+        return output;
+    }
+    output.push_str("| ID | Name | Type | MIME | Size |\n");
+    output.push_str("|---|---|---|---|---|\n");
+    for attachment in attachments {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            attachment.id,
+            attachment.name,
+            attachment.attachment_type,
+            attachment.mimetype.as_deref().unwrap_or(""),
+            format_size(attachment.size)
+        ));
+    }
+    for (attachment, text) in inlined {
+        let fence = fence_for(text);
+        output.push_str(&format!("\n## {}\n\n", attachment.name));
+        output.push_str(&format!("{}\n{}", fence, text));
+        if !text.ends_with('\n') {
+            output.push('\n');
+        }
+        output.push_str(&format!("{}\n", fence));
+    }
+    let linked = attachments.len() - inlined.len();
+    if linked > 0 {
+        output.push_str(&format!(
+            "\n{} attachments are returned as `{}://` resource links; read them with the MCP resources API.\n",
+            linked, ATTACHMENT_SCHEME
+        ));
+    }
+    output
+}
+
+fn resource_link(input: &EventAttachmentsInput, attachment: &EventAttachment) -> Content {
+    Content::resource_link(RawResource {
+        uri: attachment_uri(
+            &input.organization_slug,
+            &input.project_slug,
+            &input.event_id,
+            &attachment.id,
+        ),
+        name: attachment.name.clone(),
+        title: None,
+        description: Some(attachment.attachment_type.clone()),
+        mime_type: attachment.mimetype.clone(),
+        size: u32::try_from(attachment.size).ok(),
+        icons: None,
+        meta: None,
+    })
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: EventAttachmentsInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let mut attachments = client
+        .list_event_attachments(org, &input.project_slug, &input.event_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    if let Some(id) = input.attachment_id.as_deref().map(str::trim) {
+        attachments.retain(|a| a.id == id);
+        if attachments.is_empty() {
+            return Err(McpError::invalid_params(
+                format!("Attachment {} not found on this event", id),
+                None,
+            ));
+        }
+    }
+    let mut inlined: Vec<(&EventAttachment, String)> = Vec::new();
+    if input.download.unwrap_or(false) {
+        for attachment in &attachments {
+            if !is_text_attachment(attachment) || attachment.size > MAX_INLINE_BYTES {
+                continue;
+            }
+            let bytes = client
+                .download_event_attachment(
+                    org,
+                    &input.project_slug,
+                    &input.event_id,
+                    &attachment.id,
+                )
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            if let (false, Ok(text)) = (looks_binary(&bytes), String::from_utf8(bytes)) {
+                inlined.push((attachment, text));
+            }
+        }
+    }
+    let mut content = vec![Content::text(format_attachments_output(
+        &input.event_id,
+        &attachments,
+        &inlined,
+    ))];
+    for attachment in &attachments {
+        if !inlined.iter().any(|(a, _)| a.id == attachment.id) {
+            content.push(resource_link(&input, attachment));
+        }
+    }
+    Ok(CallToolResult::success(content))
+}
+
+/// Serves a `sentry-attachment://` resource: text as-is, anything else base64-encoded.
+pub async fn read_resource(
+    client: &impl SentryApi,
+    uri: &str,
+) -> Result<ReadResourceResult, McpError> {
+    let target = parse_attachment_uri(uri).ok_or_else(|| {
+        McpError::resource_not_found(format!("Unknown resource URI: {}", uri), None)
+    })?;
+    let attachments = client
+        .list_event_attachments(
+            &target.organization_slug,
+            &target.project_slug,
+            &target.event_id,
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let attachment = attachments
+        .iter()
+        .find(|a| a.id == target.attachment_id)
+        .ok_or_else(|| {
+            McpError::resource_not_found(format!("Attachment not found: {}", uri), None)
+        })?;
+    if attachment.size > MAX_RESOURCE_BYTES {
+        return Err(McpError::invalid_params(
+            format!(
+                "Attachment {} is {} bytes; attachments over {} bytes are not downloaded",
+                attachment.name, attachment.size, MAX_RESOURCE_BYTES
+            ),
+            None,
+        ));
+    }
+    let bytes = client
+        .download_event_attachment(
+            &target.organization_slug,
+            &target.project_slug,
+            &target.event_id,
+            &target.attachment_id,
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let mime_type = attachment.mimetype.clone();
+    let contents = if looks_binary(&bytes) {
+        blob(uri, mime_type, &bytes)
+    } else {
+        match String::from_utf8(bytes) {
+            Ok(text) => ResourceContents::TextResourceContents {
+                uri: uri.to_string(),
+                mime_type: mime_type.or_else(|| Some("text/plain".to_string())),
+                text,
+                meta: None,
+            },
+            Err(e) => blob(uri, mime_type, e.as_bytes()),
+        }
+    };
+    Ok(ReadResourceResult {
+        contents: vec![contents],
+    })
+}
+
+fn blob(uri: &str, mime_type: Option<String>, bytes: &[u8]) -> ResourceContents {
+    ResourceContents::BlobResourceContents {
+        uri: uri.to_string(),
+        mime_type: mime_type.or_else(|| Some("application/octet-stream".to_string())),
+        blob: base64::engine::general_purpose::STANDARD.encode(bytes),
+        meta: None,
+    }
+}
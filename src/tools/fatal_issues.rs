@@ -0,0 +1,146 @@
+use crate::api_client::{Event, Issue, IssuesQuery, SentryApi};
+use crate::json_ext::ValueExt;
+use crate::tools::cluster_issue_events::primary_exception;
+use crate::tools::get_issue_details::{
+    StackStyle, context_line_at, format_frame_location, is_in_app,
+};
+use crate::tools::list_environments::resolve_environment;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct FatalIssuesInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug (omit to check the whole organization)")]
+    pub project_slug: Option<String>,
+    #[schemars(description = "Only issues seen in the last N hours (default: 24, max: 336)")]
+    pub hours: Option<u32>,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+    #[schemars(description = "Maximum number of issues to return (default: 10, max: 25)")]
+    pub limit: Option<i32>,
+}
+
+/// Search query for fatal issues seen within the last `hours`.
+pub fn fatal_query(hours: u32) -> String {
+    format!("level:fatal lastSeen:-{}h", hours)
+}
+
+/// The innermost in-app frame of the raised exception, or its innermost frame
+/// when none is in-app.
+pub fn crash_frame<'a>(event: &'a Event, in_app_include: &[String]) -> Option<&'a Value> {
+    let frames = primary_exception(event)?
+        .get("stacktrace")?
+        .array_field("frames")?;
+    frames
+        .iter()
+        .rev()
+        .find(|f| is_in_app(f, in_app_include))
+        .or_else(|| frames.last())
+}
+
+/// The exception and its crash frame with the source line, as a code block.
+pub fn format_crash(output: &mut String, event: &Event, in_app_include: &[String]) {
+    if let Some(exc) = primary_exception(event) {
+        output.push_str(&format!(
+            "**Exception:** {}: {}\n",
+            exc.str_field("type").unwrap_or("Error"),
+            exc.str_field("value").unwrap_or("")
+        ));
+    }
+    let Some(frame) = crash_frame(event, in_app_include) else {
+        output.push_str("No stack trace on the latest event.\n");
+        return;
+    };
+    let style = StackStyle::for_platform(event.platform.as_deref());
+    output.push_str(&format!("```\n{}\n", format_frame_location(frame, style)));
+    let line = context_line_at(frame, frame.i64_field("lineNo").unwrap_or(0)).trim();
+    if !line.is_empty() {
+        output.push_str(&format!("    {}\n", line));
+    }
+    output.push_str("```\n");
+}
+
+pub fn format_fatal_issues(
+    scope: &str,
+    hours: u32,
+    issues: &[(Issue, Option<Event>)],
+    in_app_include: &[String],
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Fatal Issues: {}\n\n", scope));
+    output.push_str(&format!("**Window:** last {}h\n", hours));
+    output.push_str(&format!("**Found:** {} issues\n\n", issues.len()));
+    if issues.is_empty() {
+        output.push_str(&format!(
+            "No fatal issues seen in the last {} hours.\n",
+            hours
+        ));
+        return output;
+    }
+    for (issue, event) in issues {
+        output.push_str(&format!("## {}: {}\n\n", issue.short_id, issue.title));
+        output.push_str(&format!(
+            "**Status:** {} | **Events:** {} | **Users:** {} | **Last Seen:** {}\n",
+            issue.substatus.as_deref().unwrap_or(&issue.status),
+            issue.count,
+            issue.user_count,
+            issue.last_seen.as_deref().unwrap_or("-")
+        ));
+        match event {
+            Some(event) => format_crash(&mut output, event, in_app_include),
+            None => output.push_str("Latest event unavailable.\n"),
+        }
+        output.push('\n');
+    }
+    output.push_str("Use get_issue_details for the full stack trace of an issue.\n");
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: FatalIssuesInput,
+    in_app_include: &[String],
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let hours = input.hours.unwrap_or(24).clamp(1, 336);
+    let project_id = match &input.project_slug {
+        Some(slug) => Some(
+            client
+                .get_project(org, slug)
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?
+                .id,
+        ),
+        None => None,
+    };
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let query = IssuesQuery {
+        query: Some(fatal_query(hours)),
+        project: project_id,
+        environment,
+        stats_period: Some(format!("{}h", hours)),
+        sort: Some("date".to_string()),
+        limit: Some(input.limit.unwrap_or(10).clamp(1, 25)),
+        ..Default::default()
+    };
+    let issues = client
+        .list_issues(org, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let mut with_events = Vec::new();
+    for issue in issues {
+        let event = client.get_latest_event(org, &issue.id).await.ok();
+        with_events.push((issue, event));
+    }
+    let scope = input.project_slug.as_deref().unwrap_or(org);
+    let output = format_fatal_issues(scope, hours, &with_events, in_app_include);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,250 @@
+use crate::api_client::{DataScrubbing, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Key fragments scrubbed when "use default scrubbers" is on (matched case-insensitively
+/// as substrings of the key, as Relay does).
+pub const DEFAULT_SENSITIVE_KEYS: &[&str] = &[
+    "password",
+    "secret",
+    "passwd",
+    "api_key",
+    "apikey",
+    "auth",
+    "credentials",
+    "mysql_pwd",
+    "privatekey",
+    "private_key",
+    "token",
+    "session",
+];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetDataScrubbingInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(
+        description = "Variable or key name to check, e.g. 'api_token', to explain why it shows [Filtered]"
+    )]
+    pub field: Option<String>,
+}
+
+fn union(a: &[String], b: &[String]) -> Vec<String> {
+    let mut merged: Vec<String> = Vec::new();
+    for field in a.iter().chain(b) {
+        if !merged.iter().any(|f| f.eq_ignore_ascii_case(field)) {
+            merged.push(field.clone());
+        }
+    }
+    merged
+}
+
+/// Settings in effect for the project: organization switches are enforced on every
+/// project and organization field lists are added to the project's.
+pub fn effective_settings(org: &DataScrubbing, project: &DataScrubbing) -> DataScrubbing {
+    DataScrubbing {
+        data_scrubber: org.data_scrubber || project.data_scrubber,
+        data_scrubber_defaults: org.data_scrubber_defaults || project.data_scrubber_defaults,
+        scrub_ip_addresses: org.scrub_ip_addresses || project.scrub_ip_addresses,
+        sensitive_fields: union(&org.sensitive_fields, &project.sensitive_fields),
+        safe_fields: union(&org.safe_fields, &project.safe_fields),
+        relay_pii_config: project.relay_pii_config.clone(),
+        store_crash_reports: project.store_crash_reports.or(org.store_crash_reports),
+    }
+}
+
+/// Advanced scrubbing rules as `selector → rule` lines. Built-in rules such as
+/// `@password:remove` are shown as is; custom rules by type and redaction method.
+pub fn describe_advanced_rules(config: &str) -> Vec<String> {
+    let Ok(config) = serde_json::from_str::<Value>(config) else {
+        return vec![];
+    };
+    let rules = config.get("rules");
+    let Some(applications) = config.get("applications").and_then(Value::as_object) else {
+        return vec![];
+    };
+    let mut lines = Vec::new();
+    for (selector, rule_ids) in applications {
+        for rule_id in rule_ids.as_array().into_iter().flatten() {
+            let Some(rule_id) = rule_id.as_str() else {
+                continue;
+            };
+            let rule = match rules.and_then(|r| r.get(rule_id)) {
+                Some(rule) => {
+                    let kind = rule.get("type").and_then(Value::as_str).unwrap_or("rule");
+                    let method = rule
+                        .get("redaction")
+                        .and_then(|r| r.get("method"))
+                        .and_then(Value::as_str)
+                        .unwrap_or("default");
+                    match rule.get("pattern").and_then(Value::as_str) {
+                        Some(pattern) => format!("{} `{}` ({})", kind, pattern, method),
+                        None => format!("{} ({})", kind, method),
+                    }
+                }
+                None => rule_id.to_string(),
+            };
+            lines.push(format!("`{}` → {}", selector, rule));
+        }
+    }
+    lines
+}
+
+fn find_fragment<'a>(field: &str, fragments: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let key = field.to_lowercase();
+    fragments
+        .into_iter()
+        .find(|fragment| !fragment.is_empty() && key.contains(&fragment.to_lowercase()))
+}
+
+/// Why the data scrubber would (or would not) replace `field`'s value with `[Filtered]`.
+pub fn field_verdict(field: &str, settings: &DataScrubbing) -> String {
+    if !settings.data_scrubber {
+        return format!(
+            "`{}` is not filtered by key: the data scrubber is off. Advanced rules below may still apply.",
+            field
+        );
+    }
+    if settings
+        .safe_fields
+        .iter()
+        .any(|safe| safe.eq_ignore_ascii_case(field))
+    {
+        return format!("`{}` is a safe field and is never scrubbed by key.", field);
+    }
+    if let Some(fragment) =
+        find_fragment(field, settings.sensitive_fields.iter().map(String::as_str))
+    {
+        return format!(
+            "`{}` is filtered: it contains the additional sensitive field `{}`.",
+            field, fragment
+        );
+    }
+    if settings.data_scrubber_defaults
+        && let Some(fragment) = find_fragment(field, DEFAULT_SENSITIVE_KEYS.iter().copied())
+    {
+        return format!(
+            "`{}` is filtered: it contains the default sensitive key `{}`. Add it to safe fields to keep it.",
+            field, fragment
+        );
+    }
+    format!(
+        "`{}` does not match any sensitive key. If it shows [Filtered], its value looked like a credit card number or password, an advanced rule matched, or the SDK removed it before sending.",
+        field
+    )
+}
+
+fn on_off(enabled: bool) -> &'static str {
+    if enabled { "on" } else { "off" }
+}
+
+fn push_setting(output: &mut String, label: &str, enabled: bool, org_enabled: bool) {
+    output.push_str(&format!("- **{}:** {}", label, on_off(enabled)));
+    if org_enabled {
+        output.push_str(" (enforced by organization)");
+    }
+    output.push('\n');
+}
+
+fn push_list(output: &mut String, label: &str, fields: &[String]) {
+    if fields.is_empty() {
+        output.push_str(&format!("- **{}:** none\n", label));
+    } else {
+        output.push_str(&format!("- **{}:** {}\n", label, fields.join(", ")));
+    }
+}
+
+pub fn format_data_scrubbing_output(
+    project_slug: &str,
+    org: &DataScrubbing,
+    project: &DataScrubbing,
+    field: Option<&str>,
+) -> String {
+    let settings = effective_settings(org, project);
+    let mut output = String::new();
+    output.push_str(&format!("# Data Scrubbing for {}\n\n", project_slug));
+    push_setting(
+        &mut output,
+        "Data scrubber",
+        settings.data_scrubber,
+        org.data_scrubber,
+    );
+    push_setting(
+        &mut output,
+        "Default sensitive keys",
+        settings.data_scrubber_defaults,
+        org.data_scrubber_defaults,
+    );
+    push_setting(
+        &mut output,
+        "Scrub IP addresses",
+        settings.scrub_ip_addresses,
+        org.scrub_ip_addresses,
+    );
+    push_list(
+        &mut output,
+        "Additional sensitive fields",
+        &settings.sensitive_fields,
+    );
+    push_list(&mut output, "Safe fields", &settings.safe_fields);
+    match settings.store_crash_reports {
+        Some(0) | None => output.push_str("- **Stored crash reports:** none\n"),
+        Some(-1) => output.push_str("- **Stored crash reports:** unlimited\n"),
+        Some(n) => output.push_str(&format!("- **Stored crash reports:** {} per issue\n", n)),
+    }
+    let mut rules = Vec::new();
+    for (scope, config) in [
+        ("organization", &org.relay_pii_config),
+        ("project", &project.relay_pii_config),
+    ] {
+        if let Some(config) = config {
+            rules.extend(
+                describe_advanced_rules(config)
+                    .into_iter()
+                    .map(|rule| format!("{} ({})", rule, scope)),
+            );
+        }
+    }
+    output.push_str("\n## Advanced Scrubbing Rules\n\n");
+    if rules.is_empty() {
+        output.push_str("None.\n");
+    }
+    for rule in &rules {
+        output.push_str(&format!("- {}\n", rule));
+    }
+    if let Some(field) = field.map(str::trim).filter(|f| !f.is_empty()) {
+        output.push_str(&format!(
+            "\n## Field Check\n\n{}\n",
+            field_verdict(field, &settings)
+        ));
+    }
+    output.push_str(
+        "\nScrubbing happens before events are stored, so Sentry keeps no unscrubbed copy. \
+        Values removed by the SDK (e.g. `send_default_pii` off or `before_send`) never reach \
+        Sentry. Stored crash reports and attachments are not scrubbed and may contain raw data.\n",
+    );
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: GetDataScrubbingInput,
+) -> Result<CallToolResult, McpError> {
+    let org = client
+        .get_org_data_scrubbing(&input.organization_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let project = client
+        .get_project_data_scrubbing(&input.organization_slug, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output =
+        format_data_scrubbing_output(&input.project_slug, &org, &project, input.field.as_deref());
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
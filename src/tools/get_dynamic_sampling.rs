@@ -0,0 +1,156 @@
+use crate::api_client::{OrgSampling, OrgStats, OrgStatsQuery, ProjectSampling, SentryApi};
+use crate::tools::sampling_report::{SamplingRow, sampling_rows};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Project option holding the target sample rate when the organization samples per project.
+const PROJECT_TARGET_RATE_OPTION: &str = "sentry:target_sample_rate";
+/// Categories dynamic sampling applies to; `*_indexed` counts the stored samples.
+const SAMPLED_CATEGORIES: &[&str] = &["transaction", "transaction_indexed", "span", "span_indexed"];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetDynamicSamplingInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(
+        description = "Window for the retained/dropped ratio, e.g. '1h', '24h', '7d' (default: 24h)"
+    )]
+    pub stats_period: Option<String>,
+}
+
+/// What a dynamic sampling bias does, as labelled in Sentry's settings.
+pub fn describe_bias(id: &str) -> &str {
+    match id {
+        "boostEnvironments" => "Prioritize dev environments",
+        "boostLatestRelease" => "Prioritize new releases",
+        "ignoreHealthChecks" => "Deprioritize health checks",
+        "boostKeyTransactions" => "Prioritize key transactions",
+        "boostLowVolumeTransactions" => "Prioritize low-volume transactions",
+        "boostLowVolumeProjects" => "Prioritize low-volume projects",
+        "boostReplayId" => "Prioritize transactions with replays",
+        "minimumSampleRate" => "Always keep a minimum sample rate",
+        other => other,
+    }
+}
+
+/// The target sample rate in effect for the project, and where it is configured.
+pub fn target_rate(org: &OrgSampling, project: &ProjectSampling) -> Option<(f64, &'static str)> {
+    if org.sampling_mode.as_deref() == Some("project") {
+        let rate = project
+            .options
+            .get(PROJECT_TARGET_RATE_OPTION)
+            .and_then(|v| v.as_f64().or_else(|| v.as_str()?.parse().ok()))?;
+        return Some((rate, "project"));
+    }
+    org.target_sample_rate.map(|rate| (rate, "organization"))
+}
+
+/// Sampling rows for the categories dynamic sampling applies to.
+pub fn dynamic_sampling_rows(stats: &OrgStats) -> Vec<SamplingRow> {
+    sampling_rows(stats)
+        .into_iter()
+        .filter(|row| SAMPLED_CATEGORIES.contains(&row.category.as_str()))
+        .collect()
+}
+
+pub fn format_dynamic_sampling_output(
+    project_slug: &str,
+    period: &str,
+    org: &OrgSampling,
+    project: &ProjectSampling,
+    stats: &OrgStats,
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Dynamic Sampling: {}\n\n", project_slug));
+    if let Some(mode) = &org.sampling_mode {
+        output.push_str(&format!("**Mode:** {}\n", mode));
+    }
+    match target_rate(org, project) {
+        Some((rate, scope)) => output.push_str(&format!(
+            "**Target sample rate:** {:.1}% (set on the {})\n",
+            rate * 100.0,
+            scope
+        )),
+        None => output.push_str("**Target sample rate:** not reported\n"),
+    }
+    output.push_str("\n## Biases\n\n");
+    if project.dynamic_sampling_biases.is_empty() {
+        output.push_str("No biases reported for this project.\n");
+    }
+    for bias in &project.dynamic_sampling_biases {
+        output.push_str(&format!(
+            "- {} `{}`: {}\n",
+            if bias.active { "[on]" } else { "[off]" },
+            bias.id,
+            describe_bias(&bias.id)
+        ));
+    }
+    output.push_str(&format!("\n## Retained vs Dropped (last {})\n\n", period));
+    let rows = dynamic_sampling_rows(stats);
+    if rows.is_empty() {
+        output.push_str(
+            "Dynamic sampling dropped no transactions or spans in this period, so gaps in performance data reflect real traffic or client-side sampling.\n",
+        );
+        return output;
+    }
+    output.push_str("| Category | Retained | Dropped by sampling | Retained share |\n");
+    output.push_str("|---|---|---|---|\n");
+    for row in &rows {
+        output.push_str(&format!(
+            "| {} | {:.0} | {:.0} | {} |\n",
+            row.category,
+            row.accepted,
+            row.dynamically_sampled,
+            row.dynamic_rate()
+                .map(|rate| format!("{:.1}%", rate * 100.0))
+                .unwrap_or_else(|| "-".to_string())
+        ));
+    }
+    output.push_str(
+        "\nDropped events were received and counted in metrics but not stored, so they are missing from event and trace searches while totals and rates stay accurate.\n",
+    );
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: GetDynamicSamplingInput,
+) -> Result<CallToolResult, McpError> {
+    let org_slug = &input.organization_slug;
+    let period = input.stats_period.as_deref().unwrap_or("24h");
+    let project = client
+        .get_project(org_slug, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let project_settings = client
+        .get_project_sampling(org_slug, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let org_settings = client
+        .get_org_sampling(org_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let query = OrgStatsQuery {
+        projects: vec![project.id],
+        categories: vec![],
+        stats_period: Some(period.to_string()),
+        interval: Some("1h".to_string()),
+    };
+    let stats = client
+        .get_org_stats(org_slug, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_dynamic_sampling_output(
+        &input.project_slug,
+        period,
+        &org_settings,
+        &project_settings,
+        &stats,
+    );
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
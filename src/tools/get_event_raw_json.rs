@@ -0,0 +1,130 @@
+use crate::api_client::SentryApi;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetEventRawJsonInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(
+        description = "Issue ID like 'PROJECT-123' or a numeric ID; its project is used and, without event_id, its latest event"
+    )]
+    pub issue_id: Option<String>,
+    #[schemars(description = "Project slug (required with event_id when issue_id is omitted)")]
+    pub project_slug: Option<String>,
+    #[schemars(description = "Event ID (default: the issue's latest event)")]
+    pub event_id: Option<String>,
+    #[schemars(
+        description = "Only these top-level keys, e.g. ['breadcrumbs', 'request', 'spans'] (default: the whole payload)"
+    )]
+    pub keys: Option<Vec<String>>,
+}
+
+/// The payload with only `keys` kept, and the requested keys it does not have.
+pub fn select_keys(payload: &Value, keys: &[String]) -> (Value, Vec<String>) {
+    let Some(object) = payload.as_object() else {
+        return (payload.clone(), Vec::new());
+    };
+    let mut selected = Map::new();
+    let mut missing = Vec::new();
+    for key in keys.iter().map(|k| k.trim()).filter(|k| !k.is_empty()) {
+        match object.get(key) {
+            Some(value) => {
+                selected.insert(key.to_string(), value.clone());
+            }
+            None if !missing.iter().any(|m| m == key) => missing.push(key.to_string()),
+            None => {}
+        }
+    }
+    (Value::Object(selected), missing)
+}
+
+pub fn format_raw_event(
+    event_id: &str,
+    project: &str,
+    payload: &Value,
+    missing: &[String],
+    available: &[String],
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Raw Event: {}\n\n", event_id));
+    output.push_str(&format!("**Project:** {}\n", project));
+    if !missing.is_empty() {
+        output.push_str(&format!("**Missing Keys:** {}\n", missing.join(", ")));
+        output.push_str(&format!("**Available Keys:** {}\n", available.join(", ")));
+    }
+    output.push_str(&format!(
+        "\n```json\n{}\n```\n",
+        serde_json::to_string_pretty(payload).unwrap_or_default()
+    ));
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: GetEventRawJsonInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let issue_id = input
+        .issue_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|i| !i.is_empty());
+    let event_id = input
+        .event_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|e| !e.is_empty());
+    let project_slug = input
+        .project_slug
+        .as_deref()
+        .map(str::trim)
+        .filter(|p| !p.is_empty());
+    let (project, event_id) = match (issue_id, project_slug, event_id) {
+        (_, Some(project), Some(event_id)) => (project.to_string(), event_id.to_string()),
+        (Some(issue_id), _, event_id) => {
+            let issue = client
+                .get_issue(org, issue_id)
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            let event_id = match event_id {
+                Some(event_id) => event_id.to_string(),
+                None => {
+                    client
+                        .get_latest_event(org, issue_id)
+                        .await
+                        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+                        .event_id
+                }
+            };
+            (issue.project.slug, event_id)
+        }
+        _ => {
+            return Err(McpError::invalid_params(
+                "Provide issue_id, or project_slug and event_id".to_string(),
+                None,
+            ));
+        }
+    };
+    let payload = client
+        .get_event_json(org, &project, &event_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = match input.keys.as_deref().filter(|k| !k.is_empty()) {
+        Some(keys) => {
+            let (selected, missing) = select_keys(&payload, keys);
+            let mut available: Vec<String> = payload
+                .as_object()
+                .map(|o| o.keys().cloned().collect())
+                .unwrap_or_default();
+            available.sort();
+            format_raw_event(&event_id, &project, &selected, &missing, &available)
+        }
+        None => format_raw_event(&event_id, &project, &payload, &[], &[]),
+    };
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
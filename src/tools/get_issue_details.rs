@@ -1,5 +1,11 @@
-use crate::api_client::SentryApi;
+use crate::api_client::{
+    Commit, Committer, Event, IssueAnnotation, IssueUpdate, Project, SentryApi,
+};
+use crate::format::{DEFAULT_MAX_TOKENS, estimate_tokens, field, opt_field, truncate_chars};
 use crate::json_ext::ValueExt;
+use crate::tools::repo_paths::apply_code_mappings;
+use crate::tools::source_context::fill_missing_context;
+use crate::tools::stacktrace_link::link_relevant_frames;
 use regex::Regex;
 use rmcp::{ErrorData as McpError, model::CallToolResult};
 use schemars::JsonSchema;
@@ -7,15 +13,237 @@ use serde::Deserialize;
 use serde_json::Value;
 use std::sync::LazyLock;
 
-pub fn format_frame_detail(output: &mut String, frame: &Value) {
-    let filename = frame.str_field("filename").unwrap_or("?");
+/// Default nesting depth expanded when rendering local variables.
+pub const DEFAULT_VAR_DEPTH: usize = 2;
+/// Default length at which a rendered variable value is cut.
+pub const DEFAULT_VAR_MAX_CHARS: usize = 60;
+/// Entries of a collection shown before the rest is summarized.
+const MAX_VAR_ENTRIES: usize = 10;
+
+/// Layout of stack frame lines, following each platform's native traceback format.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StackStyle {
+    /// `File "app.py", line 12, in handler`
+    #[default]
+    Python,
+    /// `at com.acme.Service.handle(Service.java:12)`
+    Java,
+    /// `at handler (src/app.js:12:5)`
+    Node,
+    /// `handler at src/main.rs:12`
+    Native,
+}
+
+impl StackStyle {
+    pub fn for_platform(platform: Option<&str>) -> Self {
+        match platform.unwrap_or_default() {
+            "java" | "kotlin" | "scala" | "groovy" | "clojure" | "android" => Self::Java,
+            "javascript" | "node" => Self::Node,
+            "native" | "c" | "cocoa" | "objc" | "swift" | "rust" | "go" | "elixir" => Self::Native,
+            _ => Self::Python,
+        }
+    }
+}
+
+/// One-line location of a frame in the given style.
+pub fn format_frame_location(frame: &Value, style: StackStyle) -> String {
+    let filename = frame
+        .str_field("filename")
+        .or_else(|| frame.str_field("absPath"))
+        .unwrap_or("?");
     let lineno = frame.i64_field("lineNo").unwrap_or(0);
     let func = frame.str_field("function").unwrap_or("?");
+    match style {
+        StackStyle::Python => format!("File \"{}\", line {}, in {}", filename, lineno, func),
+        StackStyle::Java => {
+            let qualified = match frame.str_field("module") {
+                Some(module) => format!("{}.{}", module, func),
+                None => func.to_string(),
+            };
+            format!("at {}({}:{})", qualified, filename, lineno)
+        }
+        StackStyle::Node => {
+            let mut location = format!("{}:{}", filename, lineno);
+            if let Some(col) = frame.i64_field("colNo") {
+                location.push_str(&format!(":{}", col));
+            }
+            match frame.str_field("function") {
+                Some(func) => format!("at {} ({})", func, location),
+                None => format!("at {}", location),
+            }
+        }
+        StackStyle::Native => format!("{} at {}:{}", func, filename, lineno),
+    }
+}
+
+/// Controls how much of each stack frame is rendered.
+#[derive(Debug, Clone)]
+pub struct FormatOptions {
+    pub include_vars: bool,
+    pub include_context: bool,
+    pub in_app_only: bool,
+    /// Extra in-app patterns; see [`is_in_app`].
+    pub in_app_include: Vec<String>,
+    /// Collection levels expanded in local variables; deeper values are shown inline.
+    pub var_depth: usize,
+    /// Length at which a single variable line is truncated.
+    pub var_max_chars: usize,
+    /// Frame line layout; set from the event's platform.
+    pub style: StackStyle,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            include_vars: true,
+            include_context: true,
+            in_app_only: false,
+            in_app_include: Vec::new(),
+            var_depth: DEFAULT_VAR_DEPTH,
+            var_max_chars: DEFAULT_VAR_MAX_CHARS,
+            style: StackStyle::default(),
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Applies `steps` size reductions in order: drop local variables,
+    /// then context lines, then non-in-app frames.
+    pub fn reduced(&self, steps: usize) -> Self {
+        let mut opts = self.clone();
+        if steps >= 1 {
+            opts.include_vars = false;
+        }
+        if steps >= 2 {
+            opts.include_context = false;
+        }
+        if steps >= 3 {
+            opts.in_app_only = true;
+        }
+        opts
+    }
+}
+
+/// Number of reductions `FormatOptions::reduced` can apply.
+const MAX_REDUCTION_STEPS: usize = 3;
+
+/// Matches `value` against a pattern: `*` is a wildcard, and a pattern without
+/// one matches as a prefix.
+fn matches_pattern(pattern: &str, value: &str) -> bool {
+    if !pattern.contains('*') {
+        return value.starts_with(pattern);
+    }
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if !value.starts_with(first) || value.len() < first.len() + last.len() {
+        return false;
+    }
+    let mut rest = &value[first.len()..];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+/// Whether a frame is in-app: the SDK's `inApp` flag, or a configured pattern
+/// matching its filename, absolute path, module or package.
+pub fn is_in_app(frame: &Value, include: &[String]) -> bool {
+    if frame.bool_field("inApp").unwrap_or(false) {
+        return true;
+    }
+    ["filename", "absPath", "module", "package"]
+        .iter()
+        .filter_map(|field| frame.str_field(field))
+        .any(|value| include.iter().any(|p| matches_pattern(p, value)))
+}
+
+/// Python-style one-line rendering of a variable value.
+fn inline_repr(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("\"{}\"", s),
+        Value::Null => "None".to_string(),
+        Value::Array(items) => format!(
+            "[{}]",
+            items.iter().map(inline_repr).collect::<Vec<_>>().join(", ")
+        ),
+        Value::Object(map) => format!(
+            "{{{}}}",
+            map.iter()
+                .map(|(k, v)| format!("\"{}\": {}", k, inline_repr(v)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        _ => value.to_string(),
+    }
+}
+
+fn collection_label(value: &Value) -> Option<String> {
+    let (len, noun) = match value {
+        Value::Array(items) if !items.is_empty() => (items.len(), "item"),
+        Value::Object(map) if !map.is_empty() => (map.len(), "key"),
+        _ => return None,
+    };
+    let plural = if len == 1 { "" } else { "s" };
+    Some(match value {
+        Value::Array(_) => format!("[{} {}{}]", len, noun, plural),
+        _ => format!("{{{} {}{}}}", len, noun, plural),
+    })
+}
+
+/// Renders a local variable value as lines: a summary line, then one indented line
+/// per entry for collections within `depth` levels. Collections are labelled with
+/// their size; lines are cut at `max_chars`.
+pub fn format_var_value(value: &Value, depth: usize, max_chars: usize) -> Vec<String> {
+    let Some(label) = collection_label(value) else {
+        return vec![truncate_chars(&inline_repr(value), max_chars)];
+    };
+    if depth == 0 {
+        return vec![truncate_chars(
+            &format!("{} {}", label, inline_repr(value)),
+            max_chars,
+        )];
+    }
+    let entries: Vec<(String, &Value)> = match value {
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (format!("[{}]", i), v))
+            .collect(),
+        Value::Object(map) => map.iter().map(|(k, v)| (k.clone(), v)).collect(),
+        _ => vec![],
+    };
+    let mut lines = vec![label];
+    for (key, entry) in entries.iter().take(MAX_VAR_ENTRIES) {
+        let nested = format_var_value(entry, depth - 1, max_chars);
+        lines.push(format!("  {}: {}", key, nested[0]));
+        lines.extend(nested[1..].iter().map(|line| format!("  {}", line)));
+    }
+    if entries.len() > MAX_VAR_ENTRIES {
+        lines.push(format!("  ... {} more", entries.len() - MAX_VAR_ENTRIES));
+    }
+    lines
+}
+
+pub fn format_frame_detail(output: &mut String, frame: &Value) {
+    format_frame_detail_with(output, frame, &FormatOptions::default());
+}
+
+pub fn format_frame_detail_with(output: &mut String, frame: &Value, opts: &FormatOptions) {
+    let lineno = frame.i64_field("lineNo").unwrap_or(0);
     output.push_str(&format!(
-        "─────────────────────\n  File \"{}\", line {}, in {}\n\n",
-        filename, lineno, func
+        "─────────────────────\n  {}\n",
+        format_frame_location(frame, opts.style)
     ));
-    if let Some(context) = frame.array_field("context") {
+    if let Some(url) = frame.str_field("sourceLink") {
+        output.push_str(&format!("  [View source]({})\n", url));
+    }
+    output.push('\n');
+    if opts.include_context
+        && let Some(context) = frame.array_field("context")
+    {
         for line in context {
             if let Some(arr) = line.as_array()
                 && arr.len() >= 2
@@ -26,79 +254,132 @@ pub fn format_frame_detail(output: &mut String, frame: &Value) {
                 output.push_str(&format!("{}{} │{}\n", marker, num, code));
             }
         }
+        if frame.bool_field("sourceFetched").unwrap_or(false) {
+            output.push_str("    (source fetched from repository)\n");
+        }
     }
-    if let Some(vars) = frame.object_field("vars")
+    if opts.include_vars
+        && let Some(vars) = frame.object_field("vars")
         && !vars.is_empty()
     {
         output.push_str("\nLocal Variables:\n");
         for (key, val) in vars {
-            let val_str = match val {
-                Value::String(s) => format!("\"{}\"", s),
-                Value::Null => "None".to_string(),
-                _ => val.to_string(),
-            };
-            let truncated = if val_str.chars().count() > 60 {
-                format!("{}...", val_str.chars().take(57).collect::<String>())
-            } else {
-                val_str
-            };
-            output.push_str(&format!("├─ {}: {}\n", key, truncated));
+            let lines = format_var_value(val, opts.var_depth, opts.var_max_chars);
+            output.push_str(&format!("├─ {}: {}\n", key, lines[0]));
+            for line in &lines[1..] {
+                output.push_str(&format!("│  {}\n", line));
+            }
         }
     }
 }
 
 pub fn format_exception(output: &mut String, exc: &Value) {
+    format_exception_with(output, exc, &FormatOptions::default());
+}
+
+/// Source map status of a JavaScript frame, with the minified location from
+/// `rawStacktrace` when it differs from the resolved one.
+pub fn sourcemap_status(frame: &Value, raw: Option<&Value>) -> String {
+    let map = frame
+        .get("data")
+        .and_then(|d| d.str_field("sourcemap"))
+        .map(|url| url.rsplit('/').next().unwrap_or(url));
+    let minified = raw
+        .map(|r| format_frame_location(r, StackStyle::Node))
+        .filter(|loc| *loc != format_frame_location(frame, StackStyle::Node));
+    let mut status = match (map, &minified) {
+        (Some(map), _) => format!("source map applied ({})", map),
+        (None, Some(_)) => "source map applied".to_string(),
+        (None, None) => "no source map".to_string(),
+    };
+    if let Some(minified) = minified {
+        status.push_str(&format!("; minified {}", minified));
+    }
+    status
+}
+
+/// The source line at `lineno` from the frame's context, or an empty string.
+pub fn context_line_at(frame: &Value, lineno: i64) -> &str {
+    frame
+        .array_field("context")
+        .and_then(|ctx| {
+            ctx.iter().find(|line| {
+                line.as_array()
+                    .map(|arr| arr.first().and_then(|n| n.as_i64()) == Some(lineno))
+                    .unwrap_or(false)
+            })
+        })
+        .and_then(|line| line.as_array())
+        .and_then(|arr| arr.get(1))
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+}
+
+pub fn format_exception_with(output: &mut String, exc: &Value, opts: &FormatOptions) {
     let exc_type = exc.str_field("type").unwrap_or("Error");
     let exc_value = exc.str_field("value").unwrap_or("");
     output.push_str(&format!("\n### {}: {}\n", exc_type, exc_value));
     if let Some(stacktrace) = exc.get("stacktrace")
         && let Some(frames) = stacktrace.array_field("frames")
     {
-        let frames_vec: Vec<_> = frames.iter().collect();
-        if let Some(relevant) = frames_vec
+        if let Some(relevant) = frames
             .iter()
             .rev()
-            .find(|f| f.bool_field("inApp").unwrap_or(false))
+            .find(|f| is_in_app(f, &opts.in_app_include))
         {
             output.push_str("\n**Most Relevant Frame:**\n");
-            format_frame_detail(output, relevant);
+            format_frame_detail_with(output, relevant, opts);
         }
+        // Raw (minified) frames line up index-for-index with the resolved ones.
+        let raw_frames = exc
+            .get("rawStacktrace")
+            .and_then(|st| st.array_field("frames"))
+            .filter(|raw| raw.len() == frames.len());
+        let show_sourcemaps = opts.style == StackStyle::Node
+            && (raw_frames.is_some()
+                || frames
+                    .iter()
+                    .any(|f| f.get("data").and_then(|d| d.get("sourcemap")).is_some()));
         output.push_str("\n**Full Stacktrace:**\n────────────────\n```\n");
-        for frame in frames_vec.iter().rev().take(20) {
-            let filename = frame.str_field("filename").unwrap_or("?");
-            let lineno = frame.i64_field("lineNo").unwrap_or(0);
-            let func = frame.str_field("function").unwrap_or("?");
-            let context_line = frame
-                .array_field("context")
-                .and_then(|ctx| {
-                    ctx.iter().find(|line| {
-                        line.as_array()
-                            .map(|arr| arr.first().and_then(|n| n.as_i64()) == Some(lineno))
-                            .unwrap_or(false)
-                    })
-                })
-                .and_then(|line| line.as_array())
-                .and_then(|arr| arr.get(1))
-                .and_then(|v| v.as_str())
-                .unwrap_or("");
-            output.push_str(&format!(
-                "  File \"{}\", line {}, in {}\n",
-                filename, lineno, func
-            ));
-            if !context_line.is_empty() {
+        let shown: Vec<_> = frames
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, f)| !opts.in_app_only || is_in_app(f, &opts.in_app_include))
+            .collect();
+        let omitted = frames.len() - shown.len();
+        for (index, frame) in shown.iter().take(20) {
+            let context_line = context_line_at(frame, frame.i64_field("lineNo").unwrap_or(0));
+            output.push_str(&format!("  {}\n", format_frame_location(frame, opts.style)));
+            if show_sourcemaps {
+                let raw = raw_frames.map(|raw| &raw[*index]);
+                output.push_str(&format!("      [{}]\n", sourcemap_status(frame, raw)));
+            }
+            if opts.include_context && !context_line.is_empty() {
                 output.push_str(&format!("        {}\n", context_line.trim()));
             }
         }
+        if omitted > 0 {
+            output.push_str(&format!("  ... {} non-app frames omitted\n", omitted));
+        }
         output.push_str("```\n");
     }
 }
 
 pub fn format_event_entries(output: &mut String, entries: &[crate::api_client::EventEntry]) {
+    format_event_entries_with(output, entries, &FormatOptions::default());
+}
+
+pub fn format_event_entries_with(
+    output: &mut String,
+    entries: &[crate::api_client::EventEntry],
+    opts: &FormatOptions,
+) {
     for entry in entries {
         if entry.entry_type == "exception" {
             if let Some(values) = entry.data.array_field("values") {
                 for exc in values {
-                    format_exception(output, exc);
+                    format_exception_with(output, exc, opts);
                 }
             }
         } else if entry.entry_type == "message"
@@ -148,7 +429,9 @@ pub fn format_contexts(output: &mut String, contexts: &serde_json::Map<String, V
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct GetIssueDetailsInput {
-    #[schemars(description = "Full Sentry issue URL")]
+    #[schemars(
+        description = "Full Sentry issue URL, including org subdomain URLs (https://acme.sentry.io/issues/123/) and share links (/share/issue/<id>/) pasted from Slack"
+    )]
     pub issue_url: Option<String>,
     #[schemars(description = "Organization slug (required if issue_url not provided)")]
     pub organization_slug: Option<String>,
@@ -158,53 +441,191 @@ pub struct GetIssueDetailsInput {
     pub issue_id: Option<String>,
     #[schemars(description = "Specific event ID to fetch instead of latest")]
     pub event_id: Option<String>,
+    #[schemars(
+        description = "Approximate output budget in tokens (default: 8000). When exceeded, local \
+        variables, then context lines, then non-app frames are dropped."
+    )]
+    pub max_tokens: Option<usize>,
+    #[schemars(
+        description = "Fetch source lines from the linked repository for in-app frames that \
+        have no context lines (uses the project's code mappings; default: false)"
+    )]
+    pub fetch_source: Option<bool>,
+    #[schemars(
+        description = "Nesting levels of dicts/lists expanded in local variables (default: 2, max: 5)"
+    )]
+    pub var_depth: Option<usize>,
+    #[schemars(description = "Characters shown per local variable value (default: 60, min: 10)")]
+    pub var_max_length: Option<usize>,
+    #[schemars(
+        description = "Mark the issue as seen by you after fetching it, clearing Sentry's 'new' indicator (requires write access)"
+    )]
+    pub mark_seen: Option<bool>,
 }
 
 static ISSUE_URL_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"https?://[^/]+/organizations/([^/]+)/issues/([^/?]+)").unwrap());
 
+/// sentry.io links on the organization's own subdomain, e.g. `https://acme.sentry.io/issues/123/`.
+static SUBDOMAIN_ISSUE_URL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"https?://([a-z0-9][a-z0-9-]*)\.sentry\.io/issues/([^/?#]+)").unwrap()
+});
+
+/// Public share links, e.g. `https://sentry.io/share/issue/<share_id>/`.
+static SHARE_URL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"https?://[^/]+/(?:organizations/[^/]+/)?share/issue/([0-9a-fA-F-]+)").unwrap()
+});
+
 pub fn parse_issue_url(url: &str) -> Option<(String, String)> {
-    let caps = ISSUE_URL_RE.captures(url)?;
+    let caps = ISSUE_URL_RE
+        .captures(url)
+        .or_else(|| SUBDOMAIN_ISSUE_URL_RE.captures(url))?;
     Some((caps[1].to_string(), caps[2].to_string()))
 }
 
-fn format_issue_header(output: &mut String, issue: &crate::api_client::Issue) {
-    output.push_str("# Issue Details\n\n");
-    output.push_str(&format!("**ID:** {}\n", issue.short_id));
-    output.push_str(&format!("**Title:** {}\n", issue.title));
-    output.push_str(&format!("**Status:** {}\n", issue.status));
-    if let Some(substatus) = &issue.substatus {
-        output.push_str(&format!("**Substatus:** {}\n", substatus));
-    }
-    if let Some(issue_type) = &issue.issue_type {
-        output.push_str(&format!("**Issue Type:** {}\n", issue_type));
-    }
-    if let Some(issue_category) = &issue.issue_category {
-        output.push_str(&format!("**Issue Category:** {}\n", issue_category));
+/// The share ID of a public share link, which does not name the organization or issue.
+pub fn parse_share_url(url: &str) -> Option<String> {
+    SHARE_URL_RE.captures(url).map(|caps| caps[1].to_string())
+}
+
+/// Resolves an issue URL to its organization slug and issue ID, looking share links
+/// up through the share endpoint.
+pub async fn resolve_issue_url(
+    client: &impl SentryApi,
+    url: &str,
+) -> Result<(String, String), McpError> {
+    let url = url.trim().trim_start_matches('<').trim_end_matches('>');
+    if let Some(share_id) = parse_share_url(url) {
+        let shared = client.get_shared_issue(&share_id).await.map_err(|e| {
+            McpError::invalid_params(
+                format!(
+                    "Could not resolve share link {}: {}. Sharing may have been turned off for the issue",
+                    share_id, e
+                ),
+                None,
+            )
+        })?;
+        return Ok((shared.project.organization.slug, shared.short_id));
     }
-    if let Some(level) = &issue.level {
-        output.push_str(&format!("**Level:** {}\n", level));
+    parse_issue_url(url).ok_or_else(|| McpError::invalid_params("Invalid issue URL format", None))
+}
+
+/// Describes where a resolved issue is tracked as fixed, from its `statusDetails`.
+pub fn format_resolution(status_details: &Value) -> Option<String> {
+    if let Some(release) = status_details.str_field("inRelease") {
+        return Some(format!("release {}", release));
     }
-    if let Some(culprit) = &issue.culprit {
-        output.push_str(&format!("**Culprit:** {}\n", culprit));
+    if status_details.bool_field("inNextRelease") == Some(true) {
+        return Some("next release".to_string());
     }
-    output.push_str(&format!(
-        "**Project:** {} ({})\n",
-        issue.project.name, issue.project.slug
-    ));
-    if let Some(platform) = &issue.platform {
-        output.push_str(&format!("**Platform:** {}\n", platform));
+    let commit = status_details.get("inCommit")?;
+    let sha = commit
+        .str_field("id")
+        .or_else(|| commit.str_field("commit"))?;
+    let repo = commit
+        .get("repository")
+        .and_then(|r| r.as_str().or_else(|| r.str_field("name")));
+    Some(match repo {
+        Some(repo) => format!("commit {} ({})", sha, repo),
+        None => format!("commit {}", sha),
+    })
+}
+
+/// Describes when an ignored issue will come back, from its `statusDetails`.
+pub fn format_ignore(status_details: &Value) -> Option<String> {
+    if let Some(until) = status_details.str_field("ignoreUntil") {
+        return Some(format!("until {}", until));
     }
-    if let Some(first_seen) = &issue.first_seen {
-        output.push_str(&format!("**First Seen:** {}\n", first_seen));
+    let with_window = |what: &str, count: i64, window: Option<i64>| match window {
+        Some(window) => format!("until {} more {} within {} minutes", count, what, window),
+        None => format!("until {} more {}", count, what),
+    };
+    if let Some(count) = status_details.i64_field("ignoreCount") {
+        return Some(with_window(
+            "events",
+            count,
+            status_details.i64_field("ignoreWindow"),
+        ));
     }
-    if let Some(last_seen) = &issue.last_seen {
-        output.push_str(&format!("**Last Seen:** {}\n", last_seen));
+    if let Some(count) = status_details.i64_field("ignoreUserCount") {
+        return Some(with_window(
+            "users",
+            count,
+            status_details.i64_field("ignoreUserWindow"),
+        ));
     }
-    output.push_str(&format!("**Event Count:** {}\n", issue.count));
-    output.push_str(&format!("**User Count:** {}\n", issue.user_count));
-    if let Some(permalink) = &issue.permalink {
-        output.push_str(&format!("**URL:** {}\n", permalink));
+    status_details
+        .i64_field("ignoreDuration")
+        .map(|minutes| format!("for {} minutes", minutes))
+}
+
+/// Names the issue's assignee from `assignedTo` (a user or team object).
+pub fn format_assignee(assigned_to: &Value) -> Option<String> {
+    let name = assigned_to
+        .str_field("name")
+        .or_else(|| assigned_to.str_field("email"))?;
+    Some(match assigned_to.str_field("type") {
+        Some("team") => format!("#{}", name),
+        _ => name.to_string(),
+    })
+}
+
+/// `[JIRA-123](https://...), GH-45`: linked tickets, as links where a URL is known.
+pub fn format_annotations(annotations: &[IssueAnnotation]) -> String {
+    annotations
+        .iter()
+        .map(|a| match &a.url {
+            Some(url) => format!("[{}]({})", a.display_name, url),
+            None => a.display_name.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Status, resolution/ignore details, substatus and assignee lines.
+fn format_issue_status(output: &mut String, issue: &crate::api_client::Issue) {
+    field(output, "Status", &issue.status);
+    opt_field(
+        output,
+        "Resolved In",
+        format_resolution(&issue.status_details),
+    );
+    opt_field(output, "Ignored", format_ignore(&issue.status_details));
+    opt_field(output, "Substatus", issue.substatus.as_ref());
+    opt_field(
+        output,
+        "Assigned To",
+        issue.assigned_to.as_ref().and_then(format_assignee),
+    );
+}
+
+fn format_issue_header(output: &mut String, issue: &crate::api_client::Issue) {
+    output.push_str("# Issue Details\n\n");
+    field(output, "ID", &issue.short_id);
+    field(output, "Title", &issue.title);
+    format_issue_status(output, issue);
+    opt_field(output, "Issue Type", issue.issue_type.as_ref());
+    opt_field(output, "Issue Category", issue.issue_category.as_ref());
+    opt_field(output, "Level", issue.level.as_ref());
+    opt_field(output, "Priority", issue.priority.as_ref());
+    opt_field(output, "Culprit", issue.culprit.as_ref());
+    field(
+        output,
+        "Project",
+        format!("{} ({})", issue.project.name, issue.project.slug),
+    );
+    opt_field(output, "Platform", issue.platform.as_ref());
+    opt_field(output, "First Seen", issue.first_seen.as_ref());
+    opt_field(output, "Last Seen", issue.last_seen.as_ref());
+    field(output, "Event Count", &issue.count);
+    field(output, "User Count", issue.user_count);
+    opt_field(output, "URL", issue.permalink.as_ref());
+    if !issue.annotations.is_empty() {
+        field(
+            output,
+            "Linked Tickets",
+            format_annotations(&issue.annotations),
+        );
     }
     if !issue.tags.is_empty() {
         output.push_str("\n## Tags\n");
@@ -217,20 +638,24 @@ fn format_issue_header(output: &mut String, issue: &crate::api_client::Issue) {
     }
 }
 
-fn format_event_section(output: &mut String, event: &crate::api_client::Event) {
+fn format_event_section(
+    output: &mut String,
+    event: &crate::api_client::Event,
+    opts: &FormatOptions,
+) {
     output.push_str("\n## Latest Event\n\n");
-    output.push_str(&format!("**Event ID:** {}\n", event.event_id));
-    if let Some(date) = &event.date_created {
-        output.push_str(&format!("**Date:** {}\n", date));
-    }
-    if let Some(msg) = &event.message {
-        output.push_str(&format!("**Message:** {}\n", msg));
-    }
-    format_event_entries(output, &event.entries);
+    field(output, "Event ID", &event.event_id);
+    opt_field(output, "Date", event.date_created.as_ref());
+    opt_field(output, "Message", event.message.as_ref());
+    let opts = FormatOptions {
+        style: StackStyle::for_platform(event.platform.as_deref()),
+        ..opts.clone()
+    };
+    format_event_entries_with(output, &event.entries, &opts);
     if !event.tags.is_empty() {
         output.push_str("\n### Event Tags\n");
         for tag in &event.tags {
-            output.push_str(&format!("**{}:** {}\n", tag.key, tag.value));
+            field(output, &tag.key, &tag.value);
         }
     }
     if let Some(extra) = event.context.as_object()
@@ -248,11 +673,19 @@ fn format_event_section(output: &mut String, event: &crate::api_client::Event) {
 pub fn format_issue_output(
     issue: &crate::api_client::Issue,
     event: Option<&crate::api_client::Event>,
+) -> String {
+    format_issue_output_with(issue, event, &FormatOptions::default())
+}
+
+pub fn format_issue_output_with(
+    issue: &crate::api_client::Issue,
+    event: Option<&crate::api_client::Event>,
+    opts: &FormatOptions,
 ) -> String {
     let mut output = String::new();
     format_issue_header(&mut output, issue);
     if let Some(event) = event {
-        format_event_section(&mut output, event);
+        format_event_section(&mut output, event, opts);
     } else {
         output.push_str(
             "\n## Event\nNo events available (may have expired due to retention policy).\n",
@@ -261,13 +694,137 @@ pub fn format_issue_output(
     output
 }
 
+pub fn short_sha(sha: &str) -> &str {
+    &sha[..7.min(sha.len())]
+}
+
+/// Best link for a commit: its pull request, else the GitHub/GitLab-style commit page.
+pub fn commit_link(commit: &Commit) -> Option<String> {
+    if let Some(url) = commit
+        .pull_request
+        .as_ref()
+        .and_then(|pr| pr.external_url.clone())
+    {
+        return Some(url);
+    }
+    let repo_url = commit.repository.as_ref()?.url.as_deref()?;
+    Some(format!(
+        "{}/commit/{}",
+        repo_url.trim_end_matches('/'),
+        commit.id
+    ))
+}
+
+pub fn format_suspect_commits(output: &mut String, committers: &[Committer]) {
+    output.push_str("\n## Suspect Commits\n\n");
+    for committer in committers {
+        let author = committer
+            .author
+            .as_ref()
+            .and_then(|a| a.name.as_deref().or(a.email.as_deref()))
+            .unwrap_or("unknown");
+        for commit in &committer.commits {
+            let summary = commit
+                .message
+                .as_deref()
+                .and_then(|m| m.lines().next())
+                .unwrap_or("");
+            output.push_str(&format!(
+                "- **{}** {} ({}",
+                short_sha(&commit.id),
+                summary,
+                author
+            ));
+            if let Some(date) = &commit.date_created {
+                output.push_str(&format!(", {}", date));
+            }
+            output.push(')');
+            if let Some(repo) = &commit.repository {
+                output.push_str(&format!(" in {}", repo.name));
+            }
+            output.push('\n');
+            if let Some(pr) = &commit.pull_request {
+                let title = pr.title.as_deref().unwrap_or("(untitled)");
+                let label = format!("#{} {}", pr.id, title);
+                match &pr.external_url {
+                    Some(url) => output.push_str(&format!("  PR: [{}]({})", label, url)),
+                    None => output.push_str(&format!("  PR: {}", label)),
+                }
+                if let Some(pr_author) = pr
+                    .author
+                    .as_ref()
+                    .and_then(|a| a.username.as_deref().or(a.name.as_deref()))
+                {
+                    output.push_str(&format!(" by {}", pr_author));
+                }
+                output.push('\n');
+            } else if let Some(link) = commit_link(commit) {
+                output.push_str(&format!("  Commit: {}\n", link));
+            }
+        }
+    }
+}
+
+/// Renders issue output, progressively dropping stack detail until the
+/// estimated token count fits `max_tokens`.
+pub fn format_issue_output_within_budget(
+    issue: &crate::api_client::Issue,
+    event: Option<&crate::api_client::Event>,
+    opts: &FormatOptions,
+    max_tokens: usize,
+) -> String {
+    let mut output = format_issue_output_with(issue, event, opts);
+    let mut steps = 0;
+    while estimate_tokens(&output) > max_tokens && steps < MAX_REDUCTION_STEPS {
+        steps += 1;
+        output = format_issue_output_with(issue, event, &opts.reduced(steps));
+    }
+    if steps > 0 {
+        let dropped = ["local variables", "context lines", "non-app frames"];
+        output.push_str(&format!(
+            "\n_Output reduced to fit ~{} tokens; omitted: {}._\n",
+            max_tokens,
+            dropped[..steps].join(", ")
+        ));
+    }
+    output
+}
+
+/// Optionally fetches missing source context and links the most relevant frames,
+/// then maps frame paths into the repository. Lookups run first, while frames
+/// still carry their original paths.
+async fn enrich_frames(
+    client: &impl SentryApi,
+    org_slug: &str,
+    project: &Project,
+    event: &mut Event,
+    fetch_source: bool,
+    opts: &FormatOptions,
+) {
+    if fetch_source {
+        fill_missing_context(client, org_slug, &project.slug, event, &opts.in_app_include).await;
+    }
+    link_relevant_frames(client, org_slug, project, event, &opts.in_app_include).await;
+    apply_code_mappings(client, org_slug, project, event).await;
+}
+
 pub async fn execute(
     client: &impl SentryApi,
     input: GetIssueDetailsInput,
+) -> Result<CallToolResult, McpError> {
+    execute_with(client, input, &FormatOptions::default(), false).await
+}
+
+/// Like [`execute`], starting from `opts` (e.g. server-configured in-app patterns).
+/// `mark_seen` is only honoured when `allow_writes` is set.
+pub async fn execute_with(
+    client: &impl SentryApi,
+    input: GetIssueDetailsInput,
+    opts: &FormatOptions,
+    allow_writes: bool,
 ) -> Result<CallToolResult, McpError> {
     let (org_slug, issue_id) = if let Some(url) = &input.issue_url {
-        parse_issue_url(url)
-            .ok_or_else(|| McpError::invalid_params("Invalid issue URL format", None))?
+        resolve_issue_url(client, url).await?
     } else {
         let org = input.organization_slug.ok_or_else(|| {
             McpError::invalid_params(
@@ -283,11 +840,18 @@ pub async fn execute(
         })?;
         (org, id)
     };
+    let mark_seen = input.mark_seen.unwrap_or(false);
+    if mark_seen && !allow_writes {
+        return Err(McpError::invalid_params(
+            "mark_seen requires write access (set SENTRY_MCP_ALLOW_WRITES)",
+            None,
+        ));
+    }
     let issue = client
         .get_issue(&org_slug, &issue_id)
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
-    let event = if let Some(event_id) = &input.event_id {
+    let mut event = if let Some(event_id) = &input.event_id {
         Some(
             client
                 .get_event(&org_slug, &issue_id, event_id)
@@ -297,7 +861,39 @@ pub async fn execute(
     } else {
         client.get_latest_event(&org_slug, &issue_id).await.ok()
     };
-    let output = format_issue_output(&issue, event.as_ref());
+    if let Some(event) = event.as_mut() {
+        let fetch_source = input.fetch_source.unwrap_or(false);
+        enrich_frames(client, &org_slug, &issue.project, event, fetch_source, opts).await;
+    }
+    let committers = match &event {
+        Some(event) => client
+            .get_event_committers(&org_slug, &issue.project.slug, &event.event_id)
+            .await
+            .unwrap_or_default(),
+        None => vec![],
+    };
+    let max_tokens = input.max_tokens.unwrap_or(DEFAULT_MAX_TOKENS);
+    let opts = FormatOptions {
+        var_depth: input.var_depth.map_or(opts.var_depth, |d| d.min(5)),
+        var_max_chars: input
+            .var_max_length
+            .map_or(opts.var_max_chars, |n| n.max(10)),
+        ..opts.clone()
+    };
+    let mut output = format_issue_output_within_budget(&issue, event.as_ref(), &opts, max_tokens);
+    if committers.iter().any(|c| !c.commits.is_empty()) {
+        format_suspect_commits(&mut output, &committers);
+    }
+    if mark_seen {
+        let update = IssueUpdate {
+            has_seen: Some(true),
+            ..Default::default()
+        };
+        match client.update_issue(&org_slug, &issue_id, &update).await {
+            Ok(_) => output.push_str("\n*Marked as seen.*\n"),
+            Err(e) => output.push_str(&format!("\n*Could not mark as seen: {}*\n", e)),
+        }
+    }
     Ok(CallToolResult::success(vec![rmcp::model::Content::text(
         output,
     )]))
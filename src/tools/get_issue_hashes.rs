@@ -0,0 +1,89 @@
+use crate::api_client::{IssueHash, SentryApi};
+use crate::json_ext::ValueExt;
+use crate::tools::cluster_issue_events::primary_exception_json;
+use crate::tools::get_issue_details::{StackStyle, format_frame_location, is_in_app};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetIssueHashesInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+}
+
+/// Location of the innermost in-app frame of the main exception, which usually
+/// drives stack trace grouping.
+pub fn top_in_app_frame(event: &Value) -> Option<String> {
+    let frames = primary_exception_json(event)?
+        .get("stacktrace")?
+        .array_field("frames")?;
+    let frame = frames.iter().rev().find(|f| is_in_app(f, &[]))?;
+    let style = StackStyle::for_platform(event.str_field("platform"));
+    Some(format_frame_location(frame, style))
+}
+
+fn format_hash(output: &mut String, hash: &IssueHash) {
+    output.push_str(&format!("## `{}`\n\n", hash.id));
+    let Some(event) = &hash.latest_event else {
+        output.push_str("No event stored for this hash.\n\n");
+        return;
+    };
+    let event_id = event.str_field("eventID").unwrap_or("unknown");
+    match event.str_field("dateCreated") {
+        Some(date) => output.push_str(&format!("- **Latest event:** {} ({})\n", event_id, date)),
+        None => output.push_str(&format!("- **Latest event:** {}\n", event_id)),
+    }
+    if let Some(title) = event.str_field("title") {
+        output.push_str(&format!("- **Title:** {}\n", title));
+    }
+    if let Some(exc) = primary_exception_json(event) {
+        output.push_str(&format!(
+            "- **Exception:** {}: {}\n",
+            exc.str_field("type").unwrap_or("Error"),
+            exc.str_field("value").unwrap_or("")
+        ));
+    }
+    if let Some(frame) = top_in_app_frame(event) {
+        output.push_str(&format!("- **Top in-app frame:** `{}`\n", frame));
+    }
+    output.push('\n');
+}
+
+pub fn format_hashes_output(issue_id: &str, hashes: &[IssueHash]) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Grouping Hashes for {}\n\n", issue_id));
+    output.push_str(&format!("**Found:** {} hashes\n\n", hashes.len()));
+    if hashes.is_empty() {
+        output.push_str("No grouping hashes recorded for this issue.\n");
+        return output;
+    }
+    if hashes.len() > 1 {
+        output.push_str(
+            "Events with any of these hashes are grouped into this issue. Several hashes \
+            usually mean issues were merged or the grouping config changed; compare the \
+            latest events to see whether they are really the same problem.\n\n",
+        );
+    }
+    for hash in hashes {
+        format_hash(&mut output, hash);
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: GetIssueHashesInput,
+) -> Result<CallToolResult, McpError> {
+    let hashes = client
+        .list_issue_hashes(&input.organization_slug, &input.issue_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_hashes_output(&input.issue_id, &hashes);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,105 @@
+use crate::api_client::{Event, SentryApi};
+use crate::tools::cluster_issue_events::primary_exception;
+use crate::tools::fatal_issues::format_crash;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Issues per call.
+const MAX_ISSUES: usize = 25;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetLatestEventsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue IDs like 'PROJECT-123' or numeric IDs (max: 25)")]
+    pub issue_ids: Vec<String>,
+}
+
+/// Trimmed, non-empty issue IDs without duplicates, in the order given.
+pub fn unique_issue_ids(issue_ids: &[String]) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for id in issue_ids.iter().map(|id| id.trim()) {
+        if !id.is_empty() && !ids.iter().any(|i| i == id) {
+            ids.push(id.to_string());
+        }
+    }
+    ids
+}
+
+fn tag<'a>(event: &'a Event, key: &str) -> Option<&'a str> {
+    event
+        .tags
+        .iter()
+        .find(|t| t.key == key)
+        .map(|t| t.value.as_str())
+}
+
+fn format_event(output: &mut String, event: &Event, in_app_include: &[String]) {
+    output.push_str(&format!("**Event:** {}", event.event_id));
+    if let Some(date) = &event.date_created {
+        output.push_str(&format!(" | **Date:** {}", date));
+    }
+    for (label, key) in [("Release", "release"), ("Environment", "environment")] {
+        if let Some(value) = tag(event, key) {
+            output.push_str(&format!(" | **{}:** {}", label, value));
+        }
+    }
+    output.push('\n');
+    if primary_exception(event).is_none()
+        && let Some(message) = event.message.as_deref().filter(|m| !m.is_empty())
+    {
+        output.push_str(&format!("**Message:** {}\n", message));
+    }
+    format_crash(output, event, in_app_include);
+}
+
+pub fn format_latest_events(
+    results: &[(String, anyhow::Result<Event>)],
+    in_app_include: &[String],
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Latest Events: {} issues\n\n", results.len()));
+    for (issue_id, result) in results {
+        output.push_str(&format!("## {}\n\n", issue_id));
+        match result {
+            Ok(event) => format_event(&mut output, event, in_app_include),
+            Err(e) => output.push_str(&format!("Could not fetch the latest event: {}\n", e)),
+        }
+        output.push('\n');
+    }
+    output.push_str("Use get_issue_details for the full event of an issue.\n");
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: GetLatestEventsInput,
+    in_app_include: &[String],
+) -> Result<CallToolResult, McpError> {
+    let issue_ids = unique_issue_ids(&input.issue_ids);
+    if issue_ids.is_empty() {
+        return Err(McpError::invalid_params(
+            "issue_ids must contain at least one issue ID".to_string(),
+            None,
+        ));
+    }
+    if issue_ids.len() > MAX_ISSUES {
+        return Err(McpError::invalid_params(
+            format!(
+                "At most {} issues per call, got {}",
+                MAX_ISSUES,
+                issue_ids.len()
+            ),
+            None,
+        ));
+    }
+    let events = client
+        .get_latest_events(&input.organization_slug, &issue_ids)
+        .await;
+    let results: Vec<(String, anyhow::Result<Event>)> = issue_ids.into_iter().zip(events).collect();
+    let output = format_latest_events(&results, in_app_include);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
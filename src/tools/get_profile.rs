@@ -0,0 +1,247 @@
+use crate::api_client::{Profile, ProfileFrame, ProfileSample, SentryApi};
+use crate::format::format_duration;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+
+/// Frames listed in the hottest frames table.
+const MAX_HOT_FRAMES: usize = 15;
+/// Call paths listed.
+const MAX_CALL_PATHS: usize = 5;
+/// Frames of a call path shown, counting from the leaf.
+const MAX_PATH_FRAMES: usize = 8;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetProfileInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug of the profiled transaction")]
+    pub project_slug: String,
+    #[schemars(
+        description = "Profile ID, e.g. from the Profiles section of get_trace_details (32-character hex string)"
+    )]
+    pub profile_id: String,
+    #[schemars(
+        description = "Include samples of all threads instead of only the transaction's active thread (default: false)"
+    )]
+    pub all_threads: Option<bool>,
+}
+
+/// Sample counts of one frame: as the leaf (self) and anywhere on the stack (total).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameTime {
+    pub frame: usize,
+    pub self_samples: usize,
+    pub total_samples: usize,
+}
+
+/// Samples sharing one stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallPath {
+    pub stack: usize,
+    pub samples: usize,
+}
+
+/// The samples to summarize: the active thread's unless all threads are asked for
+/// or the profile names no active thread.
+pub fn selected_samples(profile: &Profile, all_threads: bool) -> Vec<&ProfileSample> {
+    let active = profile
+        .transaction
+        .as_ref()
+        .and_then(|t| t.active_thread_id.as_deref())
+        .filter(|_| !all_threads);
+    profile
+        .profile
+        .samples
+        .iter()
+        .filter(|s| active.is_none_or(|id| s.thread_id == id))
+        .collect()
+}
+
+/// Self and total sample counts per frame, hottest self time first.
+pub fn frame_times(profile: &Profile, samples: &[&ProfileSample]) -> Vec<FrameTime> {
+    let mut times: HashMap<usize, FrameTime> = HashMap::new();
+    for sample in samples {
+        let Some(stack) = profile.profile.stacks.get(sample.stack_id) else {
+            continue;
+        };
+        let mut seen = HashSet::new();
+        for (depth, &frame) in stack.iter().enumerate() {
+            let time = times.entry(frame).or_insert(FrameTime {
+                frame,
+                self_samples: 0,
+                total_samples: 0,
+            });
+            if depth == 0 {
+                time.self_samples += 1;
+            }
+            // Recursive frames count once per sample.
+            if seen.insert(frame) {
+                time.total_samples += 1;
+            }
+        }
+    }
+    let mut times: Vec<FrameTime> = times.into_values().collect();
+    times.sort_by(|a, b| {
+        (b.self_samples, b.total_samples, a.frame).cmp(&(a.self_samples, a.total_samples, b.frame))
+    });
+    times
+}
+
+/// Sample counts per stack, most sampled first.
+pub fn call_paths(samples: &[&ProfileSample]) -> Vec<CallPath> {
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    for sample in samples {
+        *counts.entry(sample.stack_id).or_default() += 1;
+    }
+    let mut paths: Vec<CallPath> = counts
+        .into_iter()
+        .map(|(stack, samples)| CallPath { stack, samples })
+        .collect();
+    paths.sort_by(|a, b| (b.samples, a.stack).cmp(&(a.samples, b.stack)));
+    paths
+}
+
+fn frame_name(frame: Option<&ProfileFrame>) -> &str {
+    frame.and_then(|f| f.function.as_deref()).unwrap_or("?")
+}
+
+/// Module or file of a frame, with the line when known.
+pub fn frame_location(frame: &ProfileFrame) -> String {
+    let place = frame
+        .module
+        .as_deref()
+        .or(frame.filename.as_deref())
+        .or(frame.package.as_deref())
+        .unwrap_or("?");
+    match frame.lineno {
+        Some(line) if line > 0 => format!("{}:{}", place, line),
+        _ => place.to_string(),
+    }
+}
+
+/// A call path root to leaf, e.g. `… → handler → render → escape`.
+pub fn format_call_path(profile: &Profile, stack: usize) -> String {
+    let Some(frames) = profile.profile.stacks.get(stack) else {
+        return "?".to_string();
+    };
+    let mut names: Vec<&str> = frames
+        .iter()
+        .take(MAX_PATH_FRAMES)
+        .map(|&i| frame_name(profile.profile.frames.get(i)))
+        .collect();
+    if frames.len() > MAX_PATH_FRAMES {
+        names.push("…");
+    }
+    names.reverse();
+    names.join(" → ")
+}
+
+fn percent(count: usize, total: usize) -> f64 {
+    count as f64 * 100.0 / total.max(1) as f64
+}
+
+/// Wall time covered by the samples, from their elapsed timestamps.
+fn sampled_duration_ms(samples: &[&ProfileSample]) -> Option<f64> {
+    let elapsed: Vec<f64> = samples
+        .iter()
+        .filter_map(|s| s.elapsed_since_start_ns.parse::<f64>().ok())
+        .collect();
+    let min = elapsed.iter().cloned().reduce(f64::min)?;
+    let max = elapsed.iter().cloned().reduce(f64::max)?;
+    Some((max - min) / 1_000_000.0)
+}
+
+fn format_header(profile: &Profile, samples: &[&ProfileSample], all_threads: bool) -> String {
+    let mut output = String::new();
+    if let Some(transaction) = &profile.transaction {
+        output.push_str(&format!("**Transaction:** {}\n", transaction.name));
+    }
+    if let Some(platform) = &profile.platform {
+        output.push_str(&format!("**Platform:** {}\n", platform));
+    }
+    let active = profile
+        .transaction
+        .as_ref()
+        .and_then(|t| t.active_thread_id.as_deref());
+    match active {
+        Some(id) if !all_threads => {
+            let name = profile
+                .profile
+                .thread_metadata
+                .get(id)
+                .and_then(|m| m.get("name"))
+                .and_then(|n| n.as_str());
+            match name {
+                Some(name) => output.push_str(&format!("**Thread:** {} ({})\n", name, id)),
+                None => output.push_str(&format!("**Thread:** {}\n", id)),
+            }
+        }
+        _ => output.push_str("**Thread:** all\n"),
+    }
+    output.push_str(&format!("**Samples:** {}\n", samples.len()));
+    if let Some(ms) = sampled_duration_ms(samples) {
+        output.push_str(&format!("**Sampled Duration:** {}\n", format_duration(ms)));
+    }
+    output
+}
+
+pub fn format_profile_output(profile_id: &str, profile: &Profile, all_threads: bool) -> String {
+    let samples = selected_samples(profile, all_threads);
+    let mut output = String::new();
+    output.push_str(&format!("# Profile {}\n\n", profile_id));
+    output.push_str(&format_header(profile, &samples, all_threads));
+    if samples.is_empty() {
+        output.push_str("\nNo samples recorded.\n");
+        return output;
+    }
+    let total = samples.len();
+    output.push_str("\n## Hottest Frames\n\n");
+    output.push_str("| Function | Location | In App | Self | Total |\n");
+    output.push_str("|---|---|---|---|---|\n");
+    for time in frame_times(profile, &samples)
+        .iter()
+        .filter(|t| t.self_samples > 0)
+        .take(MAX_HOT_FRAMES)
+    {
+        let frame = profile.profile.frames.get(time.frame);
+        output.push_str(&format!(
+            "| {} | {} | {} | {:.1}% | {:.1}% |\n",
+            frame_name(frame),
+            frame.map(frame_location).unwrap_or_default(),
+            if frame.and_then(|f| f.in_app).unwrap_or(false) {
+                "yes"
+            } else {
+                ""
+            },
+            percent(time.self_samples, total),
+            percent(time.total_samples, total)
+        ));
+    }
+    output.push_str("\n## Hottest Call Paths\n\n");
+    for (i, path) in call_paths(&samples).iter().take(MAX_CALL_PATHS).enumerate() {
+        output.push_str(&format!(
+            "{}. {:.1}% — {}\n",
+            i + 1,
+            percent(path.samples, total),
+            format_call_path(profile, path.stack)
+        ));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: GetProfileInput,
+) -> Result<CallToolResult, McpError> {
+    let profile_id = input.profile_id.trim();
+    let profile = client
+        .get_profile(&input.organization_slug, &input.project_slug, profile_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_profile_output(profile_id, &profile, input.all_threads.unwrap_or(false));
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
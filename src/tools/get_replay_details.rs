@@ -0,0 +1,204 @@
+use crate::api_client::{Replay, SentryApi};
+use crate::json_ext::ValueExt;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// rrweb event type of custom events, which carry Sentry breadcrumbs and spans.
+const RRWEB_CUSTOM_EVENT: i64 = 5;
+/// Timeline entries shown; the latest are kept since they lead up to the error.
+const MAX_TIMELINE_ENTRIES: usize = 200;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetReplayDetailsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(
+        description = "Replay ID (32-character hex string), e.g. from an event's replayId tag"
+    )]
+    pub replay_id: String,
+}
+
+/// One user action or failure in the replay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineEntry {
+    /// Milliseconds since the start of the recording.
+    pub offset_ms: f64,
+    pub kind: &'static str,
+    pub message: String,
+}
+
+fn breadcrumb_entry(payload: &Value) -> Option<(&'static str, String)> {
+    let message = payload.str_field("message").unwrap_or_default().to_string();
+    let data = payload.get("data");
+    match payload.str_field("category")? {
+        "ui.click" | "ui.tap" => Some(("click", message)),
+        "ui.slowClickDetected" => Some(("dead click", message)),
+        "ui.multiClick" => Some(("rage click", message)),
+        "navigation" => {
+            let to = data.and_then(|d| d.str_field("to")).unwrap_or(&message);
+            Some(("navigation", to.to_string()))
+        }
+        "console" if payload.str_field("level") == Some("error") => {
+            Some(("console error", message))
+        }
+        _ if payload.str_field("level") == Some("error") => Some(("error", message)),
+        _ => None,
+    }
+}
+
+fn span_entry(payload: &Value) -> Option<(&'static str, String)> {
+    let op = payload.str_field("op")?;
+    let description = payload.str_field("description").unwrap_or_default();
+    if op.starts_with("navigation.") {
+        return Some(("navigation", description.to_string()));
+    }
+    let data = payload.get("data")?;
+    let status = data.i64_field("statusCode").filter(|s| *s >= 400)?;
+    let method = data.str_field("method").unwrap_or("GET");
+    Some((
+        "request failed",
+        format!("{} {} → {}", method, description, status),
+    ))
+}
+
+/// Extracts clicks, navigations, console errors and failed requests from the
+/// recording, in chronological order.
+pub fn replay_timeline(events: &[Value]) -> Vec<TimelineEntry> {
+    let timestamps = events.iter().filter_map(|e| e.get("timestamp")?.as_f64());
+    let Some(start) = timestamps.reduce(f64::min) else {
+        return Vec::new();
+    };
+    let mut entries: Vec<TimelineEntry> = events
+        .iter()
+        .filter(|e| e.i64_field("type") == Some(RRWEB_CUSTOM_EVENT))
+        .filter_map(|e| {
+            let data = e.get("data")?;
+            let payload = data.get("payload")?;
+            let (kind, message) = match data.str_field("tag")? {
+                "breadcrumb" => breadcrumb_entry(payload)?,
+                "performanceSpan" => span_entry(payload)?,
+                _ => return None,
+            };
+            Some(TimelineEntry {
+                offset_ms: e.get("timestamp")?.as_f64()? - start,
+                kind,
+                message,
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| a.offset_ms.total_cmp(&b.offset_ms));
+    entries
+}
+
+/// `+MM:SS` offset from the start of the recording.
+pub fn format_offset(ms: f64) -> String {
+    let seconds = (ms / 1000.0).max(0.0) as u64;
+    format!("+{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+fn format_metadata(replay: &Replay, output: &mut String) {
+    if let Some(started) = &replay.started_at {
+        output.push_str(&format!("**Started:** {}\n", started));
+    }
+    if let Some(duration) = replay.duration {
+        output.push_str(&format!(
+            "**Duration:** {}\n",
+            format_offset(duration * 1000.0).trim_start_matches('+')
+        ));
+    }
+    if let Some(user) = ["email", "username", "id", "ip"]
+        .iter()
+        .find_map(|key| replay.user.str_field(key))
+    {
+        output.push_str(&format!("**User:** {}\n", user));
+    }
+    let client: Vec<String> = [&replay.browser, &replay.os]
+        .iter()
+        .filter_map(|v| {
+            let name = v.str_field("name")?;
+            Some(match v.str_field("version") {
+                Some(version) => format!("{} {}", name, version),
+                None => name.to_string(),
+            })
+        })
+        .collect();
+    if !client.is_empty() {
+        output.push_str(&format!("**Client:** {}\n", client.join(" / ")));
+    }
+    if let Some(env) = &replay.environment {
+        output.push_str(&format!("**Environment:** {}\n", env));
+    }
+    if !replay.releases.is_empty() {
+        output.push_str(&format!("**Release:** {}\n", replay.releases.join(", ")));
+    }
+    output.push_str(&format!(
+        "**Errors:** {} | **Dead Clicks:** {} | **Rage Clicks:** {}\n",
+        replay.count_errors.unwrap_or(replay.error_ids.len() as i64),
+        replay.count_dead_clicks.unwrap_or(0),
+        replay.count_rage_clicks.unwrap_or(0)
+    ));
+    if !replay.error_ids.is_empty() {
+        output.push_str(&format!(
+            "**Error Event IDs:** {}\n",
+            replay.error_ids.join(", ")
+        ));
+    }
+    if !replay.trace_ids.is_empty() {
+        output.push_str(&format!("**Trace IDs:** {}\n", replay.trace_ids.join(", ")));
+    }
+    if !replay.urls.is_empty() {
+        output.push_str("\n## Pages Visited\n\n");
+        for url in &replay.urls {
+            output.push_str(&format!("- {}\n", url));
+        }
+    }
+}
+
+pub fn format_replay_output(replay: &Replay, timeline: &[TimelineEntry]) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Replay {}\n\n", replay.id));
+    format_metadata(replay, &mut output);
+    output.push_str("\n## Timeline\n\n");
+    if timeline.is_empty() {
+        output.push_str("No clicks, navigations or errors recorded.\n");
+        return output;
+    }
+    let skipped = timeline.len().saturating_sub(MAX_TIMELINE_ENTRIES);
+    if skipped > 0 {
+        output.push_str(&format!("{} earlier entries not shown.\n\n", skipped));
+    }
+    output.push_str("```\n");
+    for entry in &timeline[skipped..] {
+        output.push_str(&format!(
+            "{}  {:<14} {}\n",
+            format_offset(entry.offset_ms),
+            entry.kind,
+            entry.message
+        ));
+    }
+    output.push_str("```\n");
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: GetReplayDetailsInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let replay_id = input.replay_id.trim();
+    let replay = client
+        .get_replay(org, replay_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let recording = client
+        .get_replay_recording(org, &replay.project_id, replay_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let timeline = replay_timeline(&recording);
+    let output = format_replay_output(&replay, &timeline);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -1,8 +1,13 @@
-use crate::api_client::{SentryApi, TraceMeta, TraceSpan};
+use crate::api_client::{SPAN_ATTRIBUTES, SentryApi, TraceMeta, TraceSpan};
+use crate::format::{format_duration, key_values};
+use crate::json_ext::ValueExt;
+use crate::tools::graphql::summarize_graphql;
 use rmcp::{ErrorData as McpError, model::CallToolResult};
 use schemars::JsonSchema;
 use serde::Deserialize;
-use std::collections::HashMap;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 
 /// Minimum span duration in ms to be considered interesting.
 const MIN_INTERESTING_DURATION_MS: f64 = 10.0;
@@ -10,6 +15,45 @@ const MIN_INTERESTING_DURATION_MS: f64 = 10.0;
 const MAX_INTERESTING_SPANS: usize = 20;
 /// A span is "dominated" if its single child takes this fraction of its duration.
 const DOMINATED_THRESHOLD: f64 = 0.9;
+/// Siblings sharing op and description are collapsed into one line from this count on.
+const COLLAPSE_MIN_SIBLINGS: usize = 3;
+/// Maximum number of error groups listed in the errors section.
+const MAX_ERROR_GROUPS: usize = 10;
+/// Idle time before the first child (or first server span) reported as a start gap.
+const MIN_START_GAP_MS: f64 = 100.0;
+/// ... provided it is at least this fraction of the parent's duration.
+const MIN_START_GAP_SHARE: f64 = 0.1;
+/// Maximum number of performance issues listed.
+const MAX_PERFORMANCE_ISSUES: usize = 10;
+/// Performance issue type IDs (`type` on legacy trace payloads) and their names.
+const PERFORMANCE_ISSUE_TYPES: [(i64, &str); 12] = [
+    (1001, "Slow DB Query"),
+    (1004, "Render Blocking Asset"),
+    (1006, "N+1 Query"),
+    (1007, "Consecutive DB Queries"),
+    (1008, "File IO on Main Thread"),
+    (1009, "Consecutive HTTP"),
+    (1010, "N+1 API Call"),
+    (1012, "Uncompressed Asset"),
+    (1013, "DB on Main Thread"),
+    (1015, "Large HTTP Payload"),
+    (1016, "HTTP/1.1 Overhead"),
+    (1018, "Endpoint Regression"),
+];
+/// Maximum number of start gaps listed.
+const MAX_START_GAPS: usize = 5;
+/// Maximum number of profiled transactions listed.
+const MAX_PROFILES: usize = 10;
+/// Dynamic sampling context entries shown, as `(key, label)`.
+const DSC_FIELDS: [(&str, &str); 7] = [
+    ("public_key", "Public Key"),
+    ("sample_rate", "Sample Rate"),
+    ("sampled", "Sampled"),
+    ("release", "Release"),
+    ("environment", "Environment"),
+    ("transaction", "Transaction"),
+    ("user_segment", "User Segment"),
+];
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct GetTraceDetailsInput {
@@ -19,14 +63,6 @@ pub struct GetTraceDetailsInput {
     pub trace_id: String,
 }
 
-pub fn format_duration(ms: f64) -> String {
-    if ms >= 1000.0 {
-        format!("{:.2}s", ms / 1000.0)
-    } else {
-        format!("{:.2}ms", ms)
-    }
-}
-
 pub fn collect_operations(span: &TraceSpan, ops: &mut HashMap<String, (i32, f64)>) {
     if let Some(op) = &span.op {
         let entry = ops.entry(op.clone()).or_insert((0, 0.0));
@@ -38,30 +74,610 @@ pub fn collect_operations(span: &TraceSpan, ops: &mut HashMap<String, (i32, f64)
     }
 }
 
+/// Pre-OpenTelemetry names that older SDKs still put in span `data`.
+const LEGACY_ATTRIBUTES: [(&str, &str); 2] = [
+    ("http.request.method", "http.method"),
+    ("http.response.status_code", "http.status_code"),
+];
+
+/// Selected span attributes in [`SPAN_ATTRIBUTES`] order, falling back to legacy names.
+pub fn span_attributes(span: &TraceSpan) -> Vec<(&'static str, String)> {
+    SPAN_ATTRIBUTES
+        .iter()
+        .filter_map(|key| {
+            let legacy = LEGACY_ATTRIBUTES
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, old)| *old);
+            let value = span
+                .data
+                .get(*key)
+                .or_else(|| legacy.and_then(|old| span.data.get(old)))?;
+            let text = match value {
+                serde_json::Value::Null => return None,
+                serde_json::Value::String(s) if s.is_empty() => return None,
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            Some((*key, text))
+        })
+        .collect()
+}
+
+/// Mobile measurements shown in the span tree, as `(name, label, is_duration)`.
+const MOBILE_MEASUREMENTS: [(&str, &str, bool); 6] = [
+    ("app_start_cold", "app_start_cold", true),
+    ("app_start_warm", "app_start_warm", true),
+    ("time_to_initial_display", "ttid", true),
+    ("time_to_full_display", "ttfd", true),
+    ("frames_slow", "frames_slow", false),
+    ("frames_frozen", "frames_frozen", false),
+];
+
+fn measurement(span: &TraceSpan, name: &str) -> Option<f64> {
+    let value = span
+        .measurements
+        .get(name)
+        .or_else(|| span.measurements.get(format!("measurements.{}", name)))?;
+    value
+        .as_f64()
+        .or_else(|| value.get("value").and_then(Value::as_f64))
+}
+
+/// Mobile measurements of a span in [`MOBILE_MEASUREMENTS`] order; frame counts
+/// include their share of `frames_total` when it is known.
+pub fn span_measurements(span: &TraceSpan) -> Vec<(&'static str, String)> {
+    let total = measurement(span, "frames_total").filter(|t| *t > 0.0);
+    MOBILE_MEASUREMENTS
+        .iter()
+        .filter_map(|(name, label, is_duration)| {
+            let value = measurement(span, name)?;
+            let text = match (is_duration, total) {
+                (true, _) => format_duration(value),
+                (false, Some(total)) => {
+                    format!("{:.0} ({:.1}%)", value, value / total * 100.0)
+                }
+                (false, None) => format!("{:.0}", value),
+            };
+            Some((*label, text))
+        })
+        .collect()
+}
+
+fn format_attributes(span: &TraceSpan) -> String {
+    let mut attributes = span_attributes(span);
+    attributes.extend(span_measurements(span));
+    if attributes.is_empty() {
+        return String::new();
+    }
+    format!(" {{{}}}", key_values(attributes))
+}
+
 pub fn format_span_tree(span: &TraceSpan, depth: usize, output: &mut String) {
     let indent = "  ".repeat(depth);
     let duration = format_duration(span.duration);
+    let (op, desc) = span_label(span);
+    let has_errors = !span.errors.is_empty();
+    let status_icon = if has_errors { "✗" } else { "✓" };
+    let tx_marker = if span.is_transaction { " [tx]" } else { "" };
+    let perf_marker = if span.occurrences.is_empty() {
+        String::new()
+    } else {
+        let titles: Vec<String> = span.occurrences.iter().map(occurrence_title).collect();
+        format!(" ⚠ {}", titles.join(", "))
+    };
+    output.push_str(&format!(
+        "{}{} [{}] {}{} ({}) {}{}{}\n",
+        indent,
+        status_icon,
+        op,
+        desc,
+        format_attributes(span),
+        duration,
+        span.project_slug,
+        tx_marker,
+        perf_marker
+    ));
+    format_span_siblings(&span.children, depth + 1, output);
+}
+
+/// `(op, description)` of a span.
+type SpanLabel<'a> = (&'a str, Cow<'a, str>);
+
+/// Op and description of a span; GraphQL documents are summarized to their
+/// operation and top-level fields.
+fn span_label(span: &TraceSpan) -> SpanLabel<'_> {
     let op = span.op.as_deref().unwrap_or("unknown");
     let desc = span
         .description
         .as_deref()
         .or(span.transaction.as_deref())
         .unwrap_or("(no description)");
-    let has_errors = !span.errors.is_empty();
-    let status_icon = if has_errors { "✗" } else { "✓" };
-    let tx_marker = if span.is_transaction { " [tx]" } else { "" };
-    output.push_str(&format!(
-        "{}{} [{}] {} ({}) {}{}\n",
-        indent, status_icon, op, desc, duration, span.project_slug, tx_marker
-    ));
-    for child in &span.children {
-        format_span_tree(child, depth + 1, output);
+    match summarize_graphql(desc) {
+        Some(summary) => (op, Cow::Owned(summary)),
+        None => (op, Cow::Borrowed(desc)),
+    }
+}
+
+/// Renders sibling spans, collapsing runs of identical op+description into one `×N` line.
+pub fn format_span_siblings(spans: &[TraceSpan], depth: usize, output: &mut String) {
+    let mut groups: Vec<(SpanLabel<'_>, Vec<&TraceSpan>)> = Vec::new();
+    for span in spans {
+        let label = span_label(span);
+        match groups.iter_mut().find(|(key, _)| *key == label) {
+            Some((_, members)) => members.push(span),
+            None => groups.push((label, vec![span])),
+        }
+    }
+    for ((op, desc), members) in groups {
+        if members.len() < COLLAPSE_MIN_SIBLINGS {
+            for span in members {
+                format_span_tree(span, depth, output);
+            }
+            continue;
+        }
+        let total: f64 = members.iter().map(|s| s.duration).sum();
+        let failed = members.iter().filter(|s| !s.errors.is_empty()).count();
+        let status_icon = if failed > 0 { "✗" } else { "✓" };
+        output.push_str(&format!(
+            "{}{} [{}] {} ×{} ({} total, {} avg) {}\n",
+            "  ".repeat(depth),
+            status_icon,
+            op,
+            desc,
+            members.len(),
+            format_duration(total),
+            format_duration(total / members.len() as f64),
+            members[0].project_slug
+        ));
+    }
+}
+
+/// Status code of an `http.client`/`http.server` span, if it recorded one.
+pub fn http_status_code(span: &TraceSpan) -> Option<u16> {
+    let op = span.op.as_deref()?;
+    if !(op.starts_with("http.client") || op.starts_with("http.server")) {
+        return None;
+    }
+    span_attributes(span)
+        .into_iter()
+        .find(|(key, _)| *key == "http.response.status_code")
+        .and_then(|(_, value)| value.parse().ok())
+}
+
+/// HTTP span counts by status class, plus failing (4xx/5xx) requests.
+#[derive(Debug, Default, PartialEq)]
+pub struct HttpStatusRollup {
+    /// Span count per class such as `"2xx"`.
+    pub classes: BTreeMap<String, usize>,
+    /// `(status code, request) -> count` for responses of 400 and above.
+    pub failing: BTreeMap<(u16, String), usize>,
+}
+
+pub fn rollup_http_statuses(spans: &[TraceSpan]) -> HttpStatusRollup {
+    fn visit(span: &TraceSpan, rollup: &mut HttpStatusRollup) {
+        if let Some(code) = http_status_code(span) {
+            *rollup
+                .classes
+                .entry(format!("{}xx", code / 100))
+                .or_default() += 1;
+            if code >= 400 {
+                let request = span
+                    .description
+                    .as_deref()
+                    .or(span.transaction.as_deref())
+                    .unwrap_or("(no description)")
+                    .to_string();
+                *rollup.failing.entry((code, request)).or_default() += 1;
+            }
+        }
+        for child in &span.children {
+            visit(child, rollup);
+        }
+    }
+    let mut rollup = HttpStatusRollup::default();
+    for span in spans {
+        visit(span, &mut rollup);
+    }
+    rollup
+}
+
+fn format_http_status_section(spans: &[TraceSpan], output: &mut String) {
+    let rollup = rollup_http_statuses(spans);
+    if rollup.classes.is_empty() {
+        return;
+    }
+    output.push_str("\n## HTTP Status Codes\n\n");
+    for (class, count) in &rollup.classes {
+        output.push_str(&format!("- **{}**: {}\n", class, count));
+    }
+    if rollup.failing.is_empty() {
+        return;
+    }
+    output.push_str("\n**Failing requests:**\n");
+    let mut failing: Vec<_> = rollup.failing.iter().collect();
+    failing.sort_by_key(|(_, count)| std::cmp::Reverse(**count));
+    for ((code, request), count) in failing {
+        let times = if *count > 1 {
+            format!(" (×{})", count)
+        } else {
+            String::new()
+        };
+        output.push_str(&format!("- {} {}{}\n", code, request, times));
+    }
+}
+
+/// Errors of one title attached to spans of the trace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceErrorGroup {
+    pub title: String,
+    pub level: Option<String>,
+    pub count: usize,
+    /// Short ID (or numeric ID) of the issue the errors belong to.
+    pub issue: Option<String>,
+    /// Op and description of the span with the earliest error.
+    pub first_span: (String, String),
+    /// Timestamp of the earliest error (the span start when errors carry none).
+    pub first_at: f64,
+}
+
+/// Groups the errors attached to spans by title, most frequent first.
+pub fn summarize_trace_errors(spans: &[TraceSpan]) -> Vec<TraceErrorGroup> {
+    fn visit(span: &TraceSpan, groups: &mut Vec<TraceErrorGroup>) {
+        for error in &span.errors {
+            let title = error.str_field("title").unwrap_or("(untitled error)");
+            let at = error
+                .get("timestamp")
+                .and_then(|t| t.as_f64())
+                .unwrap_or(span.start_timestamp);
+            let (op, desc) = span_label(span);
+            let group = match groups.iter_mut().find(|g| g.title == title) {
+                Some(group) => group,
+                None => {
+                    groups.push(TraceErrorGroup {
+                        title: title.to_string(),
+                        level: error.str_field("level").map(str::to_string),
+                        count: 0,
+                        issue: None,
+                        first_span: (op.to_string(), desc.to_string()),
+                        first_at: at,
+                    });
+                    groups.last_mut().expect("just pushed")
+                }
+            };
+            group.count += 1;
+            if group.issue.is_none() {
+                group.issue = error
+                    .str_field("issue_short_id")
+                    .map(str::to_string)
+                    .or_else(|| error.get("issue_id").map(|id| id.to_string()));
+            }
+            if at < group.first_at {
+                group.first_at = at;
+                group.first_span = (op.to_string(), desc.to_string());
+            }
+        }
+        for child in &span.children {
+            visit(child, groups);
+        }
+    }
+    let mut groups = Vec::new();
+    for span in spans {
+        visit(span, &mut groups);
+    }
+    groups.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then(a.first_at.total_cmp(&b.first_at))
+    });
+    groups
+}
+
+/// A performance issue occurrence attached to a span.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceOccurrence {
+    pub title: String,
+    /// Issue type, e.g. `performance_n_plus_one_db_queries`, when the payload names it.
+    pub issue_type: Option<String>,
+    /// Short ID (or numeric ID) of the performance issue.
+    pub issue: Option<String>,
+    /// Op and description of the span the occurrence is attached to.
+    pub span: (String, String),
+    /// The repeated span description (N+1 and consecutive query issues).
+    pub repeating: Option<String>,
+    /// Offending spans, e.g. the repeated queries of an N+1.
+    pub offenders: Option<usize>,
+}
+
+fn occurrence_title(occurrence: &Value) -> String {
+    occurrence
+        .str_field("title")
+        .or_else(|| {
+            let kind = occurrence.i64_field("type")?;
+            PERFORMANCE_ISSUE_TYPES
+                .iter()
+                .find(|(id, _)| *id == kind)
+                .map(|(_, name)| *name)
+        })
+        .or_else(|| occurrence.str_field("description"))
+        .unwrap_or("(untitled performance issue)")
+        .to_string()
+}
+
+fn repeating_description(evidence: &Value) -> Option<String> {
+    let value = evidence
+        .get("repeating_spans_compact")
+        .or_else(|| evidence.get("repeating_spans"))?;
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Array(items) => {
+            let parts: Vec<&str> = items.iter().filter_map(Value::as_str).collect();
+            (!parts.is_empty()).then(|| parts.join(", "))
+        }
+        _ => None,
+    }
+    .filter(|s| !s.is_empty())
+}
+
+fn offender_count(occurrence: &Value, evidence: &Value) -> Option<usize> {
+    if let Some(n) = evidence.i64_field("num_repeating_spans") {
+        return usize::try_from(n).ok();
     }
+    evidence
+        .array_field("offender_span_ids")
+        .or_else(|| occurrence.array_field("suspect_spans"))
+        .map(Vec::len)
+}
+
+/// Reads the performance issue occurrences of a span: title (or type), issue,
+/// and the evidence Sentry attaches, when present.
+pub fn parse_occurrence(span: &TraceSpan, occurrence: &Value) -> TraceOccurrence {
+    let evidence = occurrence.get("evidence_data").unwrap_or(&Value::Null);
+    let (op, desc) = span_label(span);
+    TraceOccurrence {
+        title: occurrence_title(occurrence),
+        issue_type: occurrence.str_field("issue_type").map(str::to_string),
+        issue: occurrence
+            .str_field("issue_short_id")
+            .or_else(|| occurrence.str_field("short_id"))
+            .map(str::to_string)
+            .or_else(|| occurrence.get("issue_id").map(|id| id.to_string())),
+        span: (op.to_string(), desc.to_string()),
+        repeating: repeating_description(evidence),
+        offenders: offender_count(occurrence, evidence),
+    }
+}
+
+/// Every performance issue occurrence in the trace, in span tree order.
+pub fn collect_occurrences(spans: &[TraceSpan]) -> Vec<TraceOccurrence> {
+    fn visit(span: &TraceSpan, out: &mut Vec<TraceOccurrence>) {
+        for occurrence in &span.occurrences {
+            out.push(parse_occurrence(span, occurrence));
+        }
+        for child in &span.children {
+            visit(child, out);
+        }
+    }
+    let mut out = Vec::new();
+    for span in spans {
+        visit(span, &mut out);
+    }
+    out
+}
+
+fn format_occurrences_section(spans: &[TraceSpan], output: &mut String) {
+    let occurrences = collect_occurrences(spans);
+    if occurrences.is_empty() {
+        return;
+    }
+    output.push_str("\n## Performance Issues\n\n");
+    for occurrence in occurrences.iter().take(MAX_PERFORMANCE_ISSUES) {
+        output.push_str(&format!("- **{}**", occurrence.title));
+        if let Some(issue_type) = &occurrence.issue_type {
+            output.push_str(&format!(" [{}]", issue_type));
+        }
+        if let Some(issue) = &occurrence.issue {
+            output.push_str(&format!(" (issue {})", issue));
+        }
+        output.push_str(&format!(
+            "; in [{}] {}\n",
+            occurrence.span.0, occurrence.span.1
+        ));
+        if let Some(repeating) = &occurrence.repeating {
+            output.push_str(&format!("  - Repeating: `{}`\n", repeating));
+        }
+        if let Some(offenders) = occurrence.offenders {
+            output.push_str(&format!("  - Offending spans: {}\n", offenders));
+        }
+    }
+    if occurrences.len() > MAX_PERFORMANCE_ISSUES {
+        output.push_str(&format!(
+            "- ... and {} more\n",
+            occurrences.len() - MAX_PERFORMANCE_ISSUES
+        ));
+    }
+}
+
+fn format_errors_section(spans: &[TraceSpan], output: &mut String) {
+    let groups = summarize_trace_errors(spans);
+    if groups.is_empty() {
+        return;
+    }
+    output.push_str("\n## Errors\n\n");
+    for group in groups.iter().take(MAX_ERROR_GROUPS) {
+        output.push_str(&format!("- **{}** ×{}", group.title, group.count));
+        if let Some(level) = &group.level {
+            output.push_str(&format!(" [{}]", level));
+        }
+        if let Some(issue) = &group.issue {
+            output.push_str(&format!(" (issue {})", issue));
+        }
+        output.push_str(&format!(
+            "; first in [{}] {}\n",
+            group.first_span.0, group.first_span.1
+        ));
+    }
+    if groups.len() > MAX_ERROR_GROUPS {
+        output.push_str(&format!(
+            "- ... and {} more\n",
+            groups.len() - MAX_ERROR_GROUPS
+        ));
+    }
+}
+
+/// Where a start gap was measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartGapKind {
+    /// Between a span's start and the start of its first child.
+    FirstChild,
+    /// Between the start of the trace and its first `http.server` span.
+    FirstServerSpan,
+}
+
+/// Time nothing was recorded at the start of a span or of the trace, typically
+/// spent queueing or on a cold start.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartGap {
+    pub kind: StartGapKind,
+    pub op: String,
+    pub description: String,
+    pub project_slug: String,
+    pub gap_ms: f64,
+}
+
+fn start_gap(kind: StartGapKind, span: &TraceSpan, gap_ms: f64) -> StartGap {
+    let (op, desc) = span_label(span);
+    StartGap {
+        kind,
+        op: op.to_string(),
+        description: desc.to_string(),
+        project_slug: span.project_slug.clone(),
+        gap_ms,
+    }
+}
+
+/// Gaps of at least [`MIN_START_GAP_MS`] before a span's first child or before the
+/// trace's first server span, largest first.
+pub fn find_start_gaps(spans: &[TraceSpan]) -> Vec<StartGap> {
+    fn visit(span: &TraceSpan, gaps: &mut Vec<StartGap>) {
+        let first_child = span
+            .children
+            .iter()
+            .map(|c| c.start_timestamp)
+            .filter(|t| *t > 0.0)
+            .min_by(f64::total_cmp);
+        if let Some(first_child) = first_child
+            && span.start_timestamp > 0.0
+        {
+            let gap_ms = (first_child - span.start_timestamp) * 1000.0;
+            if gap_ms >= MIN_START_GAP_MS && gap_ms >= span.duration * MIN_START_GAP_SHARE {
+                gaps.push(start_gap(StartGapKind::FirstChild, span, gap_ms));
+            }
+        }
+        for child in &span.children {
+            visit(child, gaps);
+        }
+    }
+    fn first_server_span<'a>(span: &'a TraceSpan, best: &mut Option<&'a TraceSpan>) {
+        let is_server = span
+            .op
+            .as_deref()
+            .is_some_and(|op| op.starts_with("http.server"));
+        if is_server
+            && span.start_timestamp > 0.0
+            && best.is_none_or(|b| span.start_timestamp < b.start_timestamp)
+        {
+            *best = Some(span);
+        }
+        for child in &span.children {
+            first_server_span(child, best);
+        }
+    }
+    let mut gaps = Vec::new();
+    for span in spans {
+        visit(span, &mut gaps);
+    }
+    let (trace_start, _) = compute_time_range(spans);
+    let mut server = None;
+    for span in spans {
+        first_server_span(span, &mut server);
+    }
+    if let Some(server) = server
+        && trace_start < f64::MAX
+    {
+        let gap_ms = (server.start_timestamp - trace_start) * 1000.0;
+        if gap_ms >= MIN_START_GAP_MS {
+            gaps.push(start_gap(StartGapKind::FirstServerSpan, server, gap_ms));
+        }
+    }
+    gaps.sort_by(|a, b| b.gap_ms.total_cmp(&a.gap_ms));
+    gaps
+}
+
+fn format_start_gaps_section(spans: &[TraceSpan], output: &mut String) {
+    let gaps = find_start_gaps(spans);
+    if gaps.is_empty() {
+        return;
+    }
+    output.push_str("\n## Start Gaps (queue / cold start)\n\n");
+    for gap in gaps.iter().take(MAX_START_GAPS) {
+        let location = match gap.kind {
+            StartGapKind::FirstChild => "before the first child of",
+            StartGapKind::FirstServerSpan => "between trace start and",
+        };
+        output.push_str(&format!(
+            "- {} {} [{}] {} ({})\n",
+            format_duration(gap.gap_ms),
+            location,
+            gap.op,
+            gap.description,
+            gap.project_slug
+        ));
+    }
+    output.push_str(
+        "\nNo span covers these gaps; the time usually went to request queueing, cold starts or uninstrumented code.\n",
+    );
 }
 
 /// Filter spans to show only interesting ones for display.
 /// Always includes transactions, spans with errors, and spans >= MIN_INTERESTING_DURATION_MS.
 /// Sorted by duration, truncated to max_spans.
+/// Transactions with a profile, as `(transaction, project_slug, profile_id)`.
+pub fn collect_profiles(spans: &[TraceSpan]) -> Vec<(&str, &str, &str)> {
+    fn visit<'a>(span: &'a TraceSpan, out: &mut Vec<(&'a str, &'a str, &'a str)>) {
+        if let Some(profile_id) = span.profile_id.as_deref().filter(|p| !p.is_empty()) {
+            let name = span
+                .transaction
+                .as_deref()
+                .or(span.description.as_deref())
+                .unwrap_or("unknown");
+            out.push((name, span.project_slug.as_str(), profile_id));
+        }
+        for child in &span.children {
+            visit(child, out);
+        }
+    }
+    let mut profiles = Vec::new();
+    for span in spans {
+        visit(span, &mut profiles);
+    }
+    profiles.dedup_by(|a, b| a.2 == b.2);
+    profiles
+}
+
+fn format_profiles_section(spans: &[TraceSpan], output: &mut String) {
+    let profiles = collect_profiles(spans);
+    if profiles.is_empty() {
+        return;
+    }
+    output.push_str("\n## Profiles\n\n");
+    for (name, project, profile_id) in profiles.iter().take(MAX_PROFILES) {
+        output.push_str(&format!("- {} ({}): `{}`\n", name, project, profile_id));
+    }
+    output
+        .push_str("\nUse get_profile with a project and profile ID to see where CPU time went.\n");
+}
+
 pub fn select_interesting_spans(spans: &[TraceSpan], max_spans: usize) -> Vec<TraceSpan> {
     let mut collected: Vec<TraceSpan> = Vec::new();
     for span in spans {
@@ -82,6 +698,7 @@ fn collect_interesting(span: &TraceSpan, out: &mut Vec<TraceSpan>) {
 
     let is_interesting = span.is_transaction
         || !span.errors.is_empty()
+        || !span.occurrences.is_empty()
         || span.duration >= MIN_INTERESTING_DURATION_MS;
 
     if !dominated_skip && is_interesting {
@@ -95,10 +712,57 @@ fn collect_interesting(span: &TraceSpan, out: &mut Vec<TraceSpan>) {
     }
 }
 
+fn dsc_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Renders the dynamic sampling context the trace's head SDK propagated in
+/// `baggage`, which every downstream service used for its sampling decision.
+fn format_sampling_context_section(dsc: &Value, output: &mut String) {
+    let entries: Vec<(&str, String)> = DSC_FIELDS
+        .iter()
+        .filter_map(|(key, label)| dsc.get(*key).and_then(dsc_value).map(|v| (*label, v)))
+        .collect();
+    if entries.is_empty() {
+        return;
+    }
+    output.push_str("\n## Sampling Context (baggage)\n\n");
+    for (label, value) in &entries {
+        output.push_str(&format!("- **{}:** {}\n", label, value));
+    }
+    let rate = dsc.get("sample_rate").and_then(|v| match v {
+        Value::String(s) => s.parse::<f64>().ok(),
+        other => other.as_f64(),
+    });
+    if let Some(rate) = rate.filter(|r| *r < 1.0) {
+        output.push_str(&format!(
+            "\nThe trace was head-sampled at rate {}. Services that ignore incoming baggage sample on their own and can be missing from the trace.\n",
+            rate
+        ));
+    }
+}
+
+/// The root transaction's event ID and project, whose payload carries the trace's
+/// dynamic sampling context.
+fn root_transaction(spans: &[TraceSpan]) -> Option<(&str, &str)> {
+    spans.iter().find(|s| s.is_transaction).map(|s| {
+        (
+            s.transaction_id.as_deref().unwrap_or(&s.event_id),
+            s.project_slug.as_str(),
+        )
+    })
+}
+
 pub fn format_trace_output(
     trace_id: &str,
     spans: &[TraceSpan],
     meta: Option<&TraceMeta>,
+    dsc: Option<&Value>,
 ) -> String {
     let mut output = String::new();
     output.push_str("# Trace Details\n\n");
@@ -110,6 +774,9 @@ pub fn format_trace_output(
     if let Some(meta) = meta {
         output.push_str(&format!("**Total Spans:** {}\n", meta.span_count as i64));
         output.push_str(&format!("**Errors:** {}\n", meta.errors));
+        if meta.logs > 0 {
+            output.push_str(&format!("**Logs:** {} (see get_trace_logs)\n", meta.logs));
+        }
         output.push_str(&format!(
             "**Performance Issues:** {}\n",
             meta.performance_issues
@@ -132,6 +799,10 @@ pub fn format_trace_output(
         }
     }
 
+    if let Some(dsc) = dsc {
+        format_sampling_context_section(dsc, &mut output);
+    }
+
     if let Some(meta) = meta
         && !meta.span_count_map.is_empty()
     {
@@ -161,11 +832,15 @@ pub fn format_trace_output(
         }
     }
 
+    format_errors_section(spans, &mut output);
+    format_occurrences_section(spans, &mut output);
+    format_start_gaps_section(spans, &mut output);
+    format_profiles_section(spans, &mut output);
+    format_http_status_section(spans, &mut output);
+
     let interesting = select_interesting_spans(spans, MAX_INTERESTING_SPANS);
     output.push_str("\n## Span Tree\n\n```\n");
-    for span in &interesting {
-        format_span_tree(span, 0, &mut output);
-    }
+    format_span_siblings(&interesting, 0, &mut output);
     output.push_str("```\n");
 
     output
@@ -215,7 +890,15 @@ pub async fn execute(
         .get_trace_meta(&input.organization_slug, &input.trace_id)
         .await
         .ok();
-    let output = format_trace_output(&input.trace_id, &trace, meta.as_ref());
+    let mut dsc = None;
+    if let Some((event_id, project_slug)) = root_transaction(&trace) {
+        dsc = client
+            .get_event_json(&input.organization_slug, project_slug, event_id)
+            .await
+            .ok()
+            .and_then(|event| event.get("_dsc").cloned());
+    }
+    let output = format_trace_output(&input.trace_id, &trace, meta.as_ref(), dsc.as_ref());
     Ok(CallToolResult::success(vec![rmcp::model::Content::text(
         output,
     )]))
@@ -0,0 +1,153 @@
+use crate::api_client::{DiscoverQuery, DiscoverResult, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+const FIELDS: [&str; 4] = ["timestamp", "severity", "message", "project"];
+/// Severities in display order for the per-severity counts.
+const SEVERITIES: [&str; 6] = ["fatal", "error", "warn", "info", "debug", "trace"];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetTraceLogsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Trace ID (32-character hex string)")]
+    pub trace_id: String,
+    #[schemars(description = "Only logs of this severity, e.g. 'error' or 'warn' (optional)")]
+    pub severity: Option<String>,
+    #[schemars(description = "Extra log search filter, e.g. 'message:*timeout*' (optional)")]
+    pub query: Option<String>,
+    #[schemars(description = "Maximum number of logs (default: 100, max: 100)")]
+    pub limit: Option<i32>,
+}
+
+/// Builds the log search query for a trace.
+pub fn build_logs_query(trace_id: &str, severity: Option<&str>, query: Option<&str>) -> String {
+    let mut terms = vec![format!("trace:{}", trace_id)];
+    if let Some(severity) = severity.map(str::trim).filter(|s| !s.is_empty()) {
+        terms.push(format!("severity:{}", severity.to_ascii_lowercase()));
+    }
+    if let Some(query) = query.map(str::trim).filter(|q| !q.is_empty()) {
+        terms.push(query.to_string());
+    }
+    terms.join(" ")
+}
+
+fn text<'a>(row: &'a Map<String, Value>, field: &str) -> &'a str {
+    row.get(field).and_then(Value::as_str).unwrap_or("")
+}
+
+/// Severity of a log row, lowercased, with `warning` folded into `warn`.
+fn severity(row: &Map<String, Value>) -> String {
+    match text(row, "severity").to_ascii_lowercase().as_str() {
+        "" => "info".to_string(),
+        "warning" => "warn".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Counts log rows per severity, most severe first; unknown severities go last.
+pub fn count_by_severity(rows: &[Map<String, Value>]) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for row in rows {
+        let severity = severity(row);
+        match counts.iter_mut().find(|(s, _)| *s == severity) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((severity, 1)),
+        }
+    }
+    counts.sort_by_key(|(s, _)| {
+        SEVERITIES
+            .iter()
+            .position(|known| known == s)
+            .unwrap_or(SEVERITIES.len())
+    });
+    counts
+}
+
+/// One log line: timestamp, padded severity, project and message, with
+/// continuation lines of multi-line messages indented.
+pub fn format_log_line(row: &Map<String, Value>) -> String {
+    format!(
+        "{} {:<5} [{}] {}",
+        text(row, "timestamp"),
+        severity(row).to_ascii_uppercase(),
+        text(row, "project"),
+        text(row, "message").trim_end().replace('\n', "\n    ")
+    )
+}
+
+pub fn format_trace_logs_output(
+    trace_id: &str,
+    result: &DiscoverResult,
+    total_logs: Option<i64>,
+) -> String {
+    let mut output = String::new();
+    output.push_str("# Trace Logs\n\n");
+    output.push_str(&format!("**Trace ID:** {}\n", trace_id));
+    match total_logs {
+        Some(total) if total > result.data.len() as i64 => output.push_str(&format!(
+            "**Logs:** {} shown of {} in the trace\n",
+            result.data.len(),
+            total
+        )),
+        _ => output.push_str(&format!("**Logs:** {}\n", result.data.len())),
+    }
+    if result.data.is_empty() {
+        output.push_str("\nNo logs found for this trace.\n");
+        return output;
+    }
+    let counts: Vec<String> = count_by_severity(&result.data)
+        .iter()
+        .map(|(severity, count)| format!("{} {}", severity, count))
+        .collect();
+    output.push_str(&format!("**By Severity:** {}\n", counts.join(", ")));
+    output.push_str("\n```\n");
+    for row in &result.data {
+        output.push_str(&format_log_line(row));
+        output.push('\n');
+    }
+    output.push_str("```\n");
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: GetTraceLogsInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let trace_id = input.trace_id.trim();
+    let query = DiscoverQuery {
+        fields: FIELDS.iter().map(|f| f.to_string()).collect(),
+        query: Some(build_logs_query(
+            trace_id,
+            input.severity.as_deref(),
+            input.query.as_deref(),
+        )),
+        sort: Some("timestamp".to_string()),
+        stats_period: Some("14d".to_string()),
+        dataset: Some("ourlogs".to_string()),
+        per_page: Some(input.limit.unwrap_or(100).clamp(1, 100)),
+        ..Default::default()
+    };
+    let result = client
+        .query_events(org, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    // The log total only applies when the whole trace is shown unfiltered.
+    let unfiltered = input.severity.is_none() && input.query.is_none();
+    let total_logs = if unfiltered {
+        client
+            .get_trace_meta(org, trace_id)
+            .await
+            .ok()
+            .map(|meta| meta.logs)
+    } else {
+        None
+    };
+    let output = format_trace_logs_output(trace_id, &result, total_logs);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,166 @@
+use std::iter::Peekable;
+
+/// Top-level fields listed before the rest are counted.
+const MAX_FIELDS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Name(&'a str),
+    Punct(char),
+    Spread,
+}
+
+/// Splits a GraphQL document into names, punctuators and spreads. Strings, numbers,
+/// comments and commas carry nothing the summary needs and are dropped.
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        match c {
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'"' if bytes[i..].starts_with(b"\"\"\"") => {
+                i += 3;
+                while i < bytes.len() && !bytes[i..].starts_with(b"\"\"\"") {
+                    i += 1;
+                }
+                i += 3;
+            }
+            b'"' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                i += 1;
+            }
+            b'.' if bytes[i..].starts_with(b"...") => {
+                tokens.push(Token::Spread);
+                i += 3;
+            }
+            c if c.is_ascii_alphabetic() || c == b'_' => {
+                let start = i;
+                while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                    i += 1;
+                }
+                tokens.push(Token::Name(&text[start..i]));
+            }
+            c if c.is_ascii_whitespace() || c == b',' || c.is_ascii_digit() || c == b'-' => {
+                i += 1;
+            }
+            c if c.is_ascii() => {
+                tokens.push(Token::Punct(c as char));
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    tokens
+}
+
+type Tokens<'a> = Peekable<std::vec::IntoIter<Token<'a>>>;
+
+/// Reads the operation type and name, then skips variable definitions and
+/// directives up to the opening brace of the selection set.
+fn operation_header<'a>(tokens: &mut Tokens<'a>) -> Option<(&'a str, Option<&'a str>)> {
+    let header = match tokens.peek()? {
+        Token::Punct('{') => ("query", None),
+        Token::Name(kind @ ("query" | "mutation" | "subscription")) => {
+            let kind = *kind;
+            tokens.next();
+            match tokens.peek() {
+                Some(Token::Name(name)) => (kind, Some(*name)),
+                _ => (kind, None),
+            }
+        }
+        _ => return None,
+    };
+    let mut parens = 0;
+    loop {
+        match tokens.next()? {
+            Token::Punct('(') => parens += 1,
+            Token::Punct(')') => parens -= 1,
+            Token::Punct('{') if parens == 0 => return Some(header),
+            _ => {}
+        }
+    }
+}
+
+/// Field entry for a name at the top level of the selection set, given whether it
+/// follows a spread: fragment spreads, inline fragments and aliased fields.
+fn field_entry(name: &str, spread: bool, tokens: &mut Tokens<'_>) -> Option<String> {
+    if spread && name == "on" {
+        return match tokens.next() {
+            Some(Token::Name(type_name)) => Some(format!("... on {}", type_name)),
+            _ => None,
+        };
+    }
+    if spread {
+        return Some(format!("...{}", name));
+    }
+    // `alias: field` lists the field itself.
+    if tokens.peek() == Some(&Token::Punct(':')) {
+        tokens.next();
+        return match tokens.next() {
+            Some(Token::Name(aliased)) => Some(aliased.to_string()),
+            _ => None,
+        };
+    }
+    Some(name.to_string())
+}
+
+/// Collects the fields of the selection set whose opening brace was just read.
+fn top_level_fields(tokens: &mut Tokens<'_>) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut depth = 1;
+    let mut parens = 0;
+    let mut spread = false;
+    let mut directive = false;
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Punct('(') => parens += 1,
+            Token::Punct(')') => parens -= 1,
+            Token::Punct('{') if parens == 0 => depth += 1,
+            Token::Punct('}') if parens == 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            _ if depth != 1 || parens > 0 => {}
+            Token::Spread => spread = true,
+            Token::Punct('@') => directive = true,
+            Token::Name(_) if directive => directive = false,
+            Token::Name(name) => {
+                fields.extend(field_entry(name, spread, tokens));
+                spread = false;
+            }
+            _ => {}
+        }
+    }
+    fields
+}
+
+/// Summarizes a GraphQL document as its operation and top-level fields, e.g.
+/// `query GetOrder { order, viewer }`. Returns `None` for text that is not a
+/// GraphQL operation with a selection set.
+pub fn summarize_graphql(text: &str) -> Option<String> {
+    let mut tokens = tokenize(text).into_iter().peekable();
+    let (kind, name) = operation_header(&mut tokens)?;
+    let fields = top_level_fields(&mut tokens);
+    if fields.is_empty() {
+        return None;
+    }
+    let mut shown = fields[..fields.len().min(MAX_FIELDS)].join(", ");
+    if fields.len() > MAX_FIELDS {
+        shown.push_str(&format!(", +{} more", fields.len() - MAX_FIELDS));
+    }
+    Some(match name {
+        Some(name) => format!("{} {} {{ {} }}", kind, name, shown),
+        None => format!("{} {{ {} }}", kind, shown),
+    })
+}
@@ -0,0 +1,215 @@
+use crate::api_client::{EventAttachment, SentryApi};
+use crate::tools::event_attachments::looks_binary;
+use regex::{Regex, RegexBuilder};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Attachments larger than this are not downloaded.
+const MAX_ATTACHMENT_BYTES: u64 = 10 * 1024 * 1024;
+const MAX_LINE_CHARS: usize = 500;
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GrepAttachmentInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(description = "Event ID the attachment belongs to")]
+    pub event_id: String,
+    #[schemars(
+        description = "Attachment ID (optional when the event has exactly one non-minidump attachment)"
+    )]
+    pub attachment_id: Option<String>,
+    #[schemars(description = "Regular expression to search for (Rust regex syntax)")]
+    pub pattern: String,
+    #[schemars(description = "Match case-insensitively (default: false)")]
+    pub case_insensitive: Option<bool>,
+    #[schemars(description = "Lines of context around each match (default: 2, max: 10)")]
+    pub context: Option<usize>,
+    #[schemars(description = "Maximum matching lines to return (default: 50, max: 200)")]
+    pub max_matches: Option<usize>,
+}
+
+/// One contiguous run of lines: matches plus their surrounding context.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrepBlock<'a> {
+    /// `(1-based line number, is_match, text)`.
+    pub lines: Vec<(usize, bool, &'a str)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrepResult<'a> {
+    pub blocks: Vec<GrepBlock<'a>>,
+    pub matches: usize,
+    /// More lines matched than `max_matches`.
+    pub truncated: bool,
+}
+
+/// Finds lines matching `regex`, merging overlapping context windows into blocks.
+pub fn grep_lines<'a>(
+    text: &'a str,
+    regex: &Regex,
+    context: usize,
+    max_matches: usize,
+) -> GrepResult<'a> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut blocks: Vec<GrepBlock> = Vec::new();
+    let mut matches = 0;
+    let mut truncated = false;
+    let mut last_emitted: Option<usize> = None;
+    for (i, line) in lines.iter().enumerate() {
+        if !regex.is_match(line) {
+            continue;
+        }
+        if matches == max_matches {
+            truncated = true;
+            break;
+        }
+        matches += 1;
+        let start = i.saturating_sub(context);
+        let end = (i + context).min(lines.len() - 1);
+        let from = match last_emitted {
+            Some(last) if last + 1 >= start => last + 1,
+            _ => {
+                blocks.push(GrepBlock { lines: vec![] });
+                start
+            }
+        };
+        let block = blocks.last_mut().expect("block pushed above");
+        // A match inside the previous context window is re-flagged rather than re-emitted.
+        if let Some(entry) = block.lines.iter_mut().find(|(n, _, _)| *n == i + 1) {
+            entry.1 = true;
+        }
+        for (j, l) in lines.iter().enumerate().take(end + 1).skip(from) {
+            block.lines.push((j + 1, j == i, l));
+        }
+        last_emitted = Some(last_emitted.map_or(end, |last| last.max(end)));
+    }
+    GrepResult {
+        blocks,
+        matches,
+        truncated,
+    }
+}
+
+fn truncate_line(line: &str) -> String {
+    if line.chars().count() <= MAX_LINE_CHARS {
+        return line.to_string();
+    }
+    let cut: String = line.chars().take(MAX_LINE_CHARS).collect();
+    format!("{}…", cut)
+}
+
+/// Picks the requested attachment, or the only non-minidump attachment.
+pub fn select_text_attachment<'a>(
+    attachments: &'a [EventAttachment],
+    attachment_id: Option<&str>,
+) -> Result<&'a EventAttachment, String> {
+    if let Some(id) = attachment_id {
+        return attachments
+            .iter()
+            .find(|a| a.id == id)
+            .ok_or_else(|| format!("Attachment {} not found on this event", id));
+    }
+    let candidates: Vec<&EventAttachment> = attachments
+        .iter()
+        .filter(|a| a.attachment_type != "event.minidump")
+        .collect();
+    match candidates.as_slice() {
+        [only] => Ok(only),
+        [] => Err("Event has no text attachments".to_string()),
+        many => Err(format!(
+            "Event has {} attachments; pass attachment_id, one of: {}",
+            many.len(),
+            many.iter()
+                .map(|a| format!("{} ({})", a.id, a.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+pub fn format_grep_output(
+    attachment: &EventAttachment,
+    pattern: &str,
+    result: &GrepResult,
+) -> String {
+    let mut output = String::new();
+    output.push_str("# Attachment Search\n\n");
+    output.push_str(&format!(
+        "**Attachment:** {} ({} bytes)\n",
+        attachment.name, attachment.size
+    ));
+    output.push_str(&format!("**Pattern:** `{}`\n", pattern));
+    output.push_str(&format!("**Matches:** {}", result.matches));
+    if result.truncated {
+        output.push_str(" (limit reached, more matches not shown)");
+    }
+    output.push_str("\n\n");
+    if result.blocks.is_empty() {
+        output.push_str("No lines matched.\n");
+        return output;
+    }
+    output.push_str("```\n");
+    for (i, block) in result.blocks.iter().enumerate() {
+        if i > 0 {
+            output.push_str("--\n");
+        }
+        for (number, is_match, line) in &block.lines {
+            let sep = if *is_match { ':' } else { '-' };
+            output.push_str(&format!("{}{} {}\n", number, sep, truncate_line(line)));
+        }
+    }
+    output.push_str("```\n");
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: GrepAttachmentInput,
+) -> Result<CallToolResult, McpError> {
+    let regex = RegexBuilder::new(&input.pattern)
+        .case_insensitive(input.case_insensitive.unwrap_or(false))
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+        .map_err(|e| McpError::invalid_params(format!("Invalid pattern: {}", e), None))?;
+    let org = &input.organization_slug;
+    let attachments = client
+        .list_event_attachments(org, &input.project_slug, &input.event_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let attachment = select_text_attachment(&attachments, input.attachment_id.as_deref())
+        .map_err(|message| McpError::invalid_params(message, None))?;
+    if attachment.size > MAX_ATTACHMENT_BYTES {
+        return Err(McpError::invalid_params(
+            format!(
+                "Attachment {} is {} bytes; attachments over {} bytes are not downloaded",
+                attachment.name, attachment.size, MAX_ATTACHMENT_BYTES
+            ),
+            None,
+        ));
+    }
+    let bytes = client
+        .download_event_attachment(org, &input.project_slug, &input.event_id, &attachment.id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    if looks_binary(&bytes) {
+        return Err(McpError::invalid_params(
+            format!("Attachment {} looks binary, not text", attachment.name),
+            None,
+        ));
+    }
+    let text = String::from_utf8_lossy(&bytes);
+    let result = grep_lines(
+        &text,
+        &regex,
+        input.context.unwrap_or(2).min(10),
+        input.max_matches.unwrap_or(50).clamp(1, 200),
+    );
+    let output = format_grep_output(attachment, &input.pattern, &result);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,134 @@
+use super::{ORG_ROLES, TEAM_ROLES, format_teams, invite_state};
+use crate::api_client::{MemberTeamRole, NewMemberInvite, OrgMember, SentryApi, Team};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct InviteMemberInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Email address to send the invite to")]
+    pub email: String,
+    #[schemars(
+        description = "Organization role: 'member' (default), 'admin', 'manager', 'owner' or 'billing'"
+    )]
+    pub role: Option<String>,
+    #[schemars(
+        description = "Slugs of the teams to add the member to, e.g. ['backend', 'oncall']"
+    )]
+    pub teams: Option<Vec<String>>,
+    #[schemars(
+        description = "Role in those teams: 'contributor' or 'admin' (default: Sentry's default for the organization role)"
+    )]
+    pub team_role: Option<String>,
+}
+
+fn invalid(message: String) -> McpError {
+    McpError::invalid_params(message, None)
+}
+
+fn validate_choice(field: &str, value: &str, allowed: &[&str]) -> Result<String, McpError> {
+    let value = value.trim().to_ascii_lowercase();
+    if allowed.contains(&value.as_str()) {
+        Ok(value)
+    } else {
+        Err(invalid(format!(
+            "Invalid {} '{}': expected one of {}",
+            field,
+            value,
+            allowed.join(", ")
+        )))
+    }
+}
+
+/// Validates the input against the organization's teams and current members and
+/// builds the POST body.
+pub fn build_invite(
+    input: &InviteMemberInput,
+    teams: &[Team],
+    members: &[OrgMember],
+) -> Result<NewMemberInvite, McpError> {
+    let email = input.email.trim();
+    if !email.contains('@') {
+        return Err(invalid(format!("Invalid email '{}'", email)));
+    }
+    if let Some(existing) = members.iter().find(|m| m.email.eq_ignore_ascii_case(email)) {
+        let state = if existing.pending {
+            "already has a pending invite"
+        } else {
+            "is already a member"
+        };
+        return Err(invalid(format!("{} {}", email, state)));
+    }
+    let org_role = validate_choice("role", input.role.as_deref().unwrap_or("member"), ORG_ROLES)?;
+    let team_role = input
+        .team_role
+        .as_deref()
+        .map(|r| validate_choice("team_role", r, TEAM_ROLES))
+        .transpose()?;
+    let mut team_roles: Vec<MemberTeamRole> = Vec::new();
+    for requested in input.teams.iter().flatten() {
+        let slug = requested.trim().trim_start_matches('#');
+        if !teams.iter().any(|t| t.slug == slug) {
+            let known: Vec<&str> = teams.iter().map(|t| t.slug.as_str()).collect();
+            return Err(invalid(format!(
+                "Unknown team '{}': expected one of {}",
+                slug,
+                known.join(", ")
+            )));
+        }
+        if team_roles.iter().all(|t| t.team_slug != slug) {
+            team_roles.push(MemberTeamRole {
+                team_slug: slug.to_string(),
+                role: team_role.clone(),
+            });
+        }
+    }
+    Ok(NewMemberInvite {
+        email: email.to_string(),
+        org_role,
+        team_roles,
+        send_invite: true,
+    })
+}
+
+pub fn format_invite_output(member: &OrgMember) -> String {
+    let mut output = String::new();
+    output.push_str("# Invite Sent\n\n");
+    output.push_str(&format!("**Member ID:** {}\n", member.id));
+    output.push_str(&format!("**Email:** {}\n", member.email));
+    if let Some(role) = member.org_role() {
+        output.push_str(&format!("**Role:** {}\n", role));
+    }
+    let teams = format_teams(member);
+    if !teams.is_empty() {
+        output.push_str(&format!("**Teams:** {}\n", teams));
+    }
+    output.push_str(&format!("**Status:** {}\n", invite_state(member)));
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: InviteMemberInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let teams = client
+        .list_teams(org)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let members = client
+        .list_org_members(org)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let invite = build_invite(&input, &teams, &members)?;
+    let member = client
+        .invite_org_member(org, &invite)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_invite_output(&member);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
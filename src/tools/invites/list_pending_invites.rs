@@ -0,0 +1,55 @@
+use super::{format_teams, invite_state};
+use crate::api_client::{OrgMember, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListPendingInvitesInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+}
+
+/// Members who have not accepted their invite yet.
+pub fn pending_invites(members: &[OrgMember]) -> Vec<&OrgMember> {
+    members.iter().filter(|m| m.pending).collect()
+}
+
+pub fn format_invites_output(org_slug: &str, invites: &[&OrgMember]) -> String {
+    let mut output = String::new();
+    output.push_str("# Pending Invites\n\n");
+    output.push_str(&format!("**Organization:** {}\n", org_slug));
+    output.push_str(&format!("**Pending:** {}\n\n", invites.len()));
+    if invites.is_empty() {
+        output.push_str("No pending invites.\n");
+        return output;
+    }
+    output.push_str("| Email | Role | Teams | Status | Invited By | Sent |\n");
+    output.push_str("|---|---|---|---|---|---|\n");
+    for invite in invites {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            invite.email,
+            invite.org_role().unwrap_or(""),
+            format_teams(invite),
+            invite_state(invite),
+            invite.inviter_name.as_deref().unwrap_or(""),
+            invite.date_created.as_deref().unwrap_or("")
+        ));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ListPendingInvitesInput,
+) -> Result<CallToolResult, McpError> {
+    let members = client
+        .list_org_members(&input.organization_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_invites_output(&input.organization_slug, &pending_invites(&members));
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
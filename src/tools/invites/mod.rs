@@ -0,0 +1,37 @@
+pub mod invite_member;
+pub mod list_pending_invites;
+
+use crate::api_client::OrgMember;
+
+/// Organization roles Sentry accepts for members and invites.
+pub const ORG_ROLES: &[&str] = &["member", "admin", "manager", "owner", "billing"];
+/// Team roles; `contributor` is the default for most organization roles.
+pub const TEAM_ROLES: &[&str] = &["contributor", "admin"];
+
+/// Where an invite stands: waiting for approval, expired, or sent.
+pub fn invite_state(member: &OrgMember) -> &'static str {
+    if member.expired {
+        "expired"
+    } else if member
+        .invite_status
+        .as_deref()
+        .is_some_and(|s| s != "approved")
+    {
+        "awaiting approval"
+    } else {
+        "sent"
+    }
+}
+
+/// Team slugs of a member, `#`-prefixed like in Sentry's UI.
+pub fn format_teams(member: &OrgMember) -> String {
+    member
+        .team_roles
+        .iter()
+        .map(|t| match &t.role {
+            Some(role) => format!("#{} ({})", t.team_slug, role),
+            None => format!("#{}", t.team_slug),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,200 @@
+use crate::api_client::{IssueActivity, SentryApi};
+use crate::json_ext::ValueExt;
+use crate::tools::list_issue_comments::user_name;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+const DEFAULT_LIMIT: usize = 50;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueActivityInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+    #[schemars(description = "Maximum number of most recent entries to show (default: 50)")]
+    pub limit: Option<usize>,
+    #[schemars(
+        description = "Include comments in the feed (default: false; use list_issue_comments for full text)"
+    )]
+    pub include_notes: Option<bool>,
+}
+
+fn non_empty<'a>(data: &'a Value, key: &str) -> Option<&'a str> {
+    data.str_field(key).filter(|s| !s.is_empty())
+}
+
+fn format_minutes(minutes: i64) -> String {
+    if minutes % 1440 == 0 {
+        format!("{}d", minutes / 1440)
+    } else if minutes % 60 == 0 {
+        format!("{}h", minutes / 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+fn describe_ignored(data: &Value) -> String {
+    if data.bool_field("ignoreUntilEscalating") == Some(true) {
+        return "archived until escalating".to_string();
+    }
+    if let Some(minutes) = data.i64_field("ignoreDuration") {
+        return format!("archived for {}", format_minutes(minutes));
+    }
+    if let Some(count) = data.i64_field("ignoreCount") {
+        return match data.i64_field("ignoreWindow") {
+            Some(window) => format!(
+                "archived until {} more events in {}",
+                count,
+                format_minutes(window)
+            ),
+            None => format!("archived until {} more events", count),
+        };
+    }
+    if let Some(users) = data.i64_field("ignoreUserCount") {
+        return format!("archived until {} more users are affected", users);
+    }
+    "archived".to_string()
+}
+
+fn describe_assignee(data: &Value) -> String {
+    let name = non_empty(data, "assigneeName")
+        .or_else(|| non_empty(data, "assigneeEmail"))
+        .or_else(|| non_empty(data, "assignee"));
+    match (data.str_field("assigneeType"), name) {
+        (Some("team"), Some(name)) => format!("assigned to team {}", name),
+        (_, Some(name)) => format!("assigned to {}", name),
+        _ => "assigned".to_string(),
+    }
+}
+
+fn describe_commit(data: &Value) -> String {
+    let commit = data.get("commit");
+    let id = commit
+        .and_then(|c| c.as_str().or_else(|| c.str_field("id")))
+        .map(|id| id.chars().take(12).collect::<String>());
+    let repo = commit
+        .and_then(|c| c.get("repository"))
+        .and_then(|r| r.str_field("name"));
+    match (id, repo) {
+        (Some(id), Some(repo)) => format!("resolved in commit {} ({})", id, repo),
+        (Some(id), None) => format!("resolved in commit {}", id),
+        _ => "resolved in a commit".to_string(),
+    }
+}
+
+/// One-line, human-readable summary of an activity entry. Unknown types fall back
+/// to the raw type name so new Sentry activity kinds still show up.
+pub fn describe_activity(activity: &IssueActivity) -> String {
+    let data = &activity.data;
+    match activity.activity_type.as_str() {
+        "first_seen" => "first seen".to_string(),
+        "set_resolved" => "resolved".to_string(),
+        "set_resolved_by_age" => "auto-resolved due to inactivity".to_string(),
+        "set_resolved_in_release" => match non_empty(data, "version") {
+            Some(version) => format!("resolved in release {}", version),
+            None => "resolved in the next release".to_string(),
+        },
+        "set_resolved_in_commit" => describe_commit(data),
+        "set_resolved_in_pull_request" => "resolved by a pull request".to_string(),
+        "set_unresolved" => "unresolved".to_string(),
+        "set_ignored" => describe_ignored(data),
+        "set_public" => "made public".to_string(),
+        "set_private" => "made private".to_string(),
+        "set_regression" => match non_empty(data, "version") {
+            Some(version) => format!("regressed in release {}", version),
+            None => "regressed".to_string(),
+        },
+        "set_escalating" => "marked as escalating".to_string(),
+        "auto_set_ongoing" => "marked as ongoing".to_string(),
+        "set_priority" => match non_empty(data, "priority") {
+            Some(priority) => format!("priority set to {}", priority),
+            None => "priority changed".to_string(),
+        },
+        "assigned" => describe_assignee(data),
+        "unassigned" => "unassigned".to_string(),
+        "merge" => match data.array_field("issues") {
+            Some(issues) => format!("merged {} issues into this one", issues.len()),
+            None => "merged issues".to_string(),
+        },
+        "unmerge_source" | "unmerge_destination" => "unmerged events".to_string(),
+        "create_issue" => match (non_empty(data, "provider"), non_empty(data, "location")) {
+            (Some(provider), Some(location)) => {
+                format!("created {} issue {}", provider, location)
+            }
+            (Some(provider), None) => format!("created {} issue", provider),
+            _ => "created an external issue".to_string(),
+        },
+        "release" => match non_empty(data, "version") {
+            Some(version) => format!("seen in release {}", version),
+            None => "seen in a new release".to_string(),
+        },
+        "deploy" => match (non_empty(data, "version"), non_empty(data, "environment")) {
+            (Some(version), Some(environment)) => {
+                format!("release {} deployed to {}", version, environment)
+            }
+            (Some(version), None) => format!("release {} deployed", version),
+            _ => "deployed".to_string(),
+        },
+        "note" => match non_empty(data, "text") {
+            Some(text) => {
+                let line = text.lines().next().unwrap_or_default();
+                let mut summary: String = line.chars().take(120).collect();
+                if summary.len() < text.trim_end().len() {
+                    summary.push('…');
+                }
+                format!("commented: \"{}\"", summary)
+            }
+            None => "commented".to_string(),
+        },
+        other => other.replace('_', " "),
+    }
+}
+
+/// Renders the feed oldest first so status changes read as a timeline. `activity`
+/// is in API order (newest first); only the newest `limit` entries are shown.
+pub fn format_activity_output(issue_id: &str, activity: &[&IssueActivity], limit: usize) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Activity for {}\n\n", issue_id));
+    output.push_str(&format!("**Found:** {} entries", activity.len()));
+    if activity.len() > limit {
+        output.push_str(&format!(" (showing the latest {})", limit));
+    }
+    output.push_str("\n\n");
+    if activity.is_empty() {
+        output.push_str("No activity recorded for this issue.\n");
+        return output;
+    }
+    for entry in activity.iter().take(limit).rev() {
+        let date = entry.date_created.as_deref().unwrap_or("unknown date");
+        output.push_str(&format!(
+            "- {} — {}: {}\n",
+            date,
+            user_name(entry.user.as_ref()),
+            describe_activity(entry)
+        ));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: IssueActivityInput,
+) -> Result<CallToolResult, McpError> {
+    let activity = client
+        .list_issue_activity(&input.organization_slug, &input.issue_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let include_notes = input.include_notes.unwrap_or(false);
+    let entries: Vec<&IssueActivity> = activity
+        .iter()
+        .filter(|a| include_notes || a.activity_type != "note")
+        .collect();
+    let limit = input.limit.unwrap_or(DEFAULT_LIMIT).max(1);
+    let output = format_activity_output(&input.issue_id, &entries, limit);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
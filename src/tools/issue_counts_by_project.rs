@@ -0,0 +1,151 @@
+use crate::api_client::{DiscoverQuery, DiscoverResult, Project, SentryApi};
+use crate::format::count_label;
+use crate::tools::list_environments::resolve_environment;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+const UNRESOLVED_QUERY: &str = "is:unresolved";
+/// `issues-count` stops counting here; counts at the cap are shown as `100+`.
+const COUNT_CAP: u64 = 100;
+const EVENTS: &str = "count()";
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueCountsByProjectInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+}
+
+/// Issue counts and event volume of one project.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectCounts {
+    pub slug: String,
+    /// `None` when the count could not be fetched.
+    pub unresolved: Option<u64>,
+    pub events_24h: f64,
+}
+
+/// Error events of the last 24 hours per project slug, from a Discover query
+/// grouped by `project`.
+pub fn events_by_project(result: &DiscoverResult) -> HashMap<String, f64> {
+    result
+        .data
+        .iter()
+        .filter_map(|row| {
+            let slug = row.get("project")?.as_str()?;
+            let events = row.get(EVENTS).and_then(|v| v.as_f64()).unwrap_or(0.0);
+            Some((slug.to_string(), events))
+        })
+        .collect()
+}
+
+/// One row per project, most unresolved issues first (events break ties).
+pub fn project_rows(
+    projects: &[Project],
+    counts: Vec<Option<u64>>,
+    events: &HashMap<String, f64>,
+) -> Vec<ProjectCounts> {
+    let mut rows: Vec<ProjectCounts> = projects
+        .iter()
+        .zip(counts)
+        .map(|(project, unresolved)| ProjectCounts {
+            slug: project.slug.clone(),
+            unresolved,
+            events_24h: events.get(&project.slug).copied().unwrap_or(0.0),
+        })
+        .collect();
+    rows.sort_by(|a, b| {
+        b.unresolved
+            .cmp(&a.unresolved)
+            .then_with(|| b.events_24h.total_cmp(&a.events_24h))
+            .then_with(|| a.slug.cmp(&b.slug))
+    });
+    rows
+}
+
+pub fn format_issue_counts_by_project(
+    org: &str,
+    environment: Option<&str>,
+    rows: &[ProjectCounts],
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Issue Counts by Project: {}\n\n", org));
+    if let Some(environment) = environment {
+        output.push_str(&format!("**Environment:** {}\n", environment));
+    }
+    output.push_str(&format!("**Projects:** {}\n\n", rows.len()));
+    if rows.is_empty() {
+        output.push_str("No projects found.\n");
+        return output;
+    }
+    let unresolved: u64 = rows.iter().filter_map(|r| r.unresolved).sum();
+    let capped = rows
+        .iter()
+        .any(|r| r.unresolved.is_some_and(|c| c >= COUNT_CAP));
+    let events: f64 = rows.iter().map(|r| r.events_24h).sum();
+    output.push_str("| Project | Unresolved Issues | Events (24h) |\n");
+    output.push_str("|---|---|---|\n");
+    for row in rows {
+        output.push_str(&format!(
+            "| {} | {} | {:.0} |\n",
+            row.slug,
+            row.unresolved.map_or_else(
+                || "?".to_string(),
+                |c| count_label(c as usize, COUNT_CAP as usize)
+            ),
+            row.events_24h
+        ));
+    }
+    output.push_str(&format!(
+        "| **Total** | {}{} | {:.0} |\n",
+        unresolved,
+        if capped { "+" } else { "" },
+        events
+    ));
+    if rows.iter().any(|r| r.unresolved.is_none()) {
+        output.push_str("\n`?`: the count could not be fetched for that project.\n");
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: IssueCountsByProjectInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let projects = client
+        .list_projects(org)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let project_ids: Vec<String> = projects.iter().map(|p| p.id.clone()).collect();
+    let counts = client
+        .count_issues_by_project(org, &project_ids, environment.as_deref(), UNRESOLVED_QUERY)
+        .await
+        .into_iter()
+        .map(Result::ok)
+        .collect();
+    let query = DiscoverQuery {
+        fields: vec!["project".to_string(), EVENTS.to_string()],
+        sort: Some(format!("-{}", EVENTS)),
+        stats_period: Some("24h".to_string()),
+        environments: environment.clone().into_iter().collect(),
+        dataset: Some("errors".to_string()),
+        per_page: Some(100),
+        ..Default::default()
+    };
+    let volume = client
+        .query_events(org, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let rows = project_rows(&projects, counts, &events_by_project(&volume));
+    let output = format_issue_counts_by_project(org, environment.as_deref(), &rows);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
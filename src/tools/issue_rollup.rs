@@ -0,0 +1,184 @@
+use crate::api_client::{Issue, IssuesQuery, SentryApi};
+use crate::format::escape_cell;
+use crate::tools::list_environments::resolve_environment;
+use crate::tools::search_query::{QueryTarget, check_query, push_query_warnings};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+const MAX_PROJECTS: usize = 20;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueRollupInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slugs to combine, e.g. all services of one product")]
+    pub project_slugs: Vec<String>,
+    #[schemars(description = "Sentry search query (default: 'is:unresolved')")]
+    pub query: Option<String>,
+    #[schemars(description = "Time window, e.g. '24h', '14d' (default: 24h)")]
+    pub stats_period: Option<String>,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+    #[schemars(description = "Issues to fetch per project (default: 25, max: 100)")]
+    pub per_project_limit: Option<i32>,
+    #[schemars(description = "Maximum rows in the combined report (default: 20)")]
+    pub limit: Option<usize>,
+}
+
+/// Issues from one or more projects that share a title.
+#[derive(Debug, Clone)]
+pub struct RollupGroup {
+    pub title: String,
+    pub projects: Vec<String>,
+    pub short_ids: Vec<String>,
+    pub events: i64,
+    pub users: i64,
+    pub last_seen: Option<String>,
+}
+
+/// Key used to treat issues in different services as the same problem.
+pub fn title_key(title: &str) -> String {
+    title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Merges per-project issue lists by title, ordered by combined event count.
+pub fn rollup(per_project: &[(String, Vec<Issue>)]) -> Vec<RollupGroup> {
+    let mut groups: Vec<RollupGroup> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for (project, issues) in per_project {
+        for issue in issues {
+            let slot = *index.entry(title_key(&issue.title)).or_insert_with(|| {
+                groups.push(RollupGroup {
+                    title: issue.title.clone(),
+                    projects: vec![],
+                    short_ids: vec![],
+                    events: 0,
+                    users: 0,
+                    last_seen: None,
+                });
+                groups.len() - 1
+            });
+            let group = &mut groups[slot];
+            if !group.projects.contains(project) {
+                group.projects.push(project.clone());
+            }
+            group.short_ids.push(issue.short_id.clone());
+            group.events += issue.count.parse::<i64>().unwrap_or(0);
+            group.users += issue.user_count;
+            // ISO 8601 timestamps compare correctly as strings.
+            if issue.last_seen > group.last_seen {
+                group.last_seen = issue.last_seen.clone();
+            }
+        }
+    }
+    groups.sort_by_key(|g| std::cmp::Reverse(g.events));
+    groups
+}
+
+pub fn format_rollup_output(
+    per_project: &[(String, Vec<Issue>)],
+    groups: &[RollupGroup],
+    limit: usize,
+) -> String {
+    let mut output = String::new();
+    output.push_str("# Multi-Project Issue Rollup\n\n");
+    let projects: Vec<&str> = per_project.iter().map(|(p, _)| p.as_str()).collect();
+    output.push_str(&format!("**Projects:** {}\n", projects.join(", ")));
+    let total: usize = per_project.iter().map(|(_, issues)| issues.len()).sum();
+    let shared = groups.iter().filter(|g| g.projects.len() > 1).count();
+    output.push_str(&format!(
+        "**Issues:** {} fetched, {} unique titles, {} shared across projects\n\n",
+        total,
+        groups.len(),
+        shared
+    ));
+    if groups.is_empty() {
+        output.push_str("No issues found.\n");
+        return output;
+    }
+    output.push_str("| Title | Projects | Issues | Events | Users | Last Seen |\n");
+    output.push_str("|---|---|---|---|---|---|\n");
+    for group in groups.iter().take(limit) {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            escape_cell(&group.title),
+            group.projects.join(", "),
+            group.short_ids.join(", "),
+            group.events,
+            group.users,
+            group.last_seen.as_deref().unwrap_or("-")
+        ));
+    }
+    if groups.len() > limit {
+        output.push_str(&format!(
+            "\n*{} more titles not shown.*\n",
+            groups.len() - limit
+        ));
+    }
+    output.push_str("\nUser counts are summed per issue and may double-count users affected in several projects.\n");
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: IssueRollupInput,
+) -> Result<CallToolResult, McpError> {
+    if input.project_slugs.is_empty() || input.project_slugs.len() > MAX_PROJECTS {
+        return Err(McpError::invalid_params(
+            format!(
+                "project_slugs must list between 1 and {} projects",
+                MAX_PROJECTS
+            ),
+            None,
+        ));
+    }
+    let warnings = check_query(input.query.as_deref(), QueryTarget::Issues)?;
+    let org = &input.organization_slug;
+    let per_project_limit = input.per_project_limit.unwrap_or(25).min(100);
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let mut per_project = Vec::new();
+    for slug in &input.project_slugs {
+        let project = client
+            .get_project(org, slug)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        let query = IssuesQuery {
+            query: Some(
+                input
+                    .query
+                    .clone()
+                    .unwrap_or_else(|| "is:unresolved".to_string()),
+            ),
+            project: Some(project.id),
+            environment: environment.clone(),
+            stats_period: Some(
+                input
+                    .stats_period
+                    .clone()
+                    .unwrap_or_else(|| "24h".to_string()),
+            ),
+            sort: Some("freq".to_string()),
+            limit: Some(per_project_limit),
+            ..Default::default()
+        };
+        let issues = client
+            .list_issues(org, &query)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        per_project.push((slug.clone(), issues));
+    }
+    let groups = rollup(&per_project);
+    let mut output = format_rollup_output(&per_project, &groups, input.limit.unwrap_or(20));
+    push_query_warnings(&mut output, &warnings);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,558 @@
+use crate::api_client::{EventsStatsQuery, Issue, SentryApi};
+use crate::tools::timezone::LocalZone;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+const HOURS_PER_DAY: usize = 24;
+const FORECAST_HORIZON: usize = 24;
+const HW_ALPHA: f64 = 0.3;
+const HW_BETA: f64 = 0.05;
+const HW_GAMMA: f64 = 0.3;
+const SPARKLINE_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+/// Longer series are summed into this many sparkline cells.
+const SPARKLINE_WIDTH: usize = 84;
+const PEAK_BUCKETS: usize = 5;
+/// Busiest hours listed under the hour-of-day profile.
+const PEAK_HOURS: usize = 3;
+/// Ratio between recent and earlier averages that counts as a spike (or, inverted, a decay).
+const TREND_RATIO: f64 = 2.0;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueStatsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+    #[schemars(description = "History window, e.g. '24h', '7d', '30d' (default: 7d)")]
+    pub stats_period: Option<String>,
+    #[schemars(
+        description = "Bucket size: '1h' (default) or '1d'. Daily buckets suit long windows like '30d'; forecasts need hourly buckets."
+    )]
+    pub resolution: Option<String>,
+    #[schemars(
+        description = "Timezone for bucket times and day boundaries: 'UTC' (default), an abbreviation like 'CET' or 'PST', or an offset like '+02:00'. Fixed offset; daylight saving time is not applied."
+    )]
+    pub timezone: Option<String>,
+    #[schemars(description = "Project event volume for the next 24 hours")]
+    pub forecast: Option<bool>,
+    #[schemars(
+        description = "Forecast method: 'auto' (default), 'linear', or 'holt_winters' (needs at least 48 hours of history)"
+    )]
+    pub forecast_method: Option<String>,
+    #[schemars(description = "Hourly event count to check the forecast against; implies forecast")]
+    pub threshold: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Resolution {
+    #[default]
+    Hourly,
+    Daily,
+}
+
+impl Resolution {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "1h" | "hour" | "hourly" => Some(Self::Hourly),
+            "1d" | "24h" | "day" | "daily" => Some(Self::Daily),
+            _ => None,
+        }
+    }
+    pub fn interval(self) -> &'static str {
+        match self {
+            Self::Hourly => "1h",
+            Self::Daily => "1d",
+        }
+    }
+    fn buckets_per_day(self) -> usize {
+        match self {
+            Self::Hourly => HOURS_PER_DAY,
+            Self::Daily => 1,
+        }
+    }
+    fn noun(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Hourly => ("Hourly", "Hour"),
+            Self::Daily => ("Daily", "Day"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Spiking,
+    Steady,
+    Decaying,
+}
+
+impl Trend {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Spiking => "spiking",
+            Self::Steady => "steady",
+            Self::Decaying => "decaying",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrendSummary {
+    pub trend: Trend,
+    /// Average per bucket over the most recent quarter of the window.
+    pub recent: f64,
+    /// Average per bucket over the rest of the window.
+    pub baseline: f64,
+}
+
+/// Compares the last quarter of the series with everything before it.
+///
+/// Returns `None` for series shorter than four buckets.
+pub fn classify_trend(values: &[f64]) -> Option<TrendSummary> {
+    if values.len() < 4 {
+        return None;
+    }
+    let split = values.len() - values.len().div_ceil(4);
+    let mean = |s: &[f64]| s.iter().sum::<f64>() / s.len() as f64;
+    let baseline = mean(&values[..split]);
+    let recent = mean(&values[split..]);
+    let trend = if recent >= 1.0 && recent > baseline * TREND_RATIO {
+        Trend::Spiking
+    } else if recent * TREND_RATIO < baseline {
+        Trend::Decaying
+    } else {
+        Trend::Steady
+    };
+    Some(TrendSummary {
+        trend,
+        recent,
+        baseline,
+    })
+}
+
+/// Renders `values` as a row of block characters scaled to the maximum,
+/// summing neighbouring buckets when there are more than `width`.
+pub fn sparkline(values: &[f64], width: usize) -> String {
+    if values.is_empty() || width == 0 {
+        return String::new();
+    }
+    let chunk = values.len().div_ceil(width);
+    let cells: Vec<f64> = values.chunks(chunk).map(|c| c.iter().sum()).collect();
+    let max = cells.iter().copied().fold(0.0, f64::max);
+    let top = (SPARKLINE_LEVELS.len() - 1) as f64;
+    cells
+        .iter()
+        .map(|v| {
+            let level = if max > 0.0 {
+                (v / max * top).round() as usize
+            } else {
+                0
+            };
+            SPARKLINE_LEVELS[level.min(SPARKLINE_LEVELS.len() - 1)]
+        })
+        .collect()
+}
+
+/// The `n` busiest non-empty buckets, highest first; ties keep time order.
+pub fn peak_buckets(counts: &[(i64, f64)], n: usize) -> Vec<(i64, f64)> {
+    let mut peaks: Vec<(i64, f64)> = counts.iter().copied().filter(|(_, c)| *c > 0.0).collect();
+    peaks.sort_by(|a, b| b.1.total_cmp(&a.1));
+    peaks.truncate(n);
+    peaks
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForecastMethod {
+    Auto,
+    Linear,
+    HoltWinters,
+}
+
+impl ForecastMethod {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "auto" => Some(Self::Auto),
+            "linear" => Some(Self::Linear),
+            "holt_winters" | "holtwinters" => Some(Self::HoltWinters),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Forecast {
+    pub method: ForecastMethod,
+    pub hourly: Vec<f64>,
+}
+
+impl Forecast {
+    pub fn total(&self) -> f64 {
+        self.hourly.iter().sum()
+    }
+    /// Index and value of the highest projected hour.
+    pub fn peak(&self) -> Option<(usize, f64)> {
+        self.hourly
+            .iter()
+            .copied()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+    /// First projected hour at or above `threshold`.
+    pub fn first_breach(&self, threshold: f64) -> Option<usize> {
+        self.hourly.iter().position(|v| *v >= threshold)
+    }
+}
+
+/// Least-squares trend line over `series`, extended `horizon` steps.
+pub fn linear_forecast(series: &[f64], horizon: usize) -> Vec<f64> {
+    let n = series.len() as f64;
+    if series.is_empty() {
+        return vec![0.0; horizon];
+    }
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = series.iter().sum::<f64>() / n;
+    let (mut num, mut den) = (0.0, 0.0);
+    for (i, y) in series.iter().enumerate() {
+        let dx = i as f64 - mean_x;
+        num += dx * (y - mean_y);
+        den += dx * dx;
+    }
+    let slope = if den > 0.0 { num / den } else { 0.0 };
+    (0..horizon)
+        .map(|h| (mean_y + slope * (n + h as f64 - mean_x)).max(0.0))
+        .collect()
+}
+
+/// Additive Holt-Winters with a `season`-length cycle, extended `horizon` steps.
+///
+/// Returns `None` unless the series covers at least two full seasons.
+pub fn holt_winters_forecast(series: &[f64], season: usize, horizon: usize) -> Option<Vec<f64>> {
+    if season == 0 || series.len() < 2 * season {
+        return None;
+    }
+    let mean = |s: &[f64]| s.iter().sum::<f64>() / s.len() as f64;
+    let first = mean(&series[..season]);
+    let second = mean(&series[season..2 * season]);
+    let mut level = first;
+    let mut trend = (second - first) / season as f64;
+    let mut seasonal: Vec<f64> = series[..season].iter().map(|x| x - first).collect();
+    for (i, x) in series.iter().enumerate().skip(season) {
+        let s = seasonal[i % season];
+        let prev_level = level;
+        level = HW_ALPHA * (x - s) + (1.0 - HW_ALPHA) * (level + trend);
+        trend = HW_BETA * (level - prev_level) + (1.0 - HW_BETA) * trend;
+        seasonal[i % season] = HW_GAMMA * (x - level) + (1.0 - HW_GAMMA) * s;
+    }
+    let n = series.len();
+    Some(
+        (1..=horizon)
+            .map(|h| (level + h as f64 * trend + seasonal[(n + h - 1) % season]).max(0.0))
+            .collect(),
+    )
+}
+
+/// Projects the next 24 hours; `Auto` picks Holt-Winters when enough history exists.
+pub fn forecast(series: &[f64], method: ForecastMethod) -> Forecast {
+    let seasonal = match method {
+        ForecastMethod::Linear => None,
+        _ => holt_winters_forecast(series, HOURS_PER_DAY, FORECAST_HORIZON),
+    };
+    match seasonal {
+        Some(hourly) => Forecast {
+            method: ForecastMethod::HoltWinters,
+            hourly,
+        },
+        None => Forecast {
+            method: ForecastMethod::Linear,
+            hourly: linear_forecast(series, FORECAST_HORIZON),
+        },
+    }
+}
+
+fn format_bucket(ts: i64, resolution: Resolution, zone: &LocalZone) -> String {
+    match resolution {
+        Resolution::Hourly => zone.format_time(ts),
+        Resolution::Daily => zone.format_date(ts),
+    }
+}
+
+/// The `n` busiest non-empty hours of the day, highest first.
+pub fn peak_hours(hours: &[f64; HOURS_PER_DAY], n: usize) -> Vec<(usize, f64)> {
+    let mut peaks: Vec<(usize, f64)> = hours
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, c)| *c > 0.0)
+        .collect();
+    peaks.sort_by(|a, b| b.1.total_cmp(&a.1));
+    peaks.truncate(n);
+    peaks
+}
+
+fn format_hour_of_day(output: &mut String, counts: &[(i64, f64)], zone: &LocalZone) {
+    let hours = zone.hour_of_day(counts);
+    let total: f64 = hours.iter().sum();
+    if total <= 0.0 {
+        return;
+    }
+    output.push_str(&format!("\n## By Hour of Day ({})\n\n", zone.label));
+    output.push_str(&format!(
+        "```\n{}\n```\n00:00 → 23:00\n\n",
+        sparkline(&hours, HOURS_PER_DAY)
+    ));
+    for (hour, count) in peak_hours(&hours, PEAK_HOURS) {
+        output.push_str(&format!(
+            "- {:02}:00–{:02}:00: {:.0} ({:.0}%)\n",
+            hour,
+            (hour + 1) % HOURS_PER_DAY,
+            count,
+            count / total * 100.0
+        ));
+    }
+}
+
+fn format_activity(
+    output: &mut String,
+    counts: &[(i64, f64)],
+    resolution: Resolution,
+    zone: &LocalZone,
+) {
+    let values: Vec<f64> = counts.iter().map(|(_, c)| *c).collect();
+    if let Some(summary) = classify_trend(&values) {
+        output.push_str(&format!(
+            "**Trend:** {} (recent avg {:.1} vs {:.1} before)\n",
+            summary.trend.label(),
+            summary.recent,
+            summary.baseline
+        ));
+    }
+    output.push_str("\n## Activity\n\n");
+    output.push_str(&format!(
+        "```\n{}\n```\n",
+        sparkline(&values, SPARKLINE_WIDTH)
+    ));
+    if let (Some((first, _)), Some((last, _))) = (counts.first(), counts.last()) {
+        output.push_str(&format!(
+            "{} → {}\n",
+            format_bucket(*first, resolution, zone),
+            format_bucket(*last, resolution, zone)
+        ));
+    }
+    let peaks = peak_buckets(counts, PEAK_BUCKETS);
+    if !peaks.is_empty() {
+        output.push_str("\n## Peak Buckets\n\n");
+        for (ts, count) in peaks {
+            output.push_str(&format!(
+                "- {}: {:.0}\n",
+                format_bucket(ts, resolution, zone),
+                count
+            ));
+        }
+    }
+    if resolution == Resolution::Hourly && counts.len() >= HOURS_PER_DAY {
+        format_hour_of_day(output, counts, zone);
+    }
+}
+
+fn format_forecast(
+    output: &mut String,
+    forecast: &Forecast,
+    requested: ForecastMethod,
+    last_ts: Option<i64>,
+    threshold: Option<f64>,
+    zone: &LocalZone,
+) {
+    let hour_label = |i: usize| match last_ts {
+        Some(ts) => format!(
+            "+{}h ({})",
+            i + 1,
+            zone.format_time(ts + 3600 * (i as i64 + 1))
+        ),
+        None => format!("+{}h", i + 1),
+    };
+    output.push_str("\n## Forecast (next 24h)\n\n");
+    let method = match forecast.method {
+        ForecastMethod::HoltWinters => "Holt-Winters (24h seasonality)",
+        _ => "Linear trend",
+    };
+    output.push_str(&format!("**Method:** {}\n", method));
+    if requested == ForecastMethod::HoltWinters && forecast.method != requested {
+        output.push_str(
+            "*Holt-Winters needs at least 48 hours of history; fell back to a linear trend.*\n",
+        );
+    }
+    output.push_str(&format!("**Projected Events:** {:.0}\n", forecast.total()));
+    if let Some((i, peak)) = forecast.peak() {
+        output.push_str(&format!(
+            "**Projected Peak:** {:.1}/h at {}\n",
+            peak,
+            hour_label(i)
+        ));
+    }
+    if let Some(threshold) = threshold {
+        match forecast.first_breach(threshold) {
+            Some(i) => output.push_str(&format!(
+                "**Threshold:** {}/h — ⚠️ expected to breach at {}\n",
+                threshold,
+                hour_label(i)
+            )),
+            None => output.push_str(&format!(
+                "**Threshold:** {}/h — not expected to breach in the next 24h\n",
+                threshold
+            )),
+        }
+    }
+}
+
+pub fn format_issue_stats_output(
+    issue: &Issue,
+    period: &str,
+    resolution: Resolution,
+    counts: &[(i64, f64)],
+    forecast_method: Option<ForecastMethod>,
+    threshold: Option<f64>,
+    zone: &LocalZone,
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Issue Stats: {}\n\n", issue.short_id));
+    output.push_str(&format!("**Title:** {}\n", issue.title));
+    let (adjective, noun) = resolution.noun();
+    output.push_str(&format!(
+        "**Period:** {} ({} buckets)\n",
+        period,
+        adjective.to_ascii_lowercase()
+    ));
+    if !zone.is_utc() {
+        let offset = format!("UTC{}", zone.offset);
+        if zone.label == offset {
+            output.push_str(&format!("**Timezone:** {} (fixed offset)\n", offset));
+        } else {
+            output.push_str(&format!(
+                "**Timezone:** {} ({}, fixed offset)\n",
+                zone.label, offset
+            ));
+        }
+    }
+    if counts.is_empty() {
+        output.push_str("\nNo event data for this period.\n");
+        return output;
+    }
+    let total: f64 = counts.iter().map(|(_, c)| c).sum();
+    let last_day: f64 = counts
+        .iter()
+        .rev()
+        .take(resolution.buckets_per_day())
+        .map(|(_, c)| c)
+        .sum();
+    output.push_str(&format!("**Events:** {:.0}\n", total));
+    output.push_str(&format!("**Last 24h:** {:.0}\n", last_day));
+    output.push_str(&format!(
+        "**{} Average:** {:.1}\n",
+        adjective,
+        total / counts.len() as f64
+    ));
+    if let Some((ts, peak)) = counts.iter().max_by(|a, b| a.1.total_cmp(&b.1)) {
+        output.push_str(&format!(
+            "**Peak {}:** {:.0} at {}\n",
+            noun,
+            peak,
+            format_bucket(*ts, resolution, zone)
+        ));
+    }
+    format_activity(&mut output, counts, resolution, zone);
+    if let Some(method) = forecast_method {
+        let series: Vec<f64> = counts.iter().map(|(_, c)| *c).collect();
+        let projected = forecast(&series, method);
+        let last_ts = counts.last().map(|(ts, _)| *ts);
+        format_forecast(&mut output, &projected, method, last_ts, threshold, zone);
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: IssueStatsInput,
+) -> Result<CallToolResult, McpError> {
+    let forecast_method = match input.forecast_method.as_deref() {
+        Some(value) => Some(ForecastMethod::parse(value).ok_or_else(|| {
+            McpError::invalid_params(
+                format!(
+                    "Invalid forecast_method '{}': expected auto, linear, or holt_winters",
+                    value
+                ),
+                None,
+            )
+        })?),
+        None => None,
+    };
+    let wants_forecast =
+        input.forecast.unwrap_or(false) || input.threshold.is_some() || forecast_method.is_some();
+    let forecast_method = wants_forecast.then(|| forecast_method.unwrap_or(ForecastMethod::Auto));
+    let resolution = match input.resolution.as_deref() {
+        Some(value) => Resolution::parse(value).ok_or_else(|| {
+            McpError::invalid_params(
+                format!("Invalid resolution '{}': expected 1h or 1d", value),
+                None,
+            )
+        })?,
+        None => Resolution::Hourly,
+    };
+    if forecast_method.is_some() && resolution != Resolution::Hourly {
+        return Err(McpError::invalid_params(
+            "Forecasts need hourly buckets; use resolution '1h'".to_string(),
+            None,
+        ));
+    }
+    let zone = match input.timezone.as_deref() {
+        Some(value) => LocalZone::parse(value).ok_or_else(|| {
+            McpError::invalid_params(
+                format!(
+                    "Invalid timezone '{}': expected UTC, an abbreviation like CET, or an offset like +02:00",
+                    value
+                ),
+                None,
+            )
+        })?,
+        None => LocalZone::utc(),
+    };
+    // Sentry's daily buckets start at UTC midnight, so local days are summed from hours.
+    let regroup_days = resolution == Resolution::Daily && !zone.is_utc();
+    let interval = if regroup_days {
+        Resolution::Hourly.interval()
+    } else {
+        resolution.interval()
+    };
+    let period = input.stats_period.as_deref().unwrap_or("7d");
+    let issue = client
+        .get_issue(&input.organization_slug, &input.issue_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let query = EventsStatsQuery {
+        query: Some(format!("issue.id:{}", issue.id)),
+        project: Some(issue.project.id.clone()),
+        stats_period: Some(period.to_string()),
+        interval: Some(interval.to_string()),
+        y_axis: Some("count()".to_string()),
+        ..Default::default()
+    };
+    let stats = client
+        .get_events_stats(&input.organization_slug, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let mut counts = stats.counts();
+    if regroup_days {
+        counts = zone.daily_buckets(&counts);
+    }
+    let output = format_issue_stats_output(
+        &issue,
+        period,
+        resolution,
+        &counts,
+        forecast_method,
+        input.threshold,
+        &zone,
+    );
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
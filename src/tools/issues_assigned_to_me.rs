@@ -0,0 +1,86 @@
+use crate::api_client::{Issue, IssuesQuery, SentryApi};
+use crate::format::escape_cell;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+pub const ASSIGNED_TO_ME_QUERY: &str = "assigned:me is:unresolved";
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssuesAssignedToMeInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Only issues of this project (default: all projects)")]
+    pub project_slug: Option<String>,
+    #[schemars(description = "Time window, e.g. '24h', '14d' (default: 14d)")]
+    pub stats_period: Option<String>,
+    #[schemars(description = "Maximum number of issues to return (default: 25, max: 100)")]
+    pub limit: Option<i32>,
+}
+
+pub fn format_issues_assigned_to_me(scope: &str, period: &str, issues: &[Issue]) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Assigned to Me: {}\n\n", scope));
+    output.push_str(&format!("**Period:** {}\n", period));
+    output.push_str(&format!("**Found:** {} issues\n\n", issues.len()));
+    if issues.is_empty() {
+        output.push_str("No unresolved issues are assigned to you.\n");
+        return output;
+    }
+    output.push_str("| Issue | Title | Project | Level | Users | Events | Last Seen |\n");
+    output.push_str("|---|---|---|---|---|---|---|\n");
+    for issue in issues {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} |\n",
+            issue.short_id,
+            escape_cell(&issue.title),
+            issue.project.slug,
+            issue.level.as_deref().unwrap_or("-"),
+            issue.user_count,
+            issue.count,
+            issue.last_seen.as_deref().unwrap_or("-")
+        ));
+    }
+    output.push_str("\nUse get_issue_details to investigate an issue.\n");
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: IssuesAssignedToMeInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let limit = input.limit.unwrap_or(25).clamp(1, 100);
+    let period = input.stats_period.as_deref().unwrap_or("14d");
+    let project_slug = input
+        .project_slug
+        .as_deref()
+        .map(str::trim)
+        .filter(|p| !p.is_empty());
+    let project = match project_slug {
+        Some(slug) => Some(
+            client
+                .get_project(org, slug)
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?
+                .id,
+        ),
+        None => None,
+    };
+    let query = IssuesQuery {
+        query: Some(ASSIGNED_TO_ME_QUERY.to_string()),
+        project,
+        stats_period: Some(period.to_string()),
+        sort: Some("date".to_string()),
+        limit: Some(limit),
+        ..Default::default()
+    };
+    let issues = client
+        .list_issues(org, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_issues_assigned_to_me(project_slug.unwrap_or(org), period, &issues);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,78 @@
+use crate::api_client::{SentryApi, Tombstone};
+use crate::json_ext::ValueExt;
+use crate::tools::list_issue_comments::user_name;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListDiscardedIssuesInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+}
+
+/// Title as Sentry shows it: `Type: value` for errors, otherwise the message.
+pub fn tombstone_title(tombstone: &Tombstone) -> String {
+    let meta = &tombstone.metadata;
+    if let Some(title) = meta.str_field("title").filter(|t| !t.is_empty()) {
+        return title.to_string();
+    }
+    match (meta.str_field("type"), meta.str_field("value")) {
+        (Some(kind), Some(value)) if !value.is_empty() => format!("{}: {}", kind, value),
+        (Some(kind), _) => kind.to_string(),
+        _ => tombstone
+            .message
+            .as_deref()
+            .filter(|m| !m.is_empty())
+            .unwrap_or("<unlabeled event>")
+            .to_string(),
+    }
+}
+
+pub fn format_tombstones_output(project_slug: &str, tombstones: &[Tombstone]) -> String {
+    let mut output = String::new();
+    output.push_str("# Discarded Issues\n\n");
+    output.push_str(&format!("**Project:** {}\n", project_slug));
+    output.push_str(&format!(
+        "**Found:** {} discarded issues\n\n",
+        tombstones.len()
+    ));
+    if tombstones.is_empty() {
+        output.push_str("No discarded issues in this project.\n");
+        return output;
+    }
+    for tombstone in tombstones {
+        output.push_str(&format!(
+            "## {} (tombstone {})\n\n",
+            tombstone_title(tombstone),
+            tombstone.id
+        ));
+        if let Some(culprit) = tombstone.culprit.as_deref().filter(|c| !c.is_empty()) {
+            output.push_str(&format!("- **Culprit:** {}\n", culprit));
+        }
+        if let Some(level) = &tombstone.level {
+            output.push_str(&format!("- **Level:** {}\n", level));
+        }
+        output.push_str(&format!(
+            "- **Discarded by:** {}\n\n",
+            user_name(tombstone.actor.as_ref())
+        ));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ListDiscardedIssuesInput,
+) -> Result<CallToolResult, McpError> {
+    let tombstones = client
+        .list_tombstones(&input.organization_slug, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_tombstones_output(&input.project_slug, &tombstones);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
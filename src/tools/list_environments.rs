@@ -0,0 +1,112 @@
+use crate::api_client::{Environment, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use tracing::warn;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListEnvironmentsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+}
+
+/// Maps a user-supplied environment to its exact name: exact match first, then a
+/// case-insensitive match, then a unique case-insensitive prefix (`prod` → `production`).
+pub fn match_environment<'a>(
+    environments: &'a [Environment],
+    requested: &str,
+) -> Result<&'a str, String> {
+    let requested = requested.trim();
+    if let Some(env) = environments.iter().find(|e| e.name == requested) {
+        return Ok(&env.name);
+    }
+    let lower = requested.to_lowercase();
+    let names = |pred: &dyn Fn(&str) -> bool| -> Vec<&'a str> {
+        environments
+            .iter()
+            .map(|e| e.name.as_str())
+            .filter(|name| pred(&name.to_lowercase()))
+            .collect()
+    };
+    let mut candidates = names(&|name| name == lower);
+    if candidates.is_empty() {
+        candidates = names(&|name| name.starts_with(&lower));
+    }
+    match candidates.as_slice() {
+        [only] => Ok(only),
+        [] => {
+            let mut all: Vec<&str> = environments.iter().map(|e| e.name.as_str()).collect();
+            all.sort_unstable();
+            Err(format!(
+                "Unknown environment '{}'; available: {}",
+                requested,
+                all.join(", ")
+            ))
+        }
+        many => Err(format!(
+            "Environment '{}' is ambiguous: {}",
+            requested,
+            many.join(", ")
+        )),
+    }
+}
+
+/// Validates an environment parameter against the organization's environments and
+/// completes it to the exact name. If the list can't be fetched or is empty, the
+/// value is passed through unchanged.
+pub async fn resolve_environment(
+    client: &impl SentryApi,
+    org_slug: &str,
+    requested: Option<&str>,
+) -> Result<Option<String>, McpError> {
+    let Some(requested) = requested else {
+        return Ok(None);
+    };
+    let environments = match client.list_environments(org_slug).await {
+        Ok(environments) => environments,
+        Err(e) => {
+            warn!("listing environments for {} failed: {}", org_slug, e);
+            return Ok(Some(requested.to_string()));
+        }
+    };
+    if environments.is_empty() {
+        return Ok(Some(requested.to_string()));
+    }
+    match_environment(&environments, requested)
+        .map(|name| Some(name.to_string()))
+        .map_err(|message| McpError::invalid_params(message, None))
+}
+
+pub fn format_environments_output(org_slug: &str, environments: &[Environment]) -> String {
+    let mut output = String::new();
+    output.push_str("# Environments\n\n");
+    output.push_str(&format!("**Organization:** {}\n", org_slug));
+    output.push_str(&format!(
+        "**Found:** {} environments\n\n",
+        environments.len()
+    ));
+    if environments.is_empty() {
+        output.push_str("No environments found.\n");
+        return output;
+    }
+    let mut sorted: Vec<&Environment> = environments.iter().collect();
+    sorted.sort_by(|a, b| a.name.cmp(&b.name));
+    for env in sorted {
+        output.push_str(&format!("- {} (ID: {})\n", env.name, env.id));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ListEnvironmentsInput,
+) -> Result<CallToolResult, McpError> {
+    let environments = client
+        .list_environments(&input.organization_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_environments_output(&input.organization_slug, &environments);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
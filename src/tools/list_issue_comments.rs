@@ -0,0 +1,78 @@
+use crate::api_client::{IssueComment, SentryApi, UserRef};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+const DEFAULT_LIMIT: usize = 50;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListIssueCommentsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+    #[schemars(description = "Maximum number of most recent comments to show (default: 50)")]
+    pub limit: Option<usize>,
+}
+
+pub fn comment_author(comment: &IssueComment) -> &str {
+    user_name(comment.user.as_ref())
+}
+
+/// Display name of an actor; `Sentry` when there is none (automatic or integration changes).
+pub fn user_name(user: Option<&UserRef>) -> &str {
+    user.and_then(|u| {
+        [&u.name, &u.username, &u.email]
+            .into_iter()
+            .filter_map(|field| field.as_deref())
+            .find(|name| !name.is_empty())
+    })
+    .unwrap_or("Sentry")
+}
+
+/// Renders comments oldest first so the triage discussion reads in order. `comments`
+/// is in API order (newest first); only the newest `limit` are shown.
+pub fn format_comments_output(issue_id: &str, comments: &[IssueComment], limit: usize) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Comments on {}\n\n", issue_id));
+    output.push_str(&format!("**Found:** {} comments", comments.len()));
+    if comments.len() > limit {
+        output.push_str(&format!(" (showing the latest {})", limit));
+    }
+    output.push_str("\n\n");
+    if comments.is_empty() {
+        output.push_str("No comments on this issue.\n");
+        return output;
+    }
+    for comment in comments.iter().take(limit).rev() {
+        output.push_str(&format!("### {}", comment_author(comment)));
+        if let Some(date) = &comment.date_created {
+            output.push_str(&format!(" ({})", date));
+        }
+        output.push_str("\n\n");
+        for line in comment.data.text.trim().lines() {
+            if line.trim().is_empty() {
+                output.push_str(">\n");
+            } else {
+                output.push_str(&format!("> {}\n", line));
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ListIssueCommentsInput,
+) -> Result<CallToolResult, McpError> {
+    let comments = client
+        .list_issue_comments(&input.organization_slug, &input.issue_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let limit = input.limit.unwrap_or(DEFAULT_LIMIT).max(1);
+    let output = format_comments_output(&input.issue_id, &comments, limit);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,247 @@
+use crate::api_client::{Issue, IssuesQuery, SearchHits, SentryApi};
+use crate::format::escape_cell;
+use crate::tools::cross_org::for_each_organization;
+use crate::tools::list_environments::resolve_environment;
+use crate::tools::search_query::{QueryTarget, check_query, push_query_warnings};
+use chrono::{DateTime, Utc};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+pub const SORTS: [&str; 6] = ["date", "new", "freq", "user", "trends", "inbox"];
+/// Local sort by [`attention_score`]; issues are fetched by last seen first.
+pub const ATTENTION_SORT: &str = "attention";
+/// Hours after which an issue's recency weight halves.
+const RECENCY_HALF_LIFE_HOURS: f64 = 24.0;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListIssuesInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug (omit to search the whole organization)")]
+    pub project_slug: Option<String>,
+    #[schemars(
+        description = "Sentry issue search query (default: 'is:unresolved'). Examples: \
+        'is:unresolved level:error', 'assigned:me', 'TypeError', 'release:1.2.0', \
+        'firstSeen:-24h', 'times_seen:>100'"
+    )]
+    pub query: Option<String>,
+    #[schemars(
+        description = "Only issues linked to an external ticket (Jira, GitHub, ...) when true, only unlinked ones when false (optional)"
+    )]
+    pub linked: Option<bool>,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+    #[schemars(description = "Time window, e.g. '24h', '14d' (default: 14d)")]
+    pub stats_period: Option<String>,
+    #[schemars(
+        description = "Sort order: 'date' (last seen, default), 'new' (first seen), 'freq' (events), 'user' (users), 'trends', 'inbox', or 'attention' (the recently seen issues ranked by attention score)"
+    )]
+    pub sort: Option<String>,
+    #[schemars(description = "Maximum number of issues to return (default: 25, max: 100)")]
+    pub limit: Option<i32>,
+    #[schemars(
+        description = "Return only the number of matching issues, not the issues themselves (optional)"
+    )]
+    pub count_only: Option<bool>,
+    #[schemars(
+        description = "Run the search in every organization the token can access, one section per organization (organization_slug is ignored)"
+    )]
+    pub all_organizations: Option<bool>,
+}
+
+/// Adds `is:linked` or `is:unlinked` to the search query.
+pub fn with_linked_filter(query: &str, linked: Option<bool>) -> String {
+    match linked {
+        Some(true) => format!("{} is:linked", query),
+        Some(false) => format!("{} is:unlinked", query),
+        None => query.to_string(),
+    }
+}
+
+fn level_weight(level: Option<&str>) -> f64 {
+    match level {
+        Some("fatal") => 4.0,
+        Some("error") => 3.0,
+        Some("warning") => 2.0,
+        _ => 1.0,
+    }
+}
+
+/// How much an issue deserves a look: recency × volume × users × level.
+/// Recency halves every 24 hours since the issue was last seen; events and
+/// users count logarithmically; fatal weighs 4, error 3, warning 2, others 1.
+pub fn attention_score(issue: &Issue, now: DateTime<Utc>) -> f64 {
+    let recency = issue
+        .last_seen
+        .as_deref()
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map_or(0.0, |seen| {
+            let hours = (now - seen.with_timezone(&Utc)).num_seconds().max(0) as f64 / 3600.0;
+            0.5f64.powf(hours / RECENCY_HALF_LIFE_HOURS)
+        });
+    let events: f64 = issue.count.parse().unwrap_or(0.0);
+    let volume = 1.0 + events.ln_1p();
+    let users = 1.0 + (issue.user_count.max(0) as f64).ln_1p();
+    recency * volume * users * level_weight(issue.level.as_deref())
+}
+
+/// Sorts issues by attention score, highest first.
+pub fn sort_by_attention(issues: &mut [Issue], now: DateTime<Utc>) {
+    issues.sort_by(|a, b| attention_score(b, now).total_cmp(&attention_score(a, now)));
+}
+
+/// The short ID, followed by linked tickets like `PROJ-1 (JIRA-123)`.
+fn issue_cell(issue: &Issue) -> String {
+    if issue.annotations.is_empty() {
+        return issue.short_id.clone();
+    }
+    let tickets: Vec<&str> = issue
+        .annotations
+        .iter()
+        .map(|a| a.display_name.as_str())
+        .collect();
+    format!("{} ({})", issue.short_id, tickets.join(", "))
+}
+
+pub fn format_issues_table(
+    scope: &str,
+    query: &str,
+    issues: &[Issue],
+    now: DateTime<Utc>,
+) -> String {
+    let mut output = String::new();
+    output.push_str("# Issues\n\n");
+    output.push_str(&format!("**Scope:** {}\n", scope));
+    output.push_str(&format!("**Query:** {}\n", query));
+    output.push_str(&format!("**Found:** {} issues\n\n", issues.len()));
+    if issues.is_empty() {
+        output.push_str("No issues found matching the query.\n");
+        return output;
+    }
+    output.push_str(
+        "| Issue | Title | Project | Status | Level | Events | Users | Last Seen | Score |\n",
+    );
+    output.push_str("|---|---|---|---|---|---|---|---|---|\n");
+    for issue in issues {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} | {} | {:.1} |\n",
+            issue_cell(issue),
+            escape_cell(&issue.title),
+            issue.project.slug,
+            issue.substatus.as_deref().unwrap_or(&issue.status),
+            issue.level.as_deref().unwrap_or("-"),
+            issue.count,
+            issue.user_count,
+            issue.last_seen.as_deref().unwrap_or("-"),
+            attention_score(issue, now)
+        ));
+    }
+    output
+}
+
+/// How many issues match, with `+` when Sentry stopped counting.
+pub fn format_issue_count(scope: &str, query: &str, hits: SearchHits) -> String {
+    let mut output = String::new();
+    output.push_str("# Issue Count\n\n");
+    output.push_str(&format!("**Scope:** {}\n", scope));
+    output.push_str(&format!("**Query:** {}\n", query));
+    output.push_str(&format!(
+        "**Count:** {}{}\n",
+        hits.hits,
+        if hits.capped() { "+" } else { "" }
+    ));
+    output
+}
+
+async fn list_in_org(
+    client: &impl SentryApi,
+    org_slug: &str,
+    input: &ListIssuesInput,
+    sort: &str,
+) -> Result<String, McpError> {
+    let project_id = match &input.project_slug {
+        Some(slug) => Some(
+            client
+                .get_project(org_slug, slug)
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?
+                .id,
+        ),
+        None => None,
+    };
+    let environment = resolve_environment(client, org_slug, input.environment.as_deref()).await?;
+    let attention = sort == ATTENTION_SORT;
+    let query_string = with_linked_filter(
+        input.query.as_deref().unwrap_or("is:unresolved"),
+        input.linked,
+    );
+    let query = IssuesQuery {
+        query: Some(query_string.clone()),
+        project: project_id,
+        environment,
+        stats_period: Some(
+            input
+                .stats_period
+                .clone()
+                .unwrap_or_else(|| "14d".to_string()),
+        ),
+        sort: Some(if attention { "date" } else { sort }.to_string()),
+        limit: Some(input.limit.unwrap_or(25).clamp(1, 100)),
+        ..Default::default()
+    };
+    let scope = match &input.project_slug {
+        Some(project) => format!("{}/{}", org_slug, project),
+        None => org_slug.to_string(),
+    };
+    if input.count_only.unwrap_or(false) {
+        let hits = client
+            .count_search_hits(org_slug, &query)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        return Ok(format_issue_count(&scope, &query_string, hits));
+    }
+    let mut issues = client
+        .list_issues(org_slug, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let now = Utc::now();
+    if attention {
+        sort_by_attention(&mut issues, now);
+    }
+    Ok(format_issues_table(&scope, &query_string, &issues, now))
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ListIssuesInput,
+) -> Result<CallToolResult, McpError> {
+    let sort = input.sort.as_deref().unwrap_or("date");
+    if !SORTS.contains(&sort) && sort != ATTENTION_SORT {
+        return Err(McpError::invalid_params(
+            format!(
+                "Invalid sort '{}': expected one of {}, {}",
+                sort,
+                SORTS.join(", "),
+                ATTENTION_SORT
+            ),
+            None,
+        ));
+    }
+    let warnings = check_query(input.query.as_deref(), QueryTarget::Issues)?;
+    let mut output = if input.all_organizations.unwrap_or(false) {
+        let input = &input;
+        for_each_organization(client, |org| async move {
+            list_in_org(client, &org, input, sort).await
+        })
+        .await?
+    } else {
+        list_in_org(client, &input.organization_slug, &input, sort).await?
+    };
+    push_query_warnings(&mut output, &warnings);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
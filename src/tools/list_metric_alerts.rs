@@ -0,0 +1,154 @@
+use crate::api_client::{MetricAlertAction, MetricAlertRule, MetricAlertTrigger, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListMetricAlertsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Only show rules that watch this project")]
+    pub project_slug: Option<String>,
+    #[schemars(description = "Only show rules whose name contains this text")]
+    pub filter: Option<String>,
+}
+
+pub fn filter_rules<'a>(
+    rules: &'a [MetricAlertRule],
+    project: Option<&str>,
+    filter: Option<&str>,
+) -> Vec<&'a MetricAlertRule> {
+    let needle = filter.map(|f| f.trim().to_lowercase()).unwrap_or_default();
+    rules
+        .iter()
+        .filter(|r| project.is_none_or(|p| r.projects.iter().any(|rp| rp == p)))
+        .filter(|r| needle.is_empty() || r.name.to_lowercase().contains(&needle))
+        .collect()
+}
+
+/// Whole numbers without a fractional part.
+pub fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        value.to_string()
+    }
+}
+
+/// A window in minutes as `5m`, `1h` or `1d`.
+pub fn format_window(minutes: f64) -> String {
+    if minutes >= 1440.0 && minutes % 1440.0 == 0.0 {
+        format!("{}d", minutes / 1440.0)
+    } else if minutes >= 60.0 && minutes % 60.0 == 0.0 {
+        format!("{}h", minutes / 60.0)
+    } else {
+        format!("{}m", format_number(minutes))
+    }
+}
+
+/// `above 100 (resolves below 50)` for a trigger, using the rule's direction.
+pub fn format_threshold(trigger: &MetricAlertTrigger, rule: &MetricAlertRule) -> String {
+    let below = trigger.threshold_type.or(rule.threshold_type) == Some(1);
+    let (direction, resolve_direction) = if below {
+        ("below", "above")
+    } else {
+        ("above", "below")
+    };
+    let mut text = match trigger.alert_threshold {
+        Some(threshold) => format!("{} {}", direction, format_number(threshold)),
+        None => "no threshold".to_string(),
+    };
+    if let Some(resolve) = trigger.resolve_threshold.or(rule.resolve_threshold) {
+        text.push_str(&format!(
+            " (resolves {} {})",
+            resolve_direction,
+            format_number(resolve)
+        ));
+    }
+    text
+}
+
+pub fn format_action(action: &MetricAlertAction) -> String {
+    if let Some(desc) = action.desc.as_deref().filter(|d| !d.is_empty()) {
+        return format!("{}: {}", action.action_type, desc);
+    }
+    match (&action.target_type, &action.target_identifier) {
+        (Some(kind), Some(target)) => format!("{} → {} {}", action.action_type, kind, target),
+        (None, Some(target)) => format!("{} → {}", action.action_type, target),
+        _ => action.action_type.clone(),
+    }
+}
+
+fn format_rule(output: &mut String, rule: &MetricAlertRule) {
+    output.push_str(&format!("## {} (ID: {})\n\n", rule.name, rule.id));
+    let mut metric = format!("`{}`", rule.aggregate);
+    if let Some(window) = rule.time_window {
+        metric.push_str(&format!(" over {}", format_window(window)));
+    }
+    if let Some(dataset) = &rule.dataset {
+        metric.push_str(&format!(" (dataset: {})", dataset));
+    }
+    output.push_str(&format!("- **Metric:** {}\n", metric));
+    if !rule.query.is_empty() {
+        output.push_str(&format!("- **Filter:** `{}`\n", rule.query));
+    }
+    if !rule.projects.is_empty() {
+        output.push_str(&format!("- **Projects:** {}\n", rule.projects.join(", ")));
+    }
+    if let Some(environment) = &rule.environment {
+        output.push_str(&format!("- **Environment:** {}\n", environment));
+    }
+    if let Some(owner) = &rule.owner {
+        output.push_str(&format!("- **Owner:** {}\n", owner));
+    }
+    if rule.triggers.is_empty() {
+        output.push_str("- **Triggers:** none\n");
+    } else {
+        output.push_str("- **Triggers:**\n");
+    }
+    for trigger in &rule.triggers {
+        output.push_str(&format!(
+            "  - {}: {}\n",
+            trigger.label,
+            format_threshold(trigger, rule)
+        ));
+        for action in &trigger.actions {
+            output.push_str(&format!("    - {}\n", format_action(action)));
+        }
+    }
+    output.push('\n');
+}
+
+pub fn format_metric_alerts_output(org_slug: &str, rules: &[&MetricAlertRule]) -> String {
+    let mut output = String::new();
+    output.push_str("# Metric Alerts\n\n");
+    output.push_str(&format!("**Organization:** {}\n", org_slug));
+    output.push_str(&format!("**Found:** {} rules\n\n", rules.len()));
+    if rules.is_empty() {
+        output.push_str("No metric alert rules found.\n");
+        return output;
+    }
+    for rule in rules {
+        format_rule(&mut output, rule);
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ListMetricAlertsInput,
+) -> Result<CallToolResult, McpError> {
+    let rules = client
+        .list_metric_alerts(&input.organization_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let filtered = filter_rules(
+        &rules,
+        input.project_slug.as_deref(),
+        input.filter.as_deref(),
+    );
+    let output = format_metric_alerts_output(&input.organization_slug, &filtered);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,119 @@
+use crate::api_client::{KeyRateLimit, ProjectKey, SentryApi};
+use crate::tools::list_metric_alerts::format_window;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Trailing characters of a secret left visible to tell keys apart.
+const VISIBLE_SECRET_CHARS: usize = 4;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListProjectKeysInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(
+        description = "Show secret keys and secret DSNs in full (default: false, redacted); only legacy SDKs need them"
+    )]
+    pub reveal_secrets: Option<bool>,
+}
+
+/// `****a1b2`: the secret with all but its last characters masked.
+pub fn redact(secret: &str) -> String {
+    let chars: Vec<char> = secret.chars().collect();
+    if chars.len() <= VISIBLE_SECRET_CHARS * 2 {
+        return "****".to_string();
+    }
+    let tail: String = chars[chars.len() - VISIBLE_SECRET_CHARS..].iter().collect();
+    format!("****{}", tail)
+}
+
+/// `1000 events per 1h`, or `none` without a limit.
+pub fn format_rate_limit(limit: Option<&KeyRateLimit>) -> String {
+    match limit {
+        Some(limit) if limit.window > 0 => format!(
+            "{} events per {}",
+            limit.count,
+            format_window(limit.window as f64 / 60.0)
+        ),
+        _ => "none".to_string(),
+    }
+}
+
+fn format_key(output: &mut String, key: &ProjectKey, reveal: bool) {
+    let name = key.name.as_deref().unwrap_or(&key.id);
+    let state = if key.is_active { "active" } else { "DISABLED" };
+    output.push_str(&format!("## {} ({})\n\n", name, state));
+    if let Some(dsn) = &key.dsn.public {
+        output.push_str(&format!("- **DSN:** {}\n", dsn));
+    }
+    output.push_str(&format!("- **Public Key:** {}\n", key.public));
+    if let Some(secret) = &key.secret {
+        if reveal {
+            output.push_str(&format!("- **Secret Key:** {}\n", secret));
+            if let Some(dsn) = &key.dsn.secret {
+                output.push_str(&format!("- **Secret DSN:** {}\n", dsn));
+            }
+        } else {
+            output.push_str(&format!(
+                "- **Secret Key:** {} (redacted)\n",
+                redact(secret)
+            ));
+        }
+    }
+    output.push_str(&format!(
+        "- **Rate Limit:** {}\n",
+        format_rate_limit(key.rate_limit.as_ref())
+    ));
+    if let Some(endpoint) = &key.dsn.security {
+        output.push_str(&format!("- **Security (CSP) Endpoint:** {}\n", endpoint));
+    }
+    if let Some(endpoint) = &key.dsn.minidump {
+        output.push_str(&format!("- **Minidump Endpoint:** {}\n", endpoint));
+    }
+    if let Some(version) = &key.browser_sdk_version {
+        output.push_str(&format!("- **Loader SDK Version:** {}\n", version));
+    }
+    if let Some(created) = &key.date_created {
+        output.push_str(&format!("- **Created:** {}\n", created));
+    }
+    output.push('\n');
+}
+
+pub fn format_project_keys_output(project: &str, keys: &[ProjectKey], reveal: bool) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Client Keys: {}\n\n", project));
+    let active = keys.iter().filter(|k| k.is_active).count();
+    output.push_str(&format!("**Keys:** {} ({} active)\n\n", keys.len(), active));
+    if keys.is_empty() {
+        output.push_str("No client keys found; the project cannot receive events.\n");
+        return output;
+    }
+    if active == 0 {
+        output
+            .push_str("⚠️ Every key is disabled, so events sent to this project are rejected.\n\n");
+    }
+    for key in keys {
+        format_key(&mut output, key, reveal);
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ListProjectKeysInput,
+) -> Result<CallToolResult, McpError> {
+    let keys = client
+        .list_project_keys(&input.organization_slug, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_project_keys_output(
+        &input.project_slug,
+        &keys,
+        input.reveal_secrets.unwrap_or(false),
+    );
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
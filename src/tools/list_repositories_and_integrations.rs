@@ -0,0 +1,140 @@
+use crate::api_client::{Integration, Repository, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Features that depend on an integration, with the integration features
+/// (`provider.features`) that provide them.
+const CAPABILITIES: [(&str, &[&str]); 5] = [
+    ("Stack trace links and code mappings", &["stacktrace-link"]),
+    ("Commit tracking and suspect commits", &["commits"]),
+    ("Code owners", &["codeowners"]),
+    (
+        "External issues (create or link tickets)",
+        &["issue-basic", "issue-sync"],
+    ),
+    ("Alert notifications", &["alert-rule"]),
+];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListRepositoriesAndIntegrationsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+}
+
+/// Integrations and repositories without a status are treated as active.
+fn is_active(status: Option<&str>) -> bool {
+    status.is_none_or(|s| s == "active")
+}
+
+/// Names of the active integrations offering any of `features`.
+pub fn providers_with<'a>(integrations: &'a [Integration], features: &[&str]) -> Vec<&'a str> {
+    let mut names: Vec<&str> = integrations
+        .iter()
+        .filter(|i| is_active(i.status.as_deref()))
+        .filter(|i| {
+            i.provider
+                .features
+                .iter()
+                .any(|f| features.contains(&f.as_str()))
+        })
+        .map(|i| i.provider.name.as_str())
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn format_repositories(output: &mut String, repos: &[Repository]) {
+    output.push_str(&format!("## Repositories ({})\n\n", repos.len()));
+    if repos.is_empty() {
+        output.push_str(
+            "No repositories linked; code mappings, suspect commits and release commits need one.\n",
+        );
+        return;
+    }
+    output.push_str("| Name | Provider | Status | URL |\n");
+    output.push_str("|---|---|---|---|\n");
+    for repo in repos {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            repo.name,
+            repo.provider.as_ref().map_or("-", |p| p.name.as_str()),
+            repo.status.as_deref().unwrap_or("-"),
+            repo.url.as_deref().unwrap_or("-")
+        ));
+    }
+}
+
+fn format_integrations(output: &mut String, integrations: &[Integration]) {
+    output.push_str(&format!("\n## Integrations ({})\n\n", integrations.len()));
+    if integrations.is_empty() {
+        output.push_str("No integrations installed.\n");
+        return;
+    }
+    output.push_str("| Provider | Name | Status | Features |\n");
+    output.push_str("|---|---|---|---|\n");
+    for integration in integrations {
+        let name = match &integration.domain_name {
+            Some(domain) => format!("{} ({})", integration.name, domain),
+            None => integration.name.clone(),
+        };
+        output.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            integration.provider.name,
+            name,
+            integration.status.as_deref().unwrap_or("-"),
+            integration.provider.features.join(", ")
+        ));
+    }
+}
+
+pub fn format_repositories_and_integrations(
+    org: &str,
+    repos: &[Repository],
+    integrations: &[Integration],
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Repositories and Integrations: {}\n\n", org));
+    format_repositories(&mut output, repos);
+    format_integrations(&mut output, integrations);
+    output.push_str("\n## Available Features\n\n");
+    for (capability, features) in CAPABILITIES {
+        let providers = providers_with(integrations, features);
+        if providers.is_empty() {
+            output.push_str(&format!("- ❌ {}: no active integration\n", capability));
+        } else {
+            output.push_str(&format!("- ✅ {}: {}\n", capability, providers.join(", ")));
+        }
+    }
+    let inactive = integrations
+        .iter()
+        .filter(|i| !is_active(i.status.as_deref()))
+        .count();
+    if inactive > 0 {
+        output.push_str(&format!(
+            "\n{} integrations are not active and provide no features until re-enabled.\n",
+            inactive
+        ));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ListRepositoriesAndIntegrationsInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let repos = client
+        .list_repositories(org)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let integrations = client
+        .list_integrations(org)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_repositories_and_integrations(org, &repos, &integrations);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,75 @@
+use crate::api_client::{SentryApi, Team};
+use crate::format::escape_cell;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListTeamsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Only show teams whose slug or name contains this text")]
+    pub filter: Option<String>,
+}
+
+pub fn filter_teams<'a>(teams: &'a [Team], filter: Option<&str>) -> Vec<&'a Team> {
+    let needle = filter.map(|f| f.trim().to_lowercase()).unwrap_or_default();
+    let mut matched: Vec<&Team> = teams
+        .iter()
+        .filter(|t| {
+            needle.is_empty()
+                || t.slug.to_lowercase().contains(&needle)
+                || t.name.to_lowercase().contains(&needle)
+        })
+        .collect();
+    matched.sort_by(|a, b| a.slug.cmp(&b.slug));
+    matched
+}
+
+pub fn format_teams_output(org_slug: &str, teams: &[&Team]) -> String {
+    let mut output = String::new();
+    output.push_str("# Teams\n\n");
+    output.push_str(&format!("**Organization:** {}\n", org_slug));
+    output.push_str(&format!("**Found:** {} teams\n\n", teams.len()));
+    if teams.is_empty() {
+        output.push_str("No teams found.\n");
+        return output;
+    }
+    output.push_str("| Team | Name | ID | Members | Projects |\n");
+    output.push_str("|---|---|---|---|---|\n");
+    for team in teams {
+        let projects: Vec<&str> = team.projects.iter().map(|p| p.slug.as_str()).collect();
+        let member = if team.is_member { " (member)" } else { "" };
+        output.push_str(&format!(
+            "| {}{} | {} | {} | {} | {} |\n",
+            team.slug,
+            member,
+            escape_cell(&team.name),
+            team.id,
+            team.member_count
+                .map_or_else(|| "-".to_string(), |c| c.to_string()),
+            if projects.is_empty() {
+                "-".to_string()
+            } else {
+                projects.join(", ")
+            }
+        ));
+    }
+    output.push_str("\nAssign issues to a team with `assigned_to: \"team:<id>\"`.\n");
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ListTeamsInput,
+) -> Result<CallToolResult, McpError> {
+    let teams = client
+        .list_teams(&input.organization_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let filtered = filter_teams(&teams, input.filter.as_deref());
+    let output = format_teams_output(&input.organization_slug, &filtered);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,142 @@
+use crate::api_client::{DiscoverQuery, DiscoverResult, SentryApi};
+use crate::format::{escape_cell, format_duration};
+use crate::tools::list_environments::resolve_environment;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+const TPM: &str = "tpm()";
+const P50: &str = "p50(transaction.duration)";
+const P95: &str = "p95(transaction.duration)";
+const FAILURE_RATE: &str = "failure_rate()";
+const COUNT: &str = "count()";
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListTransactionsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(
+        description = "Ranking: 'slowest' (p95, default), 'volume' (tpm) or 'failing' (failure rate)"
+    )]
+    pub sort: Option<String>,
+    #[schemars(description = "Time window, e.g. '1h', '24h', '7d' (default: 24h)")]
+    pub stats_period: Option<String>,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+    #[schemars(description = "Extra search filter, e.g. 'transaction.op:http.server' (optional)")]
+    pub query: Option<String>,
+    #[schemars(description = "Maximum number of transactions (default: 20, max: 100)")]
+    pub limit: Option<i32>,
+}
+
+/// Events endpoint `sort` for a ranking name.
+pub fn sort_field(sort: Option<&str>) -> Result<&'static str, McpError> {
+    match sort.map(|s| s.trim().to_ascii_lowercase()).as_deref() {
+        None | Some("") | Some("slowest") => Ok("-p95(transaction.duration)"),
+        Some("volume") => Ok("-tpm()"),
+        Some("failing") => Ok("-failure_rate()"),
+        Some(other) => Err(McpError::invalid_params(
+            format!(
+                "Invalid sort '{}': expected 'slowest', 'volume' or 'failing'",
+                other
+            ),
+            None,
+        )),
+    }
+}
+
+fn number(row: &Map<String, Value>, field: &str) -> f64 {
+    row.get(field).and_then(Value::as_f64).unwrap_or(0.0)
+}
+
+pub fn format_transactions_output(
+    project_slug: &str,
+    period: &str,
+    sort: &str,
+    result: &DiscoverResult,
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Transactions: {}\n\n", project_slug));
+    output.push_str(&format!("**Period:** {}\n", period));
+    output.push_str(&format!(
+        "**Sorted by:** {}\n",
+        sort.trim_start_matches('-')
+    ));
+    output.push_str(&format!(
+        "**Found:** {} transactions\n\n",
+        result.data.len()
+    ));
+    if result.data.is_empty() {
+        output.push_str("No transactions found.\n");
+        return output;
+    }
+    output.push_str("| Transaction | TPM | p50 | p95 | Failure Rate | Count |\n");
+    output.push_str("|---|---|---|---|---|---|\n");
+    for row in &result.data {
+        let name = row
+            .get("transaction")
+            .and_then(Value::as_str)
+            .unwrap_or("(unnamed)");
+        output.push_str(&format!(
+            "| {} | {:.2} | {} | {} | {:.1}% | {:.0} |\n",
+            escape_cell(name),
+            number(row, TPM),
+            format_duration(number(row, P50)),
+            format_duration(number(row, P95)),
+            number(row, FAILURE_RATE) * 100.0,
+            number(row, COUNT)
+        ));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ListTransactionsInput,
+) -> Result<CallToolResult, McpError> {
+    let sort = sort_field(input.sort.as_deref())?;
+    let org = &input.organization_slug;
+    let period = input.stats_period.as_deref().unwrap_or("24h");
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let project = client
+        .get_project(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let mut query = "event.type:transaction".to_string();
+    if let Some(extra) = input
+        .query
+        .as_deref()
+        .map(str::trim)
+        .filter(|q| !q.is_empty())
+    {
+        query.push(' ');
+        query.push_str(extra);
+    }
+    let discover = DiscoverQuery {
+        fields: ["transaction", TPM, P50, P95, FAILURE_RATE, COUNT]
+            .iter()
+            .map(|f| f.to_string())
+            .collect(),
+        query: Some(query),
+        sort: Some(sort.to_string()),
+        stats_period: Some(period.to_string()),
+        projects: vec![project.id],
+        environments: environment.into_iter().collect(),
+        dataset: Some("transactions".to_string()),
+        per_page: Some(input.limit.unwrap_or(20).clamp(1, 100)),
+        ..Default::default()
+    };
+    let result = client
+        .query_events(org, &discover)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_transactions_output(&input.project_slug, period, sort, &result);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,161 @@
+use crate::api_client::{Issue, MergeResult, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Most issues merged into the primary in one call.
+pub const MAX_MERGE_ISSUES: usize = 50;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MergeIssuesInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(
+        description = "Issue to keep, like 'PROJECT-123' or numeric ID. Sentry keeps the issue with the most events, so this must be it"
+    )]
+    pub primary_issue_id: String,
+    #[schemars(description = "Issues to fold into the primary one (same project)")]
+    pub issue_ids: Vec<String>,
+}
+
+/// Trims and de-duplicates the issues to merge, rejecting the primary itself.
+pub fn validate_merge_ids(input: &MergeIssuesInput) -> Result<Vec<String>, McpError> {
+    let primary = input.primary_issue_id.trim();
+    if primary.is_empty() {
+        return Err(McpError::invalid_params(
+            "primary_issue_id must not be empty".to_string(),
+            None,
+        ));
+    }
+    let mut ids: Vec<String> = Vec::new();
+    for id in input.issue_ids.iter().map(|id| id.trim()) {
+        if id.is_empty() || ids.iter().any(|seen| seen == id) {
+            continue;
+        }
+        if id.eq_ignore_ascii_case(primary) {
+            return Err(McpError::invalid_params(
+                format!(
+                    "{} is the primary issue; list only the issues to merge into it",
+                    id
+                ),
+                None,
+            ));
+        }
+        ids.push(id.to_string());
+    }
+    if ids.is_empty() {
+        return Err(McpError::invalid_params(
+            "issue_ids must name at least one issue to merge".to_string(),
+            None,
+        ));
+    }
+    if ids.len() > MAX_MERGE_ISSUES {
+        return Err(McpError::invalid_params(
+            format!("At most {} issues can be merged at once", MAX_MERGE_ISSUES),
+            None,
+        ));
+    }
+    Ok(ids)
+}
+
+fn event_count(issue: &Issue) -> u64 {
+    issue.count.parse().unwrap_or(0)
+}
+
+fn numeric_id(issue: &Issue) -> u64 {
+    issue.id.parse().unwrap_or(0)
+}
+
+/// Checks that Sentry will keep `primary`: merges stay within one project, and the
+/// bulk endpoint keeps the issue with the most events (ties go to the newest issue).
+pub fn check_merge(primary: &Issue, others: &[Issue]) -> Result<(), McpError> {
+    if let Some(other) = others.iter().find(|i| i.project.id != primary.project.id) {
+        return Err(McpError::invalid_params(
+            format!(
+                "{} is in project {} but {} is in {}; only issues of the same project can be merged",
+                other.short_id, other.project.slug, primary.short_id, primary.project.slug
+            ),
+            None,
+        ));
+    }
+    if let Some(other) = others.iter().find(|i| i.id == primary.id) {
+        return Err(McpError::invalid_params(
+            format!("{} is the primary issue", other.short_id),
+            None,
+        ));
+    }
+    let kept = others
+        .iter()
+        .chain(std::iter::once(primary))
+        .max_by_key(|i| (event_count(i), numeric_id(i)))
+        .unwrap_or(primary);
+    if kept.id != primary.id {
+        return Err(McpError::invalid_params(
+            format!(
+                "Sentry keeps the issue with the most events when merging: {} ({} events) would be kept instead of {} ({} events). Use {} as primary_issue_id",
+                kept.short_id, kept.count, primary.short_id, primary.count, kept.short_id
+            ),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+pub fn format_merge_output(primary: &Issue, others: &[Issue], result: &MergeResult) -> String {
+    let mut output = String::new();
+    output.push_str("# Issues Merged\n\n");
+    output.push_str(&format!(
+        "**Primary:** {} - {}\n",
+        primary.short_id, primary.title
+    ));
+    output.push_str(&format!("**Project:** {}\n", primary.project.slug));
+    if result.parent != primary.id {
+        output.push_str(&format!(
+            "**Note:** Sentry kept issue {} as the primary\n",
+            result.parent
+        ));
+    }
+    output.push_str(&format!("\n**Merged:** {} issues\n\n", others.len()));
+    for issue in others {
+        output.push_str(&format!(
+            "- {} - {} ({} events)\n",
+            issue.short_id, issue.title, issue.count
+        ));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: MergeIssuesInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let ids = validate_merge_ids(&input)?;
+    let primary = client
+        .get_issue(org, input.primary_issue_id.trim())
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let mut others = Vec::with_capacity(ids.len());
+    for id in &ids {
+        let issue = client
+            .get_issue(org, id)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        if !others.iter().any(|o: &Issue| o.id == issue.id) {
+            others.push(issue);
+        }
+    }
+    check_merge(&primary, &others)?;
+    let numeric_ids: Vec<String> = std::iter::once(&primary)
+        .chain(&others)
+        .map(|issue| issue.id.clone())
+        .collect();
+    let result = client
+        .merge_issues(org, &primary.project.slug, &numeric_ids)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_merge_output(&primary, &others, &result);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,163 @@
+use crate::api_client::{EventAttachment, SentryApi};
+use crate::minidump::{self, Minidump, architecture_name, exception_name, platform_name};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Minidumps larger than this are not downloaded.
+const MAX_MINIDUMP_BYTES: u64 = 64 * 1024 * 1024;
+const MINIDUMP_TYPE: &str = "event.minidump";
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MinidumpSummaryInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(description = "Event ID that has a minidump attachment")]
+    pub event_id: String,
+    #[schemars(description = "Attachment ID (default: the event's first minidump attachment)")]
+    pub attachment_id: Option<String>,
+    #[schemars(description = "Maximum modules to list (default: 30, 0 for none)")]
+    pub module_limit: Option<usize>,
+}
+
+/// Picks the requested attachment, or the first minidump if none was requested.
+pub fn select_attachment<'a>(
+    attachments: &'a [EventAttachment],
+    attachment_id: Option<&str>,
+) -> Option<&'a EventAttachment> {
+    match attachment_id {
+        Some(id) => attachments.iter().find(|a| a.id == id),
+        None => attachments
+            .iter()
+            .find(|a| a.attachment_type == MINIDUMP_TYPE),
+    }
+}
+
+fn os_description(dump: &Minidump) -> Option<String> {
+    let info = dump.system_info.as_ref()?;
+    let mut os = format!(
+        "{} {}.{}.{}",
+        platform_name(info.platform_id),
+        info.major_version,
+        info.minor_version,
+        info.build_number
+    );
+    if let Some(csd) = &info.csd_version {
+        os.push_str(&format!(" ({})", csd));
+    }
+    Some(os)
+}
+
+pub fn format_minidump_summary(
+    attachment: &EventAttachment,
+    dump: &Minidump,
+    module_limit: usize,
+) -> String {
+    let mut output = String::new();
+    output.push_str("# Minidump Summary\n\n");
+    output.push_str(&format!(
+        "**Attachment:** {} ({} bytes)\n",
+        attachment.name, attachment.size
+    ));
+    if let Some(os) = os_description(dump) {
+        output.push_str(&format!("**OS:** {}\n", os));
+    }
+    if let Some(info) = &dump.system_info {
+        output.push_str(&format!(
+            "**CPU:** {} ({} cores)\n",
+            architecture_name(info.processor_architecture),
+            info.processor_count
+        ));
+    }
+    output.push_str("\n## Crash\n\n");
+    match &dump.exception {
+        Some(exception) => {
+            let platform = dump.system_info.as_ref().map_or(0, |i| i.platform_id);
+            match exception_name(platform, exception.code) {
+                Some(name) => {
+                    output.push_str(&format!("**Reason:** {} ({:#x})\n", name, exception.code))
+                }
+                None => output.push_str(&format!("**Reason:** {:#x}\n", exception.code)),
+            }
+            output.push_str(&format!("**Crashing Thread:** {}\n", exception.thread_id));
+            if let Some(ip) = exception.instruction_pointer {
+                output.push_str(&format!("**Instruction Pointer:** {:#x}\n", ip));
+            }
+            output.push_str(&format!(
+                "**Exception Address:** {:#x}\n",
+                exception.address
+            ));
+            let crash_address = dump.crash_address().unwrap_or(exception.address);
+            match dump.module_at(crash_address) {
+                Some(module) => output.push_str(&format!(
+                    "**Crashing Module:** {} +{:#x}\n",
+                    module.name,
+                    crash_address - module.base
+                )),
+                None => output.push_str("**Crashing Module:** not in any loaded module\n"),
+            }
+        }
+        None => output.push_str("No exception stream; the dump may not be from a crash.\n"),
+    }
+    if module_limit > 0 && !dump.modules.is_empty() {
+        output.push_str(&format!("\n## Modules ({})\n\n", dump.modules.len()));
+        output.push_str("| Base | Size | Name |\n");
+        output.push_str("|---|---|---|\n");
+        for module in dump.modules.iter().take(module_limit) {
+            output.push_str(&format!(
+                "| {:#x} | {:#x} | {} |\n",
+                module.base, module.size, module.name
+            ));
+        }
+        if dump.modules.len() > module_limit {
+            output.push_str(&format!(
+                "\n*{} more modules not shown.*\n",
+                dump.modules.len() - module_limit
+            ));
+        }
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: MinidumpSummaryInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let attachments = client
+        .list_event_attachments(org, &input.project_slug, &input.event_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let attachment =
+        select_attachment(&attachments, input.attachment_id.as_deref()).ok_or_else(|| {
+            McpError::invalid_params(
+                format!(
+                    "Event {} has no matching minidump attachment",
+                    input.event_id
+                ),
+                None,
+            )
+        })?;
+    if attachment.size > MAX_MINIDUMP_BYTES {
+        return Err(McpError::invalid_params(
+            format!(
+                "Attachment {} is {} bytes; minidumps over {} bytes are not downloaded",
+                attachment.name, attachment.size, MAX_MINIDUMP_BYTES
+            ),
+            None,
+        ));
+    }
+    let bytes = client
+        .download_event_attachment(org, &input.project_slug, &input.event_id, &attachment.id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let dump = minidump::parse(&bytes).map_err(|e| {
+        McpError::invalid_params(format!("Failed to parse {}: {}", attachment.name, e), None)
+    })?;
+    let output = format_minidump_summary(attachment, &dump, input.module_limit.unwrap_or(30));
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
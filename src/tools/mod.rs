@@ -1,23 +1,238 @@
+pub mod add_issue_note;
+pub mod alerts;
+pub mod audit_log;
+pub mod bookmark_issue;
+pub mod cluster_issue_events;
+pub mod compare_release_traces;
+pub mod crash_free_sessions;
+pub mod create_saved_search;
+pub mod cross_org;
+pub mod debug_files;
+pub mod delete_issue;
+pub mod discard_issue;
+pub mod discover_query;
+pub mod discover_saved_queries;
+pub mod escalating_issues;
+pub mod event_attachments;
+pub mod fatal_issues;
+pub mod get_data_scrubbing;
+pub mod get_dynamic_sampling;
+pub mod get_event_raw_json;
 pub mod get_issue_details;
+pub mod get_issue_hashes;
+pub mod get_latest_events;
+pub mod get_profile;
+pub mod get_replay_details;
 pub mod get_trace_details;
+pub mod get_trace_logs;
+pub mod graphql;
+pub mod grep_attachment;
+pub mod invites;
+pub mod issue_activity;
+pub mod issue_counts_by_project;
+pub mod issue_rollup;
+pub mod issue_stats;
+pub mod issues_assigned_to_me;
+pub mod list_discarded_issues;
+pub mod list_environments;
+pub mod list_issue_comments;
+pub mod list_issues;
+pub mod list_metric_alerts;
+pub mod list_project_keys;
+pub mod list_repositories_and_integrations;
+pub mod list_teams;
+pub mod list_transactions;
+pub mod merge_issues;
+pub mod minidump_summary;
+pub mod monitor_checkins;
+pub mod org_stats;
+pub mod preview_metric_alert;
+pub mod project_stats;
+pub mod project_throughput;
+pub mod release_commits;
+pub mod release_deploys;
+pub mod release_health;
+pub mod repo_paths;
+pub mod sampling_report;
 pub mod search_issue_events;
+pub mod search_query;
+pub mod search_spans;
+pub mod server_stats;
+pub mod set_issue_priority;
+pub mod slo_report;
+pub mod source_context;
+pub mod split_issue;
+pub mod sql;
+pub mod stacktrace_link;
+pub mod subscribe_issue;
+pub mod suggest_issue_split;
+pub mod timezone;
+pub mod token_scope_advisor;
+pub mod transaction_summary;
+pub mod triage_queue;
+pub mod update_issue;
+pub mod uptime_monitors;
+pub mod user_feedback;
+pub mod weekly_report;
+pub mod whats_new;
 
-use crate::api_client::SentryApiClient;
-use get_issue_details::{GetIssueDetailsInput, execute as execute_get_issue_details};
+use crate::api_client::{SentryApiClient, USER_AGENT, with_policy};
+use crate::config::{Config, ToolPolicies};
+use crate::logging::new_call_id;
+use crate::output_style::OutputStyle;
+use add_issue_note::{AddIssueNoteInput, execute as execute_add_issue_note};
+use alerts::create_alert_rule::{CreateAlertRuleInput, execute as execute_create_alert_rule};
+use alerts::update_alert_rule::{UpdateAlertRuleInput, execute as execute_update_alert_rule};
+use audit_log::{AuditLogInput, execute as execute_audit_log};
+use bookmark_issue::{BookmarkIssueInput, execute as execute_bookmark_issue};
+use cluster_issue_events::{ClusterIssueEventsInput, execute as execute_cluster_issue_events};
+use compare_release_traces::{
+    CompareReleaseTracesInput, execute as execute_compare_release_traces,
+};
+use crash_free_sessions::{CrashFreeSessionsInput, execute as execute_crash_free_sessions};
+use create_saved_search::{CreateSavedSearchInput, execute as execute_create_saved_search};
+use debug_files::{DebugFilesInput, execute as execute_debug_files};
+use delete_issue::{DeleteIssueInput, execute as execute_delete_issue};
+use discard_issue::{DiscardIssueInput, execute as execute_discard_issue};
+use discover_query::{DiscoverQueryInput, execute as execute_discover_query};
+use discover_saved_queries::{
+    DiscoverSavedQueriesInput, execute as execute_discover_saved_queries,
+};
+use escalating_issues::{EscalatingIssuesInput, execute as execute_escalating_issues};
+use event_attachments::{
+    ATTACHMENT_SCHEME, EventAttachmentsInput, execute as execute_event_attachments,
+    read_resource as read_attachment_resource,
+};
+use fatal_issues::{FatalIssuesInput, execute as execute_fatal_issues};
+use get_data_scrubbing::{GetDataScrubbingInput, execute as execute_get_data_scrubbing};
+use get_dynamic_sampling::{GetDynamicSamplingInput, execute as execute_get_dynamic_sampling};
+use get_event_raw_json::{GetEventRawJsonInput, execute as execute_get_event_raw_json};
+use get_issue_details::{
+    FormatOptions, GetIssueDetailsInput, execute_with as execute_get_issue_details,
+};
+use get_issue_hashes::{GetIssueHashesInput, execute as execute_get_issue_hashes};
+use get_latest_events::{GetLatestEventsInput, execute as execute_get_latest_events};
+use get_profile::{GetProfileInput, execute as execute_get_profile};
+use get_replay_details::{GetReplayDetailsInput, execute as execute_get_replay_details};
 use get_trace_details::{GetTraceDetailsInput, execute as execute_get_trace_details};
+use get_trace_logs::{GetTraceLogsInput, execute as execute_get_trace_logs};
+use grep_attachment::{GrepAttachmentInput, execute as execute_grep_attachment};
+use invites::invite_member::{InviteMemberInput, execute as execute_invite_member};
+use invites::list_pending_invites::{
+    ListPendingInvitesInput, execute as execute_list_pending_invites,
+};
+use issue_activity::{IssueActivityInput, execute as execute_issue_activity};
+use issue_counts_by_project::{
+    IssueCountsByProjectInput, execute as execute_issue_counts_by_project,
+};
+use issue_rollup::{IssueRollupInput, execute as execute_issue_rollup};
+use issue_stats::{IssueStatsInput, execute as execute_issue_stats};
+use issues_assigned_to_me::{IssuesAssignedToMeInput, execute as execute_issues_assigned_to_me};
+use list_discarded_issues::{ListDiscardedIssuesInput, execute as execute_list_discarded_issues};
+use list_environments::{ListEnvironmentsInput, execute as execute_list_environments};
+use list_issue_comments::{ListIssueCommentsInput, execute as execute_list_issue_comments};
+use list_issues::{ListIssuesInput, execute as execute_list_issues};
+use list_metric_alerts::{ListMetricAlertsInput, execute as execute_list_metric_alerts};
+use list_project_keys::{ListProjectKeysInput, execute as execute_list_project_keys};
+use list_repositories_and_integrations::{
+    ListRepositoriesAndIntegrationsInput, execute as execute_list_repositories_and_integrations,
+};
+use list_teams::{ListTeamsInput, execute as execute_list_teams};
+use list_transactions::{ListTransactionsInput, execute as execute_list_transactions};
+use merge_issues::{MergeIssuesInput, execute as execute_merge_issues};
+use minidump_summary::{MinidumpSummaryInput, execute as execute_minidump_summary};
+use monitor_checkins::{MonitorCheckinsInput, execute as execute_monitor_checkins};
+use org_stats::{OrgStatsInput, execute as execute_org_stats};
+use preview_metric_alert::{PreviewMetricAlertInput, execute as execute_preview_metric_alert};
+use project_stats::{ProjectStatsInput, execute as execute_project_stats};
+use project_throughput::{ProjectThroughputInput, execute as execute_project_throughput};
+use release_commits::{ReleaseCommitsInput, execute as execute_release_commits};
+use release_deploys::{ReleaseDeploysInput, execute as execute_release_deploys};
+use release_health::{ReleaseHealthInput, execute as execute_release_health};
 use rmcp::{
     ErrorData as McpError, ServerHandler,
-    handler::server::{router::tool::ToolRouter, wrapper::Parameters},
+    handler::server::{router::tool::ToolRouter, tool::ToolCallContext, wrapper::Parameters},
     model::*,
-    tool_handler, tool_router,
+    service::RequestContext,
+    tool_router,
 };
+use sampling_report::{SamplingReportInput, execute as execute_sampling_report};
 use search_issue_events::{SearchIssueEventsInput, execute as execute_search_events};
+use search_spans::{SearchSpansInput, execute as execute_search_spans};
+use server_stats::{ServerStats, ServerStatsInput, execute as execute_server_stats};
+use set_issue_priority::{SetIssuePriorityInput, execute as execute_set_issue_priority};
+use slo_report::{SloReportInput, execute as execute_slo_report};
+use split_issue::{SplitIssueInput, execute as execute_split_issue};
+use stacktrace_link::{StacktraceLinkInput, execute as execute_stacktrace_link};
 use std::sync::Arc;
-use tracing::info;
+use std::time::Instant;
+use subscribe_issue::{SubscribeIssueInput, execute as execute_subscribe_issue};
+use suggest_issue_split::{SuggestIssueSplitInput, execute as execute_suggest_issue_split};
+use token_scope_advisor::{TokenScopeAdvisorInput, execute as execute_token_scope_advisor};
+use tracing::{Instrument, info, info_span, warn};
+use transaction_summary::{TransactionSummaryInput, execute as execute_transaction_summary};
+use triage_queue::{TriageQueueInput, execute as execute_triage_queue};
+use update_issue::{UpdateIssueInput, execute as execute_update_issue};
+use uptime_monitors::{UptimeMonitorsInput, execute as execute_uptime_monitors};
+use user_feedback::{UserFeedbackInput, execute as execute_user_feedback};
+use weekly_report::{WeeklyReportInput, execute as execute_weekly_report};
+use whats_new::{WhatsNewInput, execute as execute_whats_new};
+
+/// Tools that modify Sentry data; only registered when writes are enabled.
+pub const WRITE_TOOLS: &[&str] = &[
+    "set_issue_priority",
+    "update_issue",
+    "create_saved_search",
+    "create_alert_rule",
+    "update_alert_rule",
+    "add_issue_note",
+    "merge_issues",
+    "discard_issue",
+    "bookmark_issue",
+    "subscribe_issue",
+    "invite_member",
+    "split_issue",
+];
+
+/// Tools that permanently delete Sentry data; only registered when destructive
+/// operations are enabled, independently of [`WRITE_TOOLS`].
+pub const DESTRUCTIVE_TOOLS: &[&str] = &["delete_issue"];
+
+/// Adds the optional `ascii` argument, read by [`ascii_argument`], to every tool's input schema.
+fn declare_ascii_argument(router: &mut ToolRouter<SentryTools>) {
+    let ascii = serde_json::json!({
+        "type": ["boolean", "null"],
+        "description": "Replace symbols and box-drawing characters in the output with ASCII \
+            (default: SENTRY_MCP_ASCII)",
+    });
+    for route in router.map.values_mut() {
+        let schema = Arc::make_mut(&mut route.attr.input_schema);
+        if let Some(properties) = schema
+            .entry("properties")
+            .or_insert_with(|| serde_json::json!({}))
+            .as_object_mut()
+        {
+            properties.insert("ascii".to_string(), ascii.clone());
+        }
+    }
+}
+
+/// The `ascii` argument of a tool call, which overrides `SENTRY_MCP_ASCII`.
+fn ascii_argument(request: &CallToolRequestParam) -> Option<bool> {
+    request.arguments.as_ref()?.get("ascii")?.as_bool()
+}
 
 #[derive(Clone)]
 pub struct SentryTools {
     client: Arc<SentryApiClient>,
+    format_options: FormatOptions,
+    output_style: OutputStyle,
+    ascii_only: bool,
+    tool_policies: ToolPolicies,
+    writes_enabled: bool,
+    destructive_enabled: bool,
+    prefetch_org: Option<String>,
+    started_at: Instant,
     tool_router: ToolRouter<SentryTools>,
 }
 
@@ -30,23 +245,77 @@ impl Default for SentryTools {
 #[tool_router]
 impl SentryTools {
     pub fn new() -> Self {
+        let config = Config::from_env();
+        let mut tool_router = Self::tool_router();
+        declare_ascii_argument(&mut tool_router);
+        if !config.allow_writes {
+            for name in WRITE_TOOLS {
+                tool_router.remove_route(name);
+            }
+        }
+        if !config.allow_destructive {
+            for name in DESTRUCTIVE_TOOLS {
+                tool_router.remove_route(name);
+            }
+        }
         Self {
             client: Arc::new(SentryApiClient::new()),
-            tool_router: Self::tool_router(),
+            format_options: FormatOptions {
+                in_app_include: config.in_app_include,
+                ..Default::default()
+            },
+            output_style: config.output_style,
+            ascii_only: config.ascii_only,
+            tool_policies: config.tool_policies,
+            writes_enabled: config.allow_writes,
+            destructive_enabled: config.allow_destructive,
+            prefetch_org: config.prefetch_org,
+            started_at: Instant::now(),
+            tool_router,
         }
     }
+    /// Loads the configured organization's metadata in the background, if any.
+    /// Must be called from within a Tokio runtime.
+    pub fn spawn_prefetch(&self) {
+        let Some(org) = self.prefetch_org.clone() else {
+            return;
+        };
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            let started = Instant::now();
+            match client.prefetch_org(&org).await {
+                Ok(summary) => info!(
+                    "Prefetched {} ({}): {} projects, {} environments in {} ms",
+                    org,
+                    summary.organization.name,
+                    summary.projects,
+                    summary.environments,
+                    started.elapsed().as_millis()
+                ),
+                Err(e) => warn!("Prefetching metadata for {} failed: {}", org, e),
+            }
+        });
+    }
     #[rmcp::tool(
-        description = "Retrieve detailed information about a specific Sentry issue including metadata, tags, and optionally an event. Accepts either an issueUrl OR (organizationSlug + issueId)."
+        description = "Retrieve detailed information about a specific Sentry issue including metadata, tags, and optionally an event. Accepts either an issueUrl OR (organizationSlug + issueId).",
+        annotations(read_only_hint = true)
     )]
     async fn get_issue_details(
         &self,
         Parameters(input): Parameters<GetIssueDetailsInput>,
     ) -> Result<CallToolResult, McpError> {
         info!("get_issue_details: {:?}", input);
-        execute_get_issue_details(&*self.client, input).await
+        execute_get_issue_details(
+            &*self.client,
+            input,
+            &self.format_options,
+            self.writes_enabled,
+        )
+        .await
     }
     #[rmcp::tool(
-        description = "Retrieve trace details including span tree and timing information. Useful for analyzing distributed system performance."
+        description = "Retrieve trace details including span tree and timing information. Useful for analyzing distributed system performance.",
+        annotations(read_only_hint = true)
     )]
     async fn get_trace_details(
         &self,
@@ -56,7 +325,8 @@ impl SentryTools {
         execute_get_trace_details(&*self.client, input).await
     }
     #[rmcp::tool(
-        description = "Search events for a specific issue using a query string. Returns matching events with their details."
+        description = "Search events for a specific issue using a query string. Returns matching events with their details. Set all_organizations to search every accessible organization.",
+        annotations(read_only_hint = true)
     )]
     async fn search_issue_events(
         &self,
@@ -65,10 +335,911 @@ impl SentryTools {
         info!("search_issue_events: {:?}", input);
         execute_search_events(&*self.client, input).await
     }
+    #[rmcp::tool(
+        description = "Report what changed in a project since a timestamp: new issues, regressions, escalating (spiking) issues, resolutions, and new releases. Ideal for 'catch me up since yesterday' questions.",
+        annotations(read_only_hint = true)
+    )]
+    async fn whats_new(
+        &self,
+        Parameters(input): Parameters<WhatsNewInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("whats_new: {:?}", input);
+        execute_whats_new(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List issues currently escalating (Sentry's 'escalating' substatus) for an organization or project, with today's forecast threshold and event counts for the last 24 hours. Set all_organizations to report across every accessible organization.",
+        annotations(read_only_hint = true)
+    )]
+    async fn escalating_issues(
+        &self,
+        Parameters(input): Parameters<EscalatingIssuesInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("escalating_issues: {:?}", input);
+        execute_escalating_issues(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Set the priority of a Sentry issue to high, medium, or low. Requires write mode (SENTRY_MCP_ALLOW_WRITES).",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = true
+        )
+    )]
+    async fn set_issue_priority(
+        &self,
+        Parameters(input): Parameters<SetIssuePriorityInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("set_issue_priority: {:?}", input);
+        execute_set_issue_priority(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Resolve, ignore (archive), or reopen a Sentry issue, and/or assign it to a user or team. Resolutions can be tracked in a release, the next release, or a specific commit, which drives Sentry's regression detection; ignores can last for a duration, until an event or user count is reached, or until the issue escalates. Requires write mode (SENTRY_MCP_ALLOW_WRITES).",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = true
+        )
+    )]
+    async fn update_issue(
+        &self,
+        Parameters(input): Parameters<UpdateIssueInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("update_issue: {:?}", input);
+        execute_update_issue(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List the organization's saved Discover queries, or execute one by ID and return its results as a table. Reuses analyst-built queries without rebuilding them.",
+        annotations(read_only_hint = true)
+    )]
+    async fn discover_saved_queries(
+        &self,
+        Parameters(input): Parameters<DiscoverSavedQueriesInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("discover_saved_queries: {:?}", input);
+        execute_discover_saved_queries(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Show hourly event volume for an issue and optionally forecast the next 24 hours (linear or Holt-Winters), flagging whether an hourly threshold is likely to be breached. Useful for deciding whether a fix can wait.",
+        annotations(read_only_hint = true)
+    )]
+    async fn issue_stats(
+        &self,
+        Parameters(input): Parameters<IssueStatsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("issue_stats: {:?}", input);
+        execute_issue_stats(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Compute error rate for a project or transaction over a window against an SLO target (e.g. 99.9%) and report the remaining error budget and burn rate. Failures are failed transactions or error events.",
+        annotations(read_only_hint = true)
+    )]
+    async fn slo_report(
+        &self,
+        Parameters(input): Parameters<SloReportInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("slo_report: {:?}", input);
+        execute_slo_report(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Combine top issues from several projects (e.g. all services of one product) into one report, merging issues that share a title across services. Useful for product-level incident review.",
+        annotations(read_only_hint = true)
+    )]
+    async fn issue_rollup(
+        &self,
+        Parameters(input): Parameters<IssueRollupInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("issue_rollup: {:?}", input);
+        execute_issue_rollup(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Summarize an event's minidump attachment without symbolication: crash reason, crash address, crashing module, OS and CPU info, and the loaded module list.",
+        annotations(read_only_hint = true)
+    )]
+    async fn minidump_summary(
+        &self,
+        Parameters(input): Parameters<MinidumpSummaryInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("minidump_summary: {:?}", input);
+        execute_minidump_summary(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Search issues in a project or across an organization using Sentry search syntax, with environment, time window, sort and limit. Returns a Markdown table of matching issues.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_issues(
+        &self,
+        Parameters(input): Parameters<ListIssuesInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("list_issues: {:?}", input);
+        execute_list_issues(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Search a text attachment of an event (e.g. an attached application log) for lines matching a regex and return them with surrounding context. Output size is bounded.",
+        annotations(read_only_hint = true)
+    )]
+    async fn grep_attachment(
+        &self,
+        Parameters(input): Parameters<GrepAttachmentInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("grep_attachment: {:?}", input);
+        execute_grep_attachment(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List an organization's teams with their IDs, slugs, member counts, and the projects each team owns. Use the team ID with update_issue's assigned_to ('team:<id>').",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_teams(
+        &self,
+        Parameters(input): Parameters<ListTeamsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("list_teams: {:?}", input);
+        execute_list_teams(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List the commits shipped in a release (SHA, author, date, message, pull request), grouped by repository. Use it to connect a regression to the code changes in the release that introduced it.",
+        annotations(read_only_hint = true)
+    )]
+    async fn release_commits(
+        &self,
+        Parameters(input): Parameters<ReleaseCommitsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("release_commits: {:?}", input);
+        execute_release_commits(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List where and when a release was deployed: environment, start and finish times, and duration, most recent first. Use it to correlate an incident's start with a deploy.",
+        annotations(read_only_hint = true)
+    )]
+    async fn release_deploys(
+        &self,
+        Parameters(input): Parameters<ReleaseDeploysInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("release_deploys: {:?}", input);
+        execute_release_deploys(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Save an issue search query in Sentry so the team can reuse it from the Issues page. Requires write mode (SENTRY_MCP_ALLOW_WRITES).",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = false
+        )
+    )]
+    async fn create_saved_search(
+        &self,
+        Parameters(input): Parameters<CreateSavedSearchInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("create_saved_search: {:?}", input);
+        execute_create_saved_search(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List every environment in an organization across all projects, including hidden ones. Tools that take an environment parameter validate it against this list and complete unambiguous prefixes (e.g. 'prod' to 'production').",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_environments(
+        &self,
+        Parameters(input): Parameters<ListEnvironmentsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("list_environments: {:?}", input);
+        execute_list_environments(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Show a project's usage from the organization stats endpoint: accepted, filtered and dropped quantities per data category, the reasons for filtered and dropped data, and how each outcome trends over the period. Use it for quota, rate-limit and ingestion questions.",
+        annotations(read_only_hint = true)
+    )]
+    async fn project_stats(
+        &self,
+        Parameters(input): Parameters<ProjectStatsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("project_stats: {:?}", input);
+        execute_project_stats(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Show organization-wide usage from the stats endpoint as a table of quantities per data category (error, transaction, replay, attachment, ...) and outcome (accepted, filtered, rate_limited, invalid, client_discard, ...). Use it for quota and ingestion questions across the organization or a set of projects.",
+        annotations(read_only_hint = true)
+    )]
+    async fn org_stats(
+        &self,
+        Parameters(input): Parameters<OrgStatsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("org_stats: {:?}", input);
+        execute_org_stats(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Quick health check for a project: errors per minute and transactions per minute over the last hour compared with the hour before, with the relative change.",
+        annotations(read_only_hint = true)
+    )]
+    async fn project_throughput(
+        &self,
+        Parameters(input): Parameters<ProjectThroughputInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("project_throughput: {:?}", input);
+        execute_project_throughput(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Show this MCP server's version, session ID, Sentry API URL, uptime and whether write and destructive tools are enabled. The session ID is sent with every Sentry API request as the X-Sentry-MCP-Session header, so Sentry audit logs can be matched to this session.",
+        annotations(read_only_hint = true)
+    )]
+    async fn server_stats(
+        &self,
+        Parameters(input): Parameters<ServerStatsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("server_stats: {:?}", input);
+        execute_server_stats(&ServerStats {
+            version: env!("CARGO_PKG_VERSION"),
+            user_agent: USER_AGENT,
+            session_id: self.client.session_id(),
+            api_url: self.client.base_url(),
+            uptime: self.started_at.elapsed(),
+            writes_enabled: self.writes_enabled,
+            destructive_enabled: self.destructive_enabled,
+            tool_count: self.tool_router.list_all().len(),
+        })
+    }
+    #[rmcp::tool(
+        description = "List an organization's metric alert rules with the aggregate and time window they watch, their filter, projects, trigger thresholds (critical/warning, with resolve thresholds) and the actions each trigger fires.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_metric_alerts(
+        &self,
+        Parameters(input): Parameters<ListMetricAlertsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("list_metric_alerts: {:?}", input);
+        execute_list_metric_alerts(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Create an issue alert rule for a project from structured conditions (new issue, regression, reappeared, event or user frequency), optional filters (tag, level, occurrence count) and actions (email issue owners, a team or member; Slack channel). Input is validated before anything is sent. Requires write mode (SENTRY_MCP_ALLOW_WRITES).",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = false
+        )
+    )]
+    async fn create_alert_rule(
+        &self,
+        Parameters(input): Parameters<CreateAlertRuleInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("create_alert_rule: {:?}", input);
+        execute_create_alert_rule(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Modify an existing issue alert rule: rename it, replace its conditions, filters or actions, or change match modes, action interval, environment or owner. Unspecified settings are kept. Requires write mode (SENTRY_MCP_ALLOW_WRITES).",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = true,
+            idempotent_hint = true
+        )
+    )]
+    async fn update_alert_rule(
+        &self,
+        Parameters(input): Parameters<UpdateAlertRuleInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("update_alert_rule: {:?}", input);
+        execute_update_alert_rule(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List the notes people have left on a Sentry issue, oldest first, so prior triage context (suspected causes, linked tickets, decisions) is known before suggesting action.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_issue_comments(
+        &self,
+        Parameters(input): Parameters<ListIssueCommentsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("list_issue_comments: {:?}", input);
+        execute_list_issue_comments(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Post a note (comment) on a Sentry issue, e.g. to record an analysis or link the fix PR. Requires write mode (SENTRY_MCP_ALLOW_WRITES).",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = false
+        )
+    )]
+    async fn add_issue_note(
+        &self,
+        Parameters(input): Parameters<AddIssueNoteInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("add_issue_note: {:?}", input);
+        execute_add_issue_note(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Show the activity timeline of a Sentry issue: status changes, assignments, regressions and the releases it was resolved in. Useful for temporal context before triaging.",
+        annotations(read_only_hint = true)
+    )]
+    async fn issue_activity(
+        &self,
+        Parameters(input): Parameters<IssueActivityInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("issue_activity: {:?}", input);
+        execute_issue_activity(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Merge duplicate Sentry issues into a primary issue, e.g. when grouping split one error into several issues. Issues must belong to the same project; Sentry keeps the issue with the most events, so pass that one as the primary. Requires write mode (SENTRY_MCP_ALLOW_WRITES).",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = true,
+            idempotent_hint = false
+        )
+    )]
+    async fn merge_issues(
+        &self,
+        Parameters(input): Parameters<MergeIssuesInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("merge_issues: {:?}", input);
+        execute_merge_issues(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List a project's discarded issues (tombstones): issues whose future events Sentry drops on ingestion, with who discarded them.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_discarded_issues(
+        &self,
+        Parameters(input): Parameters<ListDiscardedIssuesInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("list_discarded_issues: {:?}", input);
+        execute_list_discarded_issues(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Discard a Sentry issue: delete it with its events and drop future events with the same grouping hash before ingestion, so the noise stops for good and no longer uses quota. Requires write mode (SENTRY_MCP_ALLOW_WRITES) and a plan with discard support.",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = true,
+            idempotent_hint = true
+        )
+    )]
+    async fn discard_issue(
+        &self,
+        Parameters(input): Parameters<DiscardIssueInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("discard_issue: {:?}", input);
+        execute_discard_issue(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Show the grouping hashes of a Sentry issue with the latest event for each, its exception and top in-app frame. Use it to understand why events were grouped together or should be split.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_issue_hashes(
+        &self,
+        Parameters(input): Parameters<GetIssueHashesInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("get_issue_hashes: {:?}", input);
+        execute_get_issue_hashes(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Show a project's data scrubbing (Security & Privacy) settings combined with the organization's: sensitive and safe fields, default scrubbers, IP scrubbing and advanced rules. Pass a field name to explain why its value shows [Filtered].",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_data_scrubbing(
+        &self,
+        Parameters(input): Parameters<GetDataScrubbingInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("get_data_scrubbing: {:?}", input);
+        execute_get_data_scrubbing(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Permanently delete a Sentry issue and all of its events. Requires confirm_short_id to repeat the issue's short ID; ask the user first. Only available when SENTRY_MCP_ALLOW_DESTRUCTIVE is set.",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = true,
+            idempotent_hint = true
+        )
+    )]
+    async fn delete_issue(
+        &self,
+        Parameters(input): Parameters<DeleteIssueInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("delete_issue: {:?}", input);
+        execute_delete_issue(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Explain sudden drops in a project's event counts: whether spike protection dropped events recently and when, plus the effective client-side and dynamic sampling rates per data category.",
+        annotations(read_only_hint = true)
+    )]
+    async fn sampling_report(
+        &self,
+        Parameters(input): Parameters<SamplingReportInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("sampling_report: {:?}", input);
+        execute_sampling_report(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Bookmark a Sentry issue (or remove the bookmark) for the user that owns the auth token, to flag it for a human to follow up on. Requires write mode (SENTRY_MCP_ALLOW_WRITES).",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = true
+        )
+    )]
+    async fn bookmark_issue(
+        &self,
+        Parameters(input): Parameters<BookmarkIssueInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("bookmark_issue: {:?}", input);
+        execute_bookmark_issue(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Subscribe the user that owns the auth token to a Sentry issue's workflow notifications (comments, status changes, regressions), or unsubscribe. Requires write mode (SENTRY_MCP_ALLOW_WRITES).",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = true
+        )
+    )]
+    async fn subscribe_issue(
+        &self,
+        Parameters(input): Parameters<SubscribeIssueInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("subscribe_issue: {:?}", input);
+        execute_subscribe_issue(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Show a project's dynamic sampling configuration (mode, target sample rate, active and inactive biases) and how many transactions and spans were retained versus dropped by sampling, to tell sampling gaps in performance data apart from real traffic changes.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_dynamic_sampling(
+        &self,
+        Parameters(input): Parameters<GetDynamicSamplingInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("get_dynamic_sampling: {:?}", input);
+        execute_get_dynamic_sampling(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Run an ad-hoc Discover query against the organization's events and return a Markdown table. Select columns and aggregates (e.g. transaction, count(), p95(transaction.duration), failure_rate()), filter with Sentry search syntax, sort with orderby, and pick a time window, projects, environment and dataset. Use for aggregations like counts by transaction or p95 by endpoint.",
+        annotations(read_only_hint = true)
+    )]
+    async fn discover_query(
+        &self,
+        Parameters(input): Parameters<DiscoverQueryInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("discover_query: {:?}", input);
+        execute_discover_query(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List a project's transactions with throughput (tpm), p50 and p95 duration, failure rate and count, ranked by slowest p95, highest volume or highest failure rate. A starting point for performance triage.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_transactions(
+        &self,
+        Parameters(input): Parameters<ListTransactionsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("list_transactions: {:?}", input);
+        execute_list_transactions(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Summarize one transaction: duration percentiles (p50, p75, p95, p99, max), apdex, failure rate, throughput, and the slowest recent events with their trace IDs for drilling in with get_trace_details.",
+        annotations(read_only_hint = true)
+    )]
+    async fn transaction_summary(
+        &self,
+        Parameters(input): Parameters<TransactionSummaryInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("transaction_summary: {:?}", input);
+        execute_transaction_summary(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Search spans across the organization (spans dataset) by operation, description text and minimum duration, without knowing a trace ID, e.g. to find slow db spans touching a table. Returns duration, op, description, transaction, project and trace ID per span.",
+        annotations(read_only_hint = true)
+    )]
+    async fn search_spans(
+        &self,
+        Parameters(input): Parameters<SearchSpansInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("search_spans: {:?}", input);
+        execute_search_spans(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Fetch the structured logs tied to a trace ID, in timestamp order across all services, with severity and project. Use with get_trace_details to see what the code logged while the trace ran.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_trace_logs(
+        &self,
+        Parameters(input): Parameters<GetTraceLogsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("get_trace_logs: {:?}", input);
+        execute_get_trace_logs(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Compare the innermost in-app frames of an issue's recent events and group them into distinct failure paths. Over-grouped issues often hide two or three different bugs; each path shows its exception, frames, share of events and sample event IDs.",
+        annotations(read_only_hint = true)
+    )]
+    async fn cluster_issue_events(
+        &self,
+        Parameters(input): Parameters<ClusterIssueEventsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("cluster_issue_events: {:?}", input);
+        execute_cluster_issue_events(&*self.client, input, &self.format_options.in_app_include)
+            .await
+    }
+    #[rmcp::tool(
+        description = "Retrieve a session replay by ID: user, browser, pages visited, error and trace IDs, and a chronological timeline of clicks, navigations, console errors and failed requests. Use it with the replayId tag of an error event to reconstruct what the user did before the error.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_replay_details(
+        &self,
+        Parameters(input): Parameters<GetReplayDetailsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("get_replay_details: {:?}", input);
+        execute_get_replay_details(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Recommend which grouping hashes to split out of an over-grouped issue by comparing the failure paths (exception and innermost in-app frames) of each hash's latest event. The recommended hashes can be passed to split_issue.",
+        annotations(read_only_hint = true)
+    )]
+    async fn suggest_issue_split(
+        &self,
+        Parameters(input): Parameters<SuggestIssueSplitInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("suggest_issue_split: {:?}", input);
+        execute_suggest_issue_split(&*self.client, input, &self.format_options).await
+    }
+    #[rmcp::tool(
+        description = "Split grouping hashes out of an over-grouped issue into one new issue through Sentry's unmerge; at least one hash must stay behind. Use suggest_issue_split to find the hashes. Requires write mode (SENTRY_MCP_ALLOW_WRITES).",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = false
+        )
+    )]
+    async fn split_issue(
+        &self,
+        Parameters(input): Parameters<SplitIssueInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("split_issue: {:?}", input);
+        execute_split_issue(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Summarize a transaction profile: the hottest frames by self and total time and the most sampled call paths, to diagnose CPU-bound slowness. Profile IDs are listed by get_trace_details.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_profile(
+        &self,
+        Parameters(input): Parameters<GetProfileInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("get_profile: {:?}", input);
+        execute_get_profile(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Simulate a proposed metric alert (aggregate, window, threshold, optional percent-change comparison) against the last 7 days of data and report how many times and when it would have fired. Use it to tune thresholds before creating an alert.",
+        annotations(read_only_hint = true)
+    )]
+    async fn preview_metric_alert(
+        &self,
+        Parameters(input): Parameters<PreviewMetricAlertInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("preview_metric_alert: {:?}", input);
+        execute_preview_metric_alert(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List a cron monitor's recent check-ins with status, duration and environment, newest first, to investigate missed or failed cron runs.",
+        annotations(read_only_hint = true)
+    )]
+    async fn monitor_checkins(
+        &self,
+        Parameters(input): Parameters<MonitorCheckinsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("monitor_checkins: {:?}", input);
+        execute_monitor_checkins(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List an organization's pending member invites with email, role, teams, who sent them and whether they expired or still await approval.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_pending_invites(
+        &self,
+        Parameters(input): Parameters<ListPendingInvitesInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("list_pending_invites: {:?}", input);
+        execute_list_pending_invites(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Invite someone to the organization by email with an organization role and optional teams, e.g. to add a new on-call engineer. Teams are checked against the organization and existing members or invites are rejected before anything is sent. Requires write mode (SENTRY_MCP_ALLOW_WRITES).",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = false
+        )
+    )]
+    async fn invite_member(
+        &self,
+        Parameters(input): Parameters<InviteMemberInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("invite_member: {:?}", input);
+        execute_invite_member(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Explain a permission (403) error: given the endpoint of the failed call, look up the scopes Sentry requires for it, compare them with the scopes of the configured token, and print the exact scopes to request.",
+        annotations(read_only_hint = true)
+    )]
+    async fn token_scope_advisor(
+        &self,
+        Parameters(input): Parameters<TokenScopeAdvisorInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("token_scope_advisor: {:?}", input);
+        execute_token_scope_advisor(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List Sentry uptime monitors (uptime alert rules) with their current status and recent check results, grouped into downtime windows with failure reasons and an issue search for each window, to correlate availability incidents with error issues. Pass monitor_id to see every check.",
+        annotations(read_only_hint = true)
+    )]
+    async fn uptime_monitors(
+        &self,
+        Parameters(input): Parameters<UptimeMonitorsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("uptime_monitors: {:?}", input);
+        execute_uptime_monitors(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List user feedback reports of a project (what users said they were doing when an error hit), newest first, with the reporter, issue and event each is attached to. Filter by issue to quote the users affected by a bug.",
+        annotations(read_only_hint = true)
+    )]
+    async fn user_feedback(
+        &self,
+        Parameters(input): Parameters<UserFeedbackInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("user_feedback: {:?}", input);
+        execute_user_feedback(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List an event's attachments (screenshots, log files, minidumps) with their type and size. With download, small text attachments are shown inline; larger or binary ones are returned as sentry-attachment:// resource links readable through the MCP resources API.",
+        annotations(read_only_hint = true)
+    )]
+    async fn event_attachments(
+        &self,
+        Parameters(input): Parameters<EventAttachmentsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("event_attachments: {:?}", input);
+        execute_event_attachments(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List a project's uploaded debug information files (dSYMs, PDBs, ELF, ProGuard) and source map artifact bundles. Given a release, or an event ID to take the release from, check whether source maps were uploaded for that release and dist, to answer why a stack trace is still minified.",
+        annotations(read_only_hint = true)
+    )]
+    async fn debug_files(
+        &self,
+        Parameters(input): Parameters<DebugFilesInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("debug_files: {:?}", input);
+        execute_debug_files(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List a project's client keys (DSNs) with whether each is active, its rate limit and its ingestion endpoints, to debug why events are not arriving (a disabled key or a rate-limited DSN). Secret keys are redacted unless reveal_secrets is set.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_project_keys(
+        &self,
+        Parameters(input): Parameters<ListProjectKeysInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("list_project_keys: {:?}", input);
+        execute_list_project_keys(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Compare a transaction before and after a release: picks a representative trace (the first at or above the median duration) from the release and from a baseline release (or every other release), and diffs span operations by count and total time to show what got slower.",
+        annotations(read_only_hint = true)
+    )]
+    async fn compare_release_traces(
+        &self,
+        Parameters(input): Parameters<CompareReleaseTracesInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("compare_release_traces: {:?}", input);
+        execute_compare_release_traces(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "List the organization's linked repositories and installed integrations (GitHub, GitLab, Jira, Slack, ...) and which integration-backed features they enable: stack trace links and code mappings, suspect commits, code owners, external issue tickets and alert notifications. Check this before relying on one of those features.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_repositories_and_integrations(
+        &self,
+        Parameters(input): Parameters<ListRepositoriesAndIntegrationsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("list_repositories_and_integrations: {:?}", input);
+        execute_list_repositories_and_integrations(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "The oncall triage queue of a project: unresolved, unassigned issues of high priority or error/fatal level, sorted by affected users, in one call.",
+        annotations(read_only_hint = true)
+    )]
+    async fn triage_queue(
+        &self,
+        Parameters(input): Parameters<TriageQueueInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("triage_queue: {:?}", input);
+        execute_triage_queue(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Resolve an issue's stack frames to repository URLs through the project's code mappings, pinned to the release's commit when known, with the line anchored. Defaults to the most relevant in-app frame of each exception; pass filename (and line_no) to pick frames. Frames that cannot be linked come with the reason, e.g. a stack root no mapping covers.",
+        annotations(read_only_hint = true)
+    )]
+    async fn stacktrace_link(
+        &self,
+        Parameters(input): Parameters<StacktraceLinkInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("stacktrace_link: {:?}", input);
+        execute_stacktrace_link(&*self.client, input, &self.format_options.in_app_include).await
+    }
+    #[rmcp::tool(
+        description = "First-responder check for 'anything on fire right now?': only fatal-level issues seen in the last N hours (default 24), each with its latest event's exception and most relevant frame inline.",
+        annotations(read_only_hint = true)
+    )]
+    async fn fatal_issues(
+        &self,
+        Parameters(input): Parameters<FatalIssuesInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("fatal_issues: {:?}", input);
+        execute_fatal_issues(&*self.client, input, &self.format_options.in_app_include).await
+    }
+    #[rmcp::tool(
+        description = "Search the organization audit log: who changed what and when (alert rules, members, projects, teams, integrations, settings). Filter by actor (user ID, email or name), event type such as 'rule.edit' or 'alertrule.edit', and a since window, e.g. to find who changed an alert rule last week. Needs the org:write scope.",
+        annotations(read_only_hint = true)
+    )]
+    async fn audit_log(
+        &self,
+        Parameters(input): Parameters<AuditLogInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("audit_log: {:?}", input);
+        execute_audit_log(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Generate a shareable markdown weekly report for a project covering the past 7 days: new vs resolved issue counts, the top 5 unresolved issues by affected users, the daily crash-free session rate compared with the previous week, and the releases created this week with the most new issues first.",
+        annotations(read_only_hint = true)
+    )]
+    async fn weekly_report(
+        &self,
+        Parameters(input): Parameters<WeeklyReportInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("weekly_report: {:?}", input);
+        execute_weekly_report(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Fetch the latest event of several issues at once (up to 25, fetched concurrently) and return a condensed summary per issue: event ID, date, release, environment, the exception and its most relevant stack frame with the source line. Use this instead of calling get_issue_details for each issue when comparing or triaging a list of issues.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_latest_events(
+        &self,
+        Parameters(input): Parameters<GetLatestEventsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("get_latest_events: {:?}", input);
+        execute_get_latest_events(&*self.client, input, &self.format_options.in_app_include).await
+    }
+    #[rmcp::tool(
+        description = "Return an event's full stored payload as raw JSON, verbatim, including the fields get_issue_details summarizes or drops (breadcrumbs, request, spans, contexts, extra, sdk, ...). Pass keys to keep only some top-level keys; the payload can be large. Takes an issue (latest event by default) or a project slug and event ID.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_event_raw_json(
+        &self,
+        Parameters(input): Parameters<GetEventRawJsonInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("get_event_raw_json: {:?}", input);
+        execute_get_event_raw_json(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Unresolved issues assigned to the token's user across the organization or one project, most recently seen first, to start a personal triage session in one call.",
+        annotations(read_only_hint = true)
+    )]
+    async fn issues_assigned_to_me(
+        &self,
+        Parameters(input): Parameters<IssuesAssignedToMeInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("issues_assigned_to_me: {:?}", input);
+        execute_issues_assigned_to_me(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Release health from the sessions API: sessions, users and crash-free session and user rates per release and/or environment, most sessions first. Filter by project, environment and release.",
+        annotations(read_only_hint = true)
+    )]
+    async fn crash_free_sessions(
+        &self,
+        Parameters(input): Parameters<CrashFreeSessionsInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("crash_free_sessions: {:?}", input);
+        execute_crash_free_sessions(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "Portfolio view of an organization: unresolved issue count and error events of the last 24 hours for every project, in one table with totals, most unresolved issues first.",
+        annotations(read_only_hint = true)
+    )]
+    async fn issue_counts_by_project(
+        &self,
+        Parameters(input): Parameters<IssueCountsByProjectInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("issue_counts_by_project: {:?}", input);
+        execute_issue_counts_by_project(&*self.client, input).await
+    }
+    #[rmcp::tool(
+        description = "One-shot health check of a release: session and user adoption, crash-free session and user rates against the whole project, and counts of issues new in the release and regressed in it, with a verdict.",
+        annotations(read_only_hint = true)
+    )]
+    async fn release_health(
+        &self,
+        Parameters(input): Parameters<ReleaseHealthInput>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("release_health: {:?}", input);
+        execute_release_health(&*self.client, input).await
+    }
 }
 
-#[tool_handler]
 impl ServerHandler for SentryTools {
+    /// Dispatches to the tool router with the tool's timeout/retry policy in scope,
+    /// inside a `tool_call` span carrying a fresh `call_id` so every log line of the
+    /// call (including API requests) can be correlated. Errors carry the ID in `data`.
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<rmcp::RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let call_id = new_call_id();
+        let span = info_span!("tool_call", tool = %request.name, call_id = %call_id);
+        let policy = self.tool_policies.get(&request.name);
+        let ascii_only = ascii_argument(&request).unwrap_or(self.ascii_only);
+        let started = Instant::now();
+        let tcc = ToolCallContext::new(self, request, context);
+        let result = with_policy(policy, self.tool_router.call(tcc))
+            .instrument(span.clone())
+            .await;
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+        span.in_scope(|| match &result {
+            Ok(_) => info!(elapsed_ms, "tool call finished"),
+            Err(e) => warn!(elapsed_ms, error = %e.message, "tool call failed"),
+        });
+        result
+            .map(|mut result| {
+                self.output_style.apply(&mut result, ascii_only);
+                result
+            })
+            .map_err(|mut e| {
+                e.data.get_or_insert_with(|| serde_json::json!({}));
+                if let Some(data) = e.data.as_mut().and_then(|d| d.as_object_mut()) {
+                    data.insert("call_id".to_string(), call_id.into());
+                }
+                e
+            })
+    }
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<rmcp::RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        Ok(ListToolsResult {
+            tools: self.tool_router.list_all(),
+            meta: None,
+            next_cursor: None,
+        })
+    }
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<rmcp::RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        Ok(ListResourceTemplatesResult {
+            resource_templates: vec![
+                RawResourceTemplate {
+                    uri_template: format!(
+                        "{}://{{organization_slug}}/{{project_slug}}/{{event_id}}/{{attachment_id}}",
+                        ATTACHMENT_SCHEME
+                    ),
+                    name: "event_attachment".to_string(),
+                    title: Some("Sentry event attachment".to_string()),
+                    description: Some(
+                        "Contents of an event attachment; text as-is, binary base64-encoded"
+                            .to_string(),
+                    ),
+                    mime_type: None,
+                }
+                .no_annotation(),
+            ],
+            meta: None,
+            next_cursor: None,
+        })
+    }
+    /// Resources are event attachments, linked from `event_attachments` results.
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        _context: RequestContext<rmcp::RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        info!("read_resource: {}", request.uri);
+        read_attachment_resource(&*self.client, &request.uri).await
+    }
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             protocol_version: ProtocolVersion::V_2024_11_05,
@@ -76,6 +1247,7 @@ impl ServerHandler for SentryTools {
                 tools: Some(ToolsCapability {
                     list_changed: Some(true),
                 }),
+                resources: Some(ResourcesCapability::default()),
                 ..Default::default()
             },
             server_info: Implementation {
@@ -89,3 +1261,60 @@ impl ServerHandler for SentryTools {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_write_tools_are_registered() {
+        let router = SentryTools::tool_router();
+        for name in WRITE_TOOLS {
+            assert!(router.has_route(name), "unknown write tool {}", name);
+        }
+    }
+    #[test]
+    fn test_read_only_tools_are_annotated() {
+        for tool in SentryTools::tool_router().list_all() {
+            let gated =
+                WRITE_TOOLS.contains(&&*tool.name) || DESTRUCTIVE_TOOLS.contains(&&*tool.name);
+            let read_only = tool.annotations.and_then(|a| a.read_only_hint);
+            assert_eq!(read_only, Some(!gated), "{}", tool.name);
+        }
+    }
+    #[test]
+    fn test_ascii_argument() {
+        let request = |arguments: serde_json::Value| CallToolRequestParam {
+            name: "get_trace_details".into(),
+            arguments: arguments.as_object().cloned(),
+        };
+        assert_eq!(
+            ascii_argument(&request(serde_json::json!({"ascii": true}))),
+            Some(true)
+        );
+        assert_eq!(
+            ascii_argument(&request(serde_json::json!({"ascii": false}))),
+            Some(false)
+        );
+        assert_eq!(ascii_argument(&request(serde_json::json!({}))), None);
+        assert_eq!(ascii_argument(&request(serde_json::Value::Null)), None);
+    }
+    #[test]
+    fn test_ascii_argument_is_declared() {
+        let mut router = SentryTools::tool_router();
+        declare_ascii_argument(&mut router);
+        for tool in router.list_all() {
+            let ascii = tool
+                .input_schema
+                .get("properties")
+                .and_then(|p| p.get("ascii"));
+            assert!(ascii.is_some(), "{}", tool.name);
+        }
+    }
+    #[test]
+    fn test_destructive_tools_are_registered() {
+        let router = SentryTools::tool_router();
+        for name in DESTRUCTIVE_TOOLS {
+            assert!(router.has_route(name), "unknown destructive tool {}", name);
+        }
+    }
+}
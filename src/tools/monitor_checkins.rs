@@ -0,0 +1,118 @@
+use crate::api_client::{MonitorCheckin, SentryApi};
+use crate::format::format_duration;
+use crate::tools::list_environments::resolve_environment;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Check-in statuses in display order for the per-status counts.
+const STATUSES: [&str; 5] = ["ok", "in_progress", "error", "timeout", "missed"];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MonitorCheckinsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Cron monitor slug, e.g. 'nightly-sync'")]
+    pub monitor_slug: String,
+    #[schemars(description = "Only show check-ins from this environment (default: all)")]
+    pub environment: Option<String>,
+    #[schemars(description = "Maximum number of check-ins, newest first (default: 50, max: 100)")]
+    pub limit: Option<i32>,
+}
+
+/// Whether a check-in means the job did not run successfully.
+pub fn is_failure(checkin: &MonitorCheckin) -> bool {
+    matches!(checkin.status.as_str(), "error" | "timeout" | "missed")
+}
+
+/// Counts check-ins per status in a fixed order; unknown statuses go last.
+pub fn count_by_status(checkins: &[MonitorCheckin]) -> Vec<(&str, usize)> {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for checkin in checkins {
+        match counts.iter_mut().find(|(s, _)| *s == checkin.status) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((&checkin.status, 1)),
+        }
+    }
+    counts.sort_by_key(|(s, _)| {
+        STATUSES
+            .iter()
+            .position(|known| known == s)
+            .unwrap_or(STATUSES.len())
+    });
+    counts
+}
+
+fn format_checkin(checkin: &MonitorCheckin) -> String {
+    let status = if is_failure(checkin) {
+        format!("**{}**", checkin.status)
+    } else {
+        checkin.status.clone()
+    };
+    format!(
+        "| {} | {} | {} | {} | {} |\n",
+        checkin.date_created.as_deref().unwrap_or(""),
+        status,
+        checkin.duration.map(format_duration).unwrap_or_default(),
+        checkin.environment.as_deref().unwrap_or(""),
+        checkin.expected_time.as_deref().unwrap_or("")
+    )
+}
+
+pub fn format_checkins_output(
+    monitor_slug: &str,
+    environment: Option<&str>,
+    checkins: &[MonitorCheckin],
+) -> String {
+    let mut output = String::new();
+    output.push_str("# Monitor Check-ins\n\n");
+    output.push_str(&format!("**Monitor:** {}\n", monitor_slug));
+    if let Some(environment) = environment {
+        output.push_str(&format!("**Environment:** {}\n", environment));
+    }
+    output.push_str(&format!("**Check-ins:** {}\n", checkins.len()));
+    if checkins.is_empty() {
+        output.push_str("\nNo check-ins recorded for this monitor.\n");
+        return output;
+    }
+    let counts: Vec<String> = count_by_status(checkins)
+        .iter()
+        .map(|(status, count)| format!("{} {}", status, count))
+        .collect();
+    output.push_str(&format!("**By Status:** {}\n", counts.join(", ")));
+    match checkins.iter().find(|c| c.status == "ok") {
+        Some(ok) => output.push_str(&format!(
+            "**Last OK:** {}\n",
+            ok.date_created.as_deref().unwrap_or("unknown")
+        )),
+        None => output.push_str("**Last OK:** none in these check-ins\n"),
+    }
+    output.push_str("\n| Time | Status | Duration | Environment | Expected |\n");
+    output.push_str("|---|---|---|---|---|\n");
+    for checkin in checkins {
+        output.push_str(&format_checkin(checkin));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: MonitorCheckinsInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let monitor_slug = input.monitor_slug.trim();
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let checkins = client
+        .list_monitor_checkins(
+            org,
+            monitor_slug,
+            environment.as_deref(),
+            input.limit.unwrap_or(50).clamp(1, 100),
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_checkins_output(monitor_slug, environment.as_deref(), &checkins);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
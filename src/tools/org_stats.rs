@@ -0,0 +1,165 @@
+use crate::api_client::{OrgStats, OrgStatsQuery, SentryApi};
+use crate::tools::project_stats::OutcomeClass;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+const QUANTITY: &str = "sum(quantity)";
+/// Column order for well-known outcomes; any others follow alphabetically.
+const OUTCOME_ORDER: &[&str] = &[
+    "accepted",
+    "filtered",
+    "rate_limited",
+    "invalid",
+    "abuse",
+    "client_discard",
+    "cardinality_limited",
+];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct OrgStatsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Time window, e.g. '24h', '7d', '30d' (default: 30d)")]
+    pub stats_period: Option<String>,
+    #[schemars(
+        description = "Data categories to include, e.g. ['error', 'transaction', 'replay', 'attachment'] (default: all)"
+    )]
+    pub categories: Option<Vec<String>>,
+    #[schemars(description = "Only count these projects (default: the whole organization)")]
+    pub project_slugs: Option<Vec<String>>,
+}
+
+/// Quantities by category, then outcome.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OutcomeMatrix {
+    /// Outcomes that occur in the data, in column order.
+    pub outcomes: Vec<String>,
+    pub rows: BTreeMap<String, BTreeMap<String, f64>>,
+}
+
+impl OutcomeMatrix {
+    pub fn get(&self, category: &str, outcome: &str) -> f64 {
+        self.rows
+            .get(category)
+            .and_then(|row| row.get(outcome))
+            .copied()
+            .unwrap_or(0.0)
+    }
+}
+
+fn outcome_rank(outcome: &str) -> (usize, String) {
+    let rank = OUTCOME_ORDER
+        .iter()
+        .position(|o| *o == outcome)
+        .unwrap_or(OUTCOME_ORDER.len());
+    (rank, outcome.to_string())
+}
+
+pub fn outcome_matrix(stats: &OrgStats) -> OutcomeMatrix {
+    let mut matrix = OutcomeMatrix::default();
+    for group in &stats.groups {
+        let quantity = group.totals.get(QUANTITY).copied().unwrap_or(0.0);
+        let outcome = group.key("outcome");
+        *matrix
+            .rows
+            .entry(group.key("category").to_string())
+            .or_default()
+            .entry(outcome.to_string())
+            .or_default() += quantity;
+        if !matrix.outcomes.iter().any(|o| o == outcome) {
+            matrix.outcomes.push(outcome.to_string());
+        }
+    }
+    matrix.outcomes.sort_by_key(|o| outcome_rank(o));
+    matrix
+}
+
+fn format_row(output: &mut String, label: &str, outcomes: &[String], value: impl Fn(&str) -> f64) {
+    output.push_str(&format!("| {} |", label));
+    let mut total = 0.0;
+    let mut not_accepted = 0.0;
+    for outcome in outcomes {
+        let quantity = value(outcome);
+        total += quantity;
+        if OutcomeClass::of(outcome) != OutcomeClass::Accepted {
+            not_accepted += quantity;
+        }
+        output.push_str(&format!(" {:.0} |", quantity));
+    }
+    let share = if total > 0.0 {
+        format!("{:.1}%", not_accepted / total * 100.0)
+    } else {
+        "-".to_string()
+    };
+    output.push_str(&format!(" {:.0} | {} |\n", total, share));
+}
+
+pub fn format_org_stats_output(
+    org_slug: &str,
+    period: &str,
+    project_slugs: &[String],
+    stats: &OrgStats,
+) -> String {
+    let matrix = outcome_matrix(stats);
+    let mut output = String::new();
+    output.push_str(&format!("# Organization Stats: {}\n\n", org_slug));
+    output.push_str(&format!("**Period:** {}\n", period));
+    if !project_slugs.is_empty() {
+        output.push_str(&format!("**Projects:** {}\n", project_slugs.join(", ")));
+    }
+    if matrix.rows.is_empty() {
+        output.push_str("\nNo usage data for this period.\n");
+        return output;
+    }
+    output.push_str("\n| Category |");
+    for outcome in &matrix.outcomes {
+        output.push_str(&format!(" {} |", outcome));
+    }
+    output.push_str(" Total | Not Accepted |\n");
+    output.push_str(&"|---".repeat(matrix.outcomes.len() + 3));
+    output.push_str("|\n");
+    for category in matrix.rows.keys() {
+        format_row(&mut output, category, &matrix.outcomes, |outcome| {
+            matrix.get(category, outcome)
+        });
+    }
+    if matrix.rows.len() > 1 {
+        format_row(&mut output, "**Total**", &matrix.outcomes, |outcome| {
+            matrix.rows.keys().map(|c| matrix.get(c, outcome)).sum()
+        });
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: OrgStatsInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let period = input.stats_period.as_deref().unwrap_or("30d");
+    let project_slugs = input.project_slugs.unwrap_or_default();
+    let mut projects = Vec::new();
+    for slug in &project_slugs {
+        let project = client
+            .get_project(org, slug)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        projects.push(project.id);
+    }
+    let query = OrgStatsQuery {
+        projects,
+        categories: input.categories.unwrap_or_default(),
+        stats_period: Some(period.to_string()),
+        interval: Some("1d".to_string()),
+    };
+    let stats = client
+        .get_org_stats(org, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_org_stats_output(org, period, &project_slugs, &stats);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
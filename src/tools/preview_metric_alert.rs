@@ -0,0 +1,387 @@
+use crate::api_client::{EventsStatsQuery, SentryApi};
+use crate::tools::list_environments::resolve_environment;
+use crate::tools::list_metric_alerts::{format_number, format_window};
+use chrono::DateTime;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Evaluation windows Sentry offers for metric alerts, in minutes.
+pub const TIME_WINDOWS: &[u32] = &[1, 5, 10, 15, 30, 60, 120, 240, 1440];
+/// Comparison offsets Sentry offers for percent-change alerts, in minutes.
+pub const COMPARISON_DELTAS: &[u32] = &[5, 15, 60, 1440, 10080];
+/// Minutes of history replayed.
+const PREVIEW_MINUTES: u32 = 7 * 1440;
+/// Firings listed; the latest are kept.
+const MAX_FIRINGS: usize = 20;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PreviewMetricAlertInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug the alert would watch")]
+    pub project_slug: String,
+    #[schemars(
+        description = "Metric to alert on, e.g. 'count()', 'p95(transaction.duration)' or 'failure_rate()' (default: count())"
+    )]
+    pub aggregate: Option<String>,
+    #[schemars(description = "Event filter, e.g. 'level:error transaction:/checkout' (optional)")]
+    pub query: Option<String>,
+    #[schemars(description = "Only count events in this environment (default: all)")]
+    pub environment: Option<String>,
+    #[schemars(
+        description = "Evaluation window in minutes: 1, 5, 10, 15, 30, 60, 120, 240 or 1440"
+    )]
+    pub time_window_minutes: u32,
+    #[schemars(
+        description = "Critical threshold; a percentage when comparison_delta_minutes is set"
+    )]
+    pub threshold: f64,
+    #[schemars(description = "'above' (default) or 'below' the threshold")]
+    pub direction: Option<String>,
+    #[schemars(
+        description = "Value that resolves a firing alert (default: the alert resolves as soon as the threshold is no longer crossed)"
+    )]
+    pub resolve_threshold: Option<f64>,
+    #[schemars(
+        description = "Rate-of-change alert: compare each window to the same window this many minutes earlier (5, 15, 60, 1440 or 10080) and treat the thresholds as percent change"
+    )]
+    pub comparison_delta_minutes: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Above,
+    Below,
+}
+
+impl Direction {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "above" => Some(Self::Above),
+            "below" => Some(Self::Below),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Above => "above",
+            Self::Below => "below",
+        }
+    }
+
+    fn opposite(self) -> &'static str {
+        match self {
+            Self::Above => "below",
+            Self::Below => "above",
+        }
+    }
+}
+
+/// The simulated alert condition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlertCondition {
+    pub direction: Direction,
+    pub threshold: f64,
+    pub resolve_threshold: Option<f64>,
+}
+
+impl AlertCondition {
+    fn breached(&self, value: f64) -> bool {
+        match self.direction {
+            Direction::Above => value > self.threshold,
+            Direction::Below => value < self.threshold,
+        }
+    }
+
+    fn resolved(&self, value: f64) -> bool {
+        match (self.resolve_threshold, self.direction) {
+            (None, _) => !self.breached(value),
+            (Some(resolve), Direction::Above) => value < resolve,
+            (Some(resolve), Direction::Below) => value > resolve,
+        }
+    }
+}
+
+/// One time the alert would have fired.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Firing {
+    pub started: i64,
+    /// Start of the window that resolved the alert; `None` while still firing.
+    pub resolved: Option<i64>,
+    /// Most extreme value while firing.
+    pub peak: f64,
+}
+
+/// Checks the window and comparison offset against what Sentry offers; the
+/// offset must also be a whole number of windows to line buckets up.
+pub fn validate_windows(window: u32, delta: Option<u32>) -> Result<(), McpError> {
+    if !TIME_WINDOWS.contains(&window) {
+        return Err(McpError::invalid_params(
+            format!(
+                "Invalid time_window_minutes {}: expected one of 1, 5, 10, 15, 30, 60, 120, 240, 1440",
+                window
+            ),
+            None,
+        ));
+    }
+    let Some(delta) = delta else {
+        return Ok(());
+    };
+    if !COMPARISON_DELTAS.contains(&delta) {
+        return Err(McpError::invalid_params(
+            format!(
+                "Invalid comparison_delta_minutes {}: expected one of 5, 15, 60, 1440, 10080",
+                delta
+            ),
+            None,
+        ));
+    }
+    if delta % window != 0 {
+        return Err(McpError::invalid_params(
+            format!(
+                "comparison_delta_minutes ({}) must be a multiple of time_window_minutes ({})",
+                delta, window
+            ),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+/// Percent change of each bucket against the bucket `offset` positions earlier.
+/// The first `offset` buckets only serve as the baseline; buckets whose baseline
+/// is zero have no defined change and are skipped.
+pub fn percent_change(series: &[(i64, f64)], offset: usize) -> Vec<(i64, f64)> {
+    series
+        .iter()
+        .skip(offset)
+        .zip(series.iter())
+        .filter(|(_, (_, before))| *before != 0.0)
+        .map(|((ts, value), (_, before))| (*ts, (value / before - 1.0) * 100.0))
+        .collect()
+}
+
+/// Replays the series window by window and records each firing: the alert fires
+/// when the threshold is crossed and stays active until the resolve condition holds.
+pub fn simulate(series: &[(i64, f64)], condition: &AlertCondition) -> Vec<Firing> {
+    let mut firings: Vec<Firing> = Vec::new();
+    for &(ts, value) in series {
+        match firings.last_mut().filter(|f| f.resolved.is_none()) {
+            Some(firing) if condition.resolved(value) => firing.resolved = Some(ts),
+            Some(firing) => {
+                firing.peak = match condition.direction {
+                    Direction::Above => firing.peak.max(value),
+                    Direction::Below => firing.peak.min(value),
+                };
+            }
+            None if condition.breached(value) => firings.push(Firing {
+                started: ts,
+                resolved: None,
+                peak: value,
+            }),
+            None => {}
+        }
+    }
+    firings
+}
+
+/// Nearest-rank percentile of the values.
+pub fn percentile(values: &[f64], p: f64) -> Option<f64> {
+    let mut sorted: Vec<f64> = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted.get(rank.saturating_sub(1)).copied()
+}
+
+fn format_ts(ts: i64) -> String {
+    DateTime::from_timestamp(ts, 0)
+        .map(|d| d.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| ts.to_string())
+}
+
+fn format_value(value: f64, comparison: bool) -> String {
+    if comparison {
+        format!("{:+.1}%", value)
+    } else {
+        format_number((value * 100.0).round() / 100.0)
+    }
+}
+
+/// `above 100 (resolves below 50)`, or the percent-change form for comparison alerts.
+pub fn format_condition(condition: &AlertCondition, delta: Option<u32>) -> String {
+    let unit = if delta.is_some() { "%" } else { "" };
+    let mut text = format!(
+        "{} {}{}",
+        condition.direction.label(),
+        format_number(condition.threshold),
+        unit
+    );
+    if let Some(delta) = delta {
+        text.push_str(&format!(
+            " change compared to {} earlier",
+            format_window(delta as f64)
+        ));
+    }
+    if let Some(resolve) = condition.resolve_threshold {
+        text.push_str(&format!(
+            " (resolves {} {}{})",
+            condition.direction.opposite(),
+            format_number(resolve),
+            unit
+        ));
+    }
+    text
+}
+
+fn format_distribution(output: &mut String, values: &[f64], comparison: bool) {
+    let stats: Vec<String> = [("p50", 50.0), ("p90", 90.0), ("p99", 99.0), ("max", 100.0)]
+        .iter()
+        .filter_map(|(label, p)| {
+            Some(format!(
+                "{} {}",
+                label,
+                format_value(percentile(values, *p)?, comparison)
+            ))
+        })
+        .collect();
+    if !stats.is_empty() {
+        output.push_str(&format!("**Observed:** {}\n", stats.join(", ")));
+    }
+}
+
+fn format_firings(output: &mut String, firings: &[Firing], comparison: bool) {
+    output.push_str("\n## Firings\n\n");
+    let skipped = firings.len().saturating_sub(MAX_FIRINGS);
+    if skipped > 0 {
+        output.push_str(&format!("{} earlier firings not shown.\n\n", skipped));
+    }
+    output.push_str("| Started | Resolved | Duration | Peak |\n");
+    output.push_str("|---|---|---|---|\n");
+    for firing in &firings[skipped..] {
+        let (resolved, duration) = match firing.resolved {
+            Some(ts) => (
+                format_ts(ts),
+                format_window(((ts - firing.started) / 60) as f64),
+            ),
+            None => ("still firing".to_string(), String::new()),
+        };
+        output.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            format_ts(firing.started),
+            resolved,
+            duration,
+            format_value(firing.peak, comparison)
+        ));
+    }
+}
+
+pub fn format_preview_output(
+    input: &PreviewMetricAlertInput,
+    condition: &AlertCondition,
+    series: &[(i64, f64)],
+    firings: &[Firing],
+) -> String {
+    let window = input.time_window_minutes;
+    let delta = input.comparison_delta_minutes;
+    let comparison = delta.is_some();
+    let mut output = String::new();
+    output.push_str("# Metric Alert Preview\n\n");
+    output.push_str(&format!(
+        "**Metric:** `{}` over {}\n",
+        input.aggregate.as_deref().unwrap_or("count()"),
+        format_window(window as f64)
+    ));
+    if let Some(query) = input.query.as_deref().filter(|q| !q.trim().is_empty()) {
+        output.push_str(&format!("**Filter:** `{}`\n", query));
+    }
+    output.push_str(&format!("**Project:** {}\n", input.project_slug));
+    if let Some(environment) = &input.environment {
+        output.push_str(&format!("**Environment:** {}\n", environment));
+    }
+    output.push_str(&format!(
+        "**Condition:** {}\n",
+        format_condition(condition, delta)
+    ));
+    output.push_str(&format!(
+        "**Period:** last 7 days ({} windows evaluated)\n",
+        series.len()
+    ));
+    if series.is_empty() {
+        output.push_str("\nNo data in the last 7 days to evaluate.\n");
+        return output;
+    }
+    let values: Vec<f64> = series.iter().map(|(_, v)| *v).collect();
+    format_distribution(&mut output, &values, comparison);
+    output.push_str(&format!("**Would have fired:** {} times\n", firings.len()));
+    if firings.is_empty() {
+        output.push_str(
+            "\nThe alert would not have fired. If it should have, move the threshold \
+            towards the observed values.\n",
+        );
+        return output;
+    }
+    format_firings(&mut output, firings, comparison);
+    output.push_str(
+        "\nSentry evaluates the window every minute; this preview evaluates back-to-back \
+        windows, so spikes straddling two windows can be missed or counted once.\n",
+    );
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: PreviewMetricAlertInput,
+) -> Result<CallToolResult, McpError> {
+    let window = input.time_window_minutes;
+    validate_windows(window, input.comparison_delta_minutes)?;
+    let direction = match input.direction.as_deref() {
+        Some(value) => Direction::parse(value).ok_or_else(|| {
+            McpError::invalid_params(
+                format!("Invalid direction '{}': expected above or below", value),
+                None,
+            )
+        })?,
+        None => Direction::Above,
+    };
+    let condition = AlertCondition {
+        direction,
+        threshold: input.threshold,
+        resolve_threshold: input.resolve_threshold,
+    };
+    let org = &input.organization_slug;
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let project = client
+        .get_project(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let delta = input.comparison_delta_minutes.unwrap_or(0);
+    let query = EventsStatsQuery {
+        query: input.query.clone().filter(|q| !q.trim().is_empty()),
+        project: Some(project.id),
+        environment,
+        stats_period: Some(format!("{}m", PREVIEW_MINUTES + delta)),
+        interval: Some(format!("{}m", window)),
+        y_axis: Some(
+            input
+                .aggregate
+                .clone()
+                .unwrap_or_else(|| "count()".to_string()),
+        ),
+    };
+    let stats = client
+        .get_events_stats(org, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let counts = stats.counts();
+    let series = match input.comparison_delta_minutes {
+        Some(delta) => percent_change(&counts, (delta / window) as usize),
+        None => counts,
+    };
+    let firings = simulate(&series, &condition);
+    let output = format_preview_output(&input, &condition, &series, &firings);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
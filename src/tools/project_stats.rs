@@ -0,0 +1,212 @@
+use crate::api_client::{OrgStats, OrgStatsQuery, SentryApi};
+use crate::tools::issue_stats::sparkline;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+const QUANTITY: &str = "sum(quantity)";
+const SPARKLINE_WIDTH: usize = 60;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjectStatsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(description = "Time window, e.g. '24h', '7d', '30d' (default: 30d)")]
+    pub stats_period: Option<String>,
+    #[schemars(description = "Bucket size, e.g. '1h', '1d' (default: 1d)")]
+    pub interval: Option<String>,
+    #[schemars(
+        description = "Only count this data category, e.g. 'error', 'transaction', 'attachment', 'replay', 'profile', 'span' (default: all)"
+    )]
+    pub category: Option<String>,
+}
+
+/// How an outcome is reported: stored, dropped by inbound filters, or dropped otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OutcomeClass {
+    Accepted,
+    Filtered,
+    Dropped,
+}
+
+impl OutcomeClass {
+    pub fn of(outcome: &str) -> Self {
+        match outcome {
+            "accepted" => Self::Accepted,
+            "filtered" => Self::Filtered,
+            _ => Self::Dropped,
+        }
+    }
+}
+
+/// Totals for one data category.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CategoryTotals {
+    pub accepted: f64,
+    pub filtered: f64,
+    pub dropped: f64,
+}
+
+impl CategoryTotals {
+    pub fn total(&self) -> f64 {
+        self.accepted + self.filtered + self.dropped
+    }
+    fn add(&mut self, class: OutcomeClass, quantity: f64) {
+        match class {
+            OutcomeClass::Accepted => self.accepted += quantity,
+            OutcomeClass::Filtered => self.filtered += quantity,
+            OutcomeClass::Dropped => self.dropped += quantity,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StatsSummary {
+    pub categories: BTreeMap<String, CategoryTotals>,
+    /// Filtered and dropped quantities keyed by `(outcome, reason, category)`.
+    pub reasons: BTreeMap<(String, String, String), f64>,
+    /// Per-interval quantities for each outcome class.
+    pub series: BTreeMap<OutcomeClass, Vec<f64>>,
+}
+
+pub fn summarize(stats: &OrgStats) -> StatsSummary {
+    let mut summary = StatsSummary::default();
+    for group in &stats.groups {
+        let outcome = group.key("outcome");
+        let category = group.key("category");
+        let class = OutcomeClass::of(outcome);
+        let quantity = group.totals.get(QUANTITY).copied().unwrap_or(0.0);
+        summary
+            .categories
+            .entry(category.to_string())
+            .or_default()
+            .add(class, quantity);
+        if class != OutcomeClass::Accepted && quantity > 0.0 {
+            let reason = group.key("reason");
+            *summary
+                .reasons
+                .entry((
+                    outcome.to_string(),
+                    reason.to_string(),
+                    category.to_string(),
+                ))
+                .or_default() += quantity;
+        }
+        if let Some(values) = group.series.get(QUANTITY) {
+            let series = summary
+                .series
+                .entry(class)
+                .or_insert_with(|| vec![0.0; stats.intervals.len()]);
+            if series.len() < values.len() {
+                series.resize(values.len(), 0.0);
+            }
+            for (slot, value) in series.iter_mut().zip(values) {
+                *slot += value;
+            }
+        }
+    }
+    summary
+}
+
+fn percent(part: f64, total: f64) -> String {
+    if total > 0.0 {
+        format!("{:.1}%", part / total * 100.0)
+    } else {
+        "-".to_string()
+    }
+}
+
+pub fn format_project_stats_output(
+    project_slug: &str,
+    period: &str,
+    interval: &str,
+    stats: &OrgStats,
+) -> String {
+    let summary = summarize(stats);
+    let mut output = String::new();
+    output.push_str(&format!("# Project Stats: {}\n\n", project_slug));
+    output.push_str(&format!(
+        "**Period:** {} ({} intervals)\n",
+        period, interval
+    ));
+    if summary.categories.is_empty() {
+        output.push_str("\nNo usage data for this period.\n");
+        return output;
+    }
+    output.push_str("\n## Totals by Category\n\n");
+    output.push_str("| Category | Accepted | Filtered | Dropped | Total | Dropped % |\n");
+    output.push_str("|---|---|---|---|---|---|\n");
+    for (category, totals) in &summary.categories {
+        output.push_str(&format!(
+            "| {} | {:.0} | {:.0} | {:.0} | {:.0} | {} |\n",
+            category,
+            totals.accepted,
+            totals.filtered,
+            totals.dropped,
+            totals.total(),
+            percent(totals.dropped, totals.total())
+        ));
+    }
+    if !summary.reasons.is_empty() {
+        let mut reasons: Vec<_> = summary.reasons.iter().collect();
+        reasons.sort_by(|a, b| b.1.total_cmp(a.1));
+        output.push_str("\n## Filtered and Dropped by Reason\n\n");
+        for ((outcome, reason, category), quantity) in reasons {
+            output.push_str(&format!(
+                "- {} / {} ({}): {:.0}\n",
+                outcome, reason, category, quantity
+            ));
+        }
+    }
+    if !summary.series.is_empty() {
+        output.push_str("\n## Over Time\n\n```\n");
+        for (class, label) in [
+            (OutcomeClass::Accepted, "accepted"),
+            (OutcomeClass::Filtered, "filtered"),
+            (OutcomeClass::Dropped, "dropped"),
+        ] {
+            if let Some(values) = summary.series.get(&class) {
+                output.push_str(&format!(
+                    "{:<8} {}\n",
+                    label,
+                    sparkline(values, SPARKLINE_WIDTH)
+                ));
+            }
+        }
+        output.push_str("```\n");
+        if let (Some(first), Some(last)) = (stats.intervals.first(), stats.intervals.last()) {
+            output.push_str(&format!("{} → {}\n", first, last));
+        }
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ProjectStatsInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let period = input.stats_period.as_deref().unwrap_or("30d");
+    let interval = input.interval.as_deref().unwrap_or("1d");
+    let project = client
+        .get_project(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let query = OrgStatsQuery {
+        projects: vec![project.id],
+        categories: input.category.iter().cloned().collect(),
+        stats_period: Some(period.to_string()),
+        interval: Some(interval.to_string()),
+    };
+    let stats = client
+        .get_org_stats(org, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_project_stats_output(&input.project_slug, period, interval, &stats);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
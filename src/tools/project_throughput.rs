@@ -0,0 +1,138 @@
+use crate::api_client::{DiscoverQuery, SentryApi};
+use crate::tools::list_environments::resolve_environment;
+use crate::tools::slo_report::aggregate;
+use chrono::{DateTime, Duration, DurationRound, SecondsFormat, Utc};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+const WINDOW_MINUTES: i64 = 60;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjectThroughputInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+}
+
+/// Event counts for the last window and the one before it.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WindowCounts {
+    pub last: f64,
+    pub previous: f64,
+}
+
+impl WindowCounts {
+    pub fn last_per_minute(&self) -> f64 {
+        self.last / WINDOW_MINUTES as f64
+    }
+    pub fn previous_per_minute(&self) -> f64 {
+        self.previous / WINDOW_MINUTES as f64
+    }
+}
+
+/// Relative change from `previous` to `last`, e.g. `+25.0%`; `new` when nothing came before.
+pub fn format_change(last: f64, previous: f64) -> String {
+    if previous <= 0.0 {
+        return if last > 0.0 { "new" } else { "-" }.to_string();
+    }
+    format!("{:+.1}%", (last - previous) / previous * 100.0)
+}
+
+fn format_row(output: &mut String, label: &str, counts: &WindowCounts) {
+    output.push_str(&format!(
+        "| {} | {:.2} | {:.2} | {} |\n",
+        label,
+        counts.last_per_minute(),
+        counts.previous_per_minute(),
+        format_change(counts.last, counts.previous)
+    ));
+}
+
+pub fn format_throughput_output(
+    project_slug: &str,
+    end: DateTime<Utc>,
+    errors: &WindowCounts,
+    transactions: &WindowCounts,
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Throughput: {}\n\n", project_slug));
+    output.push_str(&format!(
+        "**Window:** last {} min vs the {} min before, ending {}\n\n",
+        WINDOW_MINUTES,
+        WINDOW_MINUTES,
+        end.format("%Y-%m-%d %H:%M UTC")
+    ));
+    output.push_str("| Metric | Last Hour | Previous Hour | Change |\n");
+    output.push_str("|---|---|---|---|\n");
+    format_row(&mut output, "Errors/min", errors);
+    format_row(&mut output, "Transactions/min", transactions);
+    output
+}
+
+async fn count(
+    client: &impl SentryApi,
+    org: &str,
+    base: &DiscoverQuery,
+    dataset: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<f64, McpError> {
+    let query = DiscoverQuery {
+        fields: vec!["count()".to_string()],
+        dataset: Some(dataset.to_string()),
+        start: Some(start.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        end: Some(end.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        ..base.clone()
+    };
+    let result = client
+        .query_events(org, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    Ok(aggregate(&result, "count()"))
+}
+
+async fn window_counts(
+    client: &impl SentryApi,
+    org: &str,
+    base: &DiscoverQuery,
+    dataset: &str,
+    end: DateTime<Utc>,
+) -> Result<WindowCounts, McpError> {
+    let window = Duration::minutes(WINDOW_MINUTES);
+    Ok(WindowCounts {
+        last: count(client, org, base, dataset, end - window, end).await?,
+        previous: count(client, org, base, dataset, end - window * 2, end - window).await?,
+    })
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ProjectThroughputInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let project = client
+        .get_project(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let base = DiscoverQuery {
+        projects: vec![project.id],
+        environments: environment.into_iter().collect(),
+        per_page: Some(1),
+        ..Default::default()
+    };
+    let now = Utc::now();
+    let end = now.duration_trunc(Duration::minutes(1)).unwrap_or(now);
+    let errors = window_counts(client, org, &base, "errors", end).await?;
+    let transactions = window_counts(client, org, &base, "transactions", end).await?;
+    let output = format_throughput_output(&input.project_slug, end, &errors, &transactions);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
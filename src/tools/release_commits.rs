@@ -0,0 +1,121 @@
+use crate::api_client::{Commit, SentryApi};
+use crate::format::escape_cell;
+use crate::tools::get_issue_details::{commit_link, short_sha};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReleaseCommitsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Release version, e.g. 'backend@1.4.2'")]
+    pub version: String,
+    #[schemars(description = "Only show commits from this repository, e.g. 'acme/backend'")]
+    pub repository: Option<String>,
+    #[schemars(description = "Maximum number of commits to show (default: 50, max: 100)")]
+    pub limit: Option<usize>,
+}
+
+/// Groups commits by repository name, keeping the API's order within each group.
+pub fn group_by_repository(commits: &[Commit]) -> Vec<(String, Vec<&Commit>)> {
+    let mut groups: Vec<(String, Vec<&Commit>)> = Vec::new();
+    for commit in commits {
+        let repo = commit
+            .repository
+            .as_ref()
+            .map_or("(unknown repository)", |r| r.name.as_str());
+        match groups.iter_mut().find(|(name, _)| name == repo) {
+            Some((_, members)) => members.push(commit),
+            None => groups.push((repo.to_string(), vec![commit])),
+        }
+    }
+    groups
+}
+
+fn author_name(commit: &Commit) -> &str {
+    commit
+        .author
+        .as_ref()
+        .and_then(|a| a.name.as_deref().or(a.email.as_deref()))
+        .unwrap_or("unknown")
+}
+
+pub fn format_release_commits(version: &str, commits: &[Commit], total: usize) -> String {
+    let mut output = String::new();
+    output.push_str("# Release Commits\n\n");
+    output.push_str(&format!("**Release:** {}\n", version));
+    let groups = group_by_repository(commits);
+    output.push_str(&format!(
+        "**Commits:** {} in {} repositories",
+        total,
+        groups.len()
+    ));
+    if commits.len() < total {
+        output.push_str(&format!(" (showing {})", commits.len()));
+    }
+    output.push('\n');
+    if commits.is_empty() {
+        output.push_str(
+            "\nNo commits are associated with this release. Commits are only tracked when \
+             the release is created with repository integration (e.g. `sentry-cli releases set-commits`).\n",
+        );
+        return output;
+    }
+    for (repo, members) in groups {
+        output.push_str(&format!("\n## {}\n\n", repo));
+        output.push_str("| Commit | Author | Date | Message | PR |\n");
+        output.push_str("|---|---|---|---|---|\n");
+        for commit in members {
+            let sha = short_sha(&commit.id);
+            let sha_cell = match commit_link(commit) {
+                Some(url) if commit.pull_request.is_none() => format!("[{}]({})", sha, url),
+                _ => sha.to_string(),
+            };
+            let summary = commit
+                .message
+                .as_deref()
+                .and_then(|m| m.lines().next())
+                .unwrap_or("");
+            let pr = commit.pull_request.as_ref().map_or_else(
+                || "-".to_string(),
+                |pr| match &pr.external_url {
+                    Some(url) => format!("[#{}]({})", pr.id, url),
+                    None => format!("#{}", pr.id),
+                },
+            );
+            output.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                sha_cell,
+                escape_cell(author_name(commit)),
+                commit.date_created.as_deref().unwrap_or("-"),
+                escape_cell(summary),
+                pr
+            ));
+        }
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ReleaseCommitsInput,
+) -> Result<CallToolResult, McpError> {
+    let commits = client
+        .list_release_commits(&input.organization_slug, &input.version)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let mut commits: Vec<Commit> = match &input.repository {
+        Some(repo) => commits
+            .into_iter()
+            .filter(|c| c.repository.as_ref().is_some_and(|r| &r.name == repo))
+            .collect(),
+        None => commits,
+    };
+    let total = commits.len();
+    commits.truncate(input.limit.unwrap_or(50).clamp(1, 100));
+    let output = format_release_commits(&input.version, &commits, total);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,99 @@
+use crate::api_client::{Deploy, SentryApi};
+use crate::format::escape_cell;
+use crate::tools::list_environments::resolve_environment;
+use chrono::DateTime;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReleaseDeploysInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Release version, e.g. 'backend@1.4.2'")]
+    pub version: String,
+    #[schemars(description = "Only show deploys to this environment")]
+    pub environment: Option<String>,
+}
+
+/// When the deploy finished, or started if it never reported finishing.
+fn deploy_time(deploy: &Deploy) -> Option<&str> {
+    deploy
+        .date_finished
+        .as_deref()
+        .or(deploy.date_started.as_deref())
+}
+
+/// Deploys for `environment` (all when `None`), most recent first.
+pub fn sort_deploys<'a>(deploys: &'a [Deploy], environment: Option<&str>) -> Vec<&'a Deploy> {
+    let mut selected: Vec<&Deploy> = deploys
+        .iter()
+        .filter(|d| environment.is_none_or(|env| d.environment == env))
+        .collect();
+    selected.sort_by_key(|d| {
+        std::cmp::Reverse(deploy_time(d).and_then(|t| DateTime::parse_from_rfc3339(t).ok()))
+    });
+    selected
+}
+
+fn format_duration(deploy: &Deploy) -> Option<String> {
+    let start = DateTime::parse_from_rfc3339(deploy.date_started.as_deref()?).ok()?;
+    let end = DateTime::parse_from_rfc3339(deploy.date_finished.as_deref()?).ok()?;
+    let seconds = (end - start).num_seconds();
+    Some(if seconds >= 60 {
+        format!("{}m {}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}s", seconds)
+    })
+}
+
+pub fn format_deploys_output(version: &str, deploys: &[&Deploy]) -> String {
+    let mut output = String::new();
+    output.push_str("# Release Deploys\n\n");
+    output.push_str(&format!("**Release:** {}\n", version));
+    output.push_str(&format!("**Deploys:** {}\n\n", deploys.len()));
+    if deploys.is_empty() {
+        output.push_str("No deploys recorded for this release.\n");
+        return output;
+    }
+    output.push_str("| Environment | Started | Finished | Duration | Name |\n");
+    output.push_str("|---|---|---|---|---|\n");
+    for deploy in deploys {
+        let name = match (&deploy.name, &deploy.url) {
+            (Some(name), Some(url)) => format!("[{}]({})", name, url),
+            (Some(name), None) => name.clone(),
+            (None, Some(url)) => url.clone(),
+            (None, None) => "-".to_string(),
+        };
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            deploy.environment,
+            deploy.date_started.as_deref().unwrap_or("-"),
+            deploy.date_finished.as_deref().unwrap_or("-"),
+            format_duration(deploy).unwrap_or_else(|| "-".to_string()),
+            escape_cell(&name)
+        ));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ReleaseDeploysInput,
+) -> Result<CallToolResult, McpError> {
+    let environment = resolve_environment(
+        client,
+        &input.organization_slug,
+        input.environment.as_deref(),
+    )
+    .await?;
+    let deploys = client
+        .list_release_deploys(&input.organization_slug, &input.version)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let selected = sort_deploys(&deploys, environment.as_deref());
+    let output = format_deploys_output(&input.version, &selected);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
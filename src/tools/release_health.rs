@@ -0,0 +1,287 @@
+use crate::api_client::{Issue, IssuesQuery, SentryApi, SessionStats, SessionsQuery};
+use crate::format::{count_label, escape_cell, format_rate};
+use crate::tools::list_environments::resolve_environment;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+const SESSIONS: &str = "sum(session)";
+const USERS: &str = "count_unique(user)";
+const CRASH_FREE_SESSIONS: &str = "crash_free_rate(session)";
+const CRASH_FREE_USERS: &str = "crash_free_rate(user)";
+/// Issues fetched per count; counts at this limit are shown as `100+`.
+const COUNT_LIMIT: i32 = 100;
+/// Issues listed per section.
+const LISTED_ISSUES: usize = 5;
+/// Crash-free rate drop against the project, in percentage points, that
+/// marks a release as needing attention and as unhealthy.
+const WATCH_DROP_PTS: f64 = 0.5;
+const UNHEALTHY_DROP_PTS: f64 = 2.0;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReleaseHealthInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(description = "Release version, e.g. 'backend@1.2.0'")]
+    pub release: String,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+    #[schemars(description = "Time window, e.g. '24h', '7d' (default: 7d)")]
+    pub stats_period: Option<String>,
+}
+
+/// Session totals of the release or the whole project.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionTotals {
+    pub sessions: f64,
+    pub users: f64,
+    /// Fractions between 0 and 1; `None` without sessions.
+    pub crash_free_sessions: Option<f64>,
+    pub crash_free_users: Option<f64>,
+}
+
+/// Everything the health summary is made of.
+pub struct ReleaseHealth {
+    pub release: SessionTotals,
+    pub project: SessionTotals,
+    /// Issues first seen in the release.
+    pub new_issues: Vec<Issue>,
+    /// Resolved issues that came back with events in the release.
+    pub regressed: Vec<Issue>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Healthy,
+    Watch,
+    Unhealthy,
+    /// No sessions for the release in the window.
+    NoData,
+}
+
+impl Verdict {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Healthy => "Healthy",
+            Self::Watch => "Needs attention",
+            Self::Unhealthy => "Unhealthy",
+            Self::NoData => "No session data",
+        }
+    }
+}
+
+/// Totals of the first (ungrouped) session group.
+pub fn session_totals(stats: &SessionStats) -> SessionTotals {
+    let Some(group) = stats.groups.first() else {
+        return SessionTotals::default();
+    };
+    let total = |field: &str| group.totals.get(field).copied().flatten();
+    SessionTotals {
+        sessions: total(SESSIONS).unwrap_or(0.0),
+        users: total(USERS).unwrap_or(0.0),
+        crash_free_sessions: total(CRASH_FREE_SESSIONS),
+        crash_free_users: total(CRASH_FREE_USERS),
+    }
+}
+
+/// Share of `total` that `part` makes up; `None` when `total` is zero.
+pub fn adoption(part: f64, total: f64) -> Option<f64> {
+    (total > 0.0).then(|| (part / total).min(1.0))
+}
+
+/// Unhealthy when the release's crash-free session rate is more than 2 points
+/// below the project's; needs attention when it is more than 0.5 points below
+/// or issues regressed.
+pub fn verdict(health: &ReleaseHealth) -> Verdict {
+    let Some(rate) = health.release.crash_free_sessions else {
+        return Verdict::NoData;
+    };
+    let drop = health
+        .project
+        .crash_free_sessions
+        .map_or(0.0, |project| (project - rate) * 100.0);
+    if drop > UNHEALTHY_DROP_PTS {
+        Verdict::Unhealthy
+    } else if drop > WATCH_DROP_PTS || !health.regressed.is_empty() {
+        Verdict::Watch
+    } else {
+        Verdict::Healthy
+    }
+}
+
+fn rate_label(rate: Option<f64>) -> String {
+    rate.map_or_else(|| "-".to_string(), format_rate)
+}
+
+fn format_issue_list(output: &mut String, title: &str, issues: &[Issue]) {
+    output.push_str(&format!(
+        "\n## {} ({})\n\n",
+        title,
+        count_label(issues.len(), COUNT_LIMIT as usize)
+    ));
+    if issues.is_empty() {
+        output.push_str("None.\n");
+        return;
+    }
+    output.push_str("| Issue | Title | Users | Events |\n");
+    output.push_str("|---|---|---|---|\n");
+    for issue in issues.iter().take(LISTED_ISSUES) {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            issue.short_id,
+            escape_cell(&issue.title),
+            issue.user_count,
+            issue.count
+        ));
+    }
+    if issues.len() > LISTED_ISSUES {
+        let capped = issues.len() >= COUNT_LIMIT as usize;
+        output.push_str(&format!(
+            "\nAnd {}{} more.\n",
+            issues.len() - LISTED_ISSUES,
+            if capped { "+" } else { "" }
+        ));
+    }
+}
+
+pub fn format_release_health(
+    release: &str,
+    project: &str,
+    period: &str,
+    health: &ReleaseHealth,
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Release Health: {}\n\n", release));
+    output.push_str(&format!("**Project:** {}\n", project));
+    output.push_str(&format!("**Period:** {}\n", period));
+    output.push_str(&format!("**Verdict:** {}\n\n", verdict(health).label()));
+    output.push_str("| Metric | Release | Project |\n");
+    output.push_str("|---|---|---|\n");
+    output.push_str(&format!(
+        "| Sessions | {:.0} | {:.0} |\n",
+        health.release.sessions, health.project.sessions
+    ));
+    output.push_str(&format!(
+        "| Users | {:.0} | {:.0} |\n",
+        health.release.users, health.project.users
+    ));
+    output.push_str(&format!(
+        "| Adoption (sessions) | {} | - |\n",
+        rate_label(adoption(health.release.sessions, health.project.sessions))
+    ));
+    output.push_str(&format!(
+        "| Adoption (users) | {} | - |\n",
+        rate_label(adoption(health.release.users, health.project.users))
+    ));
+    output.push_str(&format!(
+        "| Crash-free sessions | {} | {} |\n",
+        rate_label(health.release.crash_free_sessions),
+        rate_label(health.project.crash_free_sessions)
+    ));
+    output.push_str(&format!(
+        "| Crash-free users | {} | {} |\n",
+        rate_label(health.release.crash_free_users),
+        rate_label(health.project.crash_free_users)
+    ));
+    format_issue_list(&mut output, "New Issues", &health.new_issues);
+    format_issue_list(&mut output, "Regressed Issues", &health.regressed);
+    output
+}
+
+async fn sessions(
+    client: &impl SentryApi,
+    org_slug: &str,
+    window: &SessionsQuery,
+    query: Option<String>,
+) -> Result<SessionTotals, McpError> {
+    let sessions_query = SessionsQuery {
+        query,
+        ..window.clone()
+    };
+    client
+        .get_session_stats(org_slug, &sessions_query)
+        .await
+        .map(|stats| session_totals(&stats))
+        .map_err(|e| McpError::internal_error(e.to_string(), None))
+}
+
+async fn search(
+    client: &impl SentryApi,
+    org_slug: &str,
+    window: &IssuesQuery,
+    query: String,
+) -> Result<Vec<Issue>, McpError> {
+    let issues_query = IssuesQuery {
+        query: Some(query),
+        ..window.clone()
+    };
+    client
+        .list_issues(org_slug, &issues_query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: ReleaseHealthInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let release = input.release.trim();
+    if release.is_empty() {
+        return Err(McpError::invalid_params(
+            "release must not be empty".to_string(),
+            None,
+        ));
+    }
+    let period = input.stats_period.as_deref().unwrap_or("7d");
+    let project = client
+        .get_project(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let release_filter = format!("release:\"{}\"", release);
+    let sessions_window = SessionsQuery {
+        fields: [SESSIONS, USERS, CRASH_FREE_SESSIONS, CRASH_FREE_USERS]
+            .map(String::from)
+            .to_vec(),
+        projects: vec![project.id.clone()],
+        environment: environment.clone(),
+        stats_period: Some(period.to_string()),
+        interval: Some("1d".to_string()),
+        ..Default::default()
+    };
+    let issues_window = IssuesQuery {
+        project: Some(project.id),
+        environment,
+        stats_period: Some(period.to_string()),
+        sort: Some("freq".to_string()),
+        limit: Some(COUNT_LIMIT),
+        ..Default::default()
+    };
+    let health = ReleaseHealth {
+        release: sessions(client, org, &sessions_window, Some(release_filter.clone())).await?,
+        project: sessions(client, org, &sessions_window, None).await?,
+        new_issues: search(
+            client,
+            org,
+            &issues_window,
+            format!("firstRelease:\"{}\"", release),
+        )
+        .await?,
+        regressed: search(
+            client,
+            org,
+            &issues_window,
+            format!("is:regressed {}", release_filter),
+        )
+        .await?,
+    };
+    let output = format_release_health(release, &input.project_slug, period, &health);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,80 @@
+use crate::api_client::{CodeMapping, Event, Project, SentryApi};
+use serde_json::Value;
+use tracing::warn;
+
+/// Rewrites `path` to its location in the repository using the code mapping with the
+/// longest matching `stack_root`, e.g. `/usr/src/app/api/views.py` with stack root
+/// `/usr/src/app/` and source root `backend/` becomes `backend/api/views.py`.
+pub fn map_path(path: &str, mappings: &[CodeMapping]) -> Option<String> {
+    let mapping = mappings
+        .iter()
+        .filter(|m| !m.stack_root.is_empty() && path.starts_with(&m.stack_root))
+        .max_by_key(|m| m.stack_root.len())?;
+    let rest = path[mapping.stack_root.len()..].trim_start_matches('/');
+    let root = mapping.source_root.trim_end_matches('/');
+    if rest.is_empty() {
+        return None;
+    }
+    Some(if root.is_empty() {
+        rest.to_string()
+    } else {
+        format!("{}/{}", root, rest)
+    })
+}
+
+fn stack_frames_mut(entry_data: &mut Value) -> impl Iterator<Item = &mut Value> {
+    entry_data
+        .get_mut("values")
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten()
+        .filter_map(|value| {
+            value
+                .get_mut("stacktrace")?
+                .get_mut("frames")?
+                .as_array_mut()
+        })
+        .flatten()
+}
+
+/// Replaces each frame's `filename` with its repository-relative path, so quoted
+/// locations match the user's checkout. The absolute path is matched first, then the
+/// filename; `absPath` itself is kept. Returns the number of frames rewritten.
+pub fn normalize_frame_paths(event: &mut Event, mappings: &[CodeMapping]) -> usize {
+    if mappings.is_empty() {
+        return 0;
+    }
+    let mut rewritten = 0;
+    for entry in &mut event.entries {
+        if entry.entry_type != "exception" && entry.entry_type != "threads" {
+            continue;
+        }
+        for frame in stack_frames_mut(&mut entry.data) {
+            let repo_path = ["absPath", "filename"]
+                .iter()
+                .filter_map(|field| frame.get(*field).and_then(Value::as_str))
+                .find_map(|path| map_path(path, mappings));
+            if let Some(repo_path) = repo_path {
+                frame["filename"] = Value::String(repo_path);
+                rewritten += 1;
+            }
+        }
+    }
+    rewritten
+}
+
+/// Rewrites frame paths with `project`'s code mappings. Best effort: if the mappings
+/// cannot be loaded, the failure is logged and the event is left as it was.
+pub async fn apply_code_mappings(
+    client: &impl SentryApi,
+    org_slug: &str,
+    project: &Project,
+    event: &mut Event,
+) {
+    match client.list_code_mappings(org_slug, &project.id).await {
+        Ok(mappings) => {
+            normalize_frame_paths(event, &mappings);
+        }
+        Err(e) => warn!("code mappings for {} failed: {}", project.slug, e),
+    }
+}
@@ -0,0 +1,250 @@
+use crate::api_client::{OrgStats, OrgStatsQuery, ProjectSampling, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+const QUANTITY: &str = "sum(quantity)";
+/// `rate_limited` reason Sentry reports for events dropped by spike protection.
+pub const SPIKE_PROTECTION_REASON: &str = "smart_rate_limit";
+/// `client_discard` reason SDKs report for events dropped by their sample rate.
+const CLIENT_SAMPLE_REASON: &str = "sample_rate";
+const SPIKE_PROTECTION_DISABLED_OPTION: &str = "quotas:spike-protection-disabled";
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SamplingReportInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(description = "Time window, e.g. '24h', '7d', '30d' (default: 7d)")]
+    pub stats_period: Option<String>,
+}
+
+/// Events of one category dropped by spike protection, and when.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpikeHit {
+    pub category: String,
+    pub dropped: f64,
+    /// First and last interval with drops.
+    pub first: Option<String>,
+    pub last: Option<String>,
+}
+
+/// Spike protection drops per category, largest first.
+pub fn spike_protection_hits(stats: &OrgStats) -> Vec<SpikeHit> {
+    let mut hits: BTreeMap<String, (f64, Option<usize>, Option<usize>)> = BTreeMap::new();
+    for group in &stats.groups {
+        if group.key("outcome") != "rate_limited" || group.key("reason") != SPIKE_PROTECTION_REASON
+        {
+            continue;
+        }
+        let quantity = group.totals.get(QUANTITY).copied().unwrap_or(0.0);
+        if quantity <= 0.0 {
+            continue;
+        }
+        let entry = hits.entry(group.key("category").to_string()).or_default();
+        entry.0 += quantity;
+        let series = group
+            .series
+            .get(QUANTITY)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let first = series.iter().position(|v| *v > 0.0);
+        let last = series.iter().rposition(|v| *v > 0.0);
+        entry.1 = match (entry.1, first) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        entry.2 = entry.2.max(last);
+    }
+    let mut hits: Vec<SpikeHit> = hits
+        .into_iter()
+        .map(|(category, (dropped, first, last))| SpikeHit {
+            category,
+            dropped,
+            first: first.and_then(|i| stats.intervals.get(i).cloned()),
+            last: last.and_then(|i| stats.intervals.get(i).cloned()),
+        })
+        .collect();
+    hits.sort_by(|a, b| b.dropped.total_cmp(&a.dropped));
+    hits
+}
+
+/// Sampling observed for one data category over the period.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SamplingRow {
+    pub category: String,
+    /// Events that reached Sentry (every outcome except client discards).
+    pub received: f64,
+    /// Events the SDK dropped because of its sample rate.
+    pub client_sampled: f64,
+    /// Events dropped by dynamic sampling (`filtered` with a `Sampled:` reason).
+    pub dynamically_sampled: f64,
+    pub accepted: f64,
+}
+
+impl SamplingRow {
+    /// Share of generated events the SDK sent, if the SDK reports its discards.
+    pub fn client_rate(&self) -> Option<f64> {
+        let generated = self.received + self.client_sampled;
+        (self.client_sampled > 0.0 && generated > 0.0).then(|| self.received / generated)
+    }
+    /// Share of events kept by dynamic sampling.
+    pub fn dynamic_rate(&self) -> Option<f64> {
+        let considered = self.accepted + self.dynamically_sampled;
+        (self.dynamically_sampled > 0.0 && considered > 0.0).then(|| self.accepted / considered)
+    }
+}
+
+/// Per-category sampling figures, limited to categories where sampling was observed.
+pub fn sampling_rows(stats: &OrgStats) -> Vec<SamplingRow> {
+    let mut rows: BTreeMap<String, SamplingRow> = BTreeMap::new();
+    for group in &stats.groups {
+        let category = group.key("category");
+        let quantity = group.totals.get(QUANTITY).copied().unwrap_or(0.0);
+        let row = rows
+            .entry(category.to_string())
+            .or_insert_with(|| SamplingRow {
+                category: category.to_string(),
+                ..Default::default()
+            });
+        match (group.key("outcome"), group.key("reason")) {
+            ("client_discard", CLIENT_SAMPLE_REASON) => row.client_sampled += quantity,
+            ("client_discard", _) => {}
+            ("filtered", reason) if reason.starts_with("Sampled") => {
+                row.received += quantity;
+                row.dynamically_sampled += quantity;
+            }
+            ("accepted", _) => {
+                row.received += quantity;
+                row.accepted += quantity;
+            }
+            _ => row.received += quantity,
+        }
+    }
+    rows.into_values()
+        .filter(|row| row.client_sampled > 0.0 || row.dynamically_sampled > 0.0)
+        .collect()
+}
+
+fn format_rate(rate: Option<f64>) -> String {
+    match rate {
+        Some(rate) => format!("{:.1}%", rate * 100.0),
+        None => "-".to_string(),
+    }
+}
+
+pub fn format_sampling_report(
+    project_slug: &str,
+    period: &str,
+    settings: &ProjectSampling,
+    stats: &OrgStats,
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Sampling Report: {}\n\n", project_slug));
+    output.push_str(&format!("**Period:** {}\n", period));
+    let spike_disabled = settings
+        .options
+        .get(SPIKE_PROTECTION_DISABLED_OPTION)
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    output.push_str(&format!(
+        "**Spike protection:** {}\n",
+        if spike_disabled {
+            "disabled for this project"
+        } else {
+            "enabled"
+        }
+    ));
+    output.push_str("\n## Spike Protection\n\n");
+    let hits = spike_protection_hits(stats);
+    if hits.is_empty() {
+        output.push_str("Spike protection did not drop any events in this period.\n");
+    }
+    for hit in &hits {
+        output.push_str(&format!(
+            "- **{}:** {:.0} dropped",
+            hit.category, hit.dropped
+        ));
+        match (&hit.first, &hit.last) {
+            (Some(first), Some(last)) if first != last => {
+                output.push_str(&format!(" between {} and {}", first, last))
+            }
+            (Some(first), _) => output.push_str(&format!(" at {}", first)),
+            _ => {}
+        }
+        output.push('\n');
+    }
+    output.push_str("\n## Sampling\n\n");
+    let rows = sampling_rows(stats);
+    if rows.is_empty() {
+        output.push_str(
+            "No client or dynamic sampling observed: the SDK reported no sample-rate discards \
+            and no events were dropped by dynamic sampling.\n",
+        );
+    } else {
+        output.push_str(
+            "| Category | Received | Client-sampled | Client rate | Dynamically sampled | Kept by dynamic sampling |\n",
+        );
+        output.push_str("|---|---|---|---|---|---|\n");
+        for row in &rows {
+            output.push_str(&format!(
+                "| {} | {:.0} | {:.0} | {} | {:.0} | {} |\n",
+                row.category,
+                row.received,
+                row.client_sampled,
+                format_rate(row.client_rate()),
+                row.dynamically_sampled,
+                format_rate(row.dynamic_rate())
+            ));
+        }
+        output.push_str(
+            "\nClient rates are derived from discards the SDK reports and are only shown when \
+            it reports them.\n",
+        );
+    }
+    let biases: Vec<&str> = settings
+        .dynamic_sampling_biases
+        .iter()
+        .filter(|b| b.active)
+        .map(|b| b.id.as_str())
+        .collect();
+    if !biases.is_empty() {
+        output.push_str(&format!(
+            "\n**Active dynamic sampling biases:** {}\n",
+            biases.join(", ")
+        ));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: SamplingReportInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let period = input.stats_period.as_deref().unwrap_or("7d");
+    let project = client
+        .get_project(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let settings = client
+        .get_project_sampling(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let query = OrgStatsQuery {
+        projects: vec![project.id],
+        categories: vec![],
+        stats_period: Some(period.to_string()),
+        interval: Some("1h".to_string()),
+    };
+    let stats = client
+        .get_org_stats(org, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_sampling_report(&input.project_slug, period, &settings, &stats);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
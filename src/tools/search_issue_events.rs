@@ -1,9 +1,16 @@
-use crate::api_client::{Event, EventsQuery, SentryApi};
+use crate::api_client::{DiscoverQuery, Event, EventsQuery, SentryApi};
+use crate::format::{DEFAULT_MAX_TOKENS, Writer, field, key_values, opt_field};
 use crate::json_ext::ValueExt;
+use crate::tools::cross_org::for_each_organization;
+use crate::tools::search_query::{QueryTarget, check_query, push_query_warnings};
 use rmcp::{ErrorData as McpError, model::CallToolResult};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
+/// Window of event counts, Sentry's default event retention.
+const COUNT_PERIOD: &str = "90d";
+const COUNT: &str = "count()";
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct SearchIssueEventsInput {
     #[schemars(description = "Organization slug")]
@@ -23,74 +30,160 @@ pub struct SearchIssueEventsInput {
     pub limit: Option<i32>,
     #[schemars(description = "Sort order: 'newest' (default) or 'oldest'")]
     pub sort: Option<String>,
+    #[schemars(
+        description = "Return only the number of matching events in the last 90 days, not the events themselves (optional)"
+    )]
+    pub count_only: Option<bool>,
+    #[schemars(
+        description = "Run the search in every organization the token can access, one section per organization (organization_slug is ignored)"
+    )]
+    pub all_organizations: Option<bool>,
 }
 
-pub fn format_events_output(issue_id: &str, query: Option<&str>, events: &[Event]) -> String {
+/// One event's section: date, platform, message, tags and exceptions.
+fn format_event(index: usize, event: &Event) -> String {
     let mut output = String::new();
-    output.push_str("# Issue Events\n\n");
-    output.push_str(&format!("**Issue:** {}\n", issue_id));
-    if let Some(q) = query {
-        output.push_str(&format!("**Query:** {}\n", q));
+    output.push_str(&format!("## Event {} - {}\n\n", index, event.event_id));
+    opt_field(&mut output, "Date", event.date_created.as_ref());
+    opt_field(&mut output, "Platform", event.platform.as_ref());
+    opt_field(&mut output, "Message", event.message.as_ref());
+    if !event.tags.is_empty() {
+        field(
+            &mut output,
+            "Tags",
+            key_values(event.tags.iter().map(|t| (&t.key, &t.value))),
+        );
     }
-    output.push_str(&format!("**Found:** {} events\n\n", events.len()));
-    for (i, event) in events.iter().enumerate() {
-        output.push_str(&format!("## Event {} - {}\n\n", i + 1, event.event_id));
-        if let Some(date) = &event.date_created {
-            output.push_str(&format!("**Date:** {}\n", date));
-        }
-        if let Some(platform) = &event.platform {
-            output.push_str(&format!("**Platform:** {}\n", platform));
-        }
-        if let Some(msg) = &event.message
-            && !msg.is_empty()
+    for entry in &event.entries {
+        if entry.entry_type == "exception"
+            && let Some(values) = entry.data.array_field("values")
         {
-            output.push_str(&format!("**Message:** {}\n", msg));
-        }
-        if !event.tags.is_empty() {
-            output.push_str("**Tags:** ");
-            let tags: Vec<String> = event
-                .tags
-                .iter()
-                .map(|t| format!("{}={}", t.key, t.value))
-                .collect();
-            output.push_str(&tags.join(", "));
-            output.push('\n');
-        }
-        for entry in &event.entries {
-            if entry.entry_type == "exception"
-                && let Some(values) = entry.data.array_field("values")
-            {
-                for exc in values {
-                    let exc_type = exc.str_field("type").unwrap_or("?");
-                    let exc_value = exc.str_field("value").unwrap_or("?");
-                    output.push_str(&format!("**Exception:** {} - {}\n", exc_type, exc_value));
-                }
+            for exc in values {
+                let exc_type = exc.str_field("type").unwrap_or("?");
+                let exc_value = exc.str_field("value").unwrap_or("?");
+                field(
+                    &mut output,
+                    "Exception",
+                    format!("{} - {}", exc_type, exc_value),
+                );
             }
         }
-        output.push('\n');
+    }
+    output.push('\n');
+    output
+}
+
+pub fn format_events_output(issue_id: &str, query: Option<&str>, events: &[Event]) -> String {
+    let mut writer = Writer::new(DEFAULT_MAX_TOKENS);
+    writer.push_str("# Issue Events\n\n");
+    writer.field("Issue", issue_id);
+    writer.opt_field("Query", query);
+    writer.push_str(&format!("**Found:** {} events\n\n", events.len()));
+    for (i, event) in events.iter().enumerate() {
+        writer.section(&format_event(i + 1, event));
     }
     if events.is_empty() {
-        output.push_str("No events found matching the query.\n");
+        writer.push_str("No events found matching the query.\n");
+    }
+    writer.finish("events")
+}
+
+/// Discover query for the issue's events matching `query`. The `issue` filter
+/// takes short IDs; numeric IDs go through `issue.id`.
+pub fn issue_events_query(issue_id: &str, query: Option<&str>) -> String {
+    let issue_id = issue_id.trim();
+    let filter = if !issue_id.is_empty() && issue_id.chars().all(|c| c.is_ascii_digit()) {
+        format!("issue.id:{}", issue_id)
+    } else {
+        format!("issue:{}", issue_id)
+    };
+    match query.map(str::trim).filter(|q| !q.is_empty()) {
+        Some(query) => format!("{} {}", filter, query),
+        None => filter,
     }
+}
+
+pub fn format_event_count(issue_id: &str, query: Option<&str>, count: u64) -> String {
+    let mut output = String::new();
+    output.push_str("# Issue Event Count\n\n");
+    field(&mut output, "Issue", issue_id);
+    opt_field(&mut output, "Query", query);
+    field(&mut output, "Period", COUNT_PERIOD);
+    field(&mut output, "Count", count);
     output
 }
 
-pub async fn execute(
+async fn count_in_org(
     client: &impl SentryApi,
-    input: SearchIssueEventsInput,
-) -> Result<CallToolResult, McpError> {
+    org_slug: &str,
+    input: &SearchIssueEventsInput,
+) -> Result<String, McpError> {
+    let query = DiscoverQuery {
+        fields: vec![COUNT.to_string()],
+        query: Some(issue_events_query(&input.issue_id, input.query.as_deref())),
+        stats_period: Some(COUNT_PERIOD.to_string()),
+        per_page: Some(1),
+        ..Default::default()
+    };
+    let result = client
+        .query_events(org_slug, &query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let count = result
+        .data
+        .first()
+        .and_then(|row| row.get(COUNT))
+        .and_then(|v| v.as_f64())
+        .unwrap_or(0.0);
+    Ok(format_event_count(
+        &input.issue_id,
+        input.query.as_deref(),
+        count as u64,
+    ))
+}
+
+async fn search_in_org(
+    client: &impl SentryApi,
+    org_slug: &str,
+    input: &SearchIssueEventsInput,
+) -> Result<String, McpError> {
+    if input.count_only.unwrap_or(false) {
+        return count_in_org(client, org_slug, input).await;
+    }
     let limit = input.limit.unwrap_or(10).min(100);
-    let sort = input.sort.unwrap_or_else(|| "newest".to_string());
+    let sort = input.sort.clone().unwrap_or_else(|| "newest".to_string());
     let query = EventsQuery {
         query: input.query.clone(),
         limit: Some(limit),
         sort: Some(sort),
+        full: None,
     };
     let events = client
-        .list_events_for_issue(&input.organization_slug, &input.issue_id, &query)
+        .list_events_for_issue(org_slug, &input.issue_id, &query)
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
-    let output = format_events_output(&input.issue_id, input.query.as_deref(), &events);
+    Ok(format_events_output(
+        &input.issue_id,
+        input.query.as_deref(),
+        &events,
+    ))
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: SearchIssueEventsInput,
+) -> Result<CallToolResult, McpError> {
+    let warnings = check_query(input.query.as_deref(), QueryTarget::Events)?;
+    let mut output = if input.all_organizations.unwrap_or(false) {
+        let input = &input;
+        for_each_organization(client, |org| async move {
+            search_in_org(client, &org, input).await
+        })
+        .await?
+    } else {
+        search_in_org(client, &input.organization_slug, &input).await?
+    };
+    push_query_warnings(&mut output, &warnings);
     Ok(CallToolResult::success(vec![rmcp::model::Content::text(
         output,
     )]))
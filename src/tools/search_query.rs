@@ -0,0 +1,346 @@
+use rmcp::ErrorData as McpError;
+
+/// What a query searches; some keys only apply to one of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryTarget {
+    Issues,
+    Events,
+}
+
+/// Keys both issue and event search understand, including the default tags.
+const COMMON_KEYS: &[&str] = &[
+    "environment",
+    "release",
+    "release.version",
+    "release.package",
+    "release.build",
+    "dist",
+    "level",
+    "logger",
+    "message",
+    "platform",
+    "transaction",
+    "url",
+    "user",
+    "user.id",
+    "user.email",
+    "user.username",
+    "user.ip",
+    "user.segment",
+    "browser",
+    "browser.name",
+    "os",
+    "os.name",
+    "device",
+    "device.family",
+    "runtime",
+    "runtime.name",
+    "server_name",
+    "sdk.name",
+    "sdk.version",
+    "error.type",
+    "error.value",
+    "error.handled",
+    "error.unhandled",
+    "error.mechanism",
+    "error.main_thread",
+    "stack.filename",
+    "stack.module",
+    "stack.function",
+    "stack.abs_path",
+    "stack.package",
+    "http.method",
+    "http.status_code",
+    "http.url",
+    "trace",
+    "handled",
+    "mechanism",
+    "has",
+    "project",
+    "title",
+    "location",
+    "timestamp",
+];
+
+const ISSUE_KEYS: &[&str] = &[
+    "is",
+    "assigned",
+    "assigned_or_suggested",
+    "bookmarks",
+    "subscribed",
+    "firstSeen",
+    "lastSeen",
+    "age",
+    "times_seen",
+    "timesSeen",
+    "firstRelease",
+    "issue",
+    "issue.id",
+    "issue.category",
+    "issue.type",
+    "issue.priority",
+    "issue.seer_actionability",
+    "event.timestamp",
+];
+
+const EVENT_KEYS: &[&str] = &[
+    "id",
+    "event.type",
+    "event_id",
+    "trace.span",
+    "trace.parent_span",
+];
+
+/// Keys from other tools' syntaxes people reach for, and what Sentry calls them.
+const ALIASES: &[(&str, &str)] = &[
+    ("status", "is"),
+    ("state", "is"),
+    ("assignee", "assigned"),
+    ("assigned_to", "assigned"),
+    ("owner", "assigned"),
+    ("env", "environment"),
+    ("severity", "level"),
+    ("priority", "issue.priority"),
+    ("category", "issue.category"),
+    ("first_seen", "firstSeen"),
+    ("last_seen", "lastSeen"),
+    ("first_release", "firstRelease"),
+    ("count", "times_seen"),
+    ("events", "times_seen"),
+];
+
+const IS_VALUES: &[&str] = &[
+    "unresolved",
+    "resolved",
+    "ignored",
+    "archived",
+    "muted",
+    "assigned",
+    "unassigned",
+    "for_review",
+    "linked",
+    "unlinked",
+    "new",
+    "ongoing",
+    "regressed",
+    "escalating",
+    "archived_until_escalating",
+    "archived_forever",
+    "archived_until_condition_met",
+];
+
+const LEVELS: &[&str] = &["fatal", "error", "warning", "info", "debug", "sample"];
+
+fn known_keys(target: QueryTarget) -> impl Iterator<Item = &'static str> {
+    let specific = match target {
+        QueryTarget::Issues => ISSUE_KEYS,
+        QueryTarget::Events => EVENT_KEYS,
+    };
+    COMMON_KEYS.iter().chain(specific).copied()
+}
+
+/// Levenshtein distance between two short strings.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + previous.min(row[j]).min(row[j + 1])
+            };
+            previous = current;
+        }
+    }
+    row[b.len()]
+}
+
+/// The candidate closest to `word`, if it is close enough to be a likely typo:
+/// a case-insensitive match, or one or two edits away depending on length.
+pub fn closest<'a>(word: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let max = if word.chars().count() <= 4 { 1 } else { 2 };
+    candidates
+        .into_iter()
+        .map(|c| {
+            let distance = if c.eq_ignore_ascii_case(word) {
+                0
+            } else {
+                edit_distance(&word.to_ascii_lowercase(), &c.to_ascii_lowercase())
+            };
+            (distance, c)
+        })
+        .filter(|(distance, _)| *distance <= max)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, c)| c)
+}
+
+/// Splits a query on whitespace outside quotes; fails on an unclosed quote or
+/// unbalanced parentheses.
+fn tokenize(query: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut in_quotes = false;
+    let mut depth: i32 = 0;
+    let mut escaped = false;
+    for c in query.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            '(' if !in_quotes => depth += 1,
+            ')' if !in_quotes => {
+                depth -= 1;
+                if depth < 0 {
+                    return Err("Unmatched ')' in query".to_string());
+                }
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        token.push(c);
+    }
+    if in_quotes {
+        return Err("Unclosed '\"' in query".to_string());
+    }
+    if depth > 0 {
+        return Err("Unclosed '(' in query".to_string());
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// `(key, value)` of a `key:value` filter; `None` for free text.
+fn split_filter(token: &str) -> Option<(&str, &str)> {
+    let token = token.trim_start_matches('(').trim_start_matches('!');
+    let (key, value) = token.split_once(':')?;
+    let valid = key
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_.-[]".contains(c));
+    valid.then(|| (key, value.trim_end_matches(')')))
+}
+
+/// Values of a filter: `[a, b]` lists are split, quotes dropped.
+fn filter_values(value: &str) -> Vec<&str> {
+    let value = value
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+        .unwrap_or(value);
+    value
+        .split(',')
+        .map(|v| v.trim().trim_matches('"'))
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+fn check_values(problems: &mut Vec<String>, key: &str, value: &str, allowed: &[&str]) {
+    for v in filter_values(value) {
+        if allowed.contains(&v) {
+            continue;
+        }
+        let hint = match closest(v, allowed.iter().copied()) {
+            Some(s) => format!("did you mean `{}:{}`?", key, s),
+            None => format!("expected one of {}", allowed.join(", ")),
+        };
+        problems.push(format!("Unknown `{}:` value `{}`: {}", key, v, hint));
+    }
+}
+
+fn check_filter(problems: &mut Vec<String>, key: &str, value: &str, target: QueryTarget) {
+    if key.starts_with("tags[")
+        || key.starts_with("flags[")
+        || key.starts_with("measurements.")
+        || key.starts_with("contexts.")
+    {
+        return;
+    }
+    if known_keys(target).any(|k| k == key) {
+        if value.is_empty() {
+            problems.push(format!(
+                "`{}:` has no value; use `{}:\"\"` to match empty values",
+                key, key
+            ));
+        }
+        match key {
+            "is" => check_values(problems, key, value, IS_VALUES),
+            "level" => check_values(problems, key, value, LEVELS),
+            _ => {}
+        }
+        return;
+    }
+    if target == QueryTarget::Events && ISSUE_KEYS.contains(&key) {
+        problems.push(format!(
+            "`{}:` filters issues, not events; use it with list_issues",
+            key
+        ));
+        return;
+    }
+    let suggestion = ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(key))
+        .map(|(_, k)| *k)
+        .filter(|k| known_keys(target).any(|known| known == *k))
+        .or_else(|| closest(key, known_keys(target)));
+    if let Some(suggestion) = suggestion {
+        problems.push(format!(
+            "Unknown key `{}`: did you mean `{}:{}`? (for a custom tag named `{}`, use `tags[{}]:{}`)",
+            key, suggestion, value, key, key, value
+        ));
+    }
+}
+
+/// Checks a search query locally. Unbalanced quotes and parentheses are an
+/// error. Likely mistakes (unknown `is:` or `level:` values, keys that look like
+/// typos or like other tools' syntax) come back as warnings with suggested
+/// corrections, since Sentry may accept them as custom tags. Keys that are not
+/// close to a known key are left alone.
+pub fn validate_query(query: &str, target: QueryTarget) -> Result<Vec<String>, String> {
+    let tokens = tokenize(query).map_err(|e| format!("Invalid search query '{}': {}", query, e))?;
+    let mut problems = Vec::new();
+    for token in &tokens {
+        if matches!(token.as_str(), "OR" | "AND") || token.starts_with('"') {
+            continue;
+        }
+        if let Some((key, value)) = split_filter(token) {
+            check_filter(&mut problems, key, value, target);
+        }
+    }
+    Ok(problems)
+}
+
+/// [`validate_query`] on an optional tool input; syntax errors fail as invalid
+/// params, the warnings are returned for [`push_query_warnings`].
+pub fn check_query(query: Option<&str>, target: QueryTarget) -> Result<Vec<String>, McpError> {
+    match query {
+        Some(query) => validate_query(query, target).map_err(|e| McpError::invalid_params(e, None)),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Appends the warnings of [`check_query`], if any, to a tool's output.
+pub fn push_query_warnings(output: &mut String, warnings: &[String]) {
+    if warnings.is_empty() {
+        return;
+    }
+    output.push_str("\n## Query Warnings\n\n");
+    output.push_str(
+        "The query ran as written, but parts of it look like mistakes; Sentry reads unknown keys as custom tags.\n\n",
+    );
+    for warning in warnings {
+        output.push_str(&format!("- {}\n", warning));
+    }
+}
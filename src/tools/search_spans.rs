@@ -0,0 +1,327 @@
+use crate::api_client::{DiscoverQuery, DiscoverResult, SentryApi};
+use crate::format::{escape_cell, format_duration};
+use crate::tools::graphql::summarize_graphql;
+use crate::tools::sql::normalize_sql;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// Characters of a span description shown before it is cut.
+const MAX_DESCRIPTION_CHARS: usize = 120;
+/// Distinct statements printed in full by the `explain` mode.
+const MAX_EXPLAIN_STATEMENTS: usize = 5;
+
+const FIELDS: [&str; 8] = [
+    "id",
+    "span.op",
+    "span.description",
+    "span.duration",
+    "transaction",
+    "project",
+    "trace",
+    "timestamp",
+];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SearchSpansInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(
+        description = "Span operation, e.g. 'db', 'db.sql.query', 'http.client'; '*' wildcards allowed (optional)"
+    )]
+    pub op: Option<String>,
+    #[schemars(
+        description = "Text the span description must contain, e.g. a table name like 'orders' (optional)"
+    )]
+    pub description: Option<String>,
+    #[schemars(description = "Only spans at least this long, in milliseconds (optional)")]
+    pub min_duration_ms: Option<f64>,
+    #[schemars(
+        description = "Extra span search filter, e.g. 'span.status:internal_error' (optional)"
+    )]
+    pub query: Option<String>,
+    #[schemars(description = "Only search these project slugs (default: all projects)")]
+    pub project_slugs: Option<Vec<String>>,
+    #[schemars(description = "Time window, e.g. '1h', '24h', '7d' (default: 24h)")]
+    pub stats_period: Option<String>,
+    #[schemars(description = "'slowest' (default) or 'newest'")]
+    pub sort: Option<String>,
+    #[schemars(description = "Maximum number of spans (default: 20, max: 100)")]
+    pub limit: Option<i32>,
+    #[schemars(
+        description = "'list' (default) shows each span; 'grouped' groups statements with literals stripped; 'explain' prints the full original SQL of the slowest distinct statements for EXPLAIN. 'grouped' and 'explain' default op to 'db*'"
+    )]
+    pub mode: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanSearchMode {
+    List,
+    Grouped,
+    Explain,
+}
+
+pub fn parse_mode(mode: Option<&str>) -> Result<SpanSearchMode, McpError> {
+    match mode.map(|m| m.trim().to_ascii_lowercase()).as_deref() {
+        None | Some("") | Some("list") => Ok(SpanSearchMode::List),
+        Some("grouped") => Ok(SpanSearchMode::Grouped),
+        Some("explain") => Ok(SpanSearchMode::Explain),
+        Some(other) => Err(McpError::invalid_params(
+            format!(
+                "Invalid mode '{}': expected 'list', 'grouped' or 'explain'",
+                other
+            ),
+            None,
+        )),
+    }
+}
+
+/// Spans whose descriptions normalize to the same statement.
+#[derive(Debug)]
+pub struct StatementGroup<'a> {
+    pub statement: String,
+    pub count: usize,
+    pub total_ms: f64,
+    pub max_ms: f64,
+    /// The slowest span of the group, whose description is the original statement.
+    pub slowest: &'a Map<String, Value>,
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Builds the span search query from the structured filters and the free-form query.
+pub fn build_spans_query(input: &SearchSpansInput) -> String {
+    let mut terms = Vec::new();
+    if let Some(op) = input.op.as_deref().map(str::trim).filter(|o| !o.is_empty()) {
+        terms.push(format!("span.op:{}", quote(op)));
+    }
+    if let Some(text) = input
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+    {
+        terms.push(format!(
+            "span.description:{}",
+            quote(&format!("*{}*", text))
+        ));
+    }
+    if let Some(ms) = input.min_duration_ms.filter(|ms| *ms > 0.0) {
+        terms.push(format!("span.duration:>={}ms", ms));
+    }
+    if let Some(query) = input
+        .query
+        .as_deref()
+        .map(str::trim)
+        .filter(|q| !q.is_empty())
+    {
+        terms.push(query.to_string());
+    }
+    terms.join(" ")
+}
+
+/// Events endpoint `sort` for a sort name.
+pub fn spans_sort(sort: Option<&str>) -> Result<&'static str, McpError> {
+    match sort.map(|s| s.trim().to_ascii_lowercase()).as_deref() {
+        None | Some("") | Some("slowest") => Ok("-span.duration"),
+        Some("newest") => Ok("-timestamp"),
+        Some(other) => Err(McpError::invalid_params(
+            format!("Invalid sort '{}': expected 'slowest' or 'newest'", other),
+            None,
+        )),
+    }
+}
+
+/// A description fit for one table cell: GraphQL documents are summarized, long
+/// text is cut.
+pub fn short_description(description: &str) -> String {
+    if let Some(summary) = summarize_graphql(description) {
+        return summary;
+    }
+    let flat = description.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut short: String = flat.chars().take(MAX_DESCRIPTION_CHARS).collect();
+    if flat.chars().count() > MAX_DESCRIPTION_CHARS {
+        short.push('…');
+    }
+    escape_cell(&short)
+}
+
+fn text<'a>(row: &'a Map<String, Value>, field: &str) -> &'a str {
+    row.get(field).and_then(Value::as_str).unwrap_or("-")
+}
+
+fn duration(row: &Map<String, Value>) -> f64 {
+    row.get("span.duration")
+        .and_then(Value::as_f64)
+        .unwrap_or(0.0)
+}
+
+/// Groups spans by normalized description, most total time first.
+pub fn group_statements(rows: &[Map<String, Value>]) -> Vec<StatementGroup<'_>> {
+    let mut groups: Vec<StatementGroup> = Vec::new();
+    for row in rows {
+        let Some(description) = row
+            .get("span.description")
+            .and_then(Value::as_str)
+            .filter(|d| !d.trim().is_empty())
+        else {
+            continue;
+        };
+        let statement = normalize_sql(description);
+        let ms = duration(row);
+        match groups.iter_mut().find(|g| g.statement == statement) {
+            Some(group) => {
+                group.count += 1;
+                group.total_ms += ms;
+                if ms > group.max_ms {
+                    group.max_ms = ms;
+                    group.slowest = row;
+                }
+            }
+            None => groups.push(StatementGroup {
+                statement,
+                count: 1,
+                total_ms: ms,
+                max_ms: ms,
+                slowest: row,
+            }),
+        }
+    }
+    groups.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+    groups
+}
+
+fn format_grouped(groups: &[StatementGroup], output: &mut String) {
+    output.push_str("| Statement | Count | Total | Max | Slowest Trace ID |\n");
+    output.push_str("|---|---|---|---|---|\n");
+    for group in groups {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            short_description(&group.statement),
+            group.count,
+            format_duration(group.total_ms),
+            format_duration(group.max_ms),
+            text(group.slowest, "trace")
+        ));
+    }
+}
+
+fn format_explain(groups: &mut [StatementGroup], output: &mut String) {
+    groups.sort_by(|a, b| b.max_ms.total_cmp(&a.max_ms));
+    for (i, group) in groups.iter().take(MAX_EXPLAIN_STATEMENTS).enumerate() {
+        let row = group.slowest;
+        output.push_str(&format!(
+            "## {}. {} ({}, {})\n\n",
+            i + 1,
+            format_duration(group.max_ms),
+            text(row, "project"),
+            text(row, "transaction")
+        ));
+        output.push_str(&format!(
+            "**Trace ID:** {} | **Seen:** {}x in results\n\n",
+            text(row, "trace"),
+            group.count
+        ));
+        output.push_str(&format!(
+            "```sql\n{}\n```\n\n",
+            text(row, "span.description")
+        ));
+    }
+    if groups.len() > MAX_EXPLAIN_STATEMENTS {
+        output.push_str(&format!(
+            "{} more distinct statements not shown.\n\n",
+            groups.len() - MAX_EXPLAIN_STATEMENTS
+        ));
+    }
+    output.push_str(
+        "Statements are shown as recorded by the SDK; fill in any remaining placeholders before running EXPLAIN.\n",
+    );
+}
+
+fn format_list(rows: &[Map<String, Value>], output: &mut String) {
+    output.push_str("| Duration | Op | Description | Transaction | Project | Trace ID |\n");
+    output.push_str("|---|---|---|---|---|---|\n");
+    for row in rows {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            format_duration(duration(row)),
+            text(row, "span.op"),
+            short_description(text(row, "span.description")),
+            escape_cell(text(row, "transaction")),
+            text(row, "project"),
+            text(row, "trace")
+        ));
+    }
+}
+
+pub fn format_spans_output(
+    query: &str,
+    period: &str,
+    mode: SpanSearchMode,
+    result: &DiscoverResult,
+) -> String {
+    let mut output = String::new();
+    output.push_str("# Span Search\n\n");
+    if !query.is_empty() {
+        output.push_str(&format!("**Query:** {}\n", query));
+    }
+    output.push_str(&format!("**Period:** {}\n", period));
+    output.push_str(&format!("**Found:** {} spans\n\n", result.data.len()));
+    if result.data.is_empty() {
+        output.push_str("No spans found.\n");
+        return output;
+    }
+    match mode {
+        SpanSearchMode::List => format_list(&result.data, &mut output),
+        SpanSearchMode::Grouped => format_grouped(&group_statements(&result.data), &mut output),
+        SpanSearchMode::Explain => {
+            format_explain(&mut group_statements(&result.data), &mut output);
+            return output;
+        }
+    }
+    output.push_str("\nUse get_trace_details with a trace ID to see the span in context.\n");
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    mut input: SearchSpansInput,
+) -> Result<CallToolResult, McpError> {
+    let sort = spans_sort(input.sort.as_deref())?;
+    let mode = parse_mode(input.mode.as_deref())?;
+    if mode != SpanSearchMode::List && input.op.is_none() {
+        input.op = Some("db*".to_string());
+    }
+    let org = &input.organization_slug;
+    let mut projects = Vec::new();
+    for slug in input.project_slugs.iter().flatten() {
+        let project = client
+            .get_project(org, slug)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        projects.push(project.id);
+    }
+    let query = build_spans_query(&input);
+    let period = input.stats_period.as_deref().unwrap_or("24h");
+    let discover = DiscoverQuery {
+        fields: FIELDS.iter().map(|f| f.to_string()).collect(),
+        query: Some(query.clone()).filter(|q| !q.is_empty()),
+        sort: Some(sort.to_string()),
+        stats_period: Some(period.to_string()),
+        projects,
+        dataset: Some("spans".to_string()),
+        per_page: Some(input.limit.unwrap_or(20).clamp(1, 100)),
+        ..Default::default()
+    };
+    let result = client
+        .query_events(org, &discover)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_spans_output(&query, period, mode, &result);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
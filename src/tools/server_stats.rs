@@ -0,0 +1,67 @@
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ServerStatsInput {}
+
+/// Facts about the running server, gathered by the tool handler.
+#[derive(Debug, Clone)]
+pub struct ServerStats<'a> {
+    pub version: &'a str,
+    pub user_agent: &'a str,
+    pub session_id: &'a str,
+    pub api_url: &'a str,
+    pub uptime: Duration,
+    pub writes_enabled: bool,
+    pub destructive_enabled: bool,
+    pub tool_count: usize,
+}
+
+pub fn format_uptime(uptime: Duration) -> String {
+    let secs = uptime.as_secs();
+    let (days, hours, minutes) = (secs / 86_400, secs / 3600 % 24, secs / 60 % 60);
+    match (days, hours) {
+        (0, 0) => format!("{}m {}s", minutes, secs % 60),
+        (0, _) => format!("{}h {}m", hours, minutes),
+        _ => format!("{}d {}h", days, hours),
+    }
+}
+
+pub fn format_server_stats(stats: &ServerStats) -> String {
+    let mut output = String::new();
+    output.push_str("# Server Stats\n\n");
+    output.push_str(&format!("**Version:** {}\n", stats.version));
+    output.push_str(&format!("**Session ID:** {}\n", stats.session_id));
+    output.push_str(&format!("**User Agent:** {}\n", stats.user_agent));
+    output.push_str(&format!("**Sentry API:** {}\n", stats.api_url));
+    output.push_str(&format!("**Uptime:** {}\n", format_uptime(stats.uptime)));
+    output.push_str(&format!(
+        "**Write Tools:** {}\n",
+        if stats.writes_enabled {
+            "enabled"
+        } else {
+            "disabled"
+        }
+    ));
+    output.push_str(&format!(
+        "**Destructive Tools:** {}\n",
+        if stats.destructive_enabled {
+            "enabled"
+        } else {
+            "disabled"
+        }
+    ));
+    output.push_str(&format!("**Registered Tools:** {}\n", stats.tool_count));
+    output.push_str(
+        "\nEvery Sentry API request carries the session ID in the `X-Sentry-MCP-Session` header.\n",
+    );
+    output
+}
+
+pub fn execute(stats: &ServerStats) -> Result<CallToolResult, McpError> {
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        format_server_stats(stats),
+    )]))
+}
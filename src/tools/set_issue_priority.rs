@@ -0,0 +1,58 @@
+use crate::api_client::{Issue, IssueUpdate, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+const PRIORITIES: [&str; 3] = ["high", "medium", "low"];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SetIssuePriorityInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+    #[schemars(description = "New priority: 'high', 'medium', or 'low'")]
+    pub priority: String,
+}
+
+pub fn format_priority_output(issue: &Issue) -> String {
+    let mut output = String::new();
+    output.push_str("# Issue Priority Updated\n\n");
+    output.push_str(&format!("**ID:** {}\n", issue.short_id));
+    output.push_str(&format!("**Title:** {}\n", issue.title));
+    output.push_str(&format!(
+        "**Priority:** {}\n",
+        issue.priority.as_deref().unwrap_or("unknown")
+    ));
+    output.push_str(&format!("**Status:** {}\n", issue.status));
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: SetIssuePriorityInput,
+) -> Result<CallToolResult, McpError> {
+    let priority = input.priority.trim().to_ascii_lowercase();
+    if !PRIORITIES.contains(&priority.as_str()) {
+        return Err(McpError::invalid_params(
+            format!(
+                "Invalid priority '{}': expected one of {}",
+                input.priority,
+                PRIORITIES.join(", ")
+            ),
+            None,
+        ));
+    }
+    let update = IssueUpdate {
+        priority: Some(priority),
+        ..Default::default()
+    };
+    let issue = client
+        .update_issue(&input.organization_slug, &input.issue_id, &update)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_priority_output(&issue);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
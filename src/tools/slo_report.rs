@@ -0,0 +1,247 @@
+use crate::api_client::{DiscoverQuery, DiscoverResult, SentryApi};
+use crate::tools::list_environments::resolve_environment;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SloReportInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(description = "Transaction name to scope the SLO to (omit for the whole project)")]
+    pub transaction: Option<String>,
+    #[schemars(description = "SLO target as a success percentage, e.g. 99.9")]
+    pub slo_target: f64,
+    #[schemars(description = "Window to evaluate, e.g. '24h', '7d', '30d' (default: 7d)")]
+    pub stats_period: Option<String>,
+    #[schemars(
+        description = "What counts as a failure: 'failed_transactions' (default) or 'errors' (error events per transaction)"
+    )]
+    pub metric: Option<String>,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SloMetric {
+    FailedTransactions,
+    Errors,
+}
+
+impl SloMetric {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "failed_transactions" | "failures" => Some(Self::FailedTransactions),
+            "errors" | "error_events" => Some(Self::Errors),
+            _ => None,
+        }
+    }
+    fn label(self) -> &'static str {
+        match self {
+            Self::FailedTransactions => "Failed transactions / total transactions",
+            Self::Errors => "Error events / total transactions",
+        }
+    }
+}
+
+/// Error budget arithmetic for `bad` failures out of `total` against a success target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SloBudget {
+    pub total: f64,
+    pub bad: f64,
+    pub error_rate: f64,
+    pub allowed: f64,
+    pub remaining: f64,
+    pub burn_rate: f64,
+}
+
+impl SloBudget {
+    pub fn compute(total: f64, bad: f64, target_pct: f64) -> Self {
+        let budget_rate = 1.0 - target_pct / 100.0;
+        let error_rate = if total > 0.0 { bad / total } else { 0.0 };
+        let allowed = total * budget_rate;
+        Self {
+            total,
+            bad,
+            error_rate,
+            allowed,
+            remaining: allowed - bad,
+            burn_rate: if budget_rate > 0.0 {
+                error_rate / budget_rate
+            } else {
+                0.0
+            },
+        }
+    }
+    /// Share of the budget left, as a fraction (negative once exhausted).
+    pub fn remaining_fraction(&self) -> Option<f64> {
+        (self.allowed > 0.0).then(|| self.remaining / self.allowed)
+    }
+}
+
+/// Quotes a search filter value, escaping embedded quotes.
+pub fn quote_filter(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\\\""))
+}
+
+/// Builds the search query for `event_type`, optionally scoped to one transaction.
+pub fn build_query(event_type: &str, transaction: Option<&str>) -> String {
+    let mut query = format!("event.type:{}", event_type);
+    if let Some(transaction) = transaction {
+        query.push_str(&format!(" transaction:{}", quote_filter(transaction)));
+    }
+    query
+}
+
+/// Reads a numeric aggregate from the first row of a Discover result.
+pub fn aggregate(result: &DiscoverResult, field: &str) -> f64 {
+    result
+        .data
+        .first()
+        .and_then(|row| row.get(field))
+        .and_then(|v| v.as_f64())
+        .unwrap_or(0.0)
+}
+
+pub fn format_slo_report(
+    scope: &str,
+    period: &str,
+    metric: SloMetric,
+    target_pct: f64,
+    budget: &SloBudget,
+) -> String {
+    let mut output = String::new();
+    output.push_str("# SLO Report\n\n");
+    output.push_str(&format!("**Scope:** {}\n", scope));
+    output.push_str(&format!("**Window:** {}\n", period));
+    output.push_str(&format!("**Metric:** {}\n", metric.label()));
+    output.push_str(&format!("**Target:** {}%\n\n", target_pct));
+    if budget.total <= 0.0 {
+        output.push_str("No transactions recorded in this window.\n");
+        return output;
+    }
+    output.push_str(&format!("**Total Transactions:** {:.0}\n", budget.total));
+    output.push_str(&format!("**Failures:** {:.0}\n", budget.bad));
+    output.push_str(&format!(
+        "**Error Rate:** {:.3}% (success {:.3}%)\n",
+        budget.error_rate * 100.0,
+        (1.0 - budget.error_rate) * 100.0
+    ));
+    output.push_str(&format!(
+        "**Error Budget:** {:.0} failures allowed\n",
+        budget.allowed
+    ));
+    match budget.remaining_fraction() {
+        Some(fraction) if fraction >= 0.0 => output.push_str(&format!(
+            "**Remaining Budget:** {:.0} failures ({:.1}%)\n",
+            budget.remaining,
+            fraction * 100.0
+        )),
+        Some(fraction) => output.push_str(&format!(
+            "**Remaining Budget:** exhausted — over by {:.0} failures ({:.1}%)\n",
+            -budget.remaining,
+            -fraction * 100.0
+        )),
+        None => output.push_str("**Remaining Budget:** none (100% target)\n"),
+    }
+    output.push_str(&format!("**Burn Rate:** {:.2}x\n", budget.burn_rate));
+    let status = if budget.remaining < 0.0 {
+        "❌ SLO violated"
+    } else if budget.burn_rate > 1.0 {
+        "⚠️ Burning budget faster than the target allows"
+    } else {
+        "✅ Within SLO"
+    };
+    output.push_str(&format!("\n**Status:** {}\n", status));
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: SloReportInput,
+) -> Result<CallToolResult, McpError> {
+    if !(input.slo_target > 0.0 && input.slo_target <= 100.0) {
+        return Err(McpError::invalid_params(
+            format!(
+                "Invalid slo_target {}: expected a percentage in (0, 100]",
+                input.slo_target
+            ),
+            None,
+        ));
+    }
+    let metric = match input.metric.as_deref() {
+        Some(value) => SloMetric::parse(value).ok_or_else(|| {
+            McpError::invalid_params(
+                format!(
+                    "Invalid metric '{}': expected failed_transactions or errors",
+                    value
+                ),
+                None,
+            )
+        })?,
+        None => SloMetric::FailedTransactions,
+    };
+    let org = &input.organization_slug;
+    let period = input.stats_period.as_deref().unwrap_or("7d");
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let project = client
+        .get_project(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let base = DiscoverQuery {
+        stats_period: Some(period.to_string()),
+        projects: vec![project.id.clone()],
+        environments: environment.into_iter().collect(),
+        per_page: Some(1),
+        ..Default::default()
+    };
+    let transaction = input.transaction.as_deref();
+    let mut fields = vec!["count()".to_string()];
+    if metric == SloMetric::FailedTransactions {
+        fields.push("failure_count()".to_string());
+    }
+    let transactions = client
+        .query_events(
+            org,
+            &DiscoverQuery {
+                fields,
+                query: Some(build_query("transaction", transaction)),
+                dataset: Some("transactions".to_string()),
+                ..base.clone()
+            },
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let total = aggregate(&transactions, "count()");
+    let bad = match metric {
+        SloMetric::FailedTransactions => aggregate(&transactions, "failure_count()"),
+        SloMetric::Errors => {
+            let errors = client
+                .query_events(
+                    org,
+                    &DiscoverQuery {
+                        fields: vec!["count()".to_string()],
+                        query: Some(build_query("error", transaction)),
+                        dataset: Some("errors".to_string()),
+                        ..base
+                    },
+                )
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            aggregate(&errors, "count()")
+        }
+    };
+    let scope = match transaction {
+        Some(t) => format!("{} / {}", input.project_slug, t),
+        None => input.project_slug.clone(),
+    };
+    let budget = SloBudget::compute(total, bad, input.slo_target);
+    let output = format_slo_report(&scope, period, metric, input.slo_target, &budget);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,181 @@
+use crate::api_client::{Event, SentryApi, StacktraceLinkQuery};
+use crate::json_ext::ValueExt;
+use crate::tools::get_issue_details::is_in_app;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use tracing::warn;
+
+/// Lines shown on each side of the frame's line.
+pub const CONTEXT_RADIUS: usize = 3;
+/// Frames per event that may trigger a source download.
+const MAX_SOURCE_FETCHES: usize = 5;
+
+/// Turns a code host "view file" URL into one that serves the raw file.
+///
+/// Supports GitHub, GitLab and Bitbucket URLs; returns `None` for anything else.
+pub fn raw_source_url(source_url: &str) -> Option<String> {
+    let url = source_url.split(['#', '?']).next()?;
+    if let Some(rest) = url.strip_prefix("https://github.com/") {
+        let (repo, path) = rest.split_once("/blob/")?;
+        return Some(format!(
+            "https://raw.githubusercontent.com/{}/{}",
+            repo, path
+        ));
+    }
+    if url.contains("/-/blob/") {
+        return Some(url.replacen("/-/blob/", "/-/raw/", 1));
+    }
+    if url.starts_with("https://bitbucket.org/") && url.contains("/src/") {
+        return Some(url.replacen("/src/", "/raw/", 1));
+    }
+    None
+}
+
+/// Returns `[line number, code]` pairs within `radius` lines of `line_no` (1-based),
+/// in the shape Sentry uses for a frame's `context`.
+pub fn source_context(text: &str, line_no: i64, radius: usize) -> Vec<Value> {
+    let Ok(line) = usize::try_from(line_no) else {
+        return vec![];
+    };
+    let lines: Vec<&str> = text.lines().collect();
+    if line == 0 || line > lines.len() {
+        return vec![];
+    }
+    let start = line.saturating_sub(radius).max(1);
+    let end = (line + radius).min(lines.len());
+    (start..=end).map(|n| json!([n, lines[n - 1]])).collect()
+}
+
+/// The commit the event's release was built from, if commits were associated.
+pub fn release_commit(event: &Event) -> Option<&str> {
+    event
+        .release
+        .get("lastCommit")
+        .and_then(|c| c.str_field("id"))
+        .filter(|id| !id.is_empty())
+}
+
+/// Stacktrace-link lookup for a frame, pinned to `commit_id` when known.
+pub fn frame_link_query(
+    frame: &Value,
+    platform: Option<&str>,
+    commit_id: Option<&str>,
+) -> Option<StacktraceLinkQuery> {
+    let file = frame
+        .str_field("filename")
+        .or_else(|| frame.str_field("absPath"))?;
+    Some(StacktraceLinkQuery {
+        file: file.to_string(),
+        platform: frame.str_field("platform").or(platform).map(str::to_string),
+        line_no: frame.i64_field("lineNo"),
+        abs_path: frame.str_field("absPath").map(str::to_string),
+        module: frame.str_field("module").map(str::to_string),
+        package: frame.str_field("package").map(str::to_string),
+        commit_id: commit_id.map(str::to_string),
+    })
+}
+
+fn needs_context(frame: &Value, in_app_include: &[String]) -> bool {
+    let has_context = frame
+        .array_field("context")
+        .is_some_and(|context| !context.is_empty());
+    !has_context && frame.i64_field("lineNo").unwrap_or(0) > 0 && is_in_app(frame, in_app_include)
+}
+
+/// Positions `(entry, exception, frame)` of in-app frames lacking context, innermost first.
+pub fn frames_missing_context(
+    event: &Event,
+    in_app_include: &[String],
+) -> Vec<(usize, usize, usize)> {
+    let mut found = Vec::new();
+    for (e, entry) in event.entries.iter().enumerate() {
+        if entry.entry_type != "exception" {
+            continue;
+        }
+        let Some(values) = entry.data.array_field("values") else {
+            continue;
+        };
+        for (x, exc) in values.iter().enumerate() {
+            let frames = exc
+                .get("stacktrace")
+                .and_then(|st| st.array_field("frames"))
+                .map(|f| f.as_slice())
+                .unwrap_or_default();
+            for (f, frame) in frames.iter().enumerate().rev() {
+                if needs_context(frame, in_app_include) {
+                    found.push((e, x, f));
+                }
+            }
+        }
+    }
+    found
+}
+
+/// The frame at `(entry, exception, frame)`, as returned by [`frames_missing_context`].
+pub fn frame_mut(event: &mut Event, (e, x, f): (usize, usize, usize)) -> Option<&mut Value> {
+    event
+        .entries
+        .get_mut(e)?
+        .data
+        .get_mut("values")?
+        .get_mut(x)?
+        .get_mut("stacktrace")?
+        .get_mut("frames")?
+        .get_mut(f)
+}
+
+/// Fills in `context` for in-app frames that have none, using the project's code
+/// mappings to locate the file in its repository. Best effort: failures are logged
+/// and the frame is left as it was.
+pub async fn fill_missing_context(
+    client: &impl SentryApi,
+    org_slug: &str,
+    project_slug: &str,
+    event: &mut Event,
+    in_app_include: &[String],
+) {
+    let mut files: HashMap<String, Option<String>> = HashMap::new();
+    let platform = event.platform.clone();
+    let commit = release_commit(event).map(str::to_string);
+    let positions = frames_missing_context(event, in_app_include);
+    for position in positions.into_iter().take(MAX_SOURCE_FETCHES) {
+        let Some(frame) = frame_mut(event, position) else {
+            continue;
+        };
+        let Some(query) = frame_link_query(frame, platform.as_deref(), commit.as_deref()) else {
+            continue;
+        };
+        let link = match client
+            .get_stacktrace_link(org_slug, project_slug, &query)
+            .await
+        {
+            Ok(link) => link,
+            Err(e) => {
+                warn!("stacktrace link for {} failed: {}", query.file, e);
+                continue;
+            }
+        };
+        let Some(raw_url) = link.source_url.as_deref().and_then(raw_source_url) else {
+            continue;
+        };
+        if !files.contains_key(&raw_url) {
+            let text = client
+                .fetch_source_file(&raw_url)
+                .await
+                .inspect_err(|e| warn!("fetching {} failed: {}", raw_url, e))
+                .ok();
+            files.insert(raw_url.clone(), text);
+        }
+        let Some(text) = files.get(&raw_url).and_then(|t| t.as_deref()) else {
+            continue;
+        };
+        let context = source_context(text, query.line_no.unwrap_or(0), CONTEXT_RADIUS);
+        if context.is_empty() {
+            continue;
+        }
+        if let Some(frame) = frame_mut(event, position) {
+            frame["context"] = Value::Array(context);
+            frame["sourceFetched"] = Value::Bool(true);
+        }
+    }
+}
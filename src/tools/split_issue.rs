@@ -0,0 +1,84 @@
+use crate::api_client::{IssueHash, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SplitIssueInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+    #[schemars(
+        description = "Grouping hashes to split out into one new issue, e.g. as recommended by suggest_issue_split"
+    )]
+    pub hashes: Vec<String>,
+}
+
+/// Checks that the requested hashes belong to the issue and leave at least one
+/// behind, returning them trimmed and de-duplicated.
+pub fn validate_split(requested: &[String], hashes: &[IssueHash]) -> Result<Vec<String>, McpError> {
+    let mut split: Vec<String> = Vec::new();
+    for hash in requested.iter().map(|h| h.trim()) {
+        if hash.is_empty() || split.iter().any(|seen| seen == hash) {
+            continue;
+        }
+        if !hashes.iter().any(|h| h.id == hash) {
+            return Err(McpError::invalid_params(
+                format!("{} is not a grouping hash of this issue", hash),
+                None,
+            ));
+        }
+        split.push(hash.to_string());
+    }
+    if split.is_empty() {
+        return Err(McpError::invalid_params(
+            "hashes must name at least one grouping hash to split out".to_string(),
+            None,
+        ));
+    }
+    if split.len() == hashes.len() {
+        return Err(McpError::invalid_params(
+            "At least one hash must stay in the issue; leave out the hashes to keep".to_string(),
+            None,
+        ));
+    }
+    Ok(split)
+}
+
+pub fn format_split_output(issue_id: &str, split: &[String]) -> String {
+    let mut output = String::new();
+    output.push_str("# Issue Split\n\n");
+    output.push_str(&format!(
+        "Split {} grouping hashes out of {} into a new issue:\n\n",
+        split.len(),
+        issue_id
+    ));
+    for hash in split {
+        output.push_str(&format!("- `{}`\n", hash));
+    }
+    output.push_str(
+        "\nSentry moves the matching events in the background; the new issue appears once the move completes.\n",
+    );
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: SplitIssueInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let hashes = client
+        .list_issue_hashes(org, &input.issue_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let split = validate_split(&input.hashes, &hashes)?;
+    client
+        .unmerge_issue_hashes(org, &input.issue_id, &split)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_split_output(&input.issue_id, &split);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,67 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// A parenthesized list of two or more placeholders, e.g. an `IN` list.
+static PLACEHOLDER_LIST_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\(\s*%s(?:\s*,\s*%s)+\s*\)").unwrap());
+
+/// Multi-row `VALUES (%s), (%s), ...` tails.
+static VALUES_ROWS_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\(%s\)(?:\s*,\s*\(%s\))+").unwrap());
+
+fn ends_with_word(out: &str) -> bool {
+    out.ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '"' || c == '`')
+}
+
+/// Skips a quoted literal whose opening quote was just read; doubled quotes are
+/// escapes.
+fn skip_quoted(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, quote: char) {
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            chars.next();
+        } else if c == quote {
+            if chars.peek() != Some(&quote) {
+                return;
+            }
+            chars.next();
+        }
+    }
+}
+
+/// Replaces string and number literals and bind parameters (`?`, `$1`) with `%s`,
+/// collapses placeholder lists to `(%s)` and squeezes whitespace, so statements
+/// that only differ in their values normalize to the same text.
+pub fn normalize_sql(sql: &str) -> String {
+    let mut out = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                skip_quoted(&mut chars, '\'');
+                out.push_str("%s");
+            }
+            '?' => out.push_str("%s"),
+            '$' if chars.peek().is_some_and(char::is_ascii_digit) => {
+                while chars.next_if(char::is_ascii_digit).is_some() {}
+                out.push_str("%s");
+            }
+            c if c.is_ascii_digit() && !ends_with_word(&out) => {
+                while chars
+                    .next_if(|d| d.is_ascii_alphanumeric() || *d == '.')
+                    .is_some()
+                {}
+                out.push_str("%s");
+            }
+            c if c.is_whitespace() => {
+                if !out.is_empty() && !out.ends_with(' ') {
+                    out.push(' ');
+                }
+            }
+            c => out.push(c),
+        }
+    }
+    let collapsed = PLACEHOLDER_LIST_RE.replace_all(out.trim_end(), "(%s)");
+    VALUES_ROWS_RE
+        .replace_all(&collapsed, "(%s), ...")
+        .into_owned()
+}
@@ -0,0 +1,278 @@
+use crate::api_client::{Event, Project, SentryApi};
+use crate::json_ext::ValueExt;
+use crate::tools::get_issue_details::is_in_app;
+use crate::tools::source_context::{frame_link_query, frame_mut, release_commit};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::warn;
+
+/// Frames resolved per call.
+const MAX_LINKED_FRAMES: usize = 5;
+/// Why Sentry could not link a frame, as reported in the `error` field.
+const LINK_ERRORS: [(&str, &str); 4] = [
+    (
+        "no_code_mappings_for_project",
+        "the project has no code mappings; add one in the project's source code settings",
+    ),
+    (
+        "stack_root_mismatch",
+        "no code mapping's stack root matches this path",
+    ),
+    (
+        "file_not_found",
+        "the file is not in the repository at this commit",
+    ),
+    (
+        "integration_link_forbidden",
+        "the source code integration denied access to the repository",
+    ),
+];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct StacktraceLinkInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or a numeric ID")]
+    pub issue_id: String,
+    #[schemars(description = "Event ID to take frames from (default: latest event)")]
+    pub event_id: Option<String>,
+    #[schemars(
+        description = "Only frames whose file name or path contains this (default: the most relevant in-app frame of each exception)"
+    )]
+    pub filename: Option<String>,
+    #[schemars(description = "Only frames at this line (optional, with filename)")]
+    pub line_no: Option<i64>,
+}
+
+/// A frame and where its source lives, or why that is unknown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameLink {
+    /// `app/views.py:42`.
+    pub location: String,
+    pub function: Option<String>,
+    pub url: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Appends the line anchor of the code host: `#L42`, or `#lines-42` on Bitbucket.
+pub fn line_anchor(url: &str, line: Option<i64>) -> String {
+    match line.filter(|l| *l > 0) {
+        Some(line) if url.contains("bitbucket.org/") => format!("{}#lines-{}", url, line),
+        Some(line) => format!("{}#L{}", url, line),
+        None => url.to_string(),
+    }
+}
+
+pub fn link_error_hint(error: &str) -> String {
+    LINK_ERRORS
+        .iter()
+        .find(|(code, _)| *code == error)
+        .map_or_else(|| error.to_string(), |(_, hint)| hint.to_string())
+}
+
+/// Every exception frame with its `(entry, exception, frame)` position,
+/// innermost first within each exception.
+fn exception_frames(event: &Event) -> Vec<((usize, usize, usize), &Value)> {
+    let mut found = Vec::new();
+    for (e, entry) in event.entries.iter().enumerate() {
+        if entry.entry_type != "exception" {
+            continue;
+        }
+        let Some(values) = entry.data.array_field("values") else {
+            continue;
+        };
+        for (x, exc) in values.iter().enumerate() {
+            let Some(frames) = exc
+                .get("stacktrace")
+                .and_then(|st| st.array_field("frames"))
+            else {
+                continue;
+            };
+            for (f, frame) in frames.iter().enumerate().rev() {
+                found.push(((e, x, f), frame));
+            }
+        }
+    }
+    found
+}
+
+/// The innermost in-app frame of each exception, as shown under "Most Relevant Frame".
+pub fn relevant_frames(event: &Event, in_app_include: &[String]) -> Vec<(usize, usize, usize)> {
+    let mut found: Vec<(usize, usize, usize)> = Vec::new();
+    for (position, frame) in exception_frames(event) {
+        let seen = found.iter().any(|p| (p.0, p.1) == (position.0, position.1));
+        if !seen && is_in_app(frame, in_app_include) {
+            found.push(position);
+        }
+    }
+    found
+}
+
+/// Frames whose file name or absolute path contains `filename`, optionally at `line_no`.
+pub fn matching_frames(
+    event: &Event,
+    filename: &str,
+    line_no: Option<i64>,
+) -> Vec<(usize, usize, usize)> {
+    exception_frames(event)
+        .into_iter()
+        .filter(|(_, frame)| {
+            ["filename", "absPath"]
+                .iter()
+                .any(|key| frame.str_field(key).is_some_and(|f| f.contains(filename)))
+                && line_no.is_none_or(|line| frame.i64_field("lineNo") == Some(line))
+        })
+        .map(|(position, _)| position)
+        .collect()
+}
+
+fn frame_at(event: &Event, (e, x, f): (usize, usize, usize)) -> Option<&Value> {
+    event
+        .entries
+        .get(e)?
+        .data
+        .array_field("values")?
+        .get(x)?
+        .get("stacktrace")?
+        .array_field("frames")?
+        .get(f)
+}
+
+async fn link_frame(
+    client: &impl SentryApi,
+    org_slug: &str,
+    project_slug: &str,
+    event: &Event,
+    frame: &Value,
+) -> Option<FrameLink> {
+    let query = frame_link_query(frame, event.platform.as_deref(), release_commit(event))?;
+    let location = match query.line_no {
+        Some(line) => format!("{}:{}", query.file, line),
+        None => query.file.clone(),
+    };
+    let (url, error) = match client
+        .get_stacktrace_link(org_slug, project_slug, &query)
+        .await
+    {
+        Ok(link) => (
+            link.source_url.map(|url| line_anchor(&url, query.line_no)),
+            link.error,
+        ),
+        Err(e) => (None, Some(e.to_string())),
+    };
+    Some(FrameLink {
+        location,
+        function: frame.str_field("function").map(str::to_string),
+        url,
+        error,
+    })
+}
+
+/// Stores a repository link as `sourceLink` on the primary (last) exception's most
+/// relevant frame. Skipped when the project has no code mappings; best effort.
+pub async fn link_relevant_frames(
+    client: &impl SentryApi,
+    org_slug: &str,
+    project: &Project,
+    event: &mut Event,
+    in_app_include: &[String],
+) {
+    match client.list_code_mappings(org_slug, &project.id).await {
+        Ok(mappings) if !mappings.is_empty() => {}
+        Ok(_) => return,
+        Err(e) => {
+            warn!("listing code mappings failed: {}", e);
+            return;
+        }
+    }
+    let Some(position) = relevant_frames(event, in_app_include).pop() else {
+        return;
+    };
+    let Some(frame) = frame_at(event, position) else {
+        return;
+    };
+    let Some(link) = link_frame(client, org_slug, &project.slug, event, frame).await else {
+        return;
+    };
+    if let (Some(url), Some(frame)) = (link.url, frame_mut(event, position)) {
+        frame["sourceLink"] = Value::String(url);
+    }
+}
+
+pub fn format_frame_links(
+    issue_id: &str,
+    event_id: &str,
+    commit: Option<&str>,
+    links: &[FrameLink],
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Stack Trace Links: {}\n\n", issue_id));
+    output.push_str(&format!("**Event:** {}\n", event_id));
+    match commit {
+        Some(commit) => output.push_str(&format!("**Commit:** {}\n\n", commit)),
+        None => output.push_str(
+            "**Commit:** unknown (the release has no commits), linking to the default branch\n\n",
+        ),
+    }
+    if links.is_empty() {
+        output.push_str("No matching frames in the event's stack traces.\n");
+        return output;
+    }
+    for link in links {
+        let frame = match &link.function {
+            Some(function) => format!("`{}` in `{}`", link.location, function),
+            None => format!("`{}`", link.location),
+        };
+        match (&link.url, &link.error) {
+            (Some(url), _) => output.push_str(&format!("- {}: {}\n", frame, url)),
+            (None, Some(error)) => output.push_str(&format!(
+                "- {}: not linked, {}\n",
+                frame,
+                link_error_hint(error)
+            )),
+            (None, None) => output.push_str(&format!("- {}: not linked\n", frame)),
+        }
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: StacktraceLinkInput,
+    in_app_include: &[String],
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let issue = client
+        .get_issue(org, &input.issue_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let event = match &input.event_id {
+        Some(event_id) => client.get_event(org, &input.issue_id, event_id).await,
+        None => client.get_latest_event(org, &input.issue_id).await,
+    }
+    .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let positions = match input.filename.as_deref().map(str::trim) {
+        Some(filename) if !filename.is_empty() => matching_frames(&event, filename, input.line_no),
+        _ => relevant_frames(&event, in_app_include),
+    };
+    let mut links = Vec::new();
+    for position in positions.into_iter().take(MAX_LINKED_FRAMES) {
+        let Some(frame) = frame_at(&event, position) else {
+            continue;
+        };
+        if let Some(link) = link_frame(client, org, &issue.project.slug, &event, frame).await {
+            links.push(link);
+        }
+    }
+    let output = format_frame_links(
+        &issue.short_id,
+        &event.event_id,
+        release_commit(&event),
+        &links,
+    );
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
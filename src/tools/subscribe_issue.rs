@@ -0,0 +1,61 @@
+use crate::api_client::{Issue, IssueUpdate, SentryApi};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SubscribeIssueInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+    #[schemars(
+        description = "true to subscribe to the issue, false to unsubscribe (default: true)"
+    )]
+    pub subscribed: Option<bool>,
+}
+
+pub fn format_subscription_output(issue: &Issue, subscribed: bool) -> String {
+    let mut output = String::new();
+    output.push_str(if subscribed {
+        "# Subscribed to Issue\n\n"
+    } else {
+        "# Unsubscribed from Issue\n\n"
+    });
+    output.push_str(&format!("**ID:** {}\n", issue.short_id));
+    output.push_str(&format!("**Title:** {}\n", issue.title));
+    output.push_str(&format!("**Status:** {}\n", issue.status));
+    if let Some(permalink) = &issue.permalink {
+        output.push_str(&format!("**Link:** {}\n", permalink));
+    }
+    if subscribed {
+        output.push_str(
+            "\nWorkflow notifications (new comments, status changes, regressions) for this issue go to the user that owns the auth token.\n",
+        );
+    }
+    if issue.is_subscribed.is_some_and(|s| s != subscribed) {
+        output.push_str(
+            "\n**Note:** Sentry did not apply the change; subscriptions belong to the user that owns the auth token.\n",
+        );
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: SubscribeIssueInput,
+) -> Result<CallToolResult, McpError> {
+    let subscribed = input.subscribed.unwrap_or(true);
+    let update = IssueUpdate {
+        is_subscribed: Some(subscribed),
+        ..Default::default()
+    };
+    let issue = client
+        .update_issue(&input.organization_slug, &input.issue_id, &update)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_subscription_output(&issue, subscribed);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
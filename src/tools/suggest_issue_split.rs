@@ -0,0 +1,128 @@
+use crate::api_client::{Event, IssueHash, SentryApi};
+use crate::tools::cluster_issue_events::{FailurePath, failure_path};
+use crate::tools::get_issue_details::FormatOptions;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SuggestIssueSplitInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+    #[schemars(
+        description = "In-app frames, innermost first, that must match for two hashes to share a failure path (default: 3)"
+    )]
+    pub depth: Option<usize>,
+}
+
+/// Grouping hashes whose latest events fail along the same path.
+#[derive(Debug)]
+pub struct HashGroup<'a> {
+    /// `None` for hashes without a stored event.
+    pub path: Option<FailurePath>,
+    pub hashes: Vec<&'a str>,
+}
+
+/// Groups hashes by the failure path of their latest event. The path with the most
+/// hashes comes first; hashes without an event come last.
+pub fn group_hashes<'a>(
+    hashes: &'a [IssueHash],
+    depth: usize,
+    in_app_include: &[String],
+) -> Vec<HashGroup<'a>> {
+    let mut groups: Vec<HashGroup> = Vec::new();
+    for hash in hashes {
+        let path = hash
+            .latest_event
+            .clone()
+            .and_then(|event| serde_json::from_value::<Event>(event).ok())
+            .map(|event| failure_path(&event, depth, in_app_include));
+        match groups.iter_mut().find(|g| g.path == path) {
+            Some(group) => group.hashes.push(&hash.id),
+            None => groups.push(HashGroup {
+                path,
+                hashes: vec![&hash.id],
+            }),
+        }
+    }
+    groups.sort_by_key(|g| (g.path.is_none(), std::cmp::Reverse(g.hashes.len())));
+    groups
+}
+
+fn format_group(output: &mut String, index: usize, group: &HashGroup) {
+    let label = match &group.path {
+        Some(path) => path.exception_type.as_str(),
+        None => "(no stored event)",
+    };
+    let verdict = match (index, &group.path) {
+        (_, None) => "unclassified",
+        (0, _) => "keep",
+        _ => "split out",
+    };
+    output.push_str(&format!(
+        "## Path {}: {} ({})\n\n",
+        index + 1,
+        label,
+        verdict
+    ));
+    let ids: Vec<String> = group.hashes.iter().map(|h| format!("`{}`", h)).collect();
+    output.push_str(&format!("**Hashes:** {}\n", ids.join(", ")));
+    if let Some(path) = &group.path {
+        if path.frames.is_empty() {
+            output.push_str("No in-app frames.\n");
+        }
+        for frame in &path.frames {
+            output.push_str(&format!("- `{}`\n", frame));
+        }
+    }
+    output.push('\n');
+}
+
+pub fn format_split_suggestion(
+    issue_id: &str,
+    hashes: &[IssueHash],
+    groups: &[HashGroup],
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Split Suggestion for {}\n\n", issue_id));
+    let classified = groups.iter().filter(|g| g.path.is_some()).count();
+    output.push_str(&format!("**Hashes:** {}\n", hashes.len()));
+    output.push_str(&format!("**Failure paths:** {}\n\n", classified));
+    for (i, group) in groups.iter().enumerate() {
+        format_group(&mut output, i, group);
+    }
+    let splits: Vec<&HashGroup> = groups.iter().skip(1).filter(|g| g.path.is_some()).collect();
+    if splits.is_empty() {
+        output.push_str("All hashes share one failure path; no split recommended.\n");
+        return output;
+    }
+    output.push_str("## Recommendation\n\n");
+    output.push_str(
+        "Keep path 1 in this issue and split each other path out on its own with \
+        `split_issue`; one split moves the given hashes into one new issue.\n\n",
+    );
+    for group in splits {
+        let ids: Vec<String> = group.hashes.iter().map(|h| format!("\"{}\"", h)).collect();
+        output.push_str(&format!("- `hashes: [{}]`\n", ids.join(", ")));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: SuggestIssueSplitInput,
+    opts: &FormatOptions,
+) -> Result<CallToolResult, McpError> {
+    let hashes = client
+        .list_issue_hashes(&input.organization_slug, &input.issue_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let depth = input.depth.unwrap_or(3).max(1);
+    let groups = group_hashes(&hashes, depth, &opts.in_app_include);
+    let output = format_split_suggestion(&input.issue_id, &hashes, &groups);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,173 @@
+use chrono::{DateTime, FixedOffset, NaiveTime, Offset, Timelike, Utc};
+
+/// Abbreviations accepted as timezones, with their UTC offset in minutes.
+const ABBREVIATIONS: &[(&str, i32)] = &[
+    ("UTC", 0),
+    ("GMT", 0),
+    ("WET", 0),
+    ("WEST", 60),
+    ("BST", 60),
+    ("CET", 60),
+    ("CEST", 120),
+    ("EET", 120),
+    ("EEST", 180),
+    ("MSK", 180),
+    ("IST", 330),
+    ("SGT", 480),
+    ("JST", 540),
+    ("KST", 540),
+    ("AEST", 600),
+    ("AEDT", 660),
+    ("NZST", 720),
+    ("NZDT", 780),
+    ("HST", -600),
+    ("AKST", -540),
+    ("PST", -480),
+    ("PDT", -420),
+    ("MST", -420),
+    ("MDT", -360),
+    ("CST", -360),
+    ("CDT", -300),
+    ("EST", -300),
+    ("EDT", -240),
+];
+
+/// A fixed UTC offset used to show bucket times and draw day boundaries.
+/// Daylight saving time is not applied; pick the offset in effect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalZone {
+    pub offset: FixedOffset,
+    /// Shown after times, e.g. `UTC`, `CET` or `UTC+05:30`.
+    pub label: String,
+}
+
+impl Default for LocalZone {
+    fn default() -> Self {
+        Self::utc()
+    }
+}
+
+fn parse_offset(value: &str) -> Option<i32> {
+    let (sign, digits) = match value.as_bytes().first()? {
+        b'+' => (1, &value[1..]),
+        b'-' => (-1, &value[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = match digits.split_once(':') {
+        Some((h, m)) => (h, m),
+        None if digits.len() > 2 => digits.split_at(digits.len() - 2),
+        None => (digits, "0"),
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 14 || minutes >= 60 {
+        return None;
+    }
+    Some(sign * (hours * 60 + minutes))
+}
+
+fn offset_label(minutes: i32) -> String {
+    if minutes == 0 {
+        return "UTC".to_string();
+    }
+    let sign = if minutes < 0 { '-' } else { '+' };
+    let minutes = minutes.abs();
+    format!("UTC{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+}
+
+impl LocalZone {
+    pub fn utc() -> Self {
+        Self {
+            offset: Utc.fix(),
+            label: "UTC".to_string(),
+        }
+    }
+
+    /// Parses `UTC`, an abbreviation like `CET`, or an offset like `+01:00`,
+    /// `-0530`, `+2` or `UTC+2`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let upper = value.to_ascii_uppercase();
+        let (minutes, label) = match ABBREVIATIONS.iter().find(|(name, _)| *name == upper) {
+            Some((name, minutes)) => (*minutes, name.to_string()),
+            None => {
+                let offset = ["UTC", "GMT"]
+                    .iter()
+                    .find_map(|prefix| upper.strip_prefix(prefix))
+                    .unwrap_or(&upper);
+                let minutes = parse_offset(offset)?;
+                (minutes, offset_label(minutes))
+            }
+        };
+        Some(Self {
+            offset: FixedOffset::east_opt(minutes * 60)?,
+            label,
+        })
+    }
+
+    pub fn is_utc(&self) -> bool {
+        self.offset.local_minus_utc() == 0
+    }
+
+    fn local(&self, ts: i64) -> Option<DateTime<FixedOffset>> {
+        Some(DateTime::from_timestamp(ts, 0)?.with_timezone(&self.offset))
+    }
+
+    /// `2024-05-01 02:00 CET`.
+    pub fn format_time(&self, ts: i64) -> String {
+        self.local(ts)
+            .map(|d| format!("{} {}", d.format("%Y-%m-%d %H:%M"), self.label))
+            .unwrap_or_else(|| ts.to_string())
+    }
+
+    /// `2024-05-01`, the local calendar day.
+    pub fn format_date(&self, ts: i64) -> String {
+        self.local(ts)
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| ts.to_string())
+    }
+
+    /// Local hour of day, 0 to 23.
+    pub fn hour(&self, ts: i64) -> Option<usize> {
+        self.local(ts).map(|d| d.hour() as usize)
+    }
+
+    /// Timestamp of the local midnight starting the day `ts` falls in.
+    pub fn day_start(&self, ts: i64) -> Option<i64> {
+        let local = self.local(ts)?;
+        let midnight = local.date_naive().and_time(NaiveTime::MIN);
+        Some(
+            midnight
+                .and_local_timezone(self.offset)
+                .single()?
+                .timestamp(),
+        )
+    }
+
+    /// Sums sub-daily buckets into local calendar days, keyed by the local
+    /// midnight's timestamp.
+    pub fn daily_buckets(&self, counts: &[(i64, f64)]) -> Vec<(i64, f64)> {
+        let mut days: Vec<(i64, f64)> = Vec::new();
+        for &(ts, count) in counts {
+            let Some(day) = self.day_start(ts) else {
+                continue;
+            };
+            match days.last_mut() {
+                Some((last, total)) if *last == day => *total += count,
+                _ => days.push((day, count)),
+            }
+        }
+        days
+    }
+
+    /// Sums buckets by local hour of day.
+    pub fn hour_of_day(&self, counts: &[(i64, f64)]) -> [f64; 24] {
+        let mut hours = [0.0; 24];
+        for &(ts, count) in counts {
+            if let Some(hour) = self.hour(ts) {
+                hours[hour] += count;
+            }
+        }
+        hours
+    }
+}
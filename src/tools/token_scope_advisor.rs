@@ -0,0 +1,276 @@
+use crate::api_client::SentryApi;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::collections::BTreeSet;
+
+const METHODS: [&str; 5] = ["GET", "POST", "PUT", "PATCH", "DELETE"];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TokenScopeAdvisorInput {
+    #[schemars(
+        description = "Endpoint of the failed call, e.g. 'PUT /api/0/organizations/acme/issues/123/' or a full URL; a leading HTTP method is optional"
+    )]
+    pub endpoint: String,
+    #[schemars(
+        description = "HTTP method when the endpoint does not start with one (default: GET)"
+    )]
+    pub method: Option<String>,
+}
+
+/// Scopes accepted for one family of endpoints, by kind of access. Any one scope
+/// of the list is enough; the first is the least privileged and is recommended.
+struct ScopeRule {
+    resource: &'static str,
+    /// First path segment the rule is limited to, e.g. `projects`.
+    root: Option<&'static str>,
+    segments: &'static [&'static str],
+    read: &'static [&'static str],
+    write: &'static [&'static str],
+    delete: &'static [&'static str],
+}
+
+/// Specific resources first; the `projects` and `organizations` catch-alls last.
+const RULES: &[ScopeRule] = &[
+    ScopeRule {
+        resource: "organization members",
+        root: None,
+        segments: &["members", "invite-requests"],
+        read: &["member:read", "member:write", "member:admin"],
+        write: &["member:invite", "member:write", "member:admin"],
+        delete: &["member:admin"],
+    },
+    ScopeRule {
+        resource: "teams",
+        root: None,
+        segments: &["teams"],
+        read: &["team:read", "team:write", "team:admin"],
+        write: &["team:write", "team:admin"],
+        delete: &["team:admin"],
+    },
+    ScopeRule {
+        resource: "alert rules",
+        root: None,
+        segments: &["alert-rules", "rules"],
+        read: &["alerts:read", "alerts:write"],
+        write: &["alerts:write"],
+        delete: &["alerts:write"],
+    },
+    ScopeRule {
+        resource: "releases",
+        root: None,
+        segments: &["releases"],
+        read: &["project:read", "project:releases"],
+        write: &["project:releases", "project:write", "project:admin"],
+        delete: &["project:releases", "project:admin"],
+    },
+    ScopeRule {
+        resource: "issues and events",
+        root: None,
+        segments: &["issues", "groups", "replays", "eventids", "shared"],
+        read: &["event:read", "event:write", "event:admin"],
+        write: &["event:write", "event:admin"],
+        delete: &["event:admin"],
+    },
+    ScopeRule {
+        resource: "project events",
+        root: Some("projects"),
+        segments: &["events"],
+        read: &["event:read", "event:write", "event:admin"],
+        write: &["event:write", "event:admin"],
+        delete: &["event:admin"],
+    },
+    ScopeRule {
+        resource: "projects",
+        root: Some("projects"),
+        segments: &[],
+        read: &["project:read", "project:write", "project:admin"],
+        write: &["project:write", "project:admin"],
+        delete: &["project:admin"],
+    },
+    ScopeRule {
+        resource: "organizations",
+        root: Some("organizations"),
+        segments: &[],
+        read: &["org:read", "org:write", "org:admin"],
+        write: &["org:write", "org:admin"],
+        delete: &["org:admin"],
+    },
+];
+
+/// The scopes one endpoint call needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequiredScopes {
+    pub method: String,
+    pub path: String,
+    pub resource: &'static str,
+    /// Any one of these suffices; the first is recommended.
+    pub any_of: &'static [&'static str],
+}
+
+/// Splits `[METHOD] path-or-url` into the method and the API path segments,
+/// dropping the host, the `/api/0` prefix and the query string.
+pub fn parse_endpoint(raw: &str, method: Option<&str>) -> Result<(String, Vec<String>), McpError> {
+    let raw = raw.trim();
+    let (leading, rest) = match raw.split_once(char::is_whitespace) {
+        Some((first, rest)) if METHODS.contains(&first.to_ascii_uppercase().as_str()) => {
+            (Some(first), rest.trim())
+        }
+        _ => (None, raw),
+    };
+    let method = leading
+        .or(method)
+        .unwrap_or("GET")
+        .trim()
+        .to_ascii_uppercase();
+    if !METHODS.contains(&method.as_str()) {
+        return Err(McpError::invalid_params(
+            format!(
+                "Invalid method '{}': expected one of {}",
+                method,
+                METHODS.join(", ")
+            ),
+            None,
+        ));
+    }
+    let path = match rest.split_once("://") {
+        Some((_, after_scheme)) => after_scheme.find('/').map_or("", |i| &after_scheme[i..]),
+        None => rest,
+    };
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let mut segments: Vec<String> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect();
+    if segments.first().is_some_and(|s| s == "api") {
+        segments.drain(..segments.len().min(2));
+    }
+    Ok((method, segments))
+}
+
+/// Looks up the scopes Sentry checks for an endpoint.
+pub fn required_scopes(method: &str, segments: &[String]) -> Option<RequiredScopes> {
+    let root = segments.first()?.as_str();
+    let rule = RULES.iter().find(|rule| {
+        rule.root.is_none_or(|r| r == root)
+            && (rule.segments.is_empty()
+                || segments.iter().any(|s| rule.segments.contains(&s.as_str())))
+    })?;
+    let any_of = match method {
+        "GET" => rule.read,
+        "DELETE" => rule.delete,
+        _ => rule.write,
+    };
+    Some(RequiredScopes {
+        method: method.to_string(),
+        path: format!("/{}/", segments.join("/")),
+        resource: rule.resource,
+        any_of,
+    })
+}
+
+/// A granted scope and the ones it implies: admin includes write and read, write
+/// includes read, and member write access includes inviting.
+pub fn implied_scopes(scope: &str) -> Vec<String> {
+    let mut scopes = vec![scope.to_string()];
+    let Some((resource, level)) = scope.split_once(':') else {
+        return scopes;
+    };
+    let lower: &[&str] = match level {
+        "admin" => &["write", "read"],
+        "write" => &["read"],
+        "invite" => &["read"],
+        _ => &[],
+    };
+    scopes.extend(lower.iter().map(|l| format!("{}:{}", resource, l)));
+    if resource == "member" && matches!(level, "admin" | "write") {
+        scopes.push("member:invite".to_string());
+    }
+    scopes
+}
+
+/// The required scope the token already has, if any.
+pub fn satisfying_scope<'a>(required: &RequiredScopes, granted: &'a [String]) -> Option<&'a str> {
+    granted
+        .iter()
+        .find(|g| {
+            implied_scopes(g)
+                .iter()
+                .any(|s| required.any_of.contains(&s.as_str()))
+        })
+        .map(String::as_str)
+}
+
+fn code_list(scopes: &[&str]) -> String {
+    scopes
+        .iter()
+        .map(|s| format!("`{}`", s))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn format_advice_output(required: &RequiredScopes, granted: &[String]) -> String {
+    let mut output = String::new();
+    output.push_str("# Token Scope Advice\n\n");
+    output.push_str(&format!(
+        "**Endpoint:** {} {}\n",
+        required.method, required.path
+    ));
+    output.push_str(&format!("**Resource:** {}\n", required.resource));
+    output.push_str(&format!(
+        "**Requires one of:** {}\n",
+        code_list(required.any_of)
+    ));
+    let granted_refs: Vec<&str> = granted.iter().map(String::as_str).collect();
+    if granted.is_empty() {
+        output.push_str("**Token scopes:** none\n\n");
+    } else {
+        output.push_str(&format!(
+            "**Token scopes:** {}\n\n",
+            code_list(&granted_refs)
+        ));
+    }
+    if let Some(scope) = satisfying_scope(required, granted) {
+        output.push_str(&format!(
+            "The token's `{}` scope already covers this endpoint, so a 403 is not a scope problem. \
+            Check that the token's user can access the project (team membership or open membership) \
+            and that their organization role allows the action.\n",
+            scope
+        ));
+        return output;
+    }
+    let recommended = required.any_of[0];
+    output.push_str(&format!("**Missing:** `{}`\n\n", recommended));
+    let mut request: BTreeSet<&str> = granted_refs.into_iter().collect();
+    request.insert(recommended);
+    let request: Vec<&str> = request.into_iter().collect();
+    output
+        .push_str("Create a token with these scopes (the current ones plus the missing one):\n\n");
+    output.push_str(&format!("```\n{}\n```\n", request.join(" ")));
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: TokenScopeAdvisorInput,
+) -> Result<CallToolResult, McpError> {
+    let (method, segments) = parse_endpoint(&input.endpoint, input.method.as_deref())?;
+    let required = required_scopes(&method, &segments).ok_or_else(|| {
+        McpError::invalid_params(
+            format!(
+                "Cannot tell which scopes '{}' needs; pass an API path such as /api/0/organizations/<org>/...",
+                input.endpoint.trim()
+            ),
+            None,
+        )
+    })?;
+    let granted = client
+        .get_token_scopes()
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_advice_output(&required, &granted);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,166 @@
+use crate::api_client::{DiscoverQuery, DiscoverResult, SentryApi};
+use crate::format::format_duration;
+use crate::tools::list_environments::resolve_environment;
+use crate::tools::slo_report::{aggregate, build_query};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Duration percentiles shown, as `(label, field)`.
+const PERCENTILES: [(&str, &str); 5] = [
+    ("p50", "p50(transaction.duration)"),
+    ("p75", "p75(transaction.duration)"),
+    ("p95", "p95(transaction.duration)"),
+    ("p99", "p99(transaction.duration)"),
+    ("max", "max(transaction.duration)"),
+];
+const APDEX: &str = "apdex()";
+const FAILURE_RATE: &str = "failure_rate()";
+const COUNT: &str = "count()";
+const TPM: &str = "tpm()";
+/// Slowest events listed.
+const SLOWEST_EVENTS: i32 = 5;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TransactionSummaryInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(description = "Transaction name, e.g. 'POST /api/checkout'")]
+    pub transaction: String,
+    #[schemars(description = "Time window, e.g. '1h', '24h', '7d' (default: 24h)")]
+    pub stats_period: Option<String>,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+}
+
+fn aggregate_fields() -> Vec<String> {
+    PERCENTILES
+        .iter()
+        .map(|(_, field)| *field)
+        .chain([APDEX, FAILURE_RATE, COUNT, TPM])
+        .map(str::to_string)
+        .collect()
+}
+
+fn text<'a>(row: &'a serde_json::Map<String, Value>, field: &str) -> &'a str {
+    row.get(field).and_then(Value::as_str).unwrap_or("-")
+}
+
+pub fn format_transaction_summary(
+    transaction: &str,
+    period: &str,
+    aggregates: &DiscoverResult,
+    slowest: &DiscoverResult,
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Transaction Summary: {}\n\n", transaction));
+    output.push_str(&format!("**Period:** {}\n", period));
+    let count = aggregate(aggregates, COUNT);
+    output.push_str(&format!("**Events:** {:.0}\n", count));
+    if count <= 0.0 {
+        output.push_str("\nNo events for this transaction in the period.\n");
+        return output;
+    }
+    output.push_str(&format!("**TPM:** {:.2}\n", aggregate(aggregates, TPM)));
+    output.push_str(&format!("**Apdex:** {:.3}\n", aggregate(aggregates, APDEX)));
+    output.push_str(&format!(
+        "**Failure Rate:** {:.1}%\n",
+        aggregate(aggregates, FAILURE_RATE) * 100.0
+    ));
+    output.push_str("\n## Duration\n\n");
+    output.push_str(&format!(
+        "| {} |\n",
+        PERCENTILES.map(|(label, _)| label).join(" | ")
+    ));
+    output.push_str(&format!("|{}\n", "---|".repeat(PERCENTILES.len())));
+    let cells: Vec<String> = PERCENTILES
+        .iter()
+        .map(|(_, field)| format_duration(aggregate(aggregates, field)))
+        .collect();
+    output.push_str(&format!("| {} |\n", cells.join(" | ")));
+    output.push_str("\n## Slowest Events\n\n");
+    if slowest.data.is_empty() {
+        output.push_str("No events found.\n");
+        return output;
+    }
+    output.push_str("| Event ID | Trace ID | Duration | Timestamp |\n");
+    output.push_str("|---|---|---|---|\n");
+    for row in &slowest.data {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            text(row, "id"),
+            text(row, "trace"),
+            format_duration(
+                row.get("transaction.duration")
+                    .and_then(Value::as_f64)
+                    .unwrap_or(0.0)
+            ),
+            text(row, "timestamp")
+        ));
+    }
+    output.push_str("\nUse get_trace_details with a trace ID to see where the time went.\n");
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: TransactionSummaryInput,
+) -> Result<CallToolResult, McpError> {
+    let transaction = input.transaction.trim();
+    if transaction.is_empty() {
+        return Err(McpError::invalid_params(
+            "transaction must not be empty".to_string(),
+            None,
+        ));
+    }
+    let org = &input.organization_slug;
+    let period = input.stats_period.as_deref().unwrap_or("24h");
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let project = client
+        .get_project(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let base = DiscoverQuery {
+        query: Some(build_query("transaction", Some(transaction))),
+        stats_period: Some(period.to_string()),
+        projects: vec![project.id],
+        environments: environment.into_iter().collect(),
+        dataset: Some("transactions".to_string()),
+        ..Default::default()
+    };
+    let aggregates = client
+        .query_events(
+            org,
+            &DiscoverQuery {
+                fields: aggregate_fields(),
+                per_page: Some(1),
+                ..base.clone()
+            },
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let slowest = client
+        .query_events(
+            org,
+            &DiscoverQuery {
+                fields: ["id", "trace", "transaction.duration", "timestamp"]
+                    .iter()
+                    .map(|f| f.to_string())
+                    .collect(),
+                sort: Some("-transaction.duration".to_string()),
+                per_page: Some(SLOWEST_EVENTS),
+                ..base
+            },
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_transaction_summary(transaction, period, &aggregates, &slowest);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
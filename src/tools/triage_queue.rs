@@ -0,0 +1,119 @@
+use crate::api_client::{Issue, IssuesQuery, SentryApi};
+use crate::format::escape_cell;
+use crate::tools::list_environments::resolve_environment;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Issue search cannot OR different keys, so each way an issue qualifies is
+/// searched on its own and the results merged.
+const QUEUE_QUERIES: [&str; 2] = [
+    "is:unresolved is:unassigned issue.priority:high",
+    "is:unresolved is:unassigned level:[error,fatal]",
+];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TriageQueueInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+    #[schemars(description = "Time window, e.g. '24h', '14d' (default: 14d)")]
+    pub stats_period: Option<String>,
+    #[schemars(description = "Maximum number of issues to return (default: 25, max: 100)")]
+    pub limit: Option<i32>,
+}
+
+fn event_count(issue: &Issue) -> i64 {
+    issue.count.parse().unwrap_or(0)
+}
+
+/// Merges the per-query results, dropping duplicates, most affected users first
+/// (events break ties).
+pub fn merge_queue(results: Vec<Vec<Issue>>, limit: usize) -> Vec<Issue> {
+    let mut queue: Vec<Issue> = Vec::new();
+    for issue in results.into_iter().flatten() {
+        if !queue.iter().any(|q| q.id == issue.id) {
+            queue.push(issue);
+        }
+    }
+    queue.sort_by(|a, b| {
+        b.user_count
+            .cmp(&a.user_count)
+            .then_with(|| event_count(b).cmp(&event_count(a)))
+    });
+    queue.truncate(limit);
+    queue
+}
+
+pub fn format_triage_queue(project: &str, period: &str, issues: &[Issue]) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Triage Queue: {}\n\n", project));
+    output.push_str(&format!("**Period:** {}\n", period));
+    output.push_str(&format!("**Found:** {} issues\n\n", issues.len()));
+    if issues.is_empty() {
+        output.push_str(
+            "No unresolved, unassigned issues of high priority or error level. The queue is clear.\n",
+        );
+        return output;
+    }
+    output.push_str("| Issue | Title | Priority | Level | Users | Events | Last Seen |\n");
+    output.push_str("|---|---|---|---|---|---|---|\n");
+    for issue in issues {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} |\n",
+            issue.short_id,
+            escape_cell(&issue.title),
+            issue.priority.as_deref().unwrap_or("-"),
+            issue.level.as_deref().unwrap_or("-"),
+            issue.user_count,
+            issue.count,
+            issue.last_seen.as_deref().unwrap_or("-")
+        ));
+    }
+    output.push_str(
+        "\nAssign an issue with update_issue (`assigned_to`) to take it off the queue.\n",
+    );
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: TriageQueueInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let limit = input.limit.unwrap_or(25).clamp(1, 100);
+    let period = input.stats_period.as_deref().unwrap_or("14d");
+    let project = client
+        .get_project(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let mut results = Vec::new();
+    for query in QUEUE_QUERIES {
+        let query = IssuesQuery {
+            query: Some(query.to_string()),
+            project: Some(project.id.clone()),
+            environment: environment.clone(),
+            stats_period: Some(period.to_string()),
+            sort: Some("user".to_string()),
+            limit: Some(limit),
+            ..Default::default()
+        };
+        results.push(
+            client
+                .list_issues(org, &query)
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        );
+    }
+    let queue = merge_queue(results, limit as usize);
+    let output = format_triage_queue(&input.project_slug, period, &queue);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
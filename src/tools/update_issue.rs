@@ -0,0 +1,212 @@
+use crate::api_client::{CommitRef, Issue, IssueUpdate, SentryApi, StatusDetails};
+use crate::tools::get_issue_details::{format_assignee, format_ignore, format_resolution};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+const STATUSES: [&str; 3] = ["resolved", "unresolved", "ignored"];
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateIssueInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Issue ID like 'PROJECT-123' or numeric ID")]
+    pub issue_id: String,
+    #[schemars(
+        description = "New status: 'resolved', 'ignored' (archived), or 'unresolved' (reopen). Optional when only assigning."
+    )]
+    pub status: Option<String>,
+    #[schemars(
+        description = "Resolve in this release version ('latest' for the most recent release)"
+    )]
+    pub in_release: Option<String>,
+    #[schemars(description = "Resolve in the next release that gets created")]
+    pub in_next_release: Option<bool>,
+    #[schemars(description = "Resolve in this commit SHA (requires repository)")]
+    pub in_commit: Option<String>,
+    #[schemars(description = "Repository name as configured in Sentry, e.g. 'acme/backend'")]
+    pub repository: Option<String>,
+    #[schemars(description = "Ignore for this many minutes")]
+    pub ignore_duration: Option<i64>,
+    #[schemars(description = "Ignore until this many more events occur")]
+    pub ignore_count: Option<i64>,
+    #[schemars(description = "Window in minutes for ignore_count")]
+    pub ignore_window: Option<i64>,
+    #[schemars(description = "Ignore until this many more users are affected")]
+    pub ignore_user_count: Option<i64>,
+    #[schemars(description = "Window in minutes for ignore_user_count")]
+    pub ignore_user_window: Option<i64>,
+    #[schemars(description = "Ignore until Sentry detects the issue escalating")]
+    pub ignore_until_escalating: Option<bool>,
+    #[schemars(
+        description = "Assignee: 'user:<id>', 'team:<id>', a username or email. Empty string unassigns."
+    )]
+    pub assigned_to: Option<String>,
+}
+
+fn invalid(message: &str) -> McpError {
+    McpError::invalid_params(message.to_string(), None)
+}
+
+fn positive(value: Option<i64>, name: &str) -> Result<Option<i64>, McpError> {
+    match value {
+        Some(v) if v <= 0 => Err(McpError::invalid_params(
+            format!("{} must be positive", name),
+            None,
+        )),
+        _ => Ok(value),
+    }
+}
+
+fn resolve_details(
+    input: &UpdateIssueInput,
+    status: &str,
+) -> Result<Option<StatusDetails>, McpError> {
+    let in_next_release = input.in_next_release.filter(|v| *v);
+    let targets = [
+        input.in_release.is_some(),
+        in_next_release.is_some(),
+        input.in_commit.is_some(),
+    ];
+    let target_count = targets.iter().filter(|t| **t).count();
+    if target_count > 1 {
+        return Err(invalid(
+            "Specify at most one of in_release, in_next_release, in_commit",
+        ));
+    }
+    if target_count == 1 && status != "resolved" {
+        return Err(invalid(
+            "in_release, in_next_release and in_commit require status 'resolved'",
+        ));
+    }
+    let in_commit = match (&input.in_commit, &input.repository) {
+        (Some(commit), Some(repository)) => Some(CommitRef {
+            commit: commit.clone(),
+            repository: repository.clone(),
+        }),
+        (Some(_), None) => return Err(invalid("in_commit requires repository")),
+        (None, _) => None,
+    };
+    Ok((target_count == 1).then(|| StatusDetails {
+        in_release: input.in_release.clone(),
+        in_next_release,
+        in_commit,
+        ..Default::default()
+    }))
+}
+
+/// Returns the ignore `statusDetails` and substatus for an `ignored` update.
+fn ignore_details(
+    input: &UpdateIssueInput,
+    status: &str,
+) -> Result<(Option<StatusDetails>, Option<String>), McpError> {
+    let details = StatusDetails {
+        ignore_duration: positive(input.ignore_duration, "ignore_duration")?,
+        ignore_count: positive(input.ignore_count, "ignore_count")?,
+        ignore_window: positive(input.ignore_window, "ignore_window")?,
+        ignore_user_count: positive(input.ignore_user_count, "ignore_user_count")?,
+        ignore_user_window: positive(input.ignore_user_window, "ignore_user_window")?,
+        ..Default::default()
+    };
+    let until_escalating = input.ignore_until_escalating.unwrap_or(false);
+    let conditions = [
+        details.ignore_duration.is_some(),
+        details.ignore_count.is_some(),
+        details.ignore_user_count.is_some(),
+        until_escalating,
+    ];
+    let condition_count = conditions.iter().filter(|c| **c).count();
+    if details.ignore_window.is_some() && details.ignore_count.is_none() {
+        return Err(invalid("ignore_window requires ignore_count"));
+    }
+    if details.ignore_user_window.is_some() && details.ignore_user_count.is_none() {
+        return Err(invalid("ignore_user_window requires ignore_user_count"));
+    }
+    if condition_count > 1 {
+        return Err(invalid(
+            "Specify at most one of ignore_duration, ignore_count, ignore_user_count, ignore_until_escalating",
+        ));
+    }
+    if condition_count == 1 && status != "ignored" {
+        return Err(invalid("ignore options require status 'ignored'"));
+    }
+    if until_escalating {
+        return Ok((None, Some("archived_until_escalating".to_string())));
+    }
+    Ok(((condition_count == 1).then_some(details), None))
+}
+
+/// Validates the input and builds the PUT body.
+pub fn build_update(input: &UpdateIssueInput) -> Result<IssueUpdate, McpError> {
+    let status = match &input.status {
+        Some(raw) => {
+            let status = raw.trim().to_ascii_lowercase();
+            if !STATUSES.contains(&status.as_str()) {
+                return Err(McpError::invalid_params(
+                    format!(
+                        "Invalid status '{}': expected one of {}",
+                        raw,
+                        STATUSES.join(", ")
+                    ),
+                    None,
+                ));
+            }
+            Some(status)
+        }
+        None => None,
+    };
+    if status.is_none() && input.assigned_to.is_none() {
+        return Err(invalid("Specify status, assigned_to, or both"));
+    }
+    let status_name = status.as_deref().unwrap_or("");
+    let resolution = resolve_details(input, status_name)?;
+    let (ignore, substatus) = ignore_details(input, status_name)?;
+    Ok(IssueUpdate {
+        status,
+        substatus,
+        status_details: resolution.or(ignore),
+        assigned_to: input.assigned_to.as_ref().map(|a| a.trim().to_string()),
+        ..Default::default()
+    })
+}
+
+pub fn format_update_output(issue: &Issue) -> String {
+    let mut output = String::new();
+    output.push_str("# Issue Updated\n\n");
+    output.push_str(&format!("**ID:** {}\n", issue.short_id));
+    output.push_str(&format!("**Title:** {}\n", issue.title));
+    output.push_str(&format!("**Status:** {}\n", issue.status));
+    if let Some(substatus) = &issue.substatus {
+        output.push_str(&format!("**Substatus:** {}\n", substatus));
+    }
+    if let Some(resolution) = format_resolution(&issue.status_details) {
+        output.push_str(&format!("**Resolved In:** {}\n", resolution));
+    }
+    if let Some(ignore) = format_ignore(&issue.status_details) {
+        output.push_str(&format!("**Ignored:** {}\n", ignore));
+    }
+    let assignee = issue.assigned_to.as_ref().and_then(format_assignee);
+    output.push_str(&format!(
+        "**Assigned To:** {}\n",
+        assignee.as_deref().unwrap_or("unassigned")
+    ));
+    if let Some(permalink) = &issue.permalink {
+        output.push_str(&format!("**URL:** {}\n", permalink));
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: UpdateIssueInput,
+) -> Result<CallToolResult, McpError> {
+    let update = build_update(&input)?;
+    let issue = client
+        .update_issue(&input.organization_slug, &input.issue_id, &update)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let output = format_update_output(&issue);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,285 @@
+use crate::api_client::{SentryApi, UptimeCheck, UptimeMonitor};
+use crate::format::format_duration;
+use crate::tools::list_metric_alerts::format_window;
+use chrono::DateTime;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Monitors whose checks are fetched in one call; each costs a request.
+const MAX_CHECKED_MONITORS: usize = 10;
+/// Uptime status of a monitor whose URL is failing.
+const UPTIME_DOWN: i64 = 2;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UptimeMonitorsInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Only show monitors of this project (optional)")]
+    pub project_slug: Option<String>,
+    #[schemars(
+        description = "Only show this uptime monitor, with every recent check result (optional)"
+    )]
+    pub monitor_id: Option<String>,
+    #[schemars(description = "Recent checks to fetch per monitor (default: 100, max: 100)")]
+    pub limit: Option<i32>,
+}
+
+/// A run of consecutive failed checks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Downtime {
+    pub start: String,
+    /// First successful check afterwards; `None` while still down.
+    pub end: Option<String>,
+    pub failures: usize,
+    pub reasons: Vec<String>,
+}
+
+/// Groups consecutive failed checks into downtime windows, oldest first. Missed
+/// check windows neither start nor end a downtime.
+pub fn downtime_windows(checks: &[UptimeCheck]) -> Vec<Downtime> {
+    let mut sorted: Vec<&UptimeCheck> = checks.iter().collect();
+    sorted.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    let mut windows: Vec<Downtime> = Vec::new();
+    for check in sorted {
+        let open = windows.last_mut().filter(|w| w.end.is_none());
+        match (check.check_status.as_str(), open) {
+            ("failure", Some(window)) => {
+                window.failures += 1;
+                add_reason(window, check);
+            }
+            ("failure", None) => {
+                let mut window = Downtime {
+                    start: check.timestamp.clone(),
+                    end: None,
+                    failures: 1,
+                    reasons: Vec::new(),
+                };
+                add_reason(&mut window, check);
+                windows.push(window);
+            }
+            ("success", Some(window)) => window.end = Some(check.timestamp.clone()),
+            _ => {}
+        }
+    }
+    windows
+}
+
+fn add_reason(window: &mut Downtime, check: &UptimeCheck) {
+    let reason = match (&check.check_status_reason, check.http_status_code) {
+        (Some(reason), Some(code)) => format!("{} (HTTP {})", reason, code),
+        (Some(reason), None) => reason.clone(),
+        (None, Some(code)) => format!("HTTP {}", code),
+        (None, None) => return,
+    };
+    if !window.reasons.contains(&reason) {
+        window.reasons.push(reason);
+    }
+}
+
+/// Share of successful checks among those that ran.
+pub fn success_rate(checks: &[UptimeCheck]) -> Option<f64> {
+    let ran: Vec<&UptimeCheck> = checks
+        .iter()
+        .filter(|c| c.check_status != "missed_window")
+        .collect();
+    if ran.is_empty() {
+        return None;
+    }
+    let ok = ran.iter().filter(|c| c.check_status == "success").count();
+    Some(ok as f64 * 100.0 / ran.len() as f64)
+}
+
+fn window_length(window: &Downtime) -> Option<String> {
+    let start = DateTime::parse_from_rfc3339(&window.start).ok()?;
+    let end = DateTime::parse_from_rfc3339(window.end.as_deref()?).ok()?;
+    Some(format_window((end - start).num_minutes() as f64))
+}
+
+/// Issue search matching issues that were active during the window.
+pub fn correlation_query(window: &Downtime) -> String {
+    match &window.end {
+        Some(end) => format!("lastSeen:>={} firstSeen:<={}", window.start, end),
+        None => format!("lastSeen:>={}", window.start),
+    }
+}
+
+fn monitor_state(monitor: &UptimeMonitor) -> &'static str {
+    match (monitor.status.as_deref(), monitor.uptime_status) {
+        (Some("disabled"), _) => "disabled",
+        (_, Some(UPTIME_DOWN)) => "down",
+        (_, Some(_)) => "up",
+        _ => "unknown",
+    }
+}
+
+fn format_monitor_header(output: &mut String, monitor: &UptimeMonitor) {
+    output.push_str(&format!("## {} (ID: {})\n\n", monitor.name, monitor.id));
+    output.push_str(&format!(
+        "- **URL:** {} {}\n",
+        monitor.method.as_deref().unwrap_or("GET"),
+        monitor.url
+    ));
+    output.push_str(&format!("- **Status:** {}\n", monitor_state(monitor)));
+    if let Some(project) = &monitor.project_slug {
+        output.push_str(&format!("- **Project:** {}\n", project));
+    }
+    if let Some(environment) = &monitor.environment {
+        output.push_str(&format!("- **Environment:** {}\n", environment));
+    }
+    if let Some(interval) = monitor.interval_seconds {
+        output.push_str(&format!(
+            "- **Interval:** {}\n",
+            format_window(interval as f64 / 60.0)
+        ));
+    }
+}
+
+fn format_downtimes(output: &mut String, windows: &[Downtime]) {
+    if windows.is_empty() {
+        output.push_str("\nNo downtime in these checks.\n\n");
+        return;
+    }
+    output.push_str("\n| Down Since | Back Up | Length | Failed Checks | Reasons |\n");
+    output.push_str("|---|---|---|---|---|\n");
+    for window in windows {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            window.start,
+            window.end.as_deref().unwrap_or("still down"),
+            window_length(window).unwrap_or_default(),
+            window.failures,
+            window.reasons.join(", ")
+        ));
+    }
+    output.push_str("\nIssues active during each window (list_issues query):\n");
+    for window in windows {
+        output.push_str(&format!("- `{}`\n", correlation_query(window)));
+    }
+    output.push('\n');
+}
+
+fn format_checks(output: &mut String, checks: &[UptimeCheck]) {
+    output.push_str("### Checks\n\n");
+    output.push_str("| Time | Result | HTTP | Duration | Region | Trace |\n");
+    output.push_str("|---|---|---|---|---|---|\n");
+    for check in checks {
+        let result = match &check.check_status_reason {
+            Some(reason) if check.check_status == "failure" => format!("failure ({})", reason),
+            _ => check.check_status.clone(),
+        };
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            check.timestamp,
+            result,
+            check
+                .http_status_code
+                .map(|c| c.to_string())
+                .unwrap_or_default(),
+            check.duration_ms.map(format_duration).unwrap_or_default(),
+            check.region.as_deref().unwrap_or(""),
+            check.trace_id.as_deref().unwrap_or("")
+        ));
+    }
+    output.push('\n');
+}
+
+/// One monitor with a summary of its checks; `None` when they were not fetched.
+fn format_monitor(
+    output: &mut String,
+    monitor: &UptimeMonitor,
+    checks: Option<&[UptimeCheck]>,
+    show_checks: bool,
+) {
+    format_monitor_header(output, monitor);
+    let Some(checks) = checks else {
+        output.push('\n');
+        return;
+    };
+    let rate = success_rate(checks)
+        .map(|r| format!(", {:.1}% successful", r))
+        .unwrap_or_default();
+    output.push_str(&format!("- **Recent Checks:** {}{}\n", checks.len(), rate));
+    format_downtimes(output, &downtime_windows(checks));
+    if show_checks && !checks.is_empty() {
+        format_checks(output, checks);
+    }
+}
+
+/// A monitor with its recent checks, when they were fetched.
+pub type MonitorChecks<'a> = (&'a UptimeMonitor, Option<Vec<UptimeCheck>>);
+
+pub fn format_uptime_output(entries: &[MonitorChecks], show_checks: bool) -> String {
+    let mut output = String::new();
+    output.push_str("# Uptime Monitors\n\n");
+    output.push_str(&format!("**Monitors:** {}\n", entries.len()));
+    let down = entries
+        .iter()
+        .filter(|(m, _)| monitor_state(m) == "down")
+        .count();
+    output.push_str(&format!("**Down:** {}\n\n", down));
+    if entries.is_empty() {
+        output.push_str("No uptime monitors found.\n");
+        return output;
+    }
+    for (monitor, checks) in entries {
+        format_monitor(&mut output, monitor, checks.as_deref(), show_checks);
+    }
+    if entries.len() > MAX_CHECKED_MONITORS {
+        output.push_str(&format!(
+            "Checks are shown for the first {} monitors; pass project_slug or monitor_id for the others.\n",
+            MAX_CHECKED_MONITORS
+        ));
+    }
+    output
+}
+
+/// Monitors to show: one by ID, or all of a project (or the organization).
+pub fn select_monitors<'a>(
+    monitors: &'a [UptimeMonitor],
+    project: Option<&str>,
+    monitor_id: Option<&str>,
+) -> Vec<&'a UptimeMonitor> {
+    monitors
+        .iter()
+        .filter(|m| monitor_id.is_none_or(|id| m.id == id))
+        .filter(|m| project.is_none_or(|p| m.project_slug.as_deref() == Some(p)))
+        .collect()
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: UptimeMonitorsInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let monitors = client
+        .list_uptime_monitors(org)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let monitor_id = input.monitor_id.as_deref().map(str::trim);
+    let selected = select_monitors(&monitors, input.project_slug.as_deref(), monitor_id);
+    if let (Some(id), true) = (monitor_id, selected.is_empty()) {
+        return Err(McpError::invalid_params(
+            format!("Uptime monitor {} not found", id),
+            None,
+        ));
+    }
+    let limit = input.limit.unwrap_or(100).clamp(1, 100);
+    let mut entries: Vec<MonitorChecks> = Vec::new();
+    for (i, monitor) in selected.into_iter().enumerate() {
+        let checks = match (&monitor.project_slug, i < MAX_CHECKED_MONITORS) {
+            (Some(project), true) => Some(
+                client
+                    .list_uptime_checks(org, project, &monitor.id, limit)
+                    .await
+                    .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+            ),
+            _ => None,
+        };
+        entries.push((monitor, checks));
+    }
+    let output = format_uptime_output(&entries, monitor_id.is_some());
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,131 @@
+use crate::api_client::{SentryApi, UserFeedback};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UserFeedbackInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(
+        description = "Only show feedback on this issue, like 'PROJECT-123' or a numeric ID (optional)"
+    )]
+    pub issue_id: Option<String>,
+    #[schemars(
+        description = "Include feedback on resolved and ignored issues (default: false, only unresolved)"
+    )]
+    pub include_resolved: Option<bool>,
+    #[schemars(description = "Maximum number of reports, newest first (default: 25, max: 100)")]
+    pub limit: Option<i32>,
+}
+
+/// Reports attached to `issue_id`, matched by numeric or short ID.
+pub fn filter_by_issue<'a>(
+    feedback: &'a [UserFeedback],
+    issue_id: Option<&str>,
+) -> Vec<&'a UserFeedback> {
+    let issue_id = issue_id.map(str::trim);
+    feedback
+        .iter()
+        .filter(|f| {
+            issue_id.is_none_or(|id| {
+                f.issue.as_ref().is_some_and(|issue| {
+                    issue.id == id
+                        || issue
+                            .short_id
+                            .as_deref()
+                            .is_some_and(|s| s.eq_ignore_ascii_case(id))
+                })
+            })
+        })
+        .collect()
+}
+
+/// Who left the report: name, email, both, or `Anonymous`.
+pub fn reporter(feedback: &UserFeedback) -> String {
+    let name = feedback.name.as_deref().filter(|n| !n.is_empty());
+    let email = feedback.email.as_deref().filter(|e| !e.is_empty());
+    match (name, email) {
+        (Some(name), Some(email)) => format!("{} <{}>", name, email),
+        (Some(name), None) => name.to_string(),
+        (None, Some(email)) => email.to_string(),
+        (None, None) => "Anonymous".to_string(),
+    }
+}
+
+fn format_report(output: &mut String, feedback: &UserFeedback) {
+    output.push_str(&format!("### {}", reporter(feedback)));
+    if let Some(date) = &feedback.date_created {
+        output.push_str(&format!(" ({})", date));
+    }
+    output.push_str("\n\n");
+    if let Some(issue) = &feedback.issue {
+        let id = issue.short_id.as_deref().unwrap_or(&issue.id);
+        match &issue.title {
+            Some(title) => output.push_str(&format!("**Issue:** {} - {}\n", id, title)),
+            None => output.push_str(&format!("**Issue:** {}\n", id)),
+        }
+    }
+    if let Some(event_id) = &feedback.event_id {
+        output.push_str(&format!("**Event:** {}\n", event_id));
+    }
+    if feedback.issue.is_some() || feedback.event_id.is_some() {
+        output.push('\n');
+    }
+    let comments = feedback.comments.trim();
+    if comments.is_empty() {
+        output.push_str("> (no comment)\n");
+    }
+    for line in comments.lines() {
+        if line.trim().is_empty() {
+            output.push_str(">\n");
+        } else {
+            output.push_str(&format!("> {}\n", line));
+        }
+    }
+    output.push('\n');
+}
+
+pub fn format_feedback_output(scope: &str, feedback: &[&UserFeedback]) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# User Feedback for {}\n\n", scope));
+    output.push_str(&format!("**Found:** {} reports\n\n", feedback.len()));
+    if feedback.is_empty() {
+        output.push_str("No user feedback found.\n");
+        return output;
+    }
+    for report in feedback {
+        format_report(&mut output, report);
+    }
+    output
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: UserFeedbackInput,
+) -> Result<CallToolResult, McpError> {
+    let limit = input.limit.unwrap_or(25).clamp(1, 100);
+    // The endpoint cannot filter by issue, so fetch a full page to filter from.
+    let fetch = if input.issue_id.is_some() { 100 } else { limit };
+    let feedback = client
+        .list_user_feedback(
+            &input.organization_slug,
+            &input.project_slug,
+            input.include_resolved.unwrap_or(false),
+            fetch,
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let mut selected = filter_by_issue(&feedback, input.issue_id.as_deref());
+    selected.truncate(limit as usize);
+    let scope = match &input.issue_id {
+        Some(issue_id) => issue_id.trim().to_string(),
+        None => input.project_slug.clone(),
+    };
+    let output = format_feedback_output(&scope, &selected);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,302 @@
+use crate::api_client::{Issue, IssuesQuery, Release, SentryApi, SessionStats, SessionsQuery};
+use crate::format::{count_label, escape_cell, format_rate};
+use crate::tools::list_environments::resolve_environment;
+use crate::tools::whats_new::{created_since, sentry_timestamp};
+use chrono::{Duration, Utc};
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+const SESSIONS: &str = "sum(session)";
+const CRASH_FREE: &str = "crash_free_rate(session)";
+/// Issues fetched per count; counts at this limit are shown as `100+`.
+const COUNT_LIMIT: i32 = 100;
+const TOP_ISSUES: i32 = 5;
+const NOTABLE_RELEASES: usize = 5;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct WeeklyReportInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(
+        description = "Environment name to filter by; unique prefixes like 'prod' are completed"
+    )]
+    pub environment: Option<String>,
+}
+
+/// Sessions and crash-free rate of one day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyRate {
+    /// `2024-05-01`.
+    pub date: String,
+    pub sessions: f64,
+    /// Fraction between 0 and 1; `None` on days without sessions.
+    pub crash_free: Option<f64>,
+}
+
+/// Everything the report is made of.
+pub struct WeeklyReport {
+    pub new_issues: Vec<Issue>,
+    pub resolved: Vec<Issue>,
+    pub top_issues: Vec<Issue>,
+    /// Daily rates of the past two weeks, oldest first.
+    pub days: Vec<DailyRate>,
+    pub releases: Vec<Release>,
+}
+
+/// Daily sessions and crash-free rates from a `1d` interval session query.
+pub fn daily_rates(stats: &SessionStats) -> Vec<DailyRate> {
+    let Some(group) = stats.groups.first() else {
+        return Vec::new();
+    };
+    let sessions = group.series.get(SESSIONS);
+    let rates = group.series.get(CRASH_FREE);
+    stats
+        .intervals
+        .iter()
+        .enumerate()
+        .map(|(i, interval)| DailyRate {
+            date: interval.chars().take(10).collect(),
+            sessions: sessions
+                .and_then(|s| s.get(i).copied().flatten())
+                .unwrap_or(0.0),
+            crash_free: rates.and_then(|r| r.get(i).copied().flatten()),
+        })
+        .collect()
+}
+
+/// Crash-free rate over several days, weighted by each day's sessions.
+pub fn weighted_rate(days: &[DailyRate]) -> Option<f64> {
+    let (crash_free, sessions) = days
+        .iter()
+        .filter_map(|d| d.crash_free.map(|rate| (rate * d.sessions, d.sessions)))
+        .fold((0.0, 0.0), |(c, s), (dc, ds)| (c + dc, s + ds));
+    (sessions > 0.0).then(|| crash_free / sessions)
+}
+
+/// Splits the daily rates into the previous week and the past week.
+pub fn split_weeks(days: &[DailyRate]) -> (&[DailyRate], &[DailyRate]) {
+    days.split_at(days.len().saturating_sub(7))
+}
+
+/// Releases created this week, the ones that brought the most new issues first.
+pub fn notable_releases(mut releases: Vec<Release>) -> Vec<Release> {
+    releases.sort_by_key(|r| std::cmp::Reverse(r.new_groups.unwrap_or(0)));
+    releases
+}
+
+fn format_crash_free_summary(output: &mut String, days: &[DailyRate]) {
+    let (previous, current) = split_weeks(days);
+    let Some(rate) = weighted_rate(current) else {
+        output.push_str("- **Crash-free sessions:** no session data\n");
+        return;
+    };
+    output.push_str(&format!("- **Crash-free sessions:** {}", format_rate(rate)));
+    if let Some(before) = weighted_rate(previous) {
+        output.push_str(&format!(
+            " (previous week {}, {:+.2} pts)",
+            format_rate(before),
+            (rate - before) * 100.0
+        ));
+    }
+    output.push('\n');
+}
+
+fn format_top_issues(output: &mut String, issues: &[Issue]) {
+    output.push_str("\n## Top Issues by Users\n\n");
+    if issues.is_empty() {
+        output.push_str("No unresolved issues this week.\n");
+        return;
+    }
+    output.push_str("| Issue | Title | Users | Events |\n");
+    output.push_str("|---|---|---|---|\n");
+    for issue in issues {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            issue.short_id,
+            escape_cell(&issue.title),
+            issue.user_count,
+            issue.count
+        ));
+    }
+}
+
+fn format_crash_free_trend(output: &mut String, days: &[DailyRate]) {
+    output.push_str("\n## Crash-Free Sessions\n\n");
+    let (_, current) = split_weeks(days);
+    if current.iter().all(|d| d.crash_free.is_none()) {
+        output.push_str(
+            "No session data; release health needs an SDK with session tracking enabled.\n",
+        );
+        return;
+    }
+    output.push_str("| Day | Sessions | Crash-Free |\n");
+    output.push_str("|---|---|---|\n");
+    for day in current {
+        output.push_str(&format!(
+            "| {} | {:.0} | {} |\n",
+            day.date,
+            day.sessions,
+            day.crash_free.map_or_else(|| "-".to_string(), format_rate)
+        ));
+    }
+}
+
+fn format_releases(output: &mut String, releases: &[Release]) {
+    output.push_str("\n## Notable Releases\n\n");
+    if releases.is_empty() {
+        output.push_str("No releases this week.\n");
+        return;
+    }
+    for release in releases.iter().take(NOTABLE_RELEASES) {
+        let version = release.short_version.as_deref().unwrap_or(&release.version);
+        output.push_str(&format!("- **{}**", version));
+        if let Some(date) = &release.date_created {
+            output.push_str(&format!(" created {}", date));
+        }
+        if let Some(new_groups) = release.new_groups {
+            output.push_str(&format!(", {} new issues", new_groups));
+        }
+        output.push('\n');
+    }
+    if releases.len() > NOTABLE_RELEASES {
+        output.push_str(&format!(
+            "- and {} more releases\n",
+            releases.len() - NOTABLE_RELEASES
+        ));
+    }
+}
+
+pub fn format_weekly_report(
+    project: &str,
+    start: &str,
+    end: &str,
+    report: &WeeklyReport,
+) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Weekly Report: {}\n\n", project));
+    output.push_str(&format!("**Period:** {} to {}\n\n", start, end));
+    output.push_str("## Summary\n\n");
+    output.push_str(&format!(
+        "- **New issues:** {}\n",
+        count_label(report.new_issues.len(), COUNT_LIMIT as usize)
+    ));
+    output.push_str(&format!(
+        "- **Resolved issues:** {}\n",
+        count_label(report.resolved.len(), COUNT_LIMIT as usize)
+    ));
+    format_crash_free_summary(&mut output, &report.days);
+    output.push_str(&format!("- **Releases:** {}\n", report.releases.len()));
+    format_top_issues(&mut output, &report.top_issues);
+    format_crash_free_trend(&mut output, &report.days);
+    format_releases(&mut output, &report.releases);
+    output
+}
+
+async fn search(
+    client: &impl SentryApi,
+    org_slug: &str,
+    window: &IssuesQuery,
+    query: String,
+    sort: &str,
+    limit: i32,
+) -> Result<Vec<Issue>, McpError> {
+    let issues_query = IssuesQuery {
+        query: Some(query),
+        sort: Some(sort.to_string()),
+        limit: Some(limit),
+        ..window.clone()
+    };
+    client
+        .list_issues(org_slug, &issues_query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: WeeklyReportInput,
+) -> Result<CallToolResult, McpError> {
+    let org = &input.organization_slug;
+    let now = Utc::now();
+    let since = now - Duration::days(7);
+    let project = client
+        .get_project(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let environment = resolve_environment(client, org, input.environment.as_deref()).await?;
+    let since_ts = sentry_timestamp(&since);
+    let window = IssuesQuery {
+        project: Some(project.id.clone()),
+        environment: environment.clone(),
+        start: Some(since_ts.clone()),
+        end: Some(sentry_timestamp(&now)),
+        ..Default::default()
+    };
+    let new_issues = search(
+        client,
+        org,
+        &window,
+        format!("firstSeen:>{}", since_ts),
+        "new",
+        COUNT_LIMIT,
+    )
+    .await?;
+    let resolved = search(
+        client,
+        org,
+        &window,
+        "is:resolved".to_string(),
+        "date",
+        COUNT_LIMIT,
+    )
+    .await?;
+    let top_issues = search(
+        client,
+        org,
+        &window,
+        "is:unresolved".to_string(),
+        "user",
+        TOP_ISSUES,
+    )
+    .await?;
+    let sessions = client
+        .get_session_stats(
+            org,
+            &SessionsQuery {
+                fields: vec![SESSIONS.to_string(), CRASH_FREE.to_string()],
+                projects: vec![project.id],
+                environment,
+                stats_period: Some("14d".to_string()),
+                interval: Some("1d".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let releases = client
+        .list_project_releases(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .into_iter()
+        .filter(|r| created_since(r, &since))
+        .collect();
+    let report = WeeklyReport {
+        new_issues,
+        resolved,
+        top_issues,
+        days: daily_rates(&sessions),
+        releases: notable_releases(releases),
+    };
+    let output = format_weekly_report(
+        &input.project_slug,
+        &since.format("%Y-%m-%d").to_string(),
+        &now.format("%Y-%m-%d").to_string(),
+        &report,
+    );
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,206 @@
+use crate::api_client::{Issue, IssuesQuery, Release, SentryApi};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use regex::Regex;
+use rmcp::{ErrorData as McpError, model::CallToolResult};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::sync::LazyLock;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct WhatsNewInput {
+    #[schemars(description = "Organization slug")]
+    pub organization_slug: String,
+    #[schemars(description = "Project slug")]
+    pub project_slug: String,
+    #[schemars(
+        description = "Start of the window: ISO 8601 timestamp ('2024-01-15T09:00:00Z', \
+        '2024-01-15') or a relative period ('24h', '3d', '1w')"
+    )]
+    pub since: String,
+    #[schemars(description = "Maximum issues per section (default: 10, max: 100)")]
+    pub limit: Option<i32>,
+}
+
+/// Everything that changed in a project within the requested window.
+/// Sentry search has no filter on when an issue's status changed, so the status
+/// sections hold issues with events in the window that are in that state now.
+pub struct WhatsNew {
+    pub new_issues: Vec<Issue>,
+    pub regressions: Vec<Issue>,
+    pub escalating: Vec<Issue>,
+    pub resolved: Vec<Issue>,
+    pub releases: Vec<Release>,
+}
+
+static RELATIVE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\d+)\s*([mhdw])$").unwrap());
+
+/// Parses an absolute timestamp or a relative period ending at `now`.
+pub fn parse_since(since: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let since = since.trim();
+    if let Some(caps) = RELATIVE_RE.captures(since) {
+        let amount: i64 = caps[1].parse().ok()?;
+        let duration = match &caps[2] {
+            "m" => Duration::try_minutes(amount)?,
+            "h" => Duration::try_hours(amount)?,
+            "d" => Duration::try_days(amount)?,
+            _ => Duration::try_weeks(amount)?,
+        };
+        return now.checked_sub_signed(duration);
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(since) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(dt) = NaiveDateTime::parse_from_str(since, "%Y-%m-%dT%H:%M:%S") {
+        return Some(dt.and_utc());
+    }
+    NaiveDate::parse_from_str(since, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+}
+
+/// Formats a timestamp the way Sentry search and `start`/`end` params expect.
+pub fn sentry_timestamp(dt: &DateTime<Utc>) -> String {
+    dt.format("%Y-%m-%dT%H:%M:%S").to_string()
+}
+
+fn format_issue_line(output: &mut String, issue: &Issue) {
+    output.push_str(&format!(
+        "- **{}** {} ({} events, {} users",
+        issue.short_id, issue.title, issue.count, issue.user_count
+    ));
+    if let Some(last_seen) = &issue.last_seen {
+        output.push_str(&format!(", last seen {}", last_seen));
+    }
+    output.push_str(")\n");
+}
+
+fn format_issue_section(output: &mut String, title: &str, issues: &[Issue]) {
+    output.push_str(&format!("\n## {} ({})\n\n", title, issues.len()));
+    if issues.is_empty() {
+        output.push_str("None.\n");
+    }
+    for issue in issues {
+        format_issue_line(output, issue);
+    }
+}
+
+pub fn format_whats_new_output(project_slug: &str, since: &str, whats_new: &WhatsNew) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# What's New in {}\n\n", project_slug));
+    output.push_str(&format!("**Since:** {}\n", since));
+    output.push_str(
+        "\nThe status sections list issues with events since then that are in that state now; \
+        the status may have changed earlier.\n",
+    );
+    format_issue_section(&mut output, "New Issues", &whats_new.new_issues);
+    format_issue_section(
+        &mut output,
+        "Regressed, Active Since",
+        &whats_new.regressions,
+    );
+    format_issue_section(
+        &mut output,
+        "Escalating, Active Since",
+        &whats_new.escalating,
+    );
+    format_issue_section(&mut output, "Resolved, Active Since", &whats_new.resolved);
+    output.push_str(&format!(
+        "\n## New Releases ({})\n\n",
+        whats_new.releases.len()
+    ));
+    if whats_new.releases.is_empty() {
+        output.push_str("None.\n");
+    }
+    for release in &whats_new.releases {
+        let version = release.short_version.as_deref().unwrap_or(&release.version);
+        output.push_str(&format!("- **{}**", version));
+        if let Some(date) = &release.date_created {
+            output.push_str(&format!(" created {}", date));
+        }
+        if let Some(new_groups) = release.new_groups {
+            output.push_str(&format!(" ({} new issues)", new_groups));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// Whether the release was created at or after `since`.
+pub fn created_since(release: &Release, since: &DateTime<Utc>) -> bool {
+    release
+        .date_created
+        .as_deref()
+        .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+        .is_some_and(|d| d.with_timezone(&Utc) >= *since)
+}
+
+async fn search(
+    client: &impl SentryApi,
+    org_slug: &str,
+    window: &IssuesQuery,
+    query: &str,
+    sort: &str,
+) -> Result<Vec<Issue>, McpError> {
+    let issues_query = IssuesQuery {
+        query: Some(query.to_string()),
+        sort: Some(sort.to_string()),
+        ..window.clone()
+    };
+    client
+        .list_issues(org_slug, &issues_query)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))
+}
+
+pub async fn execute(
+    client: &impl SentryApi,
+    input: WhatsNewInput,
+) -> Result<CallToolResult, McpError> {
+    let now = Utc::now();
+    let since = parse_since(&input.since, now).ok_or_else(|| {
+        McpError::invalid_params(
+            "Invalid 'since': expected ISO 8601 timestamp or relative period like '24h'",
+            None,
+        )
+    })?;
+    let limit = input.limit.unwrap_or(10).clamp(1, 100);
+    let org = &input.organization_slug;
+    let project = client
+        .get_project(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let since_ts = sentry_timestamp(&since);
+    let window = IssuesQuery {
+        project: Some(project.id),
+        start: Some(since_ts.clone()),
+        end: Some(sentry_timestamp(&now)),
+        limit: Some(limit),
+        ..Default::default()
+    };
+    let releases = client
+        .list_project_releases(org, &input.project_slug)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .into_iter()
+        .filter(|r| created_since(r, &since))
+        .collect();
+    let whats_new = WhatsNew {
+        new_issues: search(
+            client,
+            org,
+            &window,
+            &format!("firstSeen:>{}", since_ts),
+            "new",
+        )
+        .await?,
+        regressions: search(client, org, &window, "is:regressed", "date").await?,
+        escalating: search(client, org, &window, "is:escalating", "freq").await?,
+        resolved: search(client, org, &window, "is:resolved", "date").await?,
+        releases,
+    };
+    let output = format_whats_new_output(&input.project_slug, &since.to_rfc3339(), &whats_new);
+    Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+        output,
+    )]))
+}
@@ -0,0 +1,40 @@
+use sentry_mcp::api_client::IssueComment;
+use sentry_mcp::tools::add_issue_note::{
+    AddIssueNoteInput, MAX_NOTE_CHARS, build_note, format_note_output,
+};
+use serde_json::json;
+
+fn make_input(text: &str) -> AddIssueNoteInput {
+    AddIssueNoteInput {
+        organization_slug: "acme".to_string(),
+        issue_id: "PROJ-1".to_string(),
+        text: text.to_string(),
+    }
+}
+
+#[test]
+fn test_build_note_trims() {
+    let note = build_note(&make_input("\n Fixed by @jane in #42 \n")).unwrap();
+    assert_eq!(note.text, "Fixed by @jane in #42");
+}
+
+#[test]
+fn test_build_note_rejects_empty_and_oversized() {
+    assert!(build_note(&make_input("   ")).is_err());
+    let err = build_note(&make_input(&"x".repeat(MAX_NOTE_CHARS + 1))).unwrap_err();
+    assert!(err.message.contains("at most"));
+    assert!(build_note(&make_input(&"x".repeat(MAX_NOTE_CHARS))).is_ok());
+}
+
+#[test]
+fn test_format_note_output() {
+    let comment: IssueComment = serde_json::from_value(json!({
+        "id": "8", "user": {"name": "Jane Doe"}, "data": {"text": "hi"},
+        "dateCreated": "2024-05-01T10:00:00Z"
+    }))
+    .unwrap();
+    let output = format_note_output("PROJ-1", &comment);
+    assert!(output.contains("**Issue:** PROJ-1"));
+    assert!(output.contains("**Author:** Jane Doe"));
+    assert!(output.contains("**Created:** 2024-05-01T10:00:00Z"));
+}
@@ -0,0 +1,233 @@
+use sentry_mcp::api_client::IssueAlertRule;
+use sentry_mcp::tools::alerts::create_alert_rule::{CreateAlertRuleInput, build_alert_rule};
+use sentry_mcp::tools::alerts::update_alert_rule::{UpdateAlertRuleInput, build_updated_rule};
+use sentry_mcp::tools::alerts::{
+    ActionInput, ConditionInput, FilterInput, build_actions, build_conditions, build_filters,
+    describe_component, format_alert_rule_output, validate_owner,
+};
+use serde_json::json;
+
+fn conditions(value: serde_json::Value) -> Vec<ConditionInput> {
+    serde_json::from_value(value).unwrap()
+}
+
+fn filters(value: serde_json::Value) -> Vec<FilterInput> {
+    serde_json::from_value(value).unwrap()
+}
+
+fn actions(value: serde_json::Value) -> Vec<ActionInput> {
+    serde_json::from_value(value).unwrap()
+}
+
+fn create_input(extra: serde_json::Value) -> CreateAlertRuleInput {
+    let mut input = json!({
+        "organization_slug": "acme",
+        "project_slug": "backend",
+        "name": "New issues",
+        "conditions": [{"type": "first_seen"}],
+        "actions": [{"type": "email"}]
+    });
+    for (key, value) in extra.as_object().unwrap() {
+        input[key] = value.clone();
+    }
+    serde_json::from_value(input).unwrap()
+}
+
+#[test]
+fn test_build_conditions() {
+    let built = build_conditions(&conditions(json!([
+        {"type": "first_seen"},
+        {"type": "user_frequency", "value": 50, "interval": "1H"}
+    ])))
+    .unwrap();
+    assert_eq!(
+        built[0].id,
+        "sentry.rules.conditions.first_seen_event.FirstSeenEventCondition"
+    );
+    assert!(built[0].settings.is_empty());
+    assert_eq!(
+        built[1].id,
+        "sentry.rules.conditions.event_frequency.EventUniqueUserFrequencyCondition"
+    );
+    assert_eq!(built[1].settings["value"], 50);
+    assert_eq!(built[1].settings["interval"], "1h");
+}
+
+#[test]
+fn test_build_conditions_rejects_invalid() {
+    assert!(build_conditions(&[]).is_err());
+    let err = build_conditions(&conditions(json!([
+        {"type": "event_frequency", "value": 10, "interval": "2h"}
+    ])))
+    .unwrap_err();
+    assert!(err.message.contains("Invalid interval '2h'"));
+    assert!(
+        build_conditions(&conditions(json!([
+            {"type": "event_frequency", "value": 0, "interval": "1h"}
+        ])))
+        .is_err()
+    );
+}
+
+#[test]
+fn test_build_filters() {
+    let built = build_filters(&filters(json!([
+        {"type": "tagged_event", "key": "browser", "operator": "is_set"},
+        {"type": "level", "operator": "at_least", "level": "Error"},
+        {"type": "issue_occurrences", "value": 3}
+    ])))
+    .unwrap();
+    assert_eq!(built[0].settings.get("value"), None);
+    assert_eq!(built[0].settings["match"], "is");
+    assert_eq!(built[1].settings["match"], "gte");
+    assert_eq!(built[1].settings["level"], "40");
+    assert_eq!(built[2].settings["value"], 3);
+}
+
+#[test]
+fn test_build_filters_rejects_invalid() {
+    let err = build_filters(&filters(json!([
+        {"type": "tagged_event", "key": "url", "operator": "contains"}
+    ])))
+    .unwrap_err();
+    assert!(err.message.contains("needs a value"));
+    assert!(
+        build_filters(&filters(json!([
+            {"type": "tagged_event", "key": "url", "operator": "like", "value": "x"}
+        ])))
+        .is_err()
+    );
+    assert!(
+        build_filters(&filters(json!([
+            {"type": "level", "operator": "equals", "level": "critical"}
+        ])))
+        .is_err()
+    );
+}
+
+#[test]
+fn test_build_actions() {
+    let built = build_actions(&actions(json!([
+        {"type": "email", "fallthrough": "no_one"},
+        {"type": "email", "target": "member", "target_id": "17"},
+        {"type": "slack", "workspace": "55", "channel": "@oncall", "tags": "release, url"}
+    ])))
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(&built[0]).unwrap(),
+        json!({"id": "sentry.mail.actions.NotifyEmailAction", "targetType": "IssueOwners", "fallthroughType": "NoOne"})
+    );
+    assert_eq!(built[1].settings["targetType"], "Member");
+    assert_eq!(built[1].settings["targetIdentifier"], "17");
+    assert_eq!(built[2].settings["channel"], "@oncall");
+    assert_eq!(built[2].settings["tags"], "release, url");
+}
+
+#[test]
+fn test_build_actions_rejects_invalid() {
+    assert!(build_actions(&[]).is_err());
+    let err = build_actions(&actions(json!([{"type": "email", "target": "team"}]))).unwrap_err();
+    assert!(err.message.contains("numeric target_id"));
+    assert!(
+        build_actions(&actions(json!([
+            {"type": "email", "target": "team", "target_id": "4", "fallthrough": "no_one"}
+        ])))
+        .is_err()
+    );
+    assert!(
+        build_actions(&actions(
+            json!([{"type": "slack", "workspace": "55", "channel": "#"}])
+        ))
+        .is_err()
+    );
+}
+
+#[test]
+fn test_validate_owner() {
+    assert_eq!(validate_owner(" team:4 ").unwrap(), "team:4");
+    assert!(validate_owner("user:12").is_ok());
+    assert!(validate_owner("team:backend").is_err());
+    assert!(validate_owner("org:1").is_err());
+}
+
+#[test]
+fn test_build_alert_rule_defaults() {
+    let rule = build_alert_rule(&create_input(json!({"name": "  New issues  "}))).unwrap();
+    assert_eq!(rule.name, "New issues");
+    assert_eq!(rule.action_match, "all");
+    assert_eq!(rule.filter_match, "all");
+    assert_eq!(rule.frequency, 30);
+    assert!(rule.filters.is_empty());
+    assert_eq!(rule.owner, None);
+}
+
+#[test]
+fn test_build_alert_rule_rejects_invalid() {
+    assert!(build_alert_rule(&create_input(json!({"name": " "}))).is_err());
+    assert!(build_alert_rule(&create_input(json!({"action_match": "none"}))).is_err());
+    assert!(build_alert_rule(&create_input(json!({"frequency_minutes": 50000}))).is_err());
+    assert!(build_alert_rule(&create_input(json!({"conditions": []}))).is_err());
+}
+
+#[test]
+fn test_build_updated_rule_replaces_only_given_parts() {
+    let existing: IssueAlertRule = serde_json::from_value(json!({
+        "id": "7", "name": "Old", "actionMatch": "any", "frequency": 60,
+        "conditions": [{"id": "sentry.rules.conditions.regression_event.RegressionEventCondition"}],
+        "filters": [{"id": "sentry.rules.filters.issue_occurrences.IssueOccurrencesFilter", "value": 5}],
+        "actions": [{"id": "sentry.mail.actions.NotifyEmailAction", "targetType": "IssueOwners"}]
+    }))
+    .unwrap();
+    let input: UpdateAlertRuleInput = serde_json::from_value(json!({
+        "organization_slug": "acme", "project_slug": "backend", "rule_id": "7",
+        "name": "Renamed", "filters": []
+    }))
+    .unwrap();
+    let rule = build_updated_rule(&existing, &input).unwrap();
+    assert_eq!(rule.name, "Renamed");
+    assert_eq!(rule.action_match, "any");
+    assert_eq!(rule.filter_match, "all");
+    assert_eq!(rule.frequency, 60);
+    assert!(rule.filters.is_empty());
+    assert_eq!(rule.conditions, existing.conditions);
+    assert_eq!(rule.actions, existing.actions);
+}
+
+#[test]
+fn test_describe_component_prefers_sentry_label() {
+    let labelled = serde_json::from_value(json!({
+        "id": "sentry.rules.conditions.first_seen_event.FirstSeenEventCondition",
+        "name": "A new issue is created"
+    }))
+    .unwrap();
+    assert_eq!(describe_component(&labelled), "A new issue is created");
+    let bare = serde_json::from_value(json!({
+        "id": "sentry.rules.filters.level.LevelFilter", "match": "gte", "level": "40"
+    }))
+    .unwrap();
+    assert_eq!(
+        describe_component(&bare),
+        "LevelFilter (level=40, match=gte)"
+    );
+}
+
+#[test]
+fn test_format_alert_rule_output() {
+    let rule: IssueAlertRule = serde_json::from_value(json!({
+        "id": "12", "name": "New issues", "actionMatch": "all", "filterMatch": "any",
+        "frequency": 30, "environment": "production", "owner": "team:4",
+        "conditions": [{"id": "x.FirstSeenEventCondition", "name": "A new issue is created"}],
+        "actions": [{"id": "x.NotifyEmailAction", "name": "Send a notification to IssueOwners"}]
+    }))
+    .unwrap();
+    let output = format_alert_rule_output("Alert Rule Created", "backend", &rule);
+    assert!(output.starts_with("# Alert Rule Created\n"));
+    assert!(output.contains("**Project:** backend"));
+    assert!(output.contains("**Environment:** production"));
+    assert!(output.contains("**Action Interval:** 30 min"));
+    assert!(!output.contains("**Filters Match:**"));
+    assert!(output.contains("**Owner:** team:4"));
+    assert!(output.contains("## Conditions\n\n- A new issue is created\n"));
+    assert!(output.contains("## Actions\n\n- Send a notification to IssueOwners\n"));
+    assert!(!output.contains("## Filters"));
+}
@@ -1,5 +1,6 @@
 use sentry_mcp::api_client::{
-    Event, EventEntry, EventTag, EventsQuery, Issue, IssueTag, Project, TraceMeta, TraceSpan,
+    Event, EventEntry, EventTag, EventsQuery, Issue, IssueTag, Project, Release, TraceMeta,
+    TraceSpan, parse_trace_response,
 };
 use serde_json::json;
 
@@ -145,6 +146,7 @@ fn test_events_query_serialize_empty() {
         query: None,
         limit: None,
         sort: None,
+        full: None,
     };
     let serialized = serde_json::to_value(&query).unwrap();
     assert_eq!(serialized, json!({}));
@@ -156,11 +158,13 @@ fn test_events_query_serialize_full() {
         query: Some("browser:Chrome".to_string()),
         limit: Some(50),
         sort: Some("oldest".to_string()),
+        full: Some(true),
     };
     let serialized = serde_json::to_value(&query).unwrap();
     assert_eq!(serialized["query"], "browser:Chrome");
     assert_eq!(serialized["limit"], 50);
     assert_eq!(serialized["sort"], "oldest");
+    assert_eq!(serialized["full"], true);
 }
 
 #[test]
@@ -305,3 +309,88 @@ fn test_trace_meta_deserialize_minimal() {
     assert_eq!(meta.span_count, 0.0);
     assert!(meta.span_count_map.is_empty());
 }
+
+#[test]
+fn test_release_deserialize() {
+    let json = json!({
+        "version": "backend@1.2.3+abc",
+        "shortVersion": "1.2.3",
+        "dateCreated": "2024-01-01T00:00:00Z",
+        "dateReleased": null,
+        "newGroups": 4
+    });
+    let release: Release = serde_json::from_value(json).unwrap();
+    assert_eq!(release.version, "backend@1.2.3+abc");
+    assert_eq!(release.short_version.as_deref(), Some("1.2.3"));
+    assert_eq!(release.new_groups, Some(4));
+    assert!(release.date_released.is_none());
+}
+
+#[test]
+fn test_parse_trace_response_span_array() {
+    let spans = parse_trace_response(json!([{
+        "event_id": "s1", "project_id": 1, "project_slug": "web", "parent_span_id": null,
+        "start_timestamp": 1.0, "end_timestamp": 2.0, "duration": 1000.0, "is_transaction": true
+    }]))
+    .unwrap();
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].event_id, "s1");
+}
+
+#[test]
+fn test_parse_trace_response_legacy_transactions() {
+    let spans = parse_trace_response(json!([{
+        "event_id": "e1", "project_id": 1, "project_slug": "web", "parent_span_id": null,
+        "transaction": "/checkout", "transaction.op": "http.server",
+        "transaction.duration": 250.0, "start_timestamp": 100.0,
+        "errors": [{"event_id": "err1", "title": "KeyError"}],
+        "performance_issues": [{"title": "N+1 Query"}],
+        "measurements": {"frames_frozen": {"value": 2.0, "unit": "none"}},
+        "children": [{
+            "event_id": "e2", "project_id": 2, "project_slug": "api",
+            "transaction": "task", "transaction.duration": 100.0,
+            "start_timestamp": 100.1, "timestamp": 100.2
+        }]
+    }]))
+    .unwrap();
+    let root = &spans[0];
+    assert!(root.is_transaction);
+    assert_eq!(root.transaction_id.as_deref(), Some("e1"));
+    assert_eq!(root.op.as_deref(), Some("http.server"));
+    assert_eq!(root.end_timestamp, 100.25);
+    assert_eq!(root.errors.len(), 1);
+    assert_eq!(root.occurrences.len(), 1);
+    assert_eq!(root.measurements["frames_frozen"]["value"], 2.0);
+    assert_eq!(root.children[0].project_slug, "api");
+    assert_eq!(root.children[0].end_timestamp, 100.2);
+}
+
+#[test]
+fn test_parse_trace_response_orphan_errors() {
+    let spans = parse_trace_response(json!({
+        "transactions": [],
+        "orphan_errors": [{"event_id": "err1", "project_id": 3, "project_slug": "worker",
+                           "title": "TimeoutError", "timestamp": 50.0}]
+    }))
+    .unwrap();
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].op.as_deref(), Some("error"));
+    assert_eq!(spans[0].description.as_deref(), Some("TimeoutError"));
+    assert_eq!(spans[0].errors.len(), 1);
+}
+
+#[test]
+fn test_parse_trace_response_rejects_unknown_shape() {
+    assert!(parse_trace_response(json!({"detail": "nope"})).is_err());
+}
+
+#[test]
+fn test_issue_deserialize_numeric_count() {
+    let json = json!({
+        "id": "1", "shortId": "PROJ-1", "title": "Old", "status": "unresolved",
+        "project": {"id": "1", "name": "Project", "slug": "proj"},
+        "count": 42, "userCount": 0
+    });
+    let issue: Issue = serde_json::from_value(json).unwrap();
+    assert_eq!(issue.count, "42");
+}
@@ -0,0 +1,86 @@
+use chrono::{TimeZone, Utc};
+use sentry_mcp::api_client::{AuditLog, AuditLogEntry, OrgMember};
+use sentry_mcp::tools::audit_log::{entries_since, format_audit_log, resolve_actor};
+use serde_json::json;
+
+fn members() -> Vec<OrgMember> {
+    serde_json::from_value(json!([
+        {"id": "1", "email": "jane@example.com", "name": "Jane Doe", "user": {"id": "42"}},
+        {"id": "2", "email": "jane.d@example.com", "name": "Jane Doe", "user": {"id": "43"}},
+        {"id": "3", "email": "bob@example.com", "name": "Bob", "user": {"id": "44"}},
+        {"id": "4", "email": "invited@example.com", "pending": true}
+    ]))
+    .unwrap()
+}
+
+fn entry(event: &str, date: &str, actor: serde_json::Value) -> AuditLogEntry {
+    serde_json::from_value(json!({
+        "id": 1,
+        "actor": actor,
+        "event": event,
+        "ipAddress": "10.0.0.1",
+        "note": "edited rule \"High | low\"",
+        "targetObject": 17,
+        "dateCreated": date
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_resolve_actor() {
+    let members = members();
+    assert_eq!(resolve_actor(&members, "7").unwrap(), "7");
+    assert_eq!(resolve_actor(&members, "BOB@example.com").unwrap(), "44");
+    assert_eq!(resolve_actor(&members, "bob").unwrap(), "44");
+    let err = resolve_actor(&members, "Jane Doe").unwrap_err();
+    assert!(err.contains("jane@example.com, jane.d@example.com"));
+    assert!(resolve_actor(&members, "invited@example.com").is_err());
+}
+
+#[test]
+fn test_entries_since() {
+    let since = Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap();
+    let entries = vec![
+        entry("rule.edit", "2024-05-02T10:00:00Z", json!(null)),
+        entry("rule.add", "2024-04-30T10:00:00Z", json!(null)),
+    ];
+    let kept = entries_since(entries, &since);
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0].event, "rule.edit");
+}
+
+#[test]
+fn test_format_audit_log() {
+    let log = AuditLog {
+        rows: vec![
+            entry(
+                "rule.edit",
+                "2024-05-02T10:00:00Z",
+                json!({"id": 42, "name": "Jane Doe", "email": "jane@example.com"}),
+            ),
+            entry("rule.add", "2024-05-01T10:00:00Z", json!(null)),
+        ],
+        options: vec![],
+    };
+    let output = format_audit_log("acme", &["event rule.edit".to_string()], None, &log);
+    assert!(output.contains("**Filters:** event rule.edit\n**Entries:** 2"));
+    assert!(output.contains(
+        "| 2024-05-02T10:00:00Z | Jane Doe (jane@example.com) | rule.edit | 17 | edited rule \"High \\| low\" | 10.0.0.1 |"
+    ));
+    assert!(output.contains("| Sentry | rule.add |"));
+}
+
+#[test]
+fn test_format_audit_log_unknown_event() {
+    let log = AuditLog {
+        rows: vec![],
+        options: vec!["rule.add".to_string(), "rule.edit".to_string()],
+    };
+    let output = format_audit_log("acme", &[], Some("rule.change"), &log);
+    assert!(output.contains("No audit log entries match."));
+    assert!(output.contains(
+        "'rule.change' is not an audit log event type. Event types: rule.add, rule.edit"
+    ));
+    let output = format_audit_log("acme", &[], Some("rule.edit"), &log);
+    assert!(!output.contains("not an audit log event type"));
+}
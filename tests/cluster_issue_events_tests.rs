@@ -0,0 +1,116 @@
+use sentry_mcp::api_client::Event;
+use sentry_mcp::tools::cluster_issue_events::{
+    cluster_events, failure_path, format_clusters_output, frame_key,
+};
+use serde_json::json;
+
+fn event(id: &str, date: &str, exc_type: &str, frames: serde_json::Value) -> Event {
+    serde_json::from_value(json!({
+        "id": id,
+        "eventID": id,
+        "dateCreated": date,
+        "entries": [{
+            "type": "exception",
+            "data": {"values": [{
+                "type": exc_type,
+                "value": format!("{} happened", exc_type),
+                "stacktrace": {"frames": frames}
+            }]}
+        }]
+    }))
+    .unwrap()
+}
+
+fn checkout_frames(line: i64) -> serde_json::Value {
+    json!([
+        {"function": "dispatch", "module": "django.core.handlers", "inApp": false},
+        {"function": "checkout", "module": "shop.views", "lineNo": line, "inApp": true},
+        {"function": "charge", "module": "shop.billing", "lineNo": line + 10, "inApp": true}
+    ])
+}
+
+fn cart_frames() -> serde_json::Value {
+    json!([
+        {"function": "cart", "filename": "shop/views.py", "inApp": true},
+        {"function": "get", "filename": "vendor/cache.py", "inApp": false}
+    ])
+}
+
+#[test]
+fn test_frame_key() {
+    assert_eq!(
+        frame_key(&json!({"function": "charge", "module": "shop.billing"})),
+        "charge (shop.billing)"
+    );
+    assert_eq!(
+        frame_key(&json!({"function": "cart", "filename": "shop/views.py"})),
+        "cart (shop/views.py)"
+    );
+    assert_eq!(frame_key(&json!({})), "?");
+}
+
+#[test]
+fn test_failure_path_innermost_in_app_first() {
+    let e = event("e1", "2024-01-01T00:00:00Z", "KeyError", checkout_frames(5));
+    let path = failure_path(&e, 3, &[]);
+    assert_eq!(path.exception_type, "KeyError");
+    assert_eq!(
+        path.frames,
+        vec!["charge (shop.billing)", "checkout (shop.views)"]
+    );
+    assert_eq!(
+        failure_path(&e, 1, &[]).frames,
+        vec!["charge (shop.billing)"]
+    );
+    let vendored = failure_path(
+        &event("e2", "", "KeyError", cart_frames()),
+        3,
+        &["vendor/*".to_string()],
+    );
+    assert_eq!(
+        vendored.frames,
+        vec!["get (vendor/cache.py)", "cart (shop/views.py)"]
+    );
+}
+
+#[test]
+fn test_cluster_events_ignores_line_numbers() {
+    let events = vec![
+        event("e1", "2024-01-02T00:00:00Z", "KeyError", checkout_frames(5)),
+        event("e2", "2024-01-01T00:00:00Z", "TypeError", cart_frames()),
+        event("e3", "2024-01-03T00:00:00Z", "KeyError", checkout_frames(7)),
+    ];
+    let clusters = cluster_events(&events, 3, &[]);
+    assert_eq!(clusters.len(), 2);
+    assert_eq!(clusters[0].path.exception_type, "KeyError");
+    assert_eq!(clusters[0].events.len(), 2);
+    assert_eq!(clusters[1].events[0].event_id, "e2");
+}
+
+#[test]
+fn test_format_clusters_output() {
+    let events = vec![
+        event("e1", "2024-01-02T00:00:00Z", "KeyError", checkout_frames(5)),
+        event("e2", "2024-01-01T00:00:00Z", "TypeError", cart_frames()),
+        event("e3", "2024-01-03T00:00:00Z", "KeyError", checkout_frames(7)),
+    ];
+    let clusters = cluster_events(&events, 3, &[]);
+    let output = format_clusters_output("SHOP-1", &events, &clusters);
+    assert!(output.contains("**Distinct paths:** 2"));
+    assert!(output.contains("over-grouped"));
+    assert!(output.contains("## Path 1: KeyError (2 events, 67%)"));
+    assert!(output.contains("**Message:** KeyError happened"));
+    assert!(output.contains("**Seen:** 2024-01-02T00:00:00Z to 2024-01-03T00:00:00Z"));
+    assert!(output.contains("**Sample events:** e1, e3"));
+    assert!(output.contains("- `charge (shop.billing)`\n- `checkout (shop.views)`"));
+    assert!(output.contains("## Path 2: TypeError (1 events, 33%)"));
+}
+
+#[test]
+fn test_format_clusters_output_single_path() {
+    let events = vec![event("e1", "", "KeyError", json!([]))];
+    let clusters = cluster_events(&events, 3, &[]);
+    let output = format_clusters_output("SHOP-1", &events, &clusters);
+    assert!(output.contains("All analyzed events share one failure path."));
+    assert!(output.contains("No in-app frames."));
+}
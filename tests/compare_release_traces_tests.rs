@@ -0,0 +1,152 @@
+use sentry_mcp::api_client::TraceSpan;
+use sentry_mcp::tools::compare_release_traces::{
+    OpDiff, ReleaseSide, SampleTrace, diff_operations, format_release_comparison,
+    representative_query, sample_operations, side_query,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn ops(entries: &[(&str, i32, f64)]) -> HashMap<String, (i32, f64)> {
+    entries
+        .iter()
+        .map(|(op, count, total)| (op.to_string(), (*count, *total)))
+        .collect()
+}
+
+fn side(label: &str, p50_ms: f64, trace_id: Option<&str>) -> ReleaseSide {
+    ReleaseSide {
+        label: label.to_string(),
+        events: 100.0,
+        p50_ms,
+        sample: trace_id.map(|id| SampleTrace {
+            event_id: "e1".to_string(),
+            trace_id: id.to_string(),
+            duration_ms: p50_ms,
+        }),
+    }
+}
+
+#[test]
+fn test_side_query() {
+    assert_eq!(
+        side_query("GET /users", "1.2.0", false),
+        "event.type:transaction transaction:\"GET /users\" release:\"1.2.0\""
+    );
+    assert_eq!(
+        side_query("GET /users", "1.2.0", true),
+        "event.type:transaction transaction:\"GET /users\" !release:\"1.2.0\""
+    );
+}
+
+#[test]
+fn test_representative_query_rounds_down() {
+    assert_eq!(
+        representative_query("release:\"1.2.0\"", 250.9),
+        "release:\"1.2.0\" transaction.duration:>=250ms"
+    );
+}
+
+#[test]
+fn test_diff_operations_sorts_by_change() {
+    let before = ops(&[
+        ("db", 2, 40.0),
+        ("http.client", 1, 100.0),
+        ("cache", 3, 3.0),
+    ]);
+    let after = ops(&[
+        ("db", 12, 340.0),
+        ("http.client", 1, 90.0),
+        ("serialize", 1, 20.0),
+    ]);
+    let diffs = diff_operations(&before, &after);
+    let order: Vec<&str> = diffs.iter().map(|d| d.op.as_str()).collect();
+    assert_eq!(order, ["db", "serialize", "http.client", "cache"]);
+    assert_eq!(diffs[0].delta_ms(), 300.0);
+    assert_eq!(diffs[3].after, (0, 0.0));
+}
+
+#[test]
+fn test_sample_operations_uses_sampled_transaction() {
+    let spans: Vec<TraceSpan> = serde_json::from_value(json!([{
+        "event_id": "root",
+        "transaction_id": "front-tx",
+        "project_id": 1,
+        "project_slug": "frontend",
+        "parent_span_id": null,
+        "start_timestamp": 0.0,
+        "duration": 900.0,
+        "is_transaction": true,
+        "op": "pageload",
+        "children": [{
+            "event_id": "api",
+            "transaction_id": "api-tx",
+            "project_id": 2,
+            "project_slug": "backend",
+            "parent_span_id": "root",
+            "start_timestamp": 0.1,
+            "duration": 500.0,
+            "is_transaction": true,
+            "op": "http.server",
+            "children": [{
+                "event_id": "q",
+                "project_id": 2,
+                "project_slug": "backend",
+                "parent_span_id": "api",
+                "start_timestamp": 0.2,
+                "duration": 300.0,
+                "op": "db"
+            }]
+        }]
+    }]))
+    .unwrap();
+    let scoped = sample_operations(&spans, "api-tx");
+    assert_eq!(scoped.len(), 2);
+    assert_eq!(scoped["db"], (1, 300.0));
+    assert!(!scoped.contains_key("pageload"));
+    let whole = sample_operations(&spans, "missing");
+    assert_eq!(whole.len(), 3);
+}
+
+#[test]
+fn test_format_release_comparison() {
+    let diffs = vec![
+        OpDiff {
+            op: "db".to_string(),
+            before: (2, 40.0),
+            after: (12, 340.0),
+        },
+        OpDiff {
+            op: "cache".to_string(),
+            before: (3, 3.0),
+            after: (0, 0.0),
+        },
+    ];
+    let output = format_release_comparison(
+        "GET /users",
+        &side("release 1.1.0", 200.0, Some("aaa")),
+        &side("release 1.2.0", 500.0, Some("bbb")),
+        &diffs,
+    );
+    assert!(output.contains(
+        "**Before:** release 1.1.0 - 100 events, p50 200.00ms, sample trace aaa (200.00ms)\n"
+    ));
+    assert!(output.contains("**p50 Change:** +300.00ms (+150.0%)\n"));
+    assert!(output.contains("| db | 2 × 40.00ms | 12 × 340.00ms | +300.00ms |\n"));
+    assert!(output.contains("| cache | 3 × 3.00ms | - | -3.00ms |\n"));
+    assert!(output.contains("get_trace_details with aaa (before) or bbb (after)"));
+}
+
+#[test]
+fn test_format_release_comparison_without_sample() {
+    let mut before = side("other releases", 0.0, None);
+    before.events = 0.0;
+    let output = format_release_comparison(
+        "GET /users",
+        &before,
+        &side("release 1.2.0", 500.0, Some("bbb")),
+        &[],
+    );
+    assert!(output.contains("**Before:** other releases - no events\n"));
+    assert!(output.contains("No trace to compare on both sides"));
+    assert!(!output.contains("## Operations"));
+}
@@ -0,0 +1,87 @@
+use sentry_mcp::config::{
+    ApiCompat, ToolPolicies, ToolPolicy, parse_duration, parse_flag, parse_list, parse_output_style,
+};
+use sentry_mcp::output_style::OutputStyle;
+use std::time::Duration;
+
+#[test]
+fn test_parse_flag_truthy() {
+    for value in ["1", "true", "TRUE", "yes", "on", " True "] {
+        assert!(parse_flag(value), "{} should be truthy", value);
+    }
+}
+
+#[test]
+fn test_parse_flag_falsy() {
+    for value in ["", "0", "false", "no", "off", "enabled"] {
+        assert!(!parse_flag(value), "{} should be falsy", value);
+    }
+}
+
+#[test]
+fn test_parse_list_trims_and_drops_blanks() {
+    assert_eq!(
+        parse_list(" vendor/acme/ , @acme/*,, "),
+        vec!["vendor/acme/".to_string(), "@acme/*".to_string()]
+    );
+    assert!(parse_list("").is_empty());
+}
+
+#[test]
+fn test_parse_duration_units() {
+    assert_eq!(parse_duration("500ms"), Some(Duration::from_millis(500)));
+    assert_eq!(parse_duration("10s"), Some(Duration::from_secs(10)));
+    assert_eq!(parse_duration("2m"), Some(Duration::from_secs(120)));
+    assert_eq!(parse_duration(" 45 "), Some(Duration::from_secs(45)));
+    assert_eq!(parse_duration("0s"), None);
+    assert_eq!(parse_duration("soon"), None);
+}
+
+#[test]
+fn test_tool_policies_per_tool_and_default() {
+    let policies = ToolPolicies::parse("*=30s, get_trace_details=60s/1,get_issue_details=10s/3");
+    assert_eq!(
+        policies.get("get_trace_details"),
+        ToolPolicy {
+            timeout: Some(Duration::from_secs(60)),
+            retries: 1
+        }
+    );
+    assert_eq!(policies.get("get_issue_details").retries, 3);
+    assert_eq!(
+        policies.get("list_issues"),
+        ToolPolicy {
+            timeout: Some(Duration::from_secs(30)),
+            retries: 0
+        }
+    );
+}
+
+#[test]
+fn test_tool_policies_retries_only_and_malformed_entries() {
+    let policies = ToolPolicies::parse("list_issues=/2,bad,get_trace_details=fast,=5s");
+    assert_eq!(
+        policies.get("list_issues"),
+        ToolPolicy {
+            timeout: None,
+            retries: 2
+        }
+    );
+    assert_eq!(policies.get("get_trace_details"), ToolPolicy::default());
+    assert_eq!(ToolPolicies::parse(""), ToolPolicies::default());
+}
+
+#[test]
+fn test_api_compat_parse() {
+    assert_eq!(ApiCompat::parse(""), Some(ApiCompat::Auto));
+    assert_eq!(ApiCompat::parse("Legacy"), Some(ApiCompat::Legacy));
+    assert_eq!(ApiCompat::parse("modern"), Some(ApiCompat::Modern));
+    assert_eq!(ApiCompat::parse("9.x"), None);
+}
+
+#[test]
+fn test_parse_output_style_falls_back_to_markdown() {
+    assert_eq!(parse_output_style(" Plain "), OutputStyle::Plain);
+    assert_eq!(parse_output_style("minimal"), OutputStyle::Minimal);
+    assert_eq!(parse_output_style("html"), OutputStyle::Markdown);
+}
@@ -0,0 +1,91 @@
+use sentry_mcp::api_client::SessionStats;
+use sentry_mcp::tools::crash_free_sessions::{
+    HealthRow, format_crash_free_sessions, health_rows, parse_group_by,
+};
+use serde_json::json;
+
+fn group_by(keys: &[&str]) -> Vec<String> {
+    keys.iter().map(|k| k.to_string()).collect()
+}
+
+#[test]
+fn test_parse_group_by() {
+    assert_eq!(parse_group_by(None).unwrap(), ["release"]);
+    assert_eq!(parse_group_by(Some(&[])).unwrap(), ["release"]);
+    let keys = group_by(&["Environment", "release", "environment"]);
+    assert_eq!(
+        parse_group_by(Some(&keys)).unwrap(),
+        ["environment", "release"]
+    );
+    let err = parse_group_by(Some(&group_by(&["project"]))).unwrap_err();
+    assert!(err.contains("Unknown group_by 'project'"));
+}
+
+#[test]
+fn test_health_rows() {
+    let stats: SessionStats = serde_json::from_value(json!({
+        "intervals": [],
+        "groups": [
+            {
+                "by": {"release": "1.2.0", "environment": "production"},
+                "totals": {
+                    "sum(session)": 1200,
+                    "count_unique(user)": 300,
+                    "crash_free_rate(session)": 0.995,
+                    "crash_free_rate(user)": 0.99
+                }
+            },
+            {
+                "by": {"release": "1.1.0"},
+                "totals": {"sum(session)": 0, "crash_free_rate(session)": null}
+            }
+        ]
+    }))
+    .unwrap();
+    let rows = health_rows(&stats, &group_by(&["release", "environment"]));
+    assert_eq!(rows[0].keys, ["1.2.0", "production"]);
+    assert_eq!(rows[0].sessions, 1200.0);
+    assert_eq!(rows[0].users, Some(300.0));
+    assert_eq!(rows[0].crash_free_sessions, Some(0.995));
+    assert_eq!(rows[1].keys, ["1.1.0", "-"]);
+    assert_eq!(rows[1].users, None);
+    assert_eq!(rows[1].crash_free_sessions, None);
+}
+
+#[test]
+fn test_format_crash_free_sessions() {
+    let rows = vec![
+        HealthRow {
+            keys: vec!["1.2.0".to_string()],
+            sessions: 1200.0,
+            users: Some(300.0),
+            crash_free_sessions: Some(0.995),
+            crash_free_users: Some(0.99),
+        },
+        HealthRow {
+            keys: vec!["1.1.0".to_string()],
+            sessions: 0.0,
+            users: None,
+            crash_free_sessions: None,
+            crash_free_users: None,
+        },
+    ];
+    let filters = vec!["environment production".to_string()];
+    let output =
+        format_crash_free_sessions("backend", "14d", &filters, &group_by(&["release"]), &rows);
+    assert!(output.contains("# Crash-Free Sessions: backend"));
+    assert!(output.contains("**Filters:** environment production"));
+    assert!(output.contains(
+        "| Release | Sessions | Users | Crash-Free Sessions | Crash-Free Users |\n|---|---|---|---|---|\n"
+    ));
+    assert!(output.contains("| 1.2.0 | 1200 | 300 | 99.50% | 99.00% |"));
+    assert!(output.contains("| 1.1.0 | 0 | - | - | - |"));
+}
+
+#[test]
+fn test_format_crash_free_sessions_empty() {
+    let output = format_crash_free_sessions("acme", "24h", &[], &group_by(&["release"]), &[]);
+    assert!(output.contains("No session data"));
+    assert!(!output.contains("**Filters:**"));
+    assert!(!output.contains("| Release |"));
+}
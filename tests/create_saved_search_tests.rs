@@ -0,0 +1,63 @@
+use sentry_mcp::api_client::SavedSearch;
+use sentry_mcp::tools::create_saved_search::{
+    CreateSavedSearchInput, build_saved_search, format_saved_search_output,
+};
+
+fn input(name: &str, query: &str) -> CreateSavedSearchInput {
+    CreateSavedSearchInput {
+        organization_slug: "org".to_string(),
+        name: name.to_string(),
+        query: query.to_string(),
+        sort: None,
+        visibility: None,
+    }
+}
+
+#[test]
+fn test_build_saved_search_defaults() {
+    let search = build_saved_search(&input("Mine", " is:unresolved ")).unwrap();
+    assert_eq!(search.search_type, 0);
+    assert_eq!(search.query, "is:unresolved");
+    assert_eq!(search.sort, None);
+    assert_eq!(search.visibility, "organization");
+}
+
+#[test]
+fn test_build_saved_search_owner_visibility() {
+    let search = build_saved_search(&CreateSavedSearchInput {
+        visibility: Some("Owner".to_string()),
+        ..input("Mine", "is:unresolved")
+    })
+    .unwrap();
+    assert_eq!(search.visibility, "owner");
+}
+
+#[test]
+fn test_build_saved_search_rejects_invalid() {
+    assert!(build_saved_search(&input("", "is:unresolved")).is_err());
+    assert!(build_saved_search(&input("Name", "  ")).is_err());
+    assert!(
+        build_saved_search(&CreateSavedSearchInput {
+            visibility: Some("public".to_string()),
+            ..input("Name", "is:unresolved")
+        })
+        .is_err()
+    );
+}
+
+#[test]
+fn test_format_saved_search_output() {
+    let search = SavedSearch {
+        id: "12".to_string(),
+        name: "Hot".to_string(),
+        query: "is:unresolved times_seen:>100".to_string(),
+        sort: Some("freq".to_string()),
+        visibility: Some("owner".to_string()),
+        date_created: None,
+    };
+    let output = format_saved_search_output(&search);
+    assert!(output.contains("**ID:** 12"));
+    assert!(output.contains("**Query:** is:unresolved times_seen:>100"));
+    assert!(output.contains("**Sort:** freq"));
+    assert!(output.contains("**Visibility:** owner"));
+}
@@ -0,0 +1,39 @@
+use rmcp::ErrorData as McpError;
+use sentry_mcp::tools::cross_org::{demote_headings, format_org_sections};
+
+#[test]
+fn test_demote_headings() {
+    let text = "# Title\n\n**Bold**\n## Section\nplain";
+    assert_eq!(
+        demote_headings(text),
+        "### Title\n\n**Bold**\n#### Section\nplain"
+    );
+}
+
+#[test]
+fn test_format_org_sections_mixed_results() {
+    let sections = vec![
+        (
+            "acme".to_string(),
+            Ok("# Issue Events\n\nFound\n".to_string()),
+        ),
+        (
+            "globex".to_string(),
+            Err(McpError::internal_error("404 Not Found", None)),
+        ),
+    ];
+    let output = format_org_sections(&sections);
+    assert!(output.starts_with("# Cross-Organization Results"));
+    assert!(output.contains("**Organizations:** 2"));
+    assert!(output.contains("**Failed:** 1"));
+    assert!(output.contains("## Organization: acme\n\n### Issue Events\n\nFound\n"));
+    assert!(output.contains("## Organization: globex\n\n*Error: 404 Not Found*"));
+}
+
+#[test]
+fn test_format_org_sections_empty() {
+    let output = format_org_sections(&[]);
+    assert!(output.contains("**Organizations:** 0"));
+    assert!(!output.contains("**Failed:**"));
+    assert!(output.contains("The token has no accessible organizations."));
+}
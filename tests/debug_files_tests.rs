@@ -0,0 +1,97 @@
+use sentry_mcp::api_client::{ArtifactBundle, DebugFile, ReleaseFile};
+use sentry_mcp::tools::debug_files::{
+    ReleaseTarget, SourceMapStatus, bundle_matches, format_debug_files_output, source_map_status,
+};
+use serde_json::json;
+
+fn target(dist: Option<&str>) -> ReleaseTarget {
+    ReleaseTarget {
+        version: "web@1.0".to_string(),
+        dist: dist.map(str::to_string),
+        event_id: None,
+    }
+}
+
+fn bundles(value: serde_json::Value) -> Vec<ArtifactBundle> {
+    serde_json::from_value(value).unwrap()
+}
+
+fn release_files(value: serde_json::Value) -> Vec<ReleaseFile> {
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn test_bundle_matches_release_and_dist() {
+    let bundle = &bundles(json!([{
+        "bundleId": "b1",
+        "associations": [{"release": "web@1.0", "dist": ""}, {"release": "web@1.1", "dist": "9"}]
+    }]))[0];
+    assert!(bundle_matches(bundle, "web@1.0", None));
+    assert!(!bundle_matches(bundle, "web@1.0", Some("9")));
+    assert!(bundle_matches(bundle, "web@1.1", Some("9")));
+    assert!(!bundle_matches(bundle, "web@2.0", None));
+}
+
+#[test]
+fn test_source_map_status() {
+    let for_release =
+        bundles(json!([{"bundleId": "b1", "associations": [{"release": "web@1.0"}]}]));
+    assert_eq!(
+        source_map_status(&target(None), &for_release, &[]),
+        SourceMapStatus::Uploaded
+    );
+    assert_eq!(
+        source_map_status(&target(Some("3")), &for_release, &[]),
+        SourceMapStatus::OtherDist
+    );
+    assert_eq!(
+        source_map_status(&target(None), &[], &[]),
+        SourceMapStatus::Missing
+    );
+    let minified_only = release_files(json!([{"id": "1", "name": "~/app.min.js", "size": 10}]));
+    assert_eq!(
+        source_map_status(&target(None), &[], &minified_only),
+        SourceMapStatus::NoMaps
+    );
+    let with_map =
+        release_files(json!([{"id": "1", "name": "~/app.min.js.map", "dist": "3", "size": 10}]));
+    assert_eq!(
+        source_map_status(&target(Some("3")), &[], &with_map),
+        SourceMapStatus::Uploaded
+    );
+}
+
+#[test]
+fn test_format_debug_files_output_missing_source_maps() {
+    let debug_files: Vec<DebugFile> = serde_json::from_value(json!([{
+        "id": "1",
+        "debugId": "3249d99d-0c40-3931-8610-f4e4fb0b6b95",
+        "objectName": "libapp.so",
+        "symbolType": "elf",
+        "cpuName": "arm64",
+        "size": 2048,
+        "data": {"features": ["debug", "symtab"]}
+    }]))
+    .unwrap();
+    let output = format_debug_files_output("web", Some(&target(None)), &debug_files, &[], &[], 25);
+    assert!(output.contains("**Release:** web@1.0\n"));
+    assert!(output.contains("No source map artifacts were uploaded for release web@1.0"));
+    assert!(output.contains("`sentry-cli sourcemaps upload --release web@1.0 <build dir>`"));
+    assert!(output.contains("**Artifact Bundles:** 0\n"));
+    assert!(output.contains("**Release Files:** 0\n"));
+    assert!(output.contains(
+        "| 3249d99d-0c40-3931-8610-f4e4fb0b6b95 | libapp.so | elf | arm64 | debug, symtab | 2048 |"
+    ));
+}
+
+#[test]
+fn test_format_debug_files_output_without_release() {
+    let all = bundles(
+        json!([{"bundleId": "b1", "associations": [{"release": "web@1.0"}], "fileCount": 2}]),
+    );
+    let output = format_debug_files_output("web", None, &[], &all, &[], 25);
+    assert!(!output.contains("**Release:**"));
+    assert!(!output.contains("**Release Files:**"));
+    assert!(output.contains("| b1 | web@1.0 | 2 |  |"));
+    assert!(output.contains("No debug information files uploaded."));
+}
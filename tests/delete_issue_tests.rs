@@ -0,0 +1,42 @@
+use sentry_mcp::api_client::Issue;
+use sentry_mcp::tools::delete_issue::{check_confirmation, format_delete_output};
+use serde_json::json;
+
+fn issue() -> Issue {
+    serde_json::from_value(json!({
+        "id": "42",
+        "shortId": "API-7",
+        "title": "TypeError: boom",
+        "culprit": null,
+        "status": "unresolved",
+        "platform": "node",
+        "project": {"id": "1", "name": "API", "slug": "api"},
+        "count": "1200",
+        "userCount": 3,
+        "permalink": null
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_check_confirmation_accepts_short_id_any_case() {
+    assert!(check_confirmation(&issue(), "API-7").is_ok());
+    assert!(check_confirmation(&issue(), " api-7 ").is_ok());
+}
+
+#[test]
+fn test_check_confirmation_rejects_other_values() {
+    for confirm in ["", "42", "API-70", "yes"] {
+        let err = check_confirmation(&issue(), confirm).unwrap_err();
+        assert!(err.message.contains("confirm_short_id must be API-7"));
+        assert!(err.message.contains("\"TypeError: boom\" (1200 events)"));
+    }
+}
+
+#[test]
+fn test_format_delete_output() {
+    let output = format_delete_output(&issue());
+    assert!(output.contains("**Issue:** API-7 - TypeError: boom"));
+    assert!(output.contains("**Events:** 1200"));
+    assert!(output.contains("cannot be undone"));
+}
@@ -0,0 +1,73 @@
+use sentry_mcp::api_client::DiscoverResult;
+use sentry_mcp::tools::discover_query::{
+    DiscoverQueryInput, MAX_FIELDS, format_discover_query_output, format_typed_cell,
+    validate_discover_input,
+};
+use serde_json::json;
+
+fn input(fields: Vec<String>) -> DiscoverQueryInput {
+    DiscoverQueryInput {
+        organization_slug: "acme".to_string(),
+        fields,
+        query: None,
+        orderby: None,
+        stats_period: None,
+        start: None,
+        end: None,
+        project_slugs: None,
+        environment: None,
+        dataset: None,
+        limit: None,
+    }
+}
+
+#[test]
+fn test_validate_trims_and_limits_fields() {
+    let fields = validate_discover_input(&input(vec![" count() ".into(), "".into()])).unwrap();
+    assert_eq!(fields, vec!["count()"]);
+    assert!(validate_discover_input(&input(vec![])).is_err());
+    let too_many = (0..=MAX_FIELDS).map(|i| format!("tag{}", i)).collect();
+    assert!(validate_discover_input(&input(too_many)).is_err());
+}
+
+#[test]
+fn test_validate_requires_start_with_end() {
+    let mut partial = input(vec!["count()".into()]);
+    partial.start = Some("2024-05-01T00:00:00Z".into());
+    let err = validate_discover_input(&partial).unwrap_err();
+    assert!(err.message.contains("start and end"));
+}
+
+#[test]
+fn test_format_typed_cell_units() {
+    assert_eq!(
+        format_typed_cell(&json!(250), Some("duration"), Some("millisecond")),
+        "250.0ms"
+    );
+    assert_eq!(
+        format_typed_cell(&json!(2.5), Some("duration"), Some("second")),
+        "2.50s"
+    );
+    assert_eq!(
+        format_typed_cell(&json!(0.125), Some("percentage"), None),
+        "12.5%"
+    );
+    assert_eq!(format_typed_cell(&json!(42), Some("integer"), None), "42");
+    assert_eq!(
+        format_typed_cell(&json!("a|b"), Some("string"), None),
+        "a\\|b"
+    );
+}
+
+#[test]
+fn test_format_output_reads_flat_meta() {
+    let result: DiscoverResult = serde_json::from_value(json!({
+        "data": [{"failure_rate()": 0.05, "title": "GET /"}],
+        "meta": {"failure_rate()": "percentage", "title": "string"}
+    }))
+    .unwrap();
+    let fields = vec!["title".to_string(), "failure_rate()".to_string()];
+    let output = format_discover_query_output(&fields, &input(fields.clone()), &result);
+    assert!(output.contains("**Period:** 14d"));
+    assert!(output.contains("| title | failure_rate() |\n|---|---|\n| GET / | 5.0% |"));
+}
@@ -0,0 +1,129 @@
+use sentry_mcp::api_client::{DiscoverResult, SavedQuery};
+use sentry_mcp::tools::discover_saved_queries::{
+    dataset_param, format_cell, format_discover_table, format_saved_queries_list,
+    format_saved_query_result, saved_to_discover,
+};
+use serde_json::json;
+
+fn make_saved(value: serde_json::Value) -> SavedQuery {
+    serde_json::from_value(value).unwrap()
+}
+
+fn make_result(rows: serde_json::Value) -> DiscoverResult {
+    serde_json::from_value(json!({"data": rows, "meta": {}})).unwrap()
+}
+
+#[test]
+fn test_format_cell_values() {
+    assert_eq!(format_cell(&json!(null)), "");
+    assert_eq!(format_cell(&json!("a|b")), "a\\|b");
+    assert_eq!(format_cell(&json!(42)), "42");
+    assert_eq!(format_cell(&json!(1.23456)), "1.23");
+    assert_eq!(format_cell(&json!(2.0)), "2.0");
+    assert_eq!(format_cell(&json!(["x", 1])), "x, 1");
+}
+
+#[test]
+fn test_format_discover_table_column_order() {
+    let mut output = String::new();
+    let fields = vec!["transaction".to_string(), "count()".to_string()];
+    let result = make_result(json!([{"count()": 7, "transaction": "/api/users"}]));
+    format_discover_table(&mut output, &fields, &result);
+    assert!(output.contains("| transaction | count() |"));
+    assert!(output.contains("|---|---|"));
+    assert!(output.contains("| /api/users | 7 |"));
+}
+
+#[test]
+fn test_format_discover_table_empty() {
+    let mut output = String::new();
+    format_discover_table(&mut output, &["a".to_string()], &DiscoverResult::default());
+    assert_eq!(output, "No results.\n");
+}
+
+#[test]
+fn test_dataset_param_mapping() {
+    assert_eq!(
+        dataset_param(Some("error-events")).as_deref(),
+        Some("errors")
+    );
+    assert_eq!(
+        dataset_param(Some("transaction-like")).as_deref(),
+        Some("transactions")
+    );
+    assert_eq!(dataset_param(Some("discover")), None);
+    assert_eq!(dataset_param(None), None);
+}
+
+#[test]
+fn test_saved_to_discover_relative_range() {
+    let saved = make_saved(json!({
+        "id": "12",
+        "name": "Slow endpoints",
+        "fields": ["transaction", "p95()"],
+        "query": "event.type:transaction",
+        "orderby": "-p95",
+        "range": "7d",
+        "environment": ["production"],
+        "projects": [3, 4],
+        "queryDataset": "transaction-like"
+    }));
+    let query = saved_to_discover(&saved, 25);
+    assert_eq!(query.fields, vec!["transaction", "p95()"]);
+    assert_eq!(query.stats_period.as_deref(), Some("7d"));
+    assert!(query.start.is_none());
+    assert_eq!(query.projects, vec!["3", "4"]);
+    assert_eq!(query.environments, vec!["production"]);
+    assert_eq!(query.dataset.as_deref(), Some("transactions"));
+    assert_eq!(query.per_page, Some(25));
+    let params = query.to_params();
+    assert_eq!(params.iter().filter(|(k, _)| *k == "field").count(), 2);
+    assert!(params.contains(&("sort", "-p95".to_string())));
+}
+
+#[test]
+fn test_saved_to_discover_absolute_range() {
+    let saved = make_saved(json!({
+        "id": "1",
+        "name": "q",
+        "fields": ["title"],
+        "start": "2024-01-01T00:00:00",
+        "end": "2024-01-02T00:00:00"
+    }));
+    let query = saved_to_discover(&saved, 10);
+    assert!(query.stats_period.is_none());
+    assert_eq!(query.start.as_deref(), Some("2024-01-01T00:00:00"));
+    assert_eq!(query.end.as_deref(), Some("2024-01-02T00:00:00"));
+}
+
+#[test]
+fn test_format_saved_queries_list() {
+    let queries = vec![make_saved(json!({
+        "id": "12",
+        "name": "Errors by release",
+        "fields": ["release", "count()"],
+        "query": "event.type:error",
+        "queryDataset": "error-events"
+    }))];
+    let output = format_saved_queries_list(&queries);
+    assert!(output.contains("**Found:** 1 queries"));
+    assert!(output.contains(
+        "| 12 | Errors by release | error-events | event.type:error | release, count() |"
+    ));
+}
+
+#[test]
+fn test_format_saved_query_result() {
+    let saved = make_saved(json!({
+        "id": "12",
+        "name": "Errors by release",
+        "fields": ["release", "count()"],
+        "range": "24h"
+    }));
+    let result = make_result(json!([{"release": "1.0", "count()": 3}]));
+    let output = format_saved_query_result(&saved, &result);
+    assert!(output.contains("# Saved Query: Errors by release"));
+    assert!(output.contains("**Period:** 24h"));
+    assert!(output.contains("**Rows:** 1"));
+    assert!(output.contains("| 1.0 | 3 |"));
+}
@@ -0,0 +1,95 @@
+use chrono::{TimeZone, Utc};
+use sentry_mcp::api_client::{Issue, Project};
+use sentry_mcp::tools::escalating_issues::{
+    events_last_24h, forecast_for_day, format_escalating_output,
+};
+use serde_json::json;
+
+fn make_issue(stats: serde_json::Value, forecast: Option<serde_json::Value>) -> Issue {
+    Issue {
+        id: "1".to_string(),
+        short_id: "BACK-9".to_string(),
+        title: "DatabaseError | pool exhausted".to_string(),
+        culprit: None,
+        status: "unresolved".to_string(),
+        status_details: serde_json::json!({}),
+        substatus: Some("escalating".to_string()),
+        level: Some("error".to_string()),
+        priority: None,
+        platform: None,
+        project: Project {
+            id: "1".to_string(),
+            name: "backend".to_string(),
+            slug: "backend".to_string(),
+        },
+        first_seen: None,
+        last_seen: Some("2024-01-10T11:00:00Z".to_string()),
+        count: "900".to_string(),
+        user_count: 12,
+        permalink: None,
+        metadata: json!({}),
+        tags: vec![],
+        issue_type: None,
+        issue_category: None,
+        assigned_to: None,
+        stats,
+        forecast,
+        is_bookmarked: None,
+        is_subscribed: None,
+        annotations: vec![],
+    }
+}
+
+#[test]
+fn test_events_last_24h_sums_buckets() {
+    let issue = make_issue(json!({"24h": [[1, 5], [2, 0], [3, 12]]}), None);
+    assert_eq!(events_last_24h(&issue), Some(17));
+}
+
+#[test]
+fn test_events_last_24h_missing_stats() {
+    let issue = make_issue(json!({}), None);
+    assert_eq!(events_last_24h(&issue), None);
+}
+
+#[test]
+fn test_forecast_for_day_indexes_by_days_since_added() {
+    let forecast = json!({"data": [10, 20, 30], "date_added": "2024-01-08T00:00:00Z"});
+    let issue = make_issue(json!({}), Some(forecast));
+    let now = Utc.with_ymd_and_hms(2024, 1, 9, 15, 0, 0).unwrap();
+    assert_eq!(forecast_for_day(&issue, now), Some(20));
+}
+
+#[test]
+fn test_forecast_for_day_clamps_to_last_value() {
+    let forecast = json!({"data": [10, 20, 30], "date_added": 1704672000.0});
+    let issue = make_issue(json!({}), Some(forecast));
+    let now = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+    assert_eq!(forecast_for_day(&issue, now), Some(30));
+}
+
+#[test]
+fn test_forecast_for_day_without_forecast() {
+    let issue = make_issue(json!({}), None);
+    assert_eq!(forecast_for_day(&issue, Utc::now()), None);
+}
+
+#[test]
+fn test_format_escalating_output_empty() {
+    let output = format_escalating_output("backend", &[], Utc::now());
+    assert!(output.contains("# Escalating Issues"));
+    assert!(output.contains("**Found:** 0 issues"));
+    assert!(output.contains("No issues are currently escalating."));
+}
+
+#[test]
+fn test_format_escalating_output_table() {
+    let forecast = json!({"data": [50], "date_added": "2024-01-10T00:00:00Z"});
+    let issue = make_issue(json!({"24h": [[1, 80], [2, 40]]}), Some(forecast));
+    let now = Utc.with_ymd_and_hms(2024, 1, 10, 12, 0, 0).unwrap();
+    let output = format_escalating_output("backend", &[issue], now);
+    assert!(output.contains("| Issue | Title | Last 24h | Forecast Today | Users | Last Seen |"));
+    assert!(output.contains(
+        "| BACK-9 | DatabaseError \\| pool exhausted | 120 | 50 | 12 | 2024-01-10T11:00:00Z |"
+    ));
+}
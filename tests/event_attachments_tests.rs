@@ -0,0 +1,103 @@
+use sentry_mcp::api_client::EventAttachment;
+use sentry_mcp::tools::event_attachments::{
+    AttachmentRef, attachment_uri, format_attachments_output, is_text_attachment, looks_binary,
+    parse_attachment_uri,
+};
+
+fn make_attachment(id: &str, name: &str, mimetype: Option<&str>, size: u64) -> EventAttachment {
+    EventAttachment {
+        id: id.to_string(),
+        name: name.to_string(),
+        attachment_type: "event.attachment".to_string(),
+        size,
+        mimetype: mimetype.map(str::to_string),
+    }
+}
+
+#[test]
+fn test_attachment_uri_round_trip() {
+    let uri = attachment_uri("acme", "web", "abc123", "42");
+    assert_eq!(uri, "sentry-attachment://acme/web/abc123/42");
+    assert_eq!(
+        parse_attachment_uri(&uri),
+        Some(AttachmentRef {
+            organization_slug: "acme".to_string(),
+            project_slug: "web".to_string(),
+            event_id: "abc123".to_string(),
+            attachment_id: "42".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_parse_attachment_uri_rejects_other_uris() {
+    assert!(parse_attachment_uri("file:///etc/passwd").is_none());
+    assert!(parse_attachment_uri("sentry-attachment://acme/web/abc123").is_none());
+    assert!(parse_attachment_uri("sentry-attachment://acme//abc123/42").is_none());
+}
+
+#[test]
+fn test_is_text_attachment() {
+    assert!(is_text_attachment(&make_attachment(
+        "1",
+        "app.log",
+        Some("text/plain"),
+        10
+    )));
+    assert!(is_text_attachment(&make_attachment(
+        "1",
+        "state.json",
+        Some("application/json"),
+        10
+    )));
+    assert!(is_text_attachment(&make_attachment(
+        "1",
+        "app.log",
+        Some("application/octet-stream"),
+        10
+    )));
+    assert!(is_text_attachment(&make_attachment(
+        "1",
+        "notes.TXT",
+        None,
+        10
+    )));
+    assert!(!is_text_attachment(&make_attachment(
+        "1",
+        "screen.png",
+        Some("image/png"),
+        10
+    )));
+    assert!(!is_text_attachment(&make_attachment(
+        "1", "blob.bin", None, 10
+    )));
+    let mut minidump = make_attachment("1", "crash.txt", None, 10);
+    minidump.attachment_type = "event.minidump".to_string();
+    assert!(!is_text_attachment(&minidump));
+}
+
+#[test]
+fn test_looks_binary() {
+    assert!(looks_binary(b"PNG\0\x01"));
+    assert!(!looks_binary(b"plain text\n"));
+}
+
+#[test]
+fn test_format_attachments_output_inlines_text() {
+    let log = make_attachment("1", "app.log", Some("text/plain"), 12);
+    let screenshot = make_attachment("2", "screen.png", Some("image/png"), 204_800);
+    let attachments = vec![log.clone(), screenshot];
+    let inlined = vec![(&log, "boot ```ok```".to_string())];
+    let output = format_attachments_output("abc123", &attachments, &inlined);
+    assert!(output.contains("# Attachments of Event abc123"));
+    assert!(output.contains("| 1 | app.log | event.attachment | text/plain | 12 B |"));
+    assert!(output.contains("| 2 | screen.png | event.attachment | image/png | 200.0 KB |"));
+    assert!(output.contains("## app.log\n\n````\nboot ```ok```\n````\n"));
+    assert!(output.contains("1 attachments are returned as `sentry-attachment://` resource links"));
+}
+
+#[test]
+fn test_format_attachments_output_empty() {
+    let output = format_attachments_output("abc123", &[], &[]);
+    assert!(output.contains("No attachments on this event."));
+}
@@ -0,0 +1,91 @@
+use sentry_mcp::api_client::{Event, Issue};
+use sentry_mcp::tools::fatal_issues::{crash_frame, fatal_query, format_fatal_issues};
+use serde_json::json;
+
+fn make_issue() -> Issue {
+    serde_json::from_value(json!({
+        "id": "1",
+        "shortId": "IOS-9",
+        "title": "EXC_BAD_ACCESS",
+        "status": "unresolved",
+        "substatus": "new",
+        "level": "fatal",
+        "project": {"id": "1", "name": "iOS", "slug": "ios"},
+        "count": "12",
+        "userCount": 8,
+        "lastSeen": "2024-05-01T10:00:00Z"
+    }))
+    .unwrap()
+}
+
+fn make_event(frames: serde_json::Value) -> Event {
+    serde_json::from_value(json!({
+        "id": "e1",
+        "eventID": "e1",
+        "platform": "python",
+        "entries": [{
+            "type": "exception",
+            "data": {"values": [
+                {"type": "OSError", "value": "cause", "stacktrace": {"frames": []}},
+                {"type": "SystemExit", "value": "worker died", "stacktrace": {"frames": frames}}
+            ]}
+        }]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_fatal_query() {
+    assert_eq!(fatal_query(6), "level:fatal lastSeen:-6h");
+}
+
+#[test]
+fn test_crash_frame_prefers_in_app() {
+    let event = make_event(json!([
+        {"filename": "app/worker.py", "lineNo": 10, "function": "run", "inApp": true},
+        {"filename": "lib/pool.py", "lineNo": 99, "function": "join", "inApp": false}
+    ]));
+    let frame = crash_frame(&event, &[]).unwrap();
+    assert_eq!(frame["filename"], "app/worker.py");
+    let frame = crash_frame(&event, &["lib/".to_string()]).unwrap();
+    assert_eq!(frame["filename"], "lib/pool.py");
+}
+
+#[test]
+fn test_crash_frame_falls_back_to_innermost() {
+    let event = make_event(json!([
+        {"filename": "lib/a.py", "lineNo": 1, "inApp": false},
+        {"filename": "lib/b.py", "lineNo": 2, "inApp": false}
+    ]));
+    assert_eq!(crash_frame(&event, &[]).unwrap()["filename"], "lib/b.py");
+    assert!(crash_frame(&make_event(json!([])), &[]).is_none());
+}
+
+#[test]
+fn test_format_fatal_issues() {
+    let event = make_event(json!([{
+        "filename": "app/worker.py", "lineNo": 10, "function": "run", "inApp": true,
+        "context": [[9, "def run():"], [10, "    os._exit(1)"]]
+    }]));
+    let output = format_fatal_issues(
+        "ios",
+        24,
+        &[(make_issue(), Some(event)), (make_issue(), None)],
+        &[],
+    );
+    assert!(output.contains("**Window:** last 24h\n"));
+    assert!(output.contains("**Found:** 2 issues"));
+    assert!(output.contains("## IOS-9: EXC_BAD_ACCESS\n"));
+    assert!(output.contains("**Status:** new | **Events:** 12 | **Users:** 8"));
+    assert!(output.contains("**Exception:** SystemExit: worker died\n"));
+    assert!(
+        output.contains("```\nFile \"app/worker.py\", line 10, in run\n    os._exit(1)\n```\n")
+    );
+    assert!(output.contains("Latest event unavailable."));
+}
+
+#[test]
+fn test_format_fatal_issues_empty() {
+    let output = format_fatal_issues("acme", 6, &[], &[]);
+    assert!(output.contains("No fatal issues seen in the last 6 hours."));
+}
@@ -0,0 +1,94 @@
+use sentry_mcp::format::{
+    Writer, count_label, escape_cell, estimate_tokens, field, format_duration, format_rate,
+    key_values, opt_field, truncate_chars,
+};
+
+#[test]
+fn test_format_duration_milliseconds() {
+    assert_eq!(format_duration(100.0), "100.00ms");
+    assert_eq!(format_duration(0.5), "0.50ms");
+    assert_eq!(format_duration(999.99), "999.99ms");
+}
+
+#[test]
+fn test_format_duration_seconds() {
+    assert_eq!(format_duration(1000.0), "1.00s");
+    assert_eq!(format_duration(2500.0), "2.50s");
+    assert_eq!(format_duration(10000.0), "10.00s");
+}
+
+#[test]
+fn test_estimate_tokens() {
+    assert_eq!(estimate_tokens(""), 0);
+    assert_eq!(estimate_tokens("abcd"), 1);
+    assert_eq!(estimate_tokens("abcde"), 2);
+}
+
+#[test]
+fn test_truncate_chars() {
+    assert_eq!(truncate_chars("short", 10), "short");
+    assert_eq!(truncate_chars("exactly10!", 10), "exactly10!");
+    assert_eq!(truncate_chars("much longer text", 10), "much lo...");
+    assert_eq!(truncate_chars("ééééé", 4), "é...");
+}
+
+#[test]
+fn test_key_values() {
+    assert_eq!(
+        key_values([("env", "prod"), ("release", "1.0")]),
+        "env=prod, release=1.0"
+    );
+    assert_eq!(key_values(Vec::<(&str, i32)>::new()), "");
+}
+
+#[test]
+fn test_fields() {
+    let mut output = String::new();
+    field(&mut output, "Status", "unresolved");
+    opt_field(&mut output, "Level", Some("error"));
+    opt_field(&mut output, "Culprit", None::<&str>);
+    opt_field(&mut output, "Message", Some(""));
+    assert_eq!(output, "**Status:** unresolved\n**Level:** error\n");
+}
+
+#[test]
+fn test_writer_keeps_sections_within_budget() {
+    let mut writer = Writer::new(10);
+    writer.push_str("# Title\n\n");
+    assert!(writer.section("first section\n"));
+    assert!(!writer.section("a section that does not fit anymore\n"));
+    assert!(!writer.section("x\n"));
+    assert_eq!(writer.omitted(), 2);
+    let output = writer.finish("events");
+    assert!(output.starts_with("# Title\n\nfirst section\n"));
+    assert!(!output.contains("x\n\n"));
+    assert!(output.ends_with("\n_2 more events omitted to fit ~10 tokens._\n"));
+}
+
+#[test]
+fn test_writer_without_omissions() {
+    let mut writer = Writer::new(100);
+    writer.field("Issue", "PROJ-1");
+    writer.opt_field("Query", None::<&str>);
+    assert!(writer.section("one\n"));
+    assert_eq!(writer.tokens(), estimate_tokens("**Issue:** PROJ-1\none\n"));
+    assert_eq!(writer.finish("events"), "**Issue:** PROJ-1\none\n");
+}
+
+#[test]
+fn test_format_rate() {
+    assert_eq!(format_rate(0.99534), "99.53%");
+    assert_eq!(format_rate(1.0), "100.00%");
+}
+
+#[test]
+fn test_count_label() {
+    assert_eq!(count_label(7, 100), "7");
+    assert_eq!(count_label(100, 100), "100+");
+}
+
+#[test]
+fn test_escape_cell() {
+    assert_eq!(escape_cell("a | b\nc"), "a \\| b c");
+    assert_eq!(escape_cell("plain"), "plain");
+}
@@ -0,0 +1,68 @@
+use sentry_mcp::api_client::DataScrubbing;
+use sentry_mcp::tools::get_data_scrubbing::{
+    describe_advanced_rules, effective_settings, field_verdict, format_data_scrubbing_output,
+};
+use serde_json::json;
+
+fn settings(value: serde_json::Value) -> DataScrubbing {
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn test_effective_settings_merges_org_and_project() {
+    let org = settings(json!({
+        "dataScrubber": true, "sensitiveFields": ["ssn"], "storeCrashReports": 5
+    }));
+    let project = settings(json!({
+        "dataScrubberDefaults": true, "sensitiveFields": ["SSN", "iban"], "safeFields": ["id"]
+    }));
+    let merged = effective_settings(&org, &project);
+    assert!(merged.data_scrubber);
+    assert!(merged.data_scrubber_defaults);
+    assert!(!merged.scrub_ip_addresses);
+    assert_eq!(merged.sensitive_fields, vec!["ssn", "iban"]);
+    assert_eq!(merged.safe_fields, vec!["id"]);
+    assert_eq!(merged.store_crash_reports, Some(5));
+}
+
+#[test]
+fn test_field_verdict() {
+    let on = settings(json!({
+        "dataScrubber": true, "dataScrubberDefaults": true,
+        "sensitiveFields": ["iban"], "safeFields": ["auth_method"]
+    }));
+    assert!(field_verdict("customer_IBAN", &on).contains("additional sensitive field `iban`"));
+    assert!(field_verdict("auth_method", &on).contains("safe field"));
+    assert!(field_verdict("db_password", &on).contains("default sensitive key `password`"));
+    assert!(field_verdict("user_id", &on).contains("does not match any sensitive key"));
+    let off = settings(json!({"dataScrubber": false}));
+    assert!(field_verdict("password", &off).contains("data scrubber is off"));
+}
+
+#[test]
+fn test_describe_advanced_rules() {
+    let config = json!({
+        "rules": {"0": {"type": "pattern", "pattern": "[0-9]{9}", "redaction": {"method": "mask"}}},
+        "applications": {"$string": ["0"], "$http.headers.x-api-key": ["@anything:remove"]}
+    })
+    .to_string();
+    let rules = describe_advanced_rules(&config);
+    assert!(rules.contains(&"`$string` → pattern `[0-9]{9}` (mask)".to_string()));
+    assert!(rules.contains(&"`$http.headers.x-api-key` → @anything:remove".to_string()));
+    assert!(describe_advanced_rules("not json").is_empty());
+}
+
+#[test]
+fn test_format_data_scrubbing_output() {
+    let org = settings(json!({"scrubIPAddresses": true}));
+    let project = settings(json!({
+        "dataScrubber": true,
+        "relayPiiConfig": "{\"applications\": {\"$user.email\": [\"@email:hash\"]}}"
+    }));
+    let output = format_data_scrubbing_output("api", &org, &project, Some("email"));
+    assert!(output.contains("- **Data scrubber:** on\n"));
+    assert!(output.contains("- **Scrub IP addresses:** on (enforced by organization)"));
+    assert!(output.contains("- **Additional sensitive fields:** none"));
+    assert!(output.contains("- `$user.email` → @email:hash (project)"));
+    assert!(output.contains("## Field Check"));
+}
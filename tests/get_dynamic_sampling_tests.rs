@@ -0,0 +1,61 @@
+use sentry_mcp::api_client::{OrgSampling, OrgStats, ProjectSampling};
+use sentry_mcp::tools::get_dynamic_sampling::{
+    describe_bias, format_dynamic_sampling_output, target_rate,
+};
+use serde_json::json;
+
+fn org(settings: serde_json::Value) -> OrgSampling {
+    serde_json::from_value(settings).unwrap()
+}
+
+fn project(settings: serde_json::Value) -> ProjectSampling {
+    serde_json::from_value(settings).unwrap()
+}
+
+#[test]
+fn test_target_rate_follows_sampling_mode() {
+    let project_settings = project(json!({"options": {"sentry:target_sample_rate": "0.5"}}));
+    assert_eq!(
+        target_rate(
+            &org(json!({"samplingMode": "organization", "targetSampleRate": 0.1})),
+            &project_settings
+        ),
+        Some((0.1, "organization"))
+    );
+    assert_eq!(
+        target_rate(
+            &org(json!({"samplingMode": "project", "targetSampleRate": 0.1})),
+            &project_settings
+        ),
+        Some((0.5, "project"))
+    );
+    assert_eq!(target_rate(&org(json!({})), &project(json!({}))), None);
+}
+
+#[test]
+fn test_describe_bias_falls_back_to_id() {
+    assert_eq!(
+        describe_bias("boostLatestRelease"),
+        "Prioritize new releases"
+    );
+    assert_eq!(describe_bias("someFutureBias"), "someFutureBias");
+}
+
+#[test]
+fn test_format_without_sampling_drops() {
+    let stats: OrgStats = serde_json::from_value(json!({
+        "groups": [{"by": {"outcome": "accepted", "category": "transaction"},
+                    "totals": {"sum(quantity)": 50}}]
+    }))
+    .unwrap();
+    let output = format_dynamic_sampling_output(
+        "backend",
+        "24h",
+        &org(json!({})),
+        &project(json!({"dynamicSamplingBiases": [{"id": "boostEnvironments", "active": false}]})),
+        &stats,
+    );
+    assert!(output.contains("**Target sample rate:** not reported"));
+    assert!(output.contains("- [off] `boostEnvironments`: Prioritize dev environments"));
+    assert!(output.contains("Dynamic sampling dropped no transactions or spans in this period"));
+}
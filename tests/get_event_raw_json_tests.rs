@@ -0,0 +1,38 @@
+use sentry_mcp::tools::get_event_raw_json::{format_raw_event, select_keys};
+use serde_json::json;
+
+#[test]
+fn test_select_keys() {
+    let payload = json!({"request": {"url": "/"}, "spans": [], "sdk": {}});
+    let keys: Vec<String> = ["spans", " request ", "breadcrumbs", "breadcrumbs", ""]
+        .iter()
+        .map(|k| k.to_string())
+        .collect();
+    let (selected, missing) = select_keys(&payload, &keys);
+    assert_eq!(selected, json!({"request": {"url": "/"}, "spans": []}));
+    assert_eq!(missing, ["breadcrumbs"]);
+}
+
+#[test]
+fn test_select_keys_on_non_object() {
+    let (selected, missing) = select_keys(&json!([1, 2]), &["a".to_string()]);
+    assert_eq!(selected, json!([1, 2]));
+    assert!(missing.is_empty());
+}
+
+#[test]
+fn test_format_raw_event() {
+    let output = format_raw_event("abc", "web", &json!({"level": "error"}), &[], &[]);
+    assert_eq!(
+        output,
+        "# Raw Event: abc\n\n**Project:** web\n\n```json\n{\n  \"level\": \"error\"\n}\n```\n"
+    );
+    let output = format_raw_event(
+        "abc",
+        "web",
+        &json!({}),
+        &["spans".to_string()],
+        &["level".to_string(), "request".to_string()],
+    );
+    assert!(output.contains("**Missing Keys:** spans\n**Available Keys:** level, request\n"));
+}
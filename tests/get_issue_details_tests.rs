@@ -1,7 +1,14 @@
-use sentry_mcp::api_client::{Event, EventEntry, EventTag, Issue, IssueTag, Project};
+use sentry_mcp::api_client::{
+    Commit, Committer, Event, EventEntry, EventTag, Issue, IssueAnnotation, IssueTag, Project,
+};
+use sentry_mcp::format::estimate_tokens;
 use sentry_mcp::tools::get_issue_details::{
-    format_contexts, format_event_entries, format_exception, format_extra_data,
-    format_frame_detail, format_issue_output, parse_issue_url,
+    FormatOptions, StackStyle, commit_link, format_annotations, format_assignee, format_contexts,
+    format_event_entries, format_exception, format_exception_with, format_extra_data,
+    format_frame_detail, format_frame_detail_with, format_frame_location, format_ignore,
+    format_issue_output, format_issue_output_within_budget, format_resolution,
+    format_suspect_commits, format_var_value, is_in_app, parse_issue_url, parse_share_url,
+    sourcemap_status,
 };
 use serde_json::json;
 
@@ -162,6 +169,71 @@ fn test_format_frame_detail_truncates_long_vars() {
     assert!(output.contains("..."));
 }
 
+#[test]
+fn test_format_var_value_scalars() {
+    assert_eq!(format_var_value(&json!("hi"), 2, 60), vec!["\"hi\""]);
+    assert_eq!(format_var_value(&json!(null), 2, 60), vec!["None"]);
+    assert_eq!(format_var_value(&json!(42), 2, 60), vec!["42"]);
+    assert_eq!(format_var_value(&json!([]), 2, 60), vec!["[]"]);
+    assert_eq!(format_var_value(&json!({}), 2, 60), vec!["{}"]);
+}
+
+#[test]
+fn test_format_var_value_expands_nested_to_depth() {
+    let value = json!({"id": 7, "tags": ["a", "b"], "meta": {"deep": {"x": 1}}});
+    let lines = format_var_value(&value, 2, 60);
+    assert_eq!(
+        lines,
+        vec![
+            "{3 keys}",
+            "  id: 7",
+            "  meta: {1 key}",
+            "    deep: {1 key} {\"x\": 1}",
+            "  tags: [2 items]",
+            "    [0]: \"a\"",
+            "    [1]: \"b\"",
+        ]
+    );
+}
+
+#[test]
+fn test_format_var_value_depth_zero_is_inline_with_length() {
+    let lines = format_var_value(&json!([1, 2, 3]), 0, 60);
+    assert_eq!(lines, vec!["[3 items] [1, 2, 3]"]);
+}
+
+#[test]
+fn test_format_var_value_truncation_limit() {
+    let long = json!("x".repeat(50));
+    assert_eq!(
+        format_var_value(&long, 2, 20)[0],
+        format!("\"{}...", "x".repeat(16))
+    );
+    assert_eq!(format_var_value(&long, 2, 100)[0].len(), 52);
+}
+
+#[test]
+fn test_format_var_value_caps_entries() {
+    let value = json!((0..15).collect::<Vec<_>>());
+    let lines = format_var_value(&value, 1, 60);
+    assert_eq!(lines[0], "[15 items]");
+    assert_eq!(lines.len(), 12);
+    assert_eq!(lines[11], "  ... 5 more");
+}
+
+#[test]
+fn test_format_frame_detail_nested_vars_tree() {
+    let frame = json!({
+        "filename": "app.py",
+        "lineNo": 1,
+        "function": "f",
+        "vars": {"user": {"name": "bob"}}
+    });
+    let mut output = String::new();
+    format_frame_detail_with(&mut output, &frame, &FormatOptions::default());
+    assert!(output.contains("├─ user: {1 key}\n│    name: \"bob\"\n"));
+}
+
 #[test]
 fn test_format_exception_simple() {
     let mut output = String::new();
@@ -287,8 +359,10 @@ fn create_test_issue(project: Project) -> Issue {
         title: "Test Issue".to_string(),
         culprit: Some("app.main".to_string()),
         status: "unresolved".to_string(),
+        status_details: serde_json::json!({}),
         substatus: Some("ongoing".to_string()),
         level: Some("error".to_string()),
+        priority: None,
         platform: Some("python".to_string()),
         project,
         first_seen: Some("2024-01-01T00:00:00Z".to_string()),
@@ -304,6 +378,12 @@ fn create_test_issue(project: Project) -> Issue {
         }],
         issue_type: Some("error".to_string()),
         issue_category: Some("error".to_string()),
+        assigned_to: None,
+        stats: serde_json::json!({}),
+        forecast: None,
+        is_bookmarked: None,
+        is_subscribed: None,
+        annotations: vec![],
     }
 }
 
@@ -321,6 +401,7 @@ fn create_test_event() -> Event {
             key: "browser".to_string(),
             value: "Chrome".to_string(),
         }],
+        release: serde_json::Value::Null,
     }
 }
 
@@ -361,6 +442,28 @@ fn test_format_issue_output_with_permalink() {
     assert!(output.contains("https://sentry.io/issues/123"));
 }
 
+#[test]
+fn test_format_issue_output_with_linked_tickets() {
+    let mut issue = create_test_issue(create_test_project());
+    let output = format_issue_output(&issue, None);
+    assert!(!output.contains("**Linked Tickets:**"));
+    issue.annotations = vec![
+        IssueAnnotation {
+            display_name: "JIRA-123".to_string(),
+            url: Some("https://acme.atlassian.net/browse/JIRA-123".to_string()),
+        },
+        IssueAnnotation {
+            display_name: "OPS-7".to_string(),
+            url: None,
+        },
+    ];
+    let output = format_issue_output(&issue, None);
+    assert!(output.contains(
+        "**Linked Tickets:** [JIRA-123](https://acme.atlassian.net/browse/JIRA-123), OPS-7\n"
+    ));
+    assert_eq!(format_annotations(&[]), "");
+}
+
 #[test]
 fn test_format_issue_output_with_issue_tags() {
     let project = create_test_project();
@@ -611,8 +714,425 @@ fn test_parse_issue_url_empty_string() {
     assert!(parse_issue_url("").is_none());
 }
 
+#[test]
+fn test_parse_issue_url_org_subdomain() {
+    let url = "https://acme.sentry.io/issues/4512345/?referrer=slack&alert_rule_id=7";
+    let (org, issue) = parse_issue_url(url).unwrap();
+    assert_eq!(org, "acme");
+    assert_eq!(issue, "4512345");
+}
+
+#[test]
+fn test_parse_issue_url_subdomain_only_on_sentry_io() {
+    assert!(parse_issue_url("https://acme.example.com/issues/42/").is_none());
+}
+
+#[test]
+fn test_parse_share_url() {
+    assert_eq!(
+        parse_share_url("https://sentry.io/share/issue/3f2a9c1e5b7d4e0f/").as_deref(),
+        Some("3f2a9c1e5b7d4e0f")
+    );
+    assert_eq!(
+        parse_share_url("https://acme.sentry.io/share/issue/abc123?referrer=slack").as_deref(),
+        Some("abc123")
+    );
+    assert!(parse_share_url("https://sentry.io/organizations/acme/issues/1/").is_none());
+}
+
 #[test]
 fn test_parse_issue_url_missing_org() {
     let url = "https://sentry.io/organizations//issues/12345/";
     assert!(parse_issue_url(url).is_none());
 }
+
+fn create_event_with_big_stacktrace() -> Event {
+    let frames: Vec<serde_json::Value> = (0..15)
+        .map(|i| {
+            json!({
+                "filename": format!("lib{}.py", i),
+                "lineNo": i,
+                "function": format!("func{}", i),
+                "inApp": i == 14,
+                "context": [[i, "x".repeat(200)]],
+                "vars": {"payload": "v".repeat(200), "other": "w".repeat(200)}
+            })
+        })
+        .collect();
+    let mut event = create_test_event();
+    event.entries = vec![EventEntry {
+        entry_type: "exception".to_string(),
+        data: json!({"values": [{"type": "ValueError", "value": "bad", "stacktrace": {"frames": frames}}]}),
+    }];
+    event
+}
+
+#[test]
+fn test_format_options_reduced_order() {
+    let opts = FormatOptions::default();
+    assert!(opts.include_vars && opts.include_context && !opts.in_app_only);
+    let one = opts.reduced(1);
+    assert!(!one.include_vars && one.include_context && !one.in_app_only);
+    let two = opts.reduced(2);
+    assert!(!two.include_vars && !two.include_context && !two.in_app_only);
+    let three = opts.reduced(3);
+    assert!(!three.include_vars && !three.include_context && three.in_app_only);
+}
+
+#[test]
+fn test_format_exception_in_app_only_omits_library_frames() {
+    let mut output = String::new();
+    let exc = json!({
+        "type": "KeyError",
+        "value": "x",
+        "stacktrace": {"frames": [
+            {"filename": "lib.py", "lineNo": 5, "function": "helper", "inApp": false},
+            {"filename": "main.py", "lineNo": 20, "function": "process", "inApp": true}
+        ]}
+    });
+    format_exception_with(&mut output, &exc, &FormatOptions::default().reduced(3));
+    assert!(output.contains("main.py"));
+    assert!(!output.contains("lib.py"));
+    assert!(output.contains("1 non-app frames omitted"));
+}
+
+#[test]
+fn test_is_in_app_prefix_and_wildcard_patterns() {
+    let vendored = json!({"filename": "vendor/acme/billing/charge.py", "inApp": false});
+    let scoped = json!({"module": "@acme/ui/button", "inApp": false});
+    let library = json!({"filename": "site-packages/requests/api.py", "inApp": false});
+    let sdk_in_app = json!({"filename": "main.py", "inApp": true});
+    let patterns = vec!["vendor/acme/".to_string(), "@acme/*/button".to_string()];
+    assert!(is_in_app(&vendored, &patterns));
+    assert!(is_in_app(&scoped, &patterns));
+    assert!(!is_in_app(&library, &patterns));
+    assert!(is_in_app(&sdk_in_app, &[]));
+    assert!(!is_in_app(&vendored, &[]));
+}
+
+#[test]
+fn test_format_exception_configured_in_app_picks_relevant_frame() {
+    let exc = json!({
+        "type": "ValueError",
+        "value": "bad",
+        "stacktrace": {"frames": [
+            {"filename": "app/main.py", "lineNo": 3, "function": "run", "inApp": true},
+            {"filename": "vendor/acme/core.py", "lineNo": 42, "function": "charge", "inApp": false},
+            {"filename": "site-packages/lib.py", "lineNo": 9, "function": "call", "inApp": false}
+        ]}
+    });
+    let opts = FormatOptions {
+        in_app_include: vec!["vendor/acme/".to_string()],
+        ..Default::default()
+    };
+    let mut output = String::new();
+    format_exception_with(&mut output, &exc, &opts.reduced(3));
+    let relevant = output.split("**Full Stacktrace:**").next().unwrap();
+    assert!(relevant.contains("vendor/acme/core.py\", line 42, in charge"));
+    assert!(output.contains("1 non-app frames omitted"));
+    assert!(!output.contains("site-packages/lib.py"));
+}
+
+#[test]
+fn test_format_issue_output_within_budget_untouched_when_small() {
+    let issue = create_test_issue(create_test_project());
+    let event = create_test_event();
+    let output =
+        format_issue_output_within_budget(&issue, Some(&event), &FormatOptions::default(), 8000);
+    assert_eq!(output, format_issue_output(&issue, Some(&event)));
+}
+
+#[test]
+fn test_format_issue_output_within_budget_drops_vars_first() {
+    let issue = create_test_issue(create_test_project());
+    let event = create_event_with_big_stacktrace();
+    let full = format_issue_output(&issue, Some(&event));
+    let budget = estimate_tokens(&full) - 10;
+    let output =
+        format_issue_output_within_budget(&issue, Some(&event), &FormatOptions::default(), budget);
+    assert!(!output.contains("Local Variables"));
+    assert!(output.contains("xxxx"));
+    assert!(output.contains("omitted: local variables._"));
+}
+
+#[test]
+fn test_format_issue_output_within_budget_drops_everything_when_tiny() {
+    let issue = create_test_issue(create_test_project());
+    let event = create_event_with_big_stacktrace();
+    let output =
+        format_issue_output_within_budget(&issue, Some(&event), &FormatOptions::default(), 10);
+    assert!(!output.contains("Local Variables"));
+    assert!(!output.contains("xxxx"));
+    assert!(!output.contains("lib0.py"));
+    assert!(output.contains("lib14.py"));
+    assert!(output.contains("omitted: local variables, context lines, non-app frames"));
+}
+
+#[test]
+fn test_format_resolution_variants() {
+    assert_eq!(
+        format_resolution(&json!({"inRelease": "2.0.0"})).as_deref(),
+        Some("release 2.0.0")
+    );
+    assert_eq!(
+        format_resolution(&json!({"inNextRelease": true})).as_deref(),
+        Some("next release")
+    );
+    assert_eq!(
+        format_resolution(
+            &json!({"inCommit": {"id": "abc123", "repository": {"name": "acme/api"}}})
+        )
+        .as_deref(),
+        Some("commit abc123 (acme/api)")
+    );
+    assert_eq!(format_resolution(&json!({})), None);
+}
+
+#[test]
+fn test_format_issue_output_shows_resolution() {
+    let mut issue = create_test_issue(create_test_project());
+    issue.status = "resolved".to_string();
+    issue.status_details = json!({"inRelease": "1.0.1"});
+    let output = format_issue_output(&issue, None);
+    assert!(output.contains("**Resolved In:** release 1.0.1"));
+}
+
+fn make_committer(commit: serde_json::Value) -> Committer {
+    serde_json::from_value(json!({
+        "author": {"name": "Jane Doe", "email": "jane@example.com"},
+        "commits": [commit]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_format_suspect_commits_with_pull_request() {
+    let committer = make_committer(json!({
+        "id": "abcdef1234567890",
+        "message": "Fix null check\n\nLonger body",
+        "dateCreated": "2024-01-01T00:00:00Z",
+        "repository": {"name": "acme/backend", "url": "https://github.com/acme/backend"},
+        "pullRequest": {
+            "id": "42",
+            "title": "Handle missing user",
+            "externalUrl": "https://github.com/acme/backend/pull/42",
+            "author": {"name": "Jane Doe", "username": "jdoe"}
+        }
+    }));
+    let mut output = String::new();
+    format_suspect_commits(&mut output, &[committer]);
+    assert!(output.contains("## Suspect Commits"));
+    assert!(
+        output.contains(
+            "- **abcdef1** Fix null check (Jane Doe, 2024-01-01T00:00:00Z) in acme/backend"
+        )
+    );
+    assert!(output.contains(
+        "PR: [#42 Handle missing user](https://github.com/acme/backend/pull/42) by jdoe"
+    ));
+    assert!(!output.contains("Longer body"));
+}
+
+#[test]
+fn test_format_suspect_commits_falls_back_to_commit_url() {
+    let committer = make_committer(json!({
+        "id": "abcdef1234567890",
+        "message": "Refactor",
+        "repository": {"name": "acme/backend", "url": "https://github.com/acme/backend/"}
+    }));
+    let mut output = String::new();
+    format_suspect_commits(&mut output, &[committer]);
+    assert!(output.contains("Commit: https://github.com/acme/backend/commit/abcdef1234567890"));
+    assert!(!output.contains("PR:"));
+}
+
+#[test]
+fn test_commit_link_without_repository_url() {
+    let commit: Commit = serde_json::from_value(json!({"id": "abc"})).unwrap();
+    assert!(commit_link(&commit).is_none());
+}
+
+#[test]
+fn test_format_ignore_variants() {
+    assert_eq!(
+        format_ignore(&json!({"ignoreUntil": "2024-02-01T00:00:00Z"})).as_deref(),
+        Some("until 2024-02-01T00:00:00Z")
+    );
+    assert_eq!(
+        format_ignore(&json!({"ignoreUserCount": 10})).as_deref(),
+        Some("until 10 more users")
+    );
+    assert_eq!(
+        format_ignore(&json!({"ignoreDuration": 30})).as_deref(),
+        Some("for 30 minutes")
+    );
+    assert_eq!(format_ignore(&json!({"inRelease": "1.0"})), None);
+}
+
+#[test]
+fn test_format_assignee_user_and_team() {
+    assert_eq!(
+        format_assignee(&json!({"type": "user", "name": "Jane Doe", "email": "j@x.io"})).as_deref(),
+        Some("Jane Doe")
+    );
+    assert_eq!(
+        format_assignee(&json!({"type": "team", "name": "platform"})).as_deref(),
+        Some("#platform")
+    );
+    assert_eq!(format_assignee(&json!(null)), None);
+}
+
+#[test]
+fn test_stack_style_for_platform() {
+    assert_eq!(StackStyle::for_platform(Some("java")), StackStyle::Java);
+    assert_eq!(StackStyle::for_platform(Some("kotlin")), StackStyle::Java);
+    assert_eq!(StackStyle::for_platform(Some("node")), StackStyle::Node);
+    assert_eq!(
+        StackStyle::for_platform(Some("javascript")),
+        StackStyle::Node
+    );
+    assert_eq!(StackStyle::for_platform(Some("cocoa")), StackStyle::Native);
+    assert_eq!(StackStyle::for_platform(Some("python")), StackStyle::Python);
+    assert_eq!(StackStyle::for_platform(None), StackStyle::Python);
+}
+
+#[test]
+fn test_format_frame_location_styles() {
+    let frame = json!({
+        "filename": "Service.java",
+        "module": "com.acme.Service",
+        "function": "handle",
+        "lineNo": 12,
+        "colNo": 5
+    });
+    assert_eq!(
+        format_frame_location(&frame, StackStyle::Java),
+        "at com.acme.Service.handle(Service.java:12)"
+    );
+    assert_eq!(
+        format_frame_location(&frame, StackStyle::Node),
+        "at handle (Service.java:12:5)"
+    );
+    assert_eq!(
+        format_frame_location(&frame, StackStyle::Python),
+        "File \"Service.java\", line 12, in handle"
+    );
+    assert_eq!(
+        format_frame_location(&frame, StackStyle::Native),
+        "handle at Service.java:12"
+    );
+}
+
+#[test]
+fn test_format_frame_location_node_anonymous() {
+    let frame = json!({"filename": "app.js", "lineNo": 3});
+    assert_eq!(
+        format_frame_location(&frame, StackStyle::Node),
+        "at app.js:3"
+    );
+}
+
+#[test]
+fn test_format_issue_output_uses_event_platform_style() {
+    let issue = create_test_issue(create_test_project());
+    let mut event = create_test_event();
+    event.platform = Some("java".to_string());
+    event.entries = vec![EventEntry {
+        entry_type: "exception".to_string(),
+        data: json!({"values": [{
+            "type": "java.lang.IllegalStateException",
+            "value": "closed",
+            "stacktrace": {"frames": [
+                {"filename": "Pool.java", "module": "com.acme.db.Pool", "function": "take",
+                 "lineNo": 88, "inApp": true}
+            ]}
+        }]}),
+    }];
+    let output = format_issue_output(&issue, Some(&event));
+    assert!(output.contains("  at com.acme.db.Pool.take(Pool.java:88)\n"));
+    assert!(!output.contains("File \"Pool.java\""));
+}
+
+#[test]
+fn test_sourcemap_status_applied_with_minified_location() {
+    let frame = json!({
+        "filename": "src/cart.ts", "function": "addItem", "lineNo": 42, "colNo": 7,
+        "data": {"sourcemap": "https://cdn.example.com/static/app.min.js.map"}
+    });
+    let raw =
+        json!({"filename": "static/app.min.js", "function": "a", "lineNo": 1, "colNo": 20331});
+    assert_eq!(
+        sourcemap_status(&frame, Some(&raw)),
+        "source map applied (app.min.js.map); minified at a (static/app.min.js:1:20331)"
+    );
+}
+
+#[test]
+fn test_sourcemap_status_not_applied() {
+    let frame = json!({"filename": "static/app.min.js", "lineNo": 1, "colNo": 99});
+    assert_eq!(
+        sourcemap_status(&frame, Some(&frame.clone())),
+        "no source map"
+    );
+    assert_eq!(sourcemap_status(&frame, None), "no source map");
+}
+
+#[test]
+fn test_format_exception_node_shows_sourcemap_status() {
+    let exc = json!({
+        "type": "TypeError",
+        "value": "x is undefined",
+        "stacktrace": {"frames": [
+            {"filename": "static/vendor.min.js", "function": "r", "lineNo": 1, "colNo": 5},
+            {"filename": "src/cart.ts", "function": "addItem", "lineNo": 42, "colNo": 7,
+             "inApp": true, "data": {"sourcemap": "app.min.js.map"}}
+        ]},
+        "rawStacktrace": {"frames": [
+            {"filename": "static/vendor.min.js", "function": "r", "lineNo": 1, "colNo": 5},
+            {"filename": "static/app.min.js", "function": "a", "lineNo": 1, "colNo": 20331}
+        ]}
+    });
+    let opts = FormatOptions {
+        style: StackStyle::Node,
+        ..Default::default()
+    };
+    let mut output = String::new();
+    format_exception_with(&mut output, &exc, &opts);
+    assert!(output.contains(
+        "  at addItem (src/cart.ts:42:7)\n      [source map applied (app.min.js.map); minified at a (static/app.min.js:1:20331)]\n"
+    ));
+    assert!(output.contains("  at r (static/vendor.min.js:1:5)\n      [no source map]\n"));
+}
+
+#[test]
+fn test_format_exception_without_sourcemap_data_has_no_status() {
+    let exc = json!({
+        "type": "Error",
+        "value": "boom",
+        "stacktrace": {"frames": [{"filename": "server.js", "function": "f", "lineNo": 3}]}
+    });
+    let opts = FormatOptions {
+        style: StackStyle::Node,
+        ..Default::default()
+    };
+    let mut output = String::new();
+    format_exception_with(&mut output, &exc, &opts);
+    assert!(!output.contains("source map"));
+}
+
+#[test]
+fn test_format_frame_detail_with_source_link() {
+    let frame = json!({
+        "filename": "app/views.py",
+        "lineNo": 42,
+        "function": "index",
+        "sourceLink": "https://github.com/acme/api/blob/main/app/views.py#L42"
+    });
+    let mut output = String::new();
+    format_frame_detail(&mut output, &frame);
+    assert!(
+        output.contains(
+            "\n  [View source](https://github.com/acme/api/blob/main/app/views.py#L42)\n\n"
+        )
+    );
+}
@@ -0,0 +1,62 @@
+use sentry_mcp::api_client::IssueHash;
+use sentry_mcp::tools::get_issue_hashes::{format_hashes_output, top_in_app_frame};
+use serde_json::json;
+
+fn python_event() -> serde_json::Value {
+    json!({
+        "eventID": "e1",
+        "dateCreated": "2024-05-01T10:00:00Z",
+        "title": "KeyError: 'user'",
+        "platform": "python",
+        "entries": [{"type": "exception", "data": {"values": [
+            {"type": "ValueError", "value": "bad", "stacktrace": {"frames": []}},
+            {"type": "KeyError", "value": "'user'", "stacktrace": {"frames": [
+                {"filename": "app/views.py", "lineNo": 10, "function": "handle", "inApp": true},
+                {"filename": "app/auth.py", "lineNo": 42, "function": "load_user", "inApp": true},
+                {"filename": "django/core.py", "lineNo": 7, "function": "get", "inApp": false}
+            ]}}
+        ]}}]
+    })
+}
+
+#[test]
+fn test_top_in_app_frame_uses_main_exception() {
+    assert_eq!(
+        top_in_app_frame(&python_event()).as_deref(),
+        Some("File \"app/auth.py\", line 42, in load_user")
+    );
+    assert_eq!(top_in_app_frame(&json!({"entries": []})), None);
+}
+
+#[test]
+fn test_format_hashes_output() {
+    let hashes = vec![
+        IssueHash {
+            id: "aaa".to_string(),
+            latest_event: Some(python_event()),
+        },
+        IssueHash {
+            id: "bbb".to_string(),
+            latest_event: None,
+        },
+    ];
+    let output = format_hashes_output("PROJ-1", &hashes);
+    assert!(output.contains("**Found:** 2 hashes"));
+    assert!(output.contains("Several hashes usually mean issues were merged"));
+    assert!(output.contains("- **Latest event:** e1 (2024-05-01T10:00:00Z)"));
+    assert!(output.contains("- **Exception:** KeyError: 'user'"));
+    assert!(
+        output.contains("- **Top in-app frame:** `File \"app/auth.py\", line 42, in load_user`")
+    );
+    assert!(output.contains("## `bbb`\n\nNo event stored for this hash."));
+}
+
+#[test]
+fn test_format_hashes_output_single_hash_has_no_merge_hint() {
+    let hashes = vec![IssueHash {
+        id: "aaa".to_string(),
+        latest_event: None,
+    }];
+    let output = format_hashes_output("PROJ-1", &hashes);
+    assert!(!output.contains("Several hashes"));
+}
@@ -0,0 +1,56 @@
+use sentry_mcp::api_client::Event;
+use sentry_mcp::tools::get_latest_events::{format_latest_events, unique_issue_ids};
+use serde_json::json;
+
+fn event(value: serde_json::Value) -> Event {
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn test_unique_issue_ids() {
+    let ids: Vec<String> = ["PROJ-1", " PROJ-2", "", "PROJ-1 ", "42"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    assert_eq!(unique_issue_ids(&ids), ["PROJ-1", "PROJ-2", "42"]);
+}
+
+#[test]
+fn test_format_latest_events() {
+    let crash = event(json!({
+        "id": "1",
+        "eventID": "abc",
+        "dateCreated": "2024-05-01T10:00:00Z",
+        "platform": "python",
+        "tags": [
+            {"key": "release", "value": "api@1.2.0"},
+            {"key": "environment", "value": "production"}
+        ],
+        "entries": [{
+            "type": "exception",
+            "data": {"values": [{
+                "type": "KeyError",
+                "value": "'user'",
+                "stacktrace": {"frames": [{
+                    "filename": "app/views.py", "lineNo": 7, "function": "show", "inApp": true,
+                    "context": [[7, "    return data['user']"]]
+                }]}
+            }]}
+        }]
+    }));
+    let message = event(json!({"id": "2", "eventID": "def", "message": "Disk almost full"}));
+    let results = vec![
+        ("API-1".to_string(), Ok(crash)),
+        ("API-2".to_string(), Ok(message)),
+        ("API-3".to_string(), Err(anyhow::anyhow!("404 Not Found"))),
+    ];
+    let output = format_latest_events(&results, &[]);
+    assert!(output.contains("# Latest Events: 3 issues"));
+    assert!(output.contains(
+        "**Event:** abc | **Date:** 2024-05-01T10:00:00Z | **Release:** api@1.2.0 | **Environment:** production\n"
+    ));
+    assert!(output.contains("**Exception:** KeyError: 'user'\n"));
+    assert!(output.contains("File \"app/views.py\", line 7, in show\n    return data['user']\n"));
+    assert!(output.contains("## API-2\n\n**Event:** def\n**Message:** Disk almost full\n"));
+    assert!(output.contains("## API-3\n\nCould not fetch the latest event: 404 Not Found\n"));
+}
@@ -0,0 +1,94 @@
+use sentry_mcp::api_client::Profile;
+use sentry_mcp::tools::get_profile::{
+    FrameTime, call_paths, format_call_path, format_profile_output, frame_location, frame_times,
+    selected_samples,
+};
+use serde_json::json;
+
+/// Frames: 0 main, 1 handler (in-app), 2 render (in-app), 3 escape, 4 worker.
+fn profile() -> Profile {
+    serde_json::from_value(json!({
+        "platform": "python",
+        "profile": {
+            "samples": [
+                {"stack_id": 0, "thread_id": "1", "elapsed_since_start_ns": "0"},
+                {"stack_id": 0, "thread_id": "1", "elapsed_since_start_ns": "10000000"},
+                {"stack_id": 1, "thread_id": "1", "elapsed_since_start_ns": "20000000"},
+                {"stack_id": 2, "thread_id": "1", "elapsed_since_start_ns": "30000000"},
+                {"stack_id": 3, "thread_id": "2", "elapsed_since_start_ns": "40000000"}
+            ],
+            "stacks": [[3, 2, 1, 0], [2, 1, 0], [1, 1, 0], [4]],
+            "frames": [
+                {"function": "main", "filename": "main.py", "lineno": 3},
+                {"function": "handler", "module": "app.views", "lineno": 12, "in_app": true},
+                {"function": "render", "module": "app.templates", "in_app": true},
+                {"function": "escape", "module": "markupsafe"},
+                {"function": "worker", "module": "threading"}
+            ],
+            "thread_metadata": {"1": {"name": "MainThread"}}
+        },
+        "transaction": {"name": "GET /orders", "active_thread_id": 1}
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_selected_samples_active_thread() {
+    let profile = profile();
+    assert_eq!(selected_samples(&profile, false).len(), 4);
+    assert_eq!(selected_samples(&profile, true).len(), 5);
+}
+
+#[test]
+fn test_frame_times_self_and_total() {
+    let profile = profile();
+    let samples = selected_samples(&profile, false);
+    let times = frame_times(&profile, &samples);
+    assert_eq!(
+        times[0],
+        FrameTime {
+            frame: 3,
+            self_samples: 2,
+            total_samples: 2
+        }
+    );
+    let handler = times.iter().find(|t| t.frame == 1).unwrap();
+    // The recursive stack [1, 1, 0] counts handler once.
+    assert_eq!((handler.self_samples, handler.total_samples), (1, 4));
+}
+
+#[test]
+fn test_call_paths_most_sampled_first() {
+    let profile = profile();
+    let samples = selected_samples(&profile, false);
+    let paths = call_paths(&samples);
+    assert_eq!(paths[0].stack, 0);
+    assert_eq!(paths[0].samples, 2);
+    assert_eq!(
+        format_call_path(&profile, 0),
+        "main → handler → render → escape"
+    );
+}
+
+#[test]
+fn test_frame_location() {
+    let profile = profile();
+    assert_eq!(frame_location(&profile.profile.frames[0]), "main.py:3");
+    assert_eq!(frame_location(&profile.profile.frames[2]), "app.templates");
+}
+
+#[test]
+fn test_format_profile_output() {
+    let output = format_profile_output("p1", &profile(), false);
+    assert!(output.contains("**Transaction:** GET /orders"));
+    assert!(output.contains("**Thread:** MainThread (1)"));
+    assert!(output.contains("**Samples:** 4"));
+    assert!(output.contains("**Sampled Duration:** 30.00ms"));
+    assert!(output.contains("| escape | markupsafe |  | 50.0% | 50.0% |"));
+    assert!(output.contains("| handler | app.views:12 | yes | 25.0% | 100.0% |"));
+    assert!(!output.contains("| main |"));
+    assert!(output.contains("1. 50.0% — main → handler → render → escape"));
+    let all = format_profile_output("p1", &profile(), true);
+    assert!(all.contains("**Thread:** all"));
+    assert!(all.contains("| worker | threading |  | 20.0% | 20.0% |"));
+}
@@ -0,0 +1,120 @@
+use sentry_mcp::api_client::Replay;
+use sentry_mcp::tools::get_replay_details::{
+    TimelineEntry, format_offset, format_replay_output, replay_timeline,
+};
+use serde_json::json;
+
+fn breadcrumb(timestamp: i64, payload: serde_json::Value) -> serde_json::Value {
+    json!({"type": 5, "timestamp": timestamp, "data": {"tag": "breadcrumb", "payload": payload}})
+}
+
+fn span(timestamp: i64, payload: serde_json::Value) -> serde_json::Value {
+    json!({"type": 5, "timestamp": timestamp, "data": {"tag": "performanceSpan", "payload": payload}})
+}
+
+fn recording() -> Vec<serde_json::Value> {
+    vec![
+        json!({"type": 4, "timestamp": 10_000, "data": {"href": "https://shop.example/"}}),
+        breadcrumb(
+            75_000,
+            json!({"category": "console", "level": "error", "message": "Payment failed"}),
+        ),
+        span(
+            12_000,
+            json!({"op": "navigation.navigate", "description": "https://shop.example/cart"}),
+        ),
+        breadcrumb(
+            70_500,
+            json!({"category": "ui.click", "message": "button#pay"}),
+        ),
+        span(
+            71_000,
+            json!({"op": "resource.fetch", "description": "https://api.example/pay",
+            "data": {"method": "POST", "statusCode": 502}}),
+        ),
+        span(
+            71_100,
+            json!({"op": "resource.fetch", "description": "https://api.example/ok",
+            "data": {"method": "GET", "statusCode": 200}}),
+        ),
+        breadcrumb(
+            72_000,
+            json!({"category": "ui.multiClick", "message": "button#pay"}),
+        ),
+        breadcrumb(
+            73_000,
+            json!({"category": "navigation", "data": {"from": "/cart", "to": "/error"}}),
+        ),
+        breadcrumb(
+            73_500,
+            json!({"category": "console", "level": "log", "message": "noise"}),
+        ),
+        json!({"type": 3, "timestamp": 80_000}),
+    ]
+}
+
+#[test]
+fn test_replay_timeline() {
+    let timeline = replay_timeline(&recording());
+    let kinds: Vec<&str> = timeline.iter().map(|e| e.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            "navigation",
+            "click",
+            "request failed",
+            "rage click",
+            "navigation",
+            "console error"
+        ]
+    );
+    assert_eq!(timeline[0].offset_ms, 2000.0);
+    assert_eq!(timeline[2].message, "POST https://api.example/pay → 502");
+    assert_eq!(timeline[4].message, "/error");
+}
+
+#[test]
+fn test_replay_timeline_empty() {
+    assert!(replay_timeline(&[]).is_empty());
+}
+
+#[test]
+fn test_format_offset() {
+    assert_eq!(format_offset(0.0), "+00:00");
+    assert_eq!(format_offset(65_900.0), "+01:05");
+    assert_eq!(format_offset(-5.0), "+00:00");
+}
+
+#[test]
+fn test_format_replay_output() {
+    let replay: Replay = serde_json::from_value(json!({
+        "id": "r1",
+        "project_id": "42",
+        "started_at": "2024-05-01T10:00:00Z",
+        "duration": 70.0,
+        "count_errors": 1,
+        "count_rage_clicks": 1,
+        "urls": ["https://shop.example/", "https://shop.example/cart"],
+        "error_ids": ["e1"],
+        "trace_ids": ["t1"],
+        "releases": ["shop@1.0"],
+        "environment": "production",
+        "user": {"id": "7", "email": "ann@example.com"},
+        "browser": {"name": "Chrome", "version": "124"},
+        "os": {"name": "macOS"}
+    }))
+    .unwrap();
+    let timeline = vec![TimelineEntry {
+        offset_ms: 60_500.0,
+        kind: "click",
+        message: "button#pay".to_string(),
+    }];
+    let output = format_replay_output(&replay, &timeline);
+    assert!(output.contains("**Duration:** 01:10"));
+    assert!(output.contains("**User:** ann@example.com"));
+    assert!(output.contains("**Client:** Chrome 124 / macOS"));
+    assert!(output.contains("**Errors:** 1 | **Dead Clicks:** 0 | **Rage Clicks:** 1"));
+    assert!(output.contains("**Trace IDs:** t1"));
+    assert!(output.contains("- https://shop.example/cart"));
+    assert!(output.contains("```\n+01:00  click          button#pay\n```"));
+}
@@ -1,24 +1,12 @@
 use sentry_mcp::api_client::TraceSpan;
 use sentry_mcp::tools::get_trace_details::{
-    collect_operations, format_duration, format_span_tree, format_trace_output,
-    select_interesting_spans,
+    StartGapKind, collect_occurrences, collect_operations, collect_profiles, find_start_gaps,
+    format_span_siblings, format_span_tree, format_trace_output, http_status_code,
+    rollup_http_statuses, select_interesting_spans, span_attributes, span_measurements,
+    summarize_trace_errors,
 };
 use std::collections::HashMap;
 
-#[test]
-fn test_format_duration_milliseconds() {
-    assert_eq!(format_duration(100.0), "100.00ms");
-    assert_eq!(format_duration(0.5), "0.50ms");
-    assert_eq!(format_duration(999.99), "999.99ms");
-}
-
-#[test]
-fn test_format_duration_seconds() {
-    assert_eq!(format_duration(1000.0), "1.00s");
-    assert_eq!(format_duration(2500.0), "2.50s");
-    assert_eq!(format_duration(10000.0), "10.00s");
-}
-
 fn make_span(op: Option<&str>, duration: f64, children: Vec<TraceSpan>) -> TraceSpan {
     TraceSpan {
         event_id: "abc123".to_string(),
@@ -40,6 +28,8 @@ fn make_span(op: Option<&str>, duration: f64, children: Vec<TraceSpan>) -> Trace
         children,
         errors: vec![],
         occurrences: vec![],
+        measurements: serde_json::Value::Null,
+        data: serde_json::Value::Null,
     }
 }
 
@@ -129,7 +119,7 @@ fn test_format_span_tree_error_status() {
 #[test]
 fn test_format_trace_output_empty() {
     let spans: Vec<TraceSpan> = vec![];
-    let output = format_trace_output("abc123def456", &spans, None);
+    let output = format_trace_output("abc123def456", &spans, None, None);
     assert!(output.contains("# Trace Details"));
     assert!(output.contains("**Trace ID:** abc123def456"));
     assert!(output.contains("**Transactions:** 0"));
@@ -139,7 +129,7 @@ fn test_format_trace_output_empty() {
 fn test_format_trace_output_with_transaction() {
     let span = make_span(Some("http.request"), 150.0, vec![]);
     let spans = vec![span];
-    let output = format_trace_output("trace-id", &spans, None);
+    let output = format_trace_output("trace-id", &spans, None, None);
     assert!(output.contains("**Transactions:** 1"));
     assert!(output.contains("## Operation Breakdown"));
     assert!(output.contains("**http.request**"));
@@ -154,7 +144,7 @@ fn test_format_trace_output_duration_calculation() {
     span2.start_timestamp = 1000.5;
     span2.end_timestamp = 1002.0;
     let spans = vec![span1, span2];
-    let output = format_trace_output("trace-id", &spans, None);
+    let output = format_trace_output("trace-id", &spans, None, None);
     assert!(output.contains("**Total Duration:**"));
     assert!(output.contains("2.00s"));
 }
@@ -165,7 +155,7 @@ fn test_format_trace_output_multiple_same_operations() {
     let span2 = make_span(Some("db.query"), 30.0, vec![]);
     let span3 = make_span(Some("db.query"), 20.0, vec![]);
     let spans = vec![span1, span2, span3];
-    let output = format_trace_output("trace-id", &spans, None);
+    let output = format_trace_output("trace-id", &spans, None, None);
     assert!(output.contains("**db.query**"));
     assert!(output.contains("3 occurrences"));
     assert!(output.contains("100.00ms total"));
@@ -249,7 +239,7 @@ fn test_format_trace_output_with_meta() {
             .into_iter()
             .collect(),
     };
-    let output = format_trace_output("trace-id", &[span], Some(&meta));
+    let output = format_trace_output("trace-id", &[span], Some(&meta), None);
     assert!(output.contains("**Total Spans:** 500"));
     assert!(output.contains("**Errors:** 3"));
     assert!(output.contains("**Performance Issues:** 1"));
@@ -370,3 +360,410 @@ fn test_select_interesting_spans_children_stripped() {
         assert!(span.children.is_empty());
     }
 }
+
+#[test]
+fn test_span_attributes_ordered_and_legacy_fallback() {
+    let mut span = make_span(Some("db"), 5.0, vec![]);
+    span.data = serde_json::json!({
+        "cache.hit": false,
+        "db.system": "postgresql",
+        "http.status_code": 503,
+        "thread.name": "main"
+    });
+    let attrs = span_attributes(&span);
+    assert_eq!(
+        attrs,
+        vec![
+            ("http.response.status_code", "503".to_string()),
+            ("db.system", "postgresql".to_string()),
+            ("cache.hit", "false".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_format_span_tree_shows_attributes_inline() {
+    let mut span = make_span(Some("http.client"), 50.0, vec![]);
+    span.data = serde_json::json!({
+        "http.request.method": "GET",
+        "http.response.status_code": "500"
+    });
+    let mut output = String::new();
+    format_span_tree(&span, 0, &mut output);
+    assert!(output.contains(
+        "test description {http.request.method=GET, http.response.status_code=500} (50.00ms)"
+    ));
+}
+
+#[test]
+fn test_format_span_tree_without_attributes_unchanged() {
+    let span = make_span(Some("http"), 50.0, vec![]);
+    let mut output = String::new();
+    format_span_tree(&span, 0, &mut output);
+    assert!(output.contains("test description (50.00ms)"));
+}
+
+fn make_http_span(op: &str, description: &str, status: serde_json::Value) -> TraceSpan {
+    let mut span = make_span(Some(op), 20.0, vec![]);
+    span.description = Some(description.to_string());
+    span.data = serde_json::json!({ "http.response.status_code": status });
+    span
+}
+
+#[test]
+fn test_http_status_code_only_for_http_spans() {
+    let client = make_http_span("http.client", "GET /a", serde_json::json!("404"));
+    assert_eq!(http_status_code(&client), Some(404));
+    let db = make_http_span("db", "SELECT 1", serde_json::json!(500));
+    assert_eq!(http_status_code(&db), None);
+    let no_status = make_span(Some("http.server"), 10.0, vec![]);
+    assert_eq!(http_status_code(&no_status), None);
+}
+
+#[test]
+fn test_rollup_http_statuses_counts_classes_and_failures() {
+    let children = vec![
+        make_http_span(
+            "http.client",
+            "GET https://api/users",
+            serde_json::json!(200),
+        ),
+        make_http_span(
+            "http.client",
+            "POST https://api/pay",
+            serde_json::json!(502),
+        ),
+        make_http_span(
+            "http.client",
+            "POST https://api/pay",
+            serde_json::json!(502),
+        ),
+        make_http_span(
+            "http.client",
+            "GET https://api/missing",
+            serde_json::json!(404),
+        ),
+    ];
+    let mut root = make_http_span("http.server", "POST /checkout", serde_json::json!(500));
+    root.children = children;
+    let rollup = rollup_http_statuses(&[root]);
+    assert_eq!(rollup.classes.get("2xx"), Some(&1));
+    assert_eq!(rollup.classes.get("4xx"), Some(&1));
+    assert_eq!(rollup.classes.get("5xx"), Some(&3));
+    assert_eq!(
+        rollup
+            .failing
+            .get(&(502, "POST https://api/pay".to_string())),
+        Some(&2)
+    );
+    assert_eq!(rollup.failing.len(), 3);
+}
+
+#[test]
+fn test_format_trace_output_http_status_section() {
+    let mut root = make_http_span("http.server", "GET /", serde_json::json!(200));
+    root.children = vec![
+        make_http_span("http.client", "GET https://x/fail", serde_json::json!(503)),
+        make_http_span("http.client", "GET https://x/fail", serde_json::json!(503)),
+    ];
+    let output = format_trace_output("abc", &[root], None, None);
+    assert!(output.contains("## HTTP Status Codes"));
+    assert!(output.contains("- **2xx**: 1"));
+    assert!(output.contains("- **5xx**: 2"));
+    assert!(output.contains("- 503 GET https://x/fail (×2)"));
+}
+
+#[test]
+fn test_format_trace_output_no_http_status_section_without_codes() {
+    let span = make_span(Some("http.server"), 100.0, vec![]);
+    let output = format_trace_output("abc", &[span], None, None);
+    assert!(!output.contains("HTTP Status Codes"));
+}
+
+#[test]
+fn test_format_span_tree_collapses_identical_siblings() {
+    let mut children: Vec<TraceSpan> = (0..40)
+        .map(|_| make_span(Some("db"), 5.0, vec![]))
+        .collect();
+    children.push(make_span(Some("cache"), 1.0, vec![]));
+    let root = make_span(Some("http.server"), 300.0, children);
+    let mut output = String::new();
+    format_span_tree(&root, 0, &mut output);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].contains("[db] test description ×40 (200.00ms total, 5.00ms avg)"));
+    assert!(lines[1].starts_with("  "));
+    assert!(lines[2].contains("[cache]"));
+}
+
+#[test]
+fn test_format_span_siblings_keeps_small_groups() {
+    let spans = vec![
+        make_span(Some("db"), 5.0, vec![]),
+        make_span(Some("db"), 6.0, vec![]),
+    ];
+    let mut output = String::new();
+    format_span_siblings(&spans, 0, &mut output);
+    assert_eq!(output.lines().count(), 2);
+    assert!(!output.contains('×'));
+}
+
+#[test]
+fn test_format_span_siblings_collapsed_marks_errors() {
+    let mut spans: Vec<TraceSpan> = (0..3)
+        .map(|_| make_span(Some("http.client"), 10.0, vec![]))
+        .collect();
+    spans[1].errors = vec![serde_json::json!({"title": "boom"})];
+    let mut output = String::new();
+    format_span_siblings(&spans, 1, &mut output);
+    assert!(output.starts_with("  ✗ [http.client] test description ×3"));
+}
+
+#[test]
+fn test_summarize_trace_errors_groups_by_title() {
+    let mut db = make_span(Some("db.query"), 5.0, vec![]);
+    db.description = Some("SELECT * FROM orders".to_string());
+    db.errors = vec![serde_json::json!({
+        "title": "OperationalError: timeout", "level": "error",
+        "issue_short_id": "API-12", "timestamp": 100.5
+    })];
+    let mut http = make_span(Some("http.client"), 5.0, vec![]);
+    http.description = Some("GET /inventory".to_string());
+    http.errors = vec![
+        serde_json::json!({"title": "OperationalError: timeout", "timestamp": 100.2}),
+        serde_json::json!({"title": "KeyError: 'sku'", "issue_id": 42, "timestamp": 100.9}),
+    ];
+    let root = make_span(Some("http.server"), 50.0, vec![db, http]);
+    let groups = summarize_trace_errors(&[root]);
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].title, "OperationalError: timeout");
+    assert_eq!(groups[0].count, 2);
+    assert_eq!(groups[0].issue.as_deref(), Some("API-12"));
+    assert_eq!(
+        groups[0].first_span,
+        ("http.client".to_string(), "GET /inventory".to_string())
+    );
+    assert_eq!(groups[1].issue.as_deref(), Some("42"));
+}
+
+#[test]
+fn test_format_trace_output_errors_section() {
+    let mut span = make_span(Some("http.server"), 50.0, vec![]);
+    span.errors = vec![serde_json::json!({"title": "ValueError: bad", "level": "error"})];
+    let output = format_trace_output("abc", &[span], None, None);
+    assert!(output.contains(
+        "## Errors\n\n- **ValueError: bad** ×1 [error]; first in [http.server] test description\n"
+    ));
+    let clean = format_trace_output(
+        "abc",
+        &[make_span(Some("http.server"), 50.0, vec![])],
+        None,
+        None,
+    );
+    assert!(!clean.contains("## Errors"));
+}
+
+fn timed_span(op: &str, start: f64, duration: f64, children: Vec<TraceSpan>) -> TraceSpan {
+    let mut span = make_span(Some(op), duration, children);
+    span.start_timestamp = start;
+    span.end_timestamp = start + duration / 1000.0;
+    span
+}
+
+#[test]
+fn test_find_start_gaps_before_first_child() {
+    let db = timed_span("db.query", 1000.9, 50.0, vec![]);
+    let server = timed_span("http.server", 1000.0, 1000.0, vec![db]);
+    let gaps = find_start_gaps(&[server]);
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps[0].kind, StartGapKind::FirstChild);
+    assert_eq!(gaps[0].op, "http.server");
+    assert!((gaps[0].gap_ms - 900.0).abs() < 1.0);
+}
+
+#[test]
+fn test_find_start_gaps_ignores_small_and_untimed() {
+    let child = timed_span("db.query", 1000.05, 10.0, vec![]);
+    let server = timed_span("http.server", 1000.0, 100.0, vec![child]);
+    assert!(find_start_gaps(&[server]).is_empty());
+    let untimed = make_span(
+        Some("http.server"),
+        100.0,
+        vec![make_span(Some("db"), 5.0, vec![])],
+    );
+    assert!(find_start_gaps(&[untimed]).is_empty());
+}
+
+#[test]
+fn test_find_start_gaps_before_server_span() {
+    let server = timed_span("http.server", 1002.0, 100.0, vec![]);
+    let client = timed_span("http.client", 1000.0, 2200.0, vec![server]);
+    let pageload = timed_span("pageload", 1000.0, 3000.0, vec![client]);
+    let gaps = find_start_gaps(&[pageload]);
+    let server_gap = gaps
+        .iter()
+        .find(|g| g.kind == StartGapKind::FirstServerSpan)
+        .unwrap();
+    assert!((server_gap.gap_ms - 2000.0).abs() < 1.0);
+    let output = format_trace_output("abc", &gaps_trace(), None, None);
+    assert!(output.contains("## Start Gaps (queue / cold start)"));
+    assert!(output.contains("between trace start and [http.server]"));
+}
+
+fn gaps_trace() -> Vec<TraceSpan> {
+    let server = timed_span("http.server", 1001.5, 100.0, vec![]);
+    vec![timed_span("pageload", 1000.0, 2000.0, vec![server])]
+}
+
+#[test]
+fn test_format_span_tree_summarizes_graphql() {
+    let mut span = make_span(Some("graphql.execute"), 120.0, vec![]);
+    span.description = Some(format!(
+        "query GetOrder($id: ID!) {{ order(id: $id) {{ {} }} viewer {{ id }} }}",
+        "lineItem { sku } ".repeat(100)
+    ));
+    let mut output = String::new();
+    format_span_tree(&span, 0, &mut output);
+    assert!(output.contains("[graphql.execute] query GetOrder { order, viewer } (120.00ms)"));
+    assert!(!output.contains("lineItem"));
+}
+
+#[test]
+fn test_format_trace_output_sampling_context() {
+    let dsc = serde_json::json!({
+        "trace_id": "abc",
+        "public_key": "49d0f7386ad645858ae85020e393bef3",
+        "sample_rate": "0.25",
+        "sampled": true,
+        "release": "backend@1.2.0",
+        "environment": "production",
+        "transaction": ""
+    });
+    let span = make_span(Some("http.server"), 50.0, vec![]);
+    let output = format_trace_output("abc", &[span], None, Some(&dsc));
+    assert!(output.contains(
+        "## Sampling Context (baggage)\n\n- **Public Key:** 49d0f7386ad645858ae85020e393bef3\n- **Sample Rate:** 0.25\n- **Sampled:** true\n- **Release:** backend@1.2.0\n- **Environment:** production\n"
+    ));
+    assert!(!output.contains("**Transaction:**"));
+    assert!(output.contains("head-sampled at rate 0.25"));
+
+    let full = serde_json::json!({"public_key": "k", "sample_rate": 1.0});
+    let span = make_span(Some("http.server"), 50.0, vec![]);
+    let output = format_trace_output("abc", &[span], None, Some(&full));
+    assert!(output.contains("- **Sample Rate:** 1.0"));
+    assert!(!output.contains("head-sampled"));
+}
+
+#[test]
+fn test_collect_profiles_and_section() {
+    let mut child = make_span(Some("http.server"), 40.0, vec![]);
+    child.project_slug = "backend".to_string();
+    child.transaction = Some("GET /api/orders".to_string());
+    child.profile_id = Some("p2".to_string());
+    let mut root = make_span(Some("pageload"), 100.0, vec![child]);
+    root.profile_id = Some("p1".to_string());
+    let spans = vec![root];
+    assert_eq!(
+        collect_profiles(&spans),
+        vec![
+            ("test-transaction", "test-project", "p1"),
+            ("GET /api/orders", "backend", "p2")
+        ]
+    );
+    let output = format_trace_output("abc", &spans, None, None);
+    assert!(output.contains(
+        "## Profiles\n\n- test-transaction (test-project): `p1`\n- GET /api/orders (backend): `p2`\n"
+    ));
+    let plain = format_trace_output(
+        "abc",
+        &[make_span(Some("http.server"), 50.0, vec![])],
+        None,
+        None,
+    );
+    assert!(!plain.contains("## Profiles"));
+}
+
+#[test]
+fn test_collect_occurrences_reads_evidence() {
+    let mut query = make_span(Some("db"), 2.0, vec![]);
+    query.is_transaction = false;
+    query.description = Some("SELECT * FROM orders WHERE id = %s".to_string());
+    query.occurrences = vec![serde_json::json!({
+        "title": "N+1 Query",
+        "issue_type": "performance_n_plus_one_db_queries",
+        "issue_short_id": "BACK-12",
+        "evidence_data": {
+            "repeating_spans_compact": ["SELECT * FROM orders WHERE id = %s"],
+            "offender_span_ids": ["a", "b", "c"]
+        }
+    })];
+    let mut legacy = make_span(Some("http.server"), 80.0, vec![query]);
+    legacy.occurrences =
+        vec![serde_json::json!({"type": 1007, "issue_id": 9, "suspect_spans": ["x", "y"]})];
+    let occurrences = collect_occurrences(&[legacy]);
+    assert_eq!(occurrences.len(), 2);
+    assert_eq!(occurrences[0].title, "Consecutive DB Queries");
+    assert_eq!(occurrences[0].issue.as_deref(), Some("9"));
+    assert_eq!(occurrences[0].offenders, Some(2));
+    assert_eq!(occurrences[1].title, "N+1 Query");
+    assert_eq!(occurrences[1].span.0, "db");
+    assert_eq!(
+        occurrences[1].repeating.as_deref(),
+        Some("SELECT * FROM orders WHERE id = %s")
+    );
+    assert_eq!(occurrences[1].offenders, Some(3));
+}
+
+#[test]
+fn test_format_trace_output_performance_issues() {
+    let mut query = make_span(Some("db"), 2.0, vec![]);
+    query.is_transaction = false;
+    query.description = Some("SELECT 1".to_string());
+    query.occurrences = vec![serde_json::json!({
+        "title": "N+1 Query",
+        "short_id": "BACK-12",
+        "evidence_data": {"repeating_spans": "SELECT 1", "num_repeating_spans": 25}
+    })];
+    let spans = vec![make_span(Some("http.server"), 80.0, vec![query])];
+    let output = format_trace_output("abc", &spans, None, None);
+    assert!(output.contains(
+        "## Performance Issues\n\n- **N+1 Query** (issue BACK-12); in [db] SELECT 1\n  - Repeating: `SELECT 1`\n  - Offending spans: 25\n"
+    ));
+    // The short span is kept in the tree because it carries the occurrence.
+    assert!(output.contains("✓ [db] SELECT 1 (2.00ms) test-project ⚠ N+1 Query\n"));
+    let plain = format_trace_output(
+        "abc",
+        &[make_span(Some("http.server"), 50.0, vec![])],
+        None,
+        None,
+    );
+    assert!(!plain.contains("## Performance Issues"));
+}
+
+#[test]
+fn test_span_measurements_for_mobile_transaction() {
+    let mut span = make_span(Some("ui.load"), 1200.0, vec![]);
+    span.measurements = serde_json::json!({
+        "frames_total": {"value": 400.0, "unit": "none"},
+        "frames_slow": {"value": 12.0, "unit": "none"},
+        "frames_frozen": 1,
+        "time_to_initial_display": {"value": 812.5, "unit": "millisecond"},
+        "measurements.time_to_full_display": 1500.0,
+        "lcp": 90.0
+    });
+    assert_eq!(
+        span_measurements(&span),
+        vec![
+            ("ttid", "812.50ms".to_string()),
+            ("ttfd", "1.50s".to_string()),
+            ("frames_slow", "12 (3.0%)".to_string()),
+            ("frames_frozen", "1 (0.2%)".to_string()),
+        ]
+    );
+    let mut output = String::new();
+    format_span_tree(&span, 0, &mut output);
+    assert!(output.contains(
+        "[ui.load] test description {ttid=812.50ms, ttfd=1.50s, frames_slow=12 (3.0%), frames_frozen=1 (0.2%)} (1.20s)"
+    ));
+    assert!(span_measurements(&make_span(Some("db"), 5.0, vec![])).is_empty());
+}
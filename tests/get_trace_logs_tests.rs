@@ -0,0 +1,65 @@
+use sentry_mcp::api_client::DiscoverResult;
+use sentry_mcp::tools::get_trace_logs::{
+    build_logs_query, count_by_severity, format_log_line, format_trace_logs_output,
+};
+use serde_json::json;
+
+fn logs(rows: serde_json::Value) -> DiscoverResult {
+    serde_json::from_value(json!({ "data": rows })).unwrap()
+}
+
+#[test]
+fn test_build_logs_query() {
+    assert_eq!(build_logs_query("abc", None, None), "trace:abc");
+    assert_eq!(
+        build_logs_query("abc", Some(" Error "), Some("message:*timeout*")),
+        "trace:abc severity:error message:*timeout*"
+    );
+}
+
+#[test]
+fn test_count_by_severity_orders_most_severe_first() {
+    let result = logs(json!([
+        {"severity": "info"}, {"severity": "WARNING"}, {"severity": "custom"},
+        {"severity": "error"}, {"severity": "info"}, {}
+    ]));
+    assert_eq!(
+        count_by_severity(&result.data),
+        vec![
+            ("error".to_string(), 1),
+            ("warn".to_string(), 1),
+            ("info".to_string(), 3),
+            ("custom".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn test_format_log_line_indents_continuations() {
+    let result = logs(json!([{
+        "timestamp": "2024-05-01T10:00:00+00:00", "severity": "warn",
+        "message": "retrying\nattempt 2\n", "project": "worker"
+    }]));
+    assert_eq!(
+        format_log_line(&result.data[0]),
+        "2024-05-01T10:00:00+00:00 WARN  [worker] retrying\n    attempt 2"
+    );
+}
+
+#[test]
+fn test_format_trace_logs_output() {
+    let result = logs(json!([
+        {"timestamp": "t1", "severity": "info", "message": "a", "project": "web"},
+        {"timestamp": "t2", "severity": "error", "message": "b", "project": "api"}
+    ]));
+    let output = format_trace_logs_output("abc", &result, Some(2));
+    assert!(output.contains("**Logs:** 2\n"));
+    assert!(output.contains("**By Severity:** error 1, info 1"));
+    assert!(output.contains("```\nt1 INFO  [web] a\nt2 ERROR [api] b\n```"));
+}
+
+#[test]
+fn test_format_trace_logs_output_empty() {
+    let output = format_trace_logs_output("abc", &logs(json!([])), None);
+    assert!(output.contains("No logs found for this trace."));
+}
@@ -0,0 +1,68 @@
+use sentry_mcp::tools::graphql::summarize_graphql;
+
+#[test]
+fn test_named_query_with_variables() {
+    let query = r#"
+        # Loads the order page
+        query GetOrder($id: ID!, $withItems: Boolean = true) {
+          order(id: $id) {
+            id
+            items @include(if: $withItems) { sku quantity }
+          }
+          viewer { name }
+        }
+    "#;
+    assert_eq!(
+        summarize_graphql(query).as_deref(),
+        Some("query GetOrder { order, viewer }")
+    );
+}
+
+#[test]
+fn test_anonymous_query_aliases_and_fragments() {
+    let query = r#"{ me: viewer { ...UserFields } search(term: "a { b }") { ... on Product { id } } ...Extra }"#;
+    assert_eq!(
+        summarize_graphql(query).as_deref(),
+        Some("query { viewer, search, ...Extra }")
+    );
+}
+
+#[test]
+fn test_mutation_with_object_argument_and_directive() {
+    let query =
+        r#"mutation CreateOrder { createOrder(input: {sku: "x", qty: 2}) @deprecated { id } }"#;
+    assert_eq!(
+        summarize_graphql(query).as_deref(),
+        Some("mutation CreateOrder { createOrder }")
+    );
+}
+
+#[test]
+fn test_many_fields_are_counted() {
+    let query = "query Dashboard { a b c d e f g }";
+    assert_eq!(
+        summarize_graphql(query).as_deref(),
+        Some("query Dashboard { a, b, c, d, e, +2 more }")
+    );
+}
+
+#[test]
+fn test_non_graphql_text() {
+    assert!(summarize_graphql("SELECT * FROM orders WHERE id = 1").is_none());
+    assert!(summarize_graphql("GET /api/orders").is_none());
+    assert!(summarize_graphql("query GetOrder").is_none());
+    assert!(summarize_graphql("").is_none());
+}
+
+#[test]
+fn test_non_ascii_strings() {
+    let query = r#"
+        mutation Rename {
+          rename(name: """Café ☕""", note: "Crème \"brûlée\" — ok") { id }
+        }
+    "#;
+    assert_eq!(
+        summarize_graphql(query).as_deref(),
+        Some("mutation Rename { rename }")
+    );
+}
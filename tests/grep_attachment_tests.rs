@@ -0,0 +1,106 @@
+use regex::Regex;
+use sentry_mcp::api_client::EventAttachment;
+use sentry_mcp::tools::grep_attachment::{format_grep_output, grep_lines, select_text_attachment};
+
+const LOG: &str =
+    "boot\nconnect db\nERROR timeout\nretry\nretry\nok\nidle\nidle\nERROR crash\nexit";
+
+fn attachment(id: &str, kind: &str) -> EventAttachment {
+    EventAttachment {
+        id: id.to_string(),
+        name: format!("{}.log", id),
+        attachment_type: kind.to_string(),
+        size: 128,
+        mimetype: Some("text/plain".to_string()),
+    }
+}
+
+#[test]
+fn test_grep_lines_separate_blocks() {
+    let regex = Regex::new("ERROR").unwrap();
+    let result = grep_lines(LOG, &regex, 1, 50);
+    assert_eq!(result.matches, 2);
+    assert!(!result.truncated);
+    assert_eq!(result.blocks.len(), 2);
+    assert_eq!(
+        result.blocks[0].lines,
+        vec![
+            (2, false, "connect db"),
+            (3, true, "ERROR timeout"),
+            (4, false, "retry")
+        ]
+    );
+    assert_eq!(result.blocks[1].lines.first(), Some(&(8, false, "idle")));
+}
+
+#[test]
+fn test_grep_lines_merges_overlapping_context() {
+    let regex = Regex::new("retry").unwrap();
+    let result = grep_lines(LOG, &regex, 1, 50);
+    assert_eq!(result.blocks.len(), 1);
+    let numbers: Vec<usize> = result.blocks[0].lines.iter().map(|l| l.0).collect();
+    assert_eq!(numbers, vec![3, 4, 5, 6]);
+    let flagged: Vec<usize> = result.blocks[0]
+        .lines
+        .iter()
+        .filter(|l| l.1)
+        .map(|l| l.0)
+        .collect();
+    assert_eq!(flagged, vec![4, 5]);
+}
+
+#[test]
+fn test_grep_lines_respects_max_matches() {
+    let regex = Regex::new("i").unwrap();
+    let result = grep_lines(LOG, &regex, 0, 2);
+    assert_eq!(result.matches, 2);
+    assert!(result.truncated);
+}
+
+#[test]
+fn test_grep_lines_no_match() {
+    let regex = Regex::new("panic").unwrap();
+    let result = grep_lines(LOG, &regex, 2, 50);
+    assert_eq!(result.matches, 0);
+    assert!(result.blocks.is_empty());
+}
+
+#[test]
+fn test_select_text_attachment() {
+    let attachments = vec![
+        attachment("1", "event.minidump"),
+        attachment("2", "event.attachment"),
+    ];
+    assert_eq!(select_text_attachment(&attachments, None).unwrap().id, "2");
+    assert_eq!(
+        select_text_attachment(&attachments, Some("1")).unwrap().id,
+        "1"
+    );
+    assert!(select_text_attachment(&attachments, Some("9")).is_err());
+    assert!(select_text_attachment(&attachments[..1], None).is_err());
+    let many = vec![
+        attachment("2", "event.attachment"),
+        attachment("3", "event.attachment"),
+    ];
+    let err = select_text_attachment(&many, None).unwrap_err();
+    assert!(err.contains("2 (2.log), 3 (3.log)"));
+}
+
+#[test]
+fn test_format_grep_output() {
+    let regex = Regex::new("ERROR").unwrap();
+    let result = grep_lines(LOG, &regex, 1, 1);
+    let output = format_grep_output(&attachment("2", "event.attachment"), "ERROR", &result);
+    assert!(output.contains("**Pattern:** `ERROR`"));
+    assert!(output.contains("**Matches:** 1 (limit reached, more matches not shown)"));
+    assert!(output.contains("```\n2- connect db\n3: ERROR timeout\n4- retry\n```"));
+}
+
+#[test]
+fn test_format_grep_output_separates_blocks_and_truncates_lines() {
+    let text = format!("{}\nx\nx\nx\nA", "A".repeat(600));
+    let regex = Regex::new("A").unwrap();
+    let result = grep_lines(&text, &regex, 0, 50);
+    let output = format_grep_output(&attachment("2", "event.attachment"), "A", &result);
+    assert!(output.contains(&format!("1: {}…\n--\n5: A\n", "A".repeat(500))));
+}
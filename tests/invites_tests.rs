@@ -0,0 +1,124 @@
+use sentry_mcp::api_client::{OrgMember, Team};
+use sentry_mcp::tools::invites::invite_member::{InviteMemberInput, build_invite};
+use sentry_mcp::tools::invites::list_pending_invites::{format_invites_output, pending_invites};
+use sentry_mcp::tools::invites::{format_teams, invite_state};
+use serde_json::json;
+
+fn members() -> Vec<OrgMember> {
+    serde_json::from_value(json!([
+        {"id": "1", "email": "lead@example.com", "orgRole": "owner", "role": "owner", "pending": false},
+        {"id": "2", "email": "new@example.com", "role": "member", "pending": true,
+         "inviteStatus": "approved", "dateCreated": "2024-05-01T10:00:00Z",
+         "teamRoles": [{"teamSlug": "backend", "role": "admin"}]},
+        {"id": "3", "email": "old@example.com", "orgRole": "member", "pending": true, "expired": true},
+        {"id": "4", "email": "req@example.com", "orgRole": "member", "pending": true,
+         "inviteStatus": "requested_to_be_invited"}
+    ]))
+    .unwrap()
+}
+
+fn teams() -> Vec<Team> {
+    serde_json::from_value(json!([
+        {"id": "1", "slug": "backend", "name": "Backend"},
+        {"id": "2", "slug": "oncall", "name": "On-call"}
+    ]))
+    .unwrap()
+}
+
+fn input(email: &str) -> InviteMemberInput {
+    InviteMemberInput {
+        organization_slug: "test-org".to_string(),
+        email: email.to_string(),
+        role: None,
+        teams: None,
+        team_role: None,
+    }
+}
+
+#[test]
+fn test_org_role_falls_back_to_role() {
+    let members = members();
+    assert_eq!(members[0].org_role(), Some("owner"));
+    assert_eq!(members[1].org_role(), Some("member"));
+}
+
+#[test]
+fn test_invite_state() {
+    let members = members();
+    assert_eq!(invite_state(&members[1]), "sent");
+    assert_eq!(invite_state(&members[2]), "expired");
+    assert_eq!(invite_state(&members[3]), "awaiting approval");
+}
+
+#[test]
+fn test_format_teams() {
+    let members = members();
+    assert_eq!(format_teams(&members[1]), "#backend (admin)");
+    assert_eq!(format_teams(&members[0]), "");
+}
+
+#[test]
+fn test_format_invites_output() {
+    let members = members();
+    let invites = pending_invites(&members);
+    assert_eq!(invites.len(), 3);
+    let output = format_invites_output("test-org", &invites);
+    assert!(output.contains("**Pending:** 3"));
+    assert!(output.contains(
+        "| new@example.com | member | #backend (admin) | sent |  | 2024-05-01T10:00:00Z |"
+    ));
+    assert!(output.contains("| old@example.com | member |  | expired |"));
+    assert!(!output.contains("lead@example.com"));
+}
+
+#[test]
+fn test_format_invites_output_empty() {
+    let output = format_invites_output("test-org", &[]);
+    assert!(output.contains("No pending invites."));
+}
+
+#[test]
+fn test_build_invite_with_teams() {
+    let mut request = input("oncall@example.com");
+    request.role = Some("Admin".to_string());
+    request.teams = Some(vec![
+        "backend".to_string(),
+        "#oncall".to_string(),
+        "backend".to_string(),
+    ]);
+    request.team_role = Some("contributor".to_string());
+    let invite = build_invite(&request, &teams(), &members()).unwrap();
+    assert_eq!(invite.email, "oncall@example.com");
+    assert_eq!(invite.org_role, "admin");
+    let slugs: Vec<&str> = invite
+        .team_roles
+        .iter()
+        .map(|t| t.team_slug.as_str())
+        .collect();
+    assert_eq!(slugs, vec!["backend", "oncall"]);
+    assert_eq!(invite.team_roles[0].role.as_deref(), Some("contributor"));
+    assert!(invite.send_invite);
+}
+
+#[test]
+fn test_build_invite_rejects_invalid_input() {
+    let members = members();
+    let teams = teams();
+    assert!(build_invite(&input("not-an-email"), &teams, &members).is_err());
+    let mut bad_role = input("a@example.com");
+    bad_role.role = Some("superuser".to_string());
+    let err = build_invite(&bad_role, &teams, &members).unwrap_err();
+    assert!(err.message.contains("Invalid role 'superuser'"));
+    let mut bad_team_role = input("a@example.com");
+    bad_team_role.team_role = Some("owner".to_string());
+    assert!(build_invite(&bad_team_role, &teams, &members).is_err());
+}
+
+#[test]
+fn test_build_invite_rejects_existing_members() {
+    let members = members();
+    let err = build_invite(&input("LEAD@example.com"), &teams(), &members).unwrap_err();
+    assert!(err.message.contains("is already a member"));
+    let err = build_invite(&input("new@example.com"), &teams(), &members).unwrap_err();
+    assert!(err.message.contains("already has a pending invite"));
+}
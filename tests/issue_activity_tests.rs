@@ -0,0 +1,106 @@
+use sentry_mcp::api_client::IssueActivity;
+use sentry_mcp::tools::issue_activity::{describe_activity, format_activity_output};
+use serde_json::json;
+
+fn activity(activity_type: &str, data: serde_json::Value) -> IssueActivity {
+    serde_json::from_value(json!({"id": "1", "type": activity_type, "data": data})).unwrap()
+}
+
+#[test]
+fn test_describe_resolutions() {
+    assert_eq!(
+        describe_activity(&activity(
+            "set_resolved_in_release",
+            json!({"version": "1.2.3"})
+        )),
+        "resolved in release 1.2.3"
+    );
+    assert_eq!(
+        describe_activity(&activity("set_resolved_in_release", json!({"version": ""}))),
+        "resolved in the next release"
+    );
+    assert_eq!(
+        describe_activity(&activity(
+            "set_resolved_in_commit",
+            json!({"commit": {"id": "abcdef1234567890", "repository": {"name": "acme/api"}}})
+        )),
+        "resolved in commit abcdef123456 (acme/api)"
+    );
+    assert_eq!(
+        describe_activity(&activity("set_regression", json!({"version": "2.0.0"}))),
+        "regressed in release 2.0.0"
+    );
+}
+
+#[test]
+fn test_describe_ignored_variants() {
+    assert_eq!(
+        describe_activity(&activity("set_ignored", json!({"ignoreDuration": 120}))),
+        "archived for 2h"
+    );
+    assert_eq!(
+        describe_activity(&activity(
+            "set_ignored",
+            json!({"ignoreCount": 100, "ignoreWindow": 1440})
+        )),
+        "archived until 100 more events in 1d"
+    );
+    assert_eq!(
+        describe_activity(&activity(
+            "set_ignored",
+            json!({"ignoreUntilEscalating": true})
+        )),
+        "archived until escalating"
+    );
+    assert_eq!(
+        describe_activity(&activity("set_ignored", json!({}))),
+        "archived"
+    );
+}
+
+#[test]
+fn test_describe_assignment_and_unknown() {
+    assert_eq!(
+        describe_activity(&activity(
+            "assigned",
+            json!({"assignee": "42", "assigneeEmail": "jane@example.com", "assigneeType": "user"})
+        )),
+        "assigned to jane@example.com"
+    );
+    assert_eq!(
+        describe_activity(&activity(
+            "assigned",
+            json!({"assignee": "7", "assigneeName": "backend", "assigneeType": "team"})
+        )),
+        "assigned to team backend"
+    );
+    assert_eq!(
+        describe_activity(&activity("some_new_thing", json!({}))),
+        "some new thing"
+    );
+}
+
+#[test]
+fn test_format_activity_oldest_first_with_limit() {
+    let feed: Vec<IssueActivity> = serde_json::from_value(json!([
+        {"id": "3", "type": "set_unresolved", "user": {"username": "bob"},
+         "dateCreated": "2024-05-03T00:00:00Z"},
+        {"id": "2", "type": "set_resolved", "user": {"name": "Jane"},
+         "dateCreated": "2024-05-02T00:00:00Z"},
+        {"id": "1", "type": "first_seen", "dateCreated": "2024-05-01T00:00:00Z"}
+    ]))
+    .unwrap();
+    let entries: Vec<&IssueActivity> = feed.iter().collect();
+    let output = format_activity_output("PROJ-1", &entries, 2);
+    assert!(output.contains("**Found:** 3 entries (showing the latest 2)"));
+    assert!(output.contains(
+        "- 2024-05-02T00:00:00Z — Jane: resolved\n- 2024-05-03T00:00:00Z — bob: unresolved\n"
+    ));
+    assert!(!output.contains("first seen"));
+}
+
+#[test]
+fn test_format_activity_empty() {
+    let output = format_activity_output("PROJ-1", &[], 50);
+    assert!(output.contains("No activity recorded for this issue."));
+}
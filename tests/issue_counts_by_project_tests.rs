@@ -0,0 +1,84 @@
+use sentry_mcp::api_client::{DiscoverResult, Project};
+use sentry_mcp::tools::issue_counts_by_project::{
+    ProjectCounts, events_by_project, format_issue_counts_by_project, project_rows,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn project(id: &str, slug: &str) -> Project {
+    Project {
+        id: id.to_string(),
+        name: slug.to_string(),
+        slug: slug.to_string(),
+    }
+}
+
+#[test]
+fn test_events_by_project() {
+    let result: DiscoverResult = serde_json::from_value(json!({
+        "data": [
+            {"project": "backend", "count()": 1200},
+            {"project": "frontend", "count()": 30},
+            {"count()": 5}
+        ]
+    }))
+    .unwrap();
+    let events = events_by_project(&result);
+    assert_eq!(events.len(), 2);
+    assert_eq!(events["backend"], 1200.0);
+    assert_eq!(events["frontend"], 30.0);
+}
+
+#[test]
+fn test_project_rows_sorts_by_unresolved() {
+    let projects = vec![
+        project("1", "api"),
+        project("2", "backend"),
+        project("3", "frontend"),
+        project("4", "mobile"),
+    ];
+    let events = HashMap::from([("backend".to_string(), 50.0), ("api".to_string(), 10.0)]);
+    let rows = project_rows(&projects, vec![Some(3), Some(3), None, Some(40)], &events);
+    let slugs: Vec<&str> = rows.iter().map(|r| r.slug.as_str()).collect();
+    assert_eq!(slugs, ["mobile", "backend", "api", "frontend"]);
+    assert_eq!(rows[1].events_24h, 50.0);
+    assert_eq!(rows[0].events_24h, 0.0);
+}
+
+#[test]
+fn test_format_issue_counts_by_project() {
+    let rows = vec![
+        ProjectCounts {
+            slug: "backend".to_string(),
+            unresolved: Some(100),
+            events_24h: 1200.0,
+        },
+        ProjectCounts {
+            slug: "frontend".to_string(),
+            unresolved: Some(7),
+            events_24h: 30.0,
+        },
+        ProjectCounts {
+            slug: "mobile".to_string(),
+            unresolved: None,
+            events_24h: 0.0,
+        },
+    ];
+    let output = format_issue_counts_by_project("acme", Some("production"), &rows);
+    assert!(output.contains("# Issue Counts by Project: acme"));
+    assert!(output.contains("**Environment:** production"));
+    assert!(output.contains("**Projects:** 3"));
+    assert!(output.contains("| backend | 100+ | 1200 |"));
+    assert!(output.contains("| frontend | 7 | 30 |"));
+    assert!(output.contains("| mobile | ? | 0 |"));
+    assert!(output.contains("| **Total** | 107+ | 1230 |"));
+    assert!(output.contains("`?`: the count could not be fetched"));
+}
+
+#[test]
+fn test_format_issue_counts_by_project_empty() {
+    let output = format_issue_counts_by_project("acme", None, &[]);
+    assert!(output.contains("No projects found."));
+    assert!(!output.contains("**Environment:**"));
+    assert!(!output.contains("| Project |"));
+}
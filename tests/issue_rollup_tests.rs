@@ -0,0 +1,93 @@
+use sentry_mcp::api_client::Issue;
+use sentry_mcp::tools::issue_rollup::{format_rollup_output, rollup, title_key};
+use serde_json::json;
+
+fn make_issue(short_id: &str, title: &str, count: &str, users: i64, last_seen: &str) -> Issue {
+    serde_json::from_value(json!({
+        "id": short_id,
+        "shortId": short_id,
+        "title": title,
+        "status": "unresolved",
+        "project": {"id": "1", "name": "p", "slug": "p"},
+        "count": count,
+        "userCount": users,
+        "lastSeen": last_seen
+    }))
+    .unwrap()
+}
+
+fn sample() -> Vec<(String, Vec<Issue>)> {
+    vec![
+        (
+            "api".to_string(),
+            vec![
+                make_issue(
+                    "API-1",
+                    "ConnectionError: db down",
+                    "50",
+                    5,
+                    "2024-01-02T10:00:00Z",
+                ),
+                make_issue("API-2", "KeyError: 'user'", "10", 2, "2024-01-02T09:00:00Z"),
+            ],
+        ),
+        (
+            "worker".to_string(),
+            vec![make_issue(
+                "WRK-7",
+                "ConnectionError:  DB down",
+                "30",
+                1,
+                "2024-01-02T11:00:00Z",
+            )],
+        ),
+    ]
+}
+
+#[test]
+fn test_title_key_normalizes_case_and_whitespace() {
+    assert_eq!(title_key("  Foo   Bar "), "foo bar");
+    assert_eq!(
+        title_key("ConnectionError:  DB down"),
+        title_key("ConnectionError: db down")
+    );
+}
+
+#[test]
+fn test_rollup_merges_shared_titles() {
+    let groups = rollup(&sample());
+    assert_eq!(groups.len(), 2);
+    let shared = &groups[0];
+    assert_eq!(shared.title, "ConnectionError: db down");
+    assert_eq!(shared.projects, vec!["api", "worker"]);
+    assert_eq!(shared.short_ids, vec!["API-1", "WRK-7"]);
+    assert_eq!(shared.events, 80);
+    assert_eq!(shared.users, 6);
+    assert_eq!(shared.last_seen.as_deref(), Some("2024-01-02T11:00:00Z"));
+}
+
+#[test]
+fn test_rollup_sorts_by_events() {
+    let groups = rollup(&sample());
+    assert!(groups[0].events >= groups[1].events);
+    assert_eq!(groups[1].short_ids, vec!["API-2"]);
+}
+
+#[test]
+fn test_format_rollup_output_table_and_truncation() {
+    let per_project = sample();
+    let groups = rollup(&per_project);
+    let output = format_rollup_output(&per_project, &groups, 1);
+    assert!(output.contains("**Projects:** api, worker"));
+    assert!(output.contains("**Issues:** 3 fetched, 2 unique titles, 1 shared across projects"));
+    assert!(output.contains("| ConnectionError: db down | api, worker | API-1, WRK-7 | 80 | 6 |"));
+    assert!(!output.contains("KeyError"));
+    assert!(output.contains("*1 more titles not shown.*"));
+}
+
+#[test]
+fn test_format_rollup_output_empty() {
+    let per_project = vec![("api".to_string(), vec![])];
+    let output = format_rollup_output(&per_project, &[], 20);
+    assert!(output.contains("No issues found."));
+}
@@ -0,0 +1,315 @@
+use sentry_mcp::api_client::Issue;
+use sentry_mcp::tools::issue_stats::{
+    ForecastMethod, Resolution, Trend, classify_trend, forecast, format_issue_stats_output,
+    holt_winters_forecast, linear_forecast, peak_buckets, peak_hours, sparkline,
+};
+use sentry_mcp::tools::timezone::LocalZone;
+use serde_json::json;
+
+fn make_issue() -> Issue {
+    serde_json::from_value(json!({
+        "id": "42",
+        "shortId": "BACK-42",
+        "title": "TimeoutError",
+        "status": "unresolved",
+        "project": {"id": "1", "name": "backend", "slug": "backend"},
+        "count": "100",
+        "userCount": 3
+    }))
+    .unwrap()
+}
+
+fn daily_cycle(days: usize) -> Vec<f64> {
+    (0..days * 24)
+        .map(|h| {
+            if (9..18).contains(&(h % 24)) {
+                20.0
+            } else {
+                2.0
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_forecast_method_parse() {
+    assert_eq!(ForecastMethod::parse("auto"), Some(ForecastMethod::Auto));
+    assert_eq!(
+        ForecastMethod::parse("Linear"),
+        Some(ForecastMethod::Linear)
+    );
+    assert_eq!(
+        ForecastMethod::parse("holt-winters"),
+        Some(ForecastMethod::HoltWinters)
+    );
+    assert_eq!(ForecastMethod::parse("arima"), None);
+}
+
+#[test]
+fn test_linear_forecast_extends_trend() {
+    let series = vec![1.0, 2.0, 3.0, 4.0];
+    let projected = linear_forecast(&series, 3);
+    assert_eq!(projected.len(), 3);
+    assert!((projected[0] - 5.0).abs() < 1e-9);
+    assert!((projected[2] - 7.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_linear_forecast_clamps_to_zero() {
+    let series = vec![10.0, 5.0, 1.0];
+    let projected = linear_forecast(&series, 5);
+    assert!(projected.iter().all(|v| *v >= 0.0));
+    assert_eq!(projected[4], 0.0);
+}
+
+#[test]
+fn test_linear_forecast_empty_series() {
+    assert_eq!(linear_forecast(&[], 2), vec![0.0, 0.0]);
+}
+
+#[test]
+fn test_holt_winters_requires_two_seasons() {
+    assert!(holt_winters_forecast(&daily_cycle(1), 24, 24).is_none());
+    assert!(holt_winters_forecast(&daily_cycle(2), 24, 24).is_some());
+}
+
+#[test]
+fn test_holt_winters_follows_daily_cycle() {
+    let projected = holt_winters_forecast(&daily_cycle(7), 24, 24).unwrap();
+    assert_eq!(projected.len(), 24);
+    assert!(projected[12] > 15.0);
+    assert!(projected[3] < 5.0);
+}
+
+#[test]
+fn test_auto_forecast_falls_back_to_linear() {
+    assert_eq!(
+        forecast(&daily_cycle(1), ForecastMethod::Auto).method,
+        ForecastMethod::Linear
+    );
+    assert_eq!(
+        forecast(&daily_cycle(3), ForecastMethod::Auto).method,
+        ForecastMethod::HoltWinters
+    );
+    assert_eq!(
+        forecast(&daily_cycle(3), ForecastMethod::Linear).method,
+        ForecastMethod::Linear
+    );
+}
+
+#[test]
+fn test_forecast_first_breach() {
+    let projected = forecast(&daily_cycle(3), ForecastMethod::HoltWinters);
+    assert!(projected.first_breach(15.0).is_some());
+    assert!(projected.first_breach(1000.0).is_none());
+}
+
+#[test]
+fn test_format_issue_stats_summary() {
+    let counts = vec![
+        (1_700_000_000, 4.0),
+        (1_700_003_600, 10.0),
+        (1_700_007_200, 1.0),
+    ];
+    let output = format_issue_stats_output(
+        &make_issue(),
+        "24h",
+        Resolution::Hourly,
+        &counts,
+        None,
+        None,
+        &LocalZone::utc(),
+    );
+    assert!(output.contains("# Issue Stats: BACK-42"));
+    assert!(output.contains("**Events:** 15"));
+    assert!(output.contains("**Hourly Average:** 5.0"));
+    assert!(output.contains("**Peak Hour:** 10 at 2023-11-14 23:13 UTC"));
+    assert!(!output.contains("## Forecast"));
+}
+
+#[test]
+fn test_format_issue_stats_empty() {
+    let output = format_issue_stats_output(
+        &make_issue(),
+        "7d",
+        Resolution::Hourly,
+        &[],
+        None,
+        None,
+        &LocalZone::utc(),
+    );
+    assert!(output.contains("No event data for this period."));
+}
+
+#[test]
+fn test_format_issue_stats_forecast_no_breach() {
+    let counts: Vec<(i64, f64)> = daily_cycle(3)
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| (1_700_000_000 + 3600 * i as i64, c))
+        .collect();
+    let output = format_issue_stats_output(
+        &make_issue(),
+        "3d",
+        Resolution::Hourly,
+        &counts,
+        Some(ForecastMethod::Auto),
+        Some(500.0),
+        &LocalZone::utc(),
+    );
+    assert!(output.contains("**Method:** Holt-Winters (24h seasonality)"));
+    assert!(output.contains("not expected to breach in the next 24h"));
+}
+
+#[test]
+fn test_format_issue_stats_notes_holt_winters_fallback() {
+    let counts = vec![(1_700_000_000, 4.0), (1_700_003_600, 6.0)];
+    let output = format_issue_stats_output(
+        &make_issue(),
+        "24h",
+        Resolution::Hourly,
+        &counts,
+        Some(ForecastMethod::HoltWinters),
+        None,
+        &LocalZone::utc(),
+    );
+    assert!(output.contains("fell back to a linear trend"));
+}
+
+#[test]
+fn test_resolution_parse() {
+    assert_eq!(Resolution::parse("1h"), Some(Resolution::Hourly));
+    assert_eq!(Resolution::parse("Daily"), Some(Resolution::Daily));
+    assert_eq!(Resolution::parse("1d").unwrap().interval(), "1d");
+    assert_eq!(Resolution::parse("5m"), None);
+}
+
+#[test]
+fn test_sparkline_scales_to_max() {
+    assert_eq!(sparkline(&[0.0, 1.0, 2.0, 7.0], 10), "▁▂▃█");
+    assert_eq!(sparkline(&[0.0, 0.0], 10), "▁▁");
+    assert_eq!(sparkline(&[], 10), "");
+}
+
+#[test]
+fn test_sparkline_sums_buckets_beyond_width() {
+    let values = [1.0, 1.0, 0.0, 0.0, 4.0, 4.0];
+    assert_eq!(sparkline(&values, 3), "▃▁█");
+}
+
+#[test]
+fn test_peak_buckets_orders_by_count() {
+    let counts = vec![(1, 3.0), (2, 0.0), (3, 9.0), (4, 3.0), (5, 1.0)];
+    assert_eq!(peak_buckets(&counts, 3), vec![(3, 9.0), (1, 3.0), (4, 3.0)]);
+    assert!(peak_buckets(&[(1, 0.0)], 3).is_empty());
+}
+
+#[test]
+fn test_classify_trend() {
+    let spiking = classify_trend(&[1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 10.0, 12.0]).unwrap();
+    assert_eq!(spiking.trend, Trend::Spiking);
+    assert_eq!(spiking.recent, 11.0);
+    assert_eq!(spiking.baseline, 1.0);
+    let decaying = classify_trend(&[8.0, 9.0, 10.0, 1.0]).unwrap();
+    assert_eq!(decaying.trend, Trend::Decaying);
+    let steady = classify_trend(&[5.0, 6.0, 5.0, 6.0]).unwrap();
+    assert_eq!(steady.trend, Trend::Steady);
+    assert!(classify_trend(&[1.0, 2.0, 3.0]).is_none());
+}
+
+#[test]
+fn test_classify_trend_ignores_tiny_volumes() {
+    let trend = classify_trend(&[0.0, 0.0, 0.0, 0.5]).unwrap();
+    assert_eq!(trend.trend, Trend::Steady);
+}
+
+#[test]
+fn test_format_issue_stats_activity_and_peaks() {
+    let counts: Vec<(i64, f64)> = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 10.0, 12.0]
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| (1_699_920_000 + 86_400 * i as i64, c))
+        .collect();
+    let output = format_issue_stats_output(
+        &make_issue(),
+        "30d",
+        Resolution::Daily,
+        &counts,
+        None,
+        None,
+        &LocalZone::utc(),
+    );
+    assert!(output.contains("**Period:** 30d (daily buckets)"));
+    assert!(output.contains("**Last 24h:** 12"));
+    assert!(output.contains("**Daily Average:** 3.5"));
+    assert!(output.contains("**Peak Day:** 12 at 2023-11-21"));
+    assert!(output.contains("**Trend:** spiking (recent avg 11.0 vs 1.0 before)"));
+    assert!(output.contains("```\n▂▂▂▂▂▂▇█\n```"));
+    assert!(output.contains("2023-11-14 → 2023-11-21"));
+    assert!(output.contains("## Peak Buckets\n\n- 2023-11-21: 12\n- 2023-11-20: 10\n"));
+}
+
+#[test]
+fn test_local_zone_parse() {
+    let cet = LocalZone::parse("cet").unwrap();
+    assert_eq!(cet.label, "CET");
+    assert_eq!(cet.offset.local_minus_utc(), 3600);
+    let ist = LocalZone::parse("+05:30").unwrap();
+    assert_eq!(ist.label, "UTC+05:30");
+    assert_eq!(ist.offset.local_minus_utc(), 19_800);
+    assert_eq!(LocalZone::parse("-0800").unwrap().label, "UTC-08:00");
+    assert_eq!(LocalZone::parse("UTC+2").unwrap().label, "UTC+02:00");
+    assert_eq!(LocalZone::parse("GMT-5").unwrap().label, "UTC-05:00");
+    assert!(LocalZone::parse("utc").unwrap().is_utc());
+    assert!(LocalZone::parse("Europe/Berlin").is_none());
+    assert!(LocalZone::parse("+25:00").is_none());
+}
+
+#[test]
+fn test_local_zone_formats_times() {
+    let cet = LocalZone::parse("CET").unwrap();
+    // 2023-11-14 23:13 UTC is already the next day in CET.
+    assert_eq!(cet.format_time(1_700_003_600), "2023-11-15 00:13 CET");
+    assert_eq!(cet.format_date(1_700_003_600), "2023-11-15");
+    assert_eq!(cet.hour(1_700_003_600), Some(0));
+}
+
+#[test]
+fn test_local_zone_daily_buckets_split_at_local_midnight() {
+    let cet = LocalZone::parse("CET").unwrap();
+    // Hours from 2023-11-14 21:00 to 2023-11-15 00:00 UTC.
+    let counts: Vec<(i64, f64)> = (0..4).map(|i| (1_699_995_600 + 3600 * i, 1.0)).collect();
+    let days = cet.daily_buckets(&counts);
+    assert_eq!(days.len(), 2);
+    assert_eq!(cet.format_date(days[0].0), "2023-11-14");
+    assert_eq!(days[0].1, 2.0);
+    assert_eq!(cet.format_time(days[1].0), "2023-11-15 00:00 CET");
+    assert_eq!(days[1].1, 2.0);
+}
+
+#[test]
+fn test_peak_hours_of_local_day() {
+    let cet = LocalZone::parse("CET").unwrap();
+    // A nightly batch at 01:00 UTC shows up at 02:00 CET.
+    let counts: Vec<(i64, f64)> = (0..48)
+        .map(|h| {
+            let ts = 1_699_920_000 + 3600 * h;
+            (ts, if h % 24 == 1 { 30.0 } else { 1.0 })
+        })
+        .collect();
+    let hours = cet.hour_of_day(&counts);
+    assert_eq!(hours[2], 60.0);
+    assert_eq!(peak_hours(&hours, 1), vec![(2, 60.0)]);
+    let output = format_issue_stats_output(
+        &make_issue(),
+        "48h",
+        Resolution::Hourly,
+        &counts,
+        None,
+        None,
+        &cet,
+    );
+    assert!(output.contains("**Timezone:** CET (UTC+01:00, fixed offset)"));
+    assert!(output.contains("## By Hour of Day (CET)"));
+    assert!(output.contains("- 02:00–03:00: 60 (57%)"));
+}
@@ -0,0 +1,39 @@
+use sentry_mcp::api_client::Issue;
+use sentry_mcp::tools::issues_assigned_to_me::format_issues_assigned_to_me;
+use serde_json::json;
+
+fn make_issue(id: &str, project: &str) -> Issue {
+    serde_json::from_value(json!({
+        "id": id,
+        "shortId": format!("BACK-{}", id),
+        "title": format!("Issue {} | retry", id),
+        "status": "unresolved",
+        "level": "error",
+        "project": {"id": "1", "name": project, "slug": project},
+        "count": "42",
+        "userCount": 3,
+        "lastSeen": "2024-01-02T00:00:00Z"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_format_issues_assigned_to_me() {
+    let issues = vec![make_issue("7", "backend"), make_issue("8", "frontend")];
+    let output = format_issues_assigned_to_me("acme", "14d", &issues);
+    assert!(output.contains("# Assigned to Me: acme"));
+    assert!(output.contains("**Found:** 2 issues"));
+    assert!(output.contains(
+        "| BACK-7 | Issue 7 \\| retry | backend | error | 3 | 42 | 2024-01-02T00:00:00Z |\n"
+    ));
+    assert!(output.contains("| BACK-8 | Issue 8 \\| retry | frontend |"));
+    assert!(output.contains("get_issue_details"));
+}
+
+#[test]
+fn test_format_issues_assigned_to_me_empty() {
+    let output = format_issues_assigned_to_me("backend", "24h", &[]);
+    assert!(output.contains("**Period:** 24h"));
+    assert!(output.contains("No unresolved issues are assigned to you."));
+    assert!(!output.contains("| Issue |"));
+}
@@ -0,0 +1,38 @@
+use sentry_mcp::api_client::Tombstone;
+use sentry_mcp::tools::list_discarded_issues::{format_tombstones_output, tombstone_title};
+use serde_json::json;
+
+fn tombstone(value: serde_json::Value) -> Tombstone {
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn test_tombstone_title_variants() {
+    let error = tombstone(json!({"id": "1", "metadata": {"type": "KeyError", "value": "'id'"}}));
+    assert_eq!(tombstone_title(&error), "KeyError: 'id'");
+    let titled = tombstone(json!({"id": "2", "metadata": {"title": "N+1 Query"}}));
+    assert_eq!(tombstone_title(&titled), "N+1 Query");
+    let message = tombstone(json!({"id": "3", "message": "Disk almost full"}));
+    assert_eq!(tombstone_title(&message), "Disk almost full");
+    let bare = tombstone(json!({"id": "4"}));
+    assert_eq!(tombstone_title(&bare), "<unlabeled event>");
+}
+
+#[test]
+fn test_format_tombstones_output() {
+    let tombstones = vec![tombstone(json!({
+        "id": "9", "level": "warning", "culprit": "worker.poll",
+        "metadata": {"type": "Timeout", "value": "poll timed out"}
+    }))];
+    let output = format_tombstones_output("api", &tombstones);
+    assert!(output.contains("**Found:** 1 discarded issues"));
+    assert!(output.contains("## Timeout: poll timed out (tombstone 9)"));
+    assert!(output.contains("- **Culprit:** worker.poll"));
+    assert!(output.contains("- **Discarded by:** Sentry"));
+}
+
+#[test]
+fn test_format_tombstones_output_empty() {
+    let output = format_tombstones_output("api", &[]);
+    assert!(output.contains("No discarded issues in this project."));
+}
@@ -0,0 +1,60 @@
+use sentry_mcp::api_client::Environment;
+use sentry_mcp::tools::list_environments::{format_environments_output, match_environment};
+
+fn make_environments(names: &[&str]) -> Vec<Environment> {
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            serde_json::from_value(serde_json::json!({"id": i.to_string(), "name": name})).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_match_environment_exact() {
+    let envs = make_environments(&["production", "Production"]);
+    assert_eq!(match_environment(&envs, "Production"), Ok("Production"));
+}
+
+#[test]
+fn test_match_environment_case_insensitive() {
+    let envs = make_environments(&["Production", "staging"]);
+    assert_eq!(match_environment(&envs, " production "), Ok("Production"));
+}
+
+#[test]
+fn test_match_environment_unique_prefix() {
+    let envs = make_environments(&["production", "staging"]);
+    assert_eq!(match_environment(&envs, "prod"), Ok("production"));
+}
+
+#[test]
+fn test_match_environment_prefers_full_name_over_prefix() {
+    let envs = make_environments(&["prod", "production"]);
+    assert_eq!(match_environment(&envs, "PROD"), Ok("prod"));
+}
+
+#[test]
+fn test_match_environment_ambiguous_prefix() {
+    let envs = make_environments(&["staging", "stage-eu"]);
+    let err = match_environment(&envs, "sta").unwrap_err();
+    assert_eq!(err, "Environment 'sta' is ambiguous: staging, stage-eu");
+}
+
+#[test]
+fn test_match_environment_unknown_lists_available() {
+    let envs = make_environments(&["staging", "production"]);
+    let err = match_environment(&envs, "qa").unwrap_err();
+    assert_eq!(
+        err,
+        "Unknown environment 'qa'; available: production, staging"
+    );
+}
+
+#[test]
+fn test_format_environments_output_empty() {
+    let output = format_environments_output("acme", &[]);
+    assert!(output.contains("**Organization:** acme"));
+    assert!(output.contains("No environments found."));
+}
@@ -0,0 +1,52 @@
+use sentry_mcp::api_client::IssueComment;
+use sentry_mcp::tools::list_issue_comments::{comment_author, format_comments_output};
+use serde_json::json;
+
+fn make_comments() -> Vec<IssueComment> {
+    serde_json::from_value(json!([
+        {"id": "3", "dateCreated": "2024-05-02T09:00:00Z",
+         "user": {"name": "", "username": "bob"},
+         "data": {"text": "Fixed in 1.4.3\n\nWill verify tomorrow"}},
+        {"id": "2", "dateCreated": "2024-05-01T12:00:00Z",
+         "user": {"email": "ops@example.com"}, "data": {"text": "Rolled back"}},
+        {"id": "1", "dateCreated": "2024-05-01T10:00:00Z",
+         "user": {"name": "Jane Doe"}, "data": {"text": "Caused by the cache rollout"}}
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_comment_author_fallbacks() {
+    let comments = make_comments();
+    assert_eq!(comment_author(&comments[0]), "bob");
+    assert_eq!(comment_author(&comments[1]), "ops@example.com");
+    assert_eq!(comment_author(&comments[2]), "Jane Doe");
+    let integration: IssueComment =
+        serde_json::from_value(json!({"id": "9", "data": {"text": "Linked JIRA-9"}})).unwrap();
+    assert_eq!(comment_author(&integration), "Sentry");
+}
+
+#[test]
+fn test_format_comments_oldest_first() {
+    let output = format_comments_output("PROJ-1", &make_comments(), 50);
+    assert!(output.contains("**Found:** 3 comments\n"));
+    let jane = output.find("### Jane Doe (2024-05-01T10:00:00Z)").unwrap();
+    let ops = output.find("### ops@example.com").unwrap();
+    let bob = output.find("### bob").unwrap();
+    assert!(jane < ops && ops < bob);
+    assert!(output.contains("> Fixed in 1.4.3\n>\n> Will verify tomorrow\n"));
+}
+
+#[test]
+fn test_format_comments_limit_keeps_latest() {
+    let output = format_comments_output("PROJ-1", &make_comments(), 2);
+    assert!(output.contains("**Found:** 3 comments (showing the latest 2)"));
+    assert!(output.contains("### bob"));
+    assert!(!output.contains("Jane Doe"));
+}
+
+#[test]
+fn test_format_comments_empty() {
+    let output = format_comments_output("PROJ-1", &[], 50);
+    assert!(output.contains("No comments on this issue."));
+}
@@ -0,0 +1,142 @@
+use chrono::{DateTime, Utc};
+use sentry_mcp::api_client::{Issue, SearchHits};
+use sentry_mcp::tools::list_issues::{
+    attention_score, format_issue_count, format_issues_table, sort_by_attention, with_linked_filter,
+};
+use serde_json::json;
+
+fn now() -> DateTime<Utc> {
+    "2024-01-02T00:00:00Z".parse().unwrap()
+}
+
+fn make_issue(short_id: &str, title: &str, substatus: Option<&str>) -> Issue {
+    serde_json::from_value(json!({
+        "id": "1",
+        "shortId": short_id,
+        "title": title,
+        "status": "unresolved",
+        "substatus": substatus,
+        "level": "error",
+        "project": {"id": "1", "name": "Backend", "slug": "backend"},
+        "count": "42",
+        "userCount": 7,
+        "lastSeen": "2024-01-02T00:00:00Z"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_format_issues_table_rows() {
+    let issues = vec![
+        make_issue("BACK-1", "KeyError | missing", Some("regressed")),
+        make_issue("BACK-2", "Timeout", None),
+    ];
+    let output = format_issues_table("acme", "is:unresolved", &issues, now());
+    assert!(output.contains("**Scope:** acme"));
+    assert!(output.contains("**Query:** is:unresolved"));
+    assert!(output.contains("**Found:** 2 issues"));
+    assert!(output.contains(
+        "| BACK-1 | KeyError \\| missing | backend | regressed | error | 42 | 7 | 2024-01-02T00:00:00Z | 44.0 |"
+    ));
+    assert!(output.contains("| BACK-2 | Timeout | backend | unresolved |"));
+}
+
+#[test]
+fn test_format_issues_table_empty() {
+    let output = format_issues_table("acme/backend", "assigned:me", &[], now());
+    assert!(output.contains("**Found:** 0 issues"));
+    assert!(output.contains("No issues found matching the query."));
+    assert!(!output.contains("| Issue |"));
+}
+
+#[test]
+fn test_format_issues_table_shows_linked_tickets() {
+    let mut issue: Issue = serde_json::from_value(json!({
+        "id": "1",
+        "shortId": "BACK-3",
+        "title": "Crash",
+        "status": "unresolved",
+        "project": {"id": "1", "name": "Backend", "slug": "backend"},
+        "count": "1",
+        "userCount": 1,
+        "annotations": [
+            {"displayName": "JIRA-123", "url": "https://acme.atlassian.net/browse/JIRA-123"},
+            "<a href=\"https://github.com/acme/api/issues/45\">acme/api#45</a>"
+        ]
+    }))
+    .unwrap();
+    assert_eq!(issue.annotations[1].display_name, "acme/api#45");
+    assert_eq!(
+        issue.annotations[1].url.as_deref(),
+        Some("https://github.com/acme/api/issues/45")
+    );
+    let output = format_issues_table("acme", "is:linked", std::slice::from_ref(&issue), now());
+    assert!(output.contains("| BACK-3 (JIRA-123, acme/api#45) | Crash |"));
+    issue.annotations.clear();
+    let output = format_issues_table("acme", "is:linked", &[issue], now());
+    assert!(output.contains("| BACK-3 | Crash |"));
+}
+
+#[test]
+fn test_with_linked_filter() {
+    assert_eq!(with_linked_filter("is:unresolved", None), "is:unresolved");
+    assert_eq!(
+        with_linked_filter("is:unresolved", Some(true)),
+        "is:unresolved is:linked"
+    );
+    assert_eq!(
+        with_linked_filter("is:unresolved", Some(false)),
+        "is:unresolved is:unlinked"
+    );
+}
+
+#[test]
+fn test_attention_score_weighs_recency_volume_users_and_level() {
+    let issue = make_issue("BACK-1", "Timeout", None);
+    let fresh = attention_score(&issue, now());
+    // 3 (error) * (1 + ln 43) * (1 + ln 8)
+    assert!((fresh - 44.0).abs() < 0.1, "{}", fresh);
+    let day_later: DateTime<Utc> = "2024-01-03T00:00:00Z".parse().unwrap();
+    assert!((attention_score(&issue, day_later) - fresh / 2.0).abs() < 1e-9);
+
+    let mut fatal = issue.clone();
+    fatal.level = Some("fatal".to_string());
+    assert!((attention_score(&fatal, now()) - fresh * 4.0 / 3.0).abs() < 1e-9);
+    let mut busier = issue.clone();
+    busier.count = "1000".to_string();
+    assert!(attention_score(&busier, now()) > fresh);
+
+    let mut unseen = issue;
+    unseen.last_seen = None;
+    assert_eq!(attention_score(&unseen, now()), 0.0);
+}
+
+#[test]
+fn test_sort_by_attention() {
+    let mut stale = make_issue("BACK-1", "Stale", None);
+    stale.last_seen = Some("2023-12-20T00:00:00Z".to_string());
+    let mut warning = make_issue("BACK-2", "Warning", None);
+    warning.level = Some("warning".to_string());
+    let error = make_issue("BACK-3", "Error", None);
+    let mut issues = vec![stale, warning, error];
+    sort_by_attention(&mut issues, now());
+    let order: Vec<&str> = issues.iter().map(|i| i.short_id.as_str()).collect();
+    assert_eq!(order, ["BACK-3", "BACK-2", "BACK-1"]);
+}
+
+#[test]
+fn test_format_issue_count() {
+    let hits = SearchHits {
+        hits: 42,
+        max_hits: Some(1000),
+    };
+    let output = format_issue_count("acme/backend", "is:unresolved", hits);
+    assert!(output.contains("**Scope:** acme/backend"));
+    assert!(output.contains("**Query:** is:unresolved"));
+    assert!(output.contains("**Count:** 42\n"));
+    let hits = SearchHits {
+        hits: 1000,
+        max_hits: Some(1000),
+    };
+    assert!(format_issue_count("acme", "is:unresolved", hits).contains("**Count:** 1000+"));
+}
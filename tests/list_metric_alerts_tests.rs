@@ -0,0 +1,99 @@
+use sentry_mcp::api_client::MetricAlertRule;
+use sentry_mcp::tools::list_metric_alerts::{
+    filter_rules, format_action, format_metric_alerts_output, format_threshold,
+};
+use serde_json::json;
+
+fn make_rules() -> Vec<MetricAlertRule> {
+    serde_json::from_value(json!([
+        {
+            "id": "7", "name": "High error rate", "dataset": "events",
+            "query": "level:error", "aggregate": "count()", "timeWindow": 60.0,
+            "thresholdType": 0, "resolveThreshold": 50.0, "environment": "production",
+            "projects": ["backend"], "owner": "team:4",
+            "triggers": [
+                {"label": "critical", "alertThreshold": 100.0, "actions": [
+                    {"type": "slack", "targetType": "specific", "targetIdentifier": "#alerts",
+                     "desc": "Send a Slack notification to #alerts"}
+                ]},
+                {"label": "warning", "alertThreshold": 75.5, "actions": [
+                    {"type": "email", "targetType": "team", "targetIdentifier": "4"}
+                ]}
+            ]
+        },
+        {
+            "id": "8", "name": "Slow checkout", "dataset": "transactions",
+            "query": "", "aggregate": "p95(transaction.duration)", "timeWindow": 1440.0,
+            "thresholdType": 1, "projects": ["web"], "triggers": []
+        }
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_filter_rules_by_project_and_name() {
+    let rules = make_rules();
+    let by_project = filter_rules(&rules, Some("web"), None);
+    assert_eq!(by_project.len(), 1);
+    assert_eq!(by_project[0].id, "8");
+    let by_name = filter_rules(&rules, None, Some("ERROR"));
+    assert_eq!(by_name.len(), 1);
+    assert_eq!(by_name[0].id, "7");
+    assert_eq!(filter_rules(&rules, None, None).len(), 2);
+}
+
+#[test]
+fn test_format_threshold_uses_rule_direction() {
+    let rules = make_rules();
+    assert_eq!(
+        format_threshold(&rules[0].triggers[0], &rules[0]),
+        "above 100 (resolves below 50)"
+    );
+    assert_eq!(
+        format_threshold(&rules[0].triggers[1], &rules[0]),
+        "above 75.5 (resolves below 50)"
+    );
+    let mut trigger = rules[0].triggers[0].clone();
+    trigger.resolve_threshold = Some(20.0);
+    assert_eq!(
+        format_threshold(&trigger, &rules[1]),
+        "below 100 (resolves above 20)"
+    );
+}
+
+#[test]
+fn test_format_action_prefers_description() {
+    let rules = make_rules();
+    assert_eq!(
+        format_action(&rules[0].triggers[0].actions[0]),
+        "slack: Send a Slack notification to #alerts"
+    );
+    assert_eq!(
+        format_action(&rules[0].triggers[1].actions[0]),
+        "email → team 4"
+    );
+}
+
+#[test]
+fn test_format_metric_alerts_output() {
+    let rules = make_rules();
+    let refs: Vec<&MetricAlertRule> = rules.iter().collect();
+    let output = format_metric_alerts_output("acme", &refs);
+    assert!(output.contains("**Found:** 2 rules"));
+    assert!(output.contains("## High error rate (ID: 7)"));
+    assert!(output.contains("- **Metric:** `count()` over 1h (dataset: events)"));
+    assert!(output.contains("- **Filter:** `level:error`"));
+    assert!(output.contains("- **Owner:** team:4"));
+    assert!(output.contains("  - critical: above 100 (resolves below 50)\n    - slack: Send"));
+    assert!(
+        output
+            .contains("- **Metric:** `p95(transaction.duration)` over 1d (dataset: transactions)")
+    );
+    assert!(output.contains("- **Triggers:** none"));
+}
+
+#[test]
+fn test_format_metric_alerts_empty() {
+    let output = format_metric_alerts_output("acme", &[]);
+    assert!(output.contains("No metric alert rules found."));
+}
@@ -0,0 +1,68 @@
+use sentry_mcp::api_client::{Integration, Repository};
+use sentry_mcp::tools::list_repositories_and_integrations::{
+    format_repositories_and_integrations, providers_with,
+};
+use serde_json::json;
+
+fn integrations() -> Vec<Integration> {
+    serde_json::from_value(json!([
+        {
+            "id": "1",
+            "name": "acme",
+            "domainName": "github.com/acme",
+            "status": "active",
+            "provider": {"key": "github", "name": "GitHub",
+                         "features": ["codeowners", "commits", "issue-basic", "stacktrace-link"]}
+        },
+        {
+            "id": "2",
+            "name": "acme.atlassian.net",
+            "status": "active",
+            "provider": {"key": "jira", "name": "Jira", "features": ["issue-basic", "issue-sync"]}
+        },
+        {
+            "id": "3",
+            "name": "Acme Workspace",
+            "status": "disabled",
+            "provider": {"key": "slack", "name": "Slack", "features": ["alert-rule", "chat-unfurl"]}
+        }
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_providers_with_skips_inactive() {
+    let integrations = integrations();
+    assert_eq!(
+        providers_with(&integrations, &["issue-basic", "issue-sync"]),
+        ["GitHub", "Jira"]
+    );
+    assert!(providers_with(&integrations, &["alert-rule"]).is_empty());
+}
+
+#[test]
+fn test_format_repositories_and_integrations() {
+    let repos: Vec<Repository> = serde_json::from_value(json!([{
+        "id": 5,
+        "name": "acme/api",
+        "provider": {"id": "integrations:github", "name": "GitHub"},
+        "status": "active"
+    }]))
+    .unwrap();
+    let output = format_repositories_and_integrations("acme", &repos, &integrations());
+    assert!(output.contains("## Repositories (1)"));
+    assert!(output.contains("| acme/api | GitHub | active | - |\n"));
+    assert!(output.contains("| GitHub | acme (github.com/acme) | active | codeowners, commits, issue-basic, stacktrace-link |\n"));
+    assert!(output.contains("- ✅ External issues (create or link tickets): GitHub, Jira\n"));
+    assert!(output.contains("- ❌ Alert notifications: no active integration\n"));
+    assert!(output.contains("1 integrations are not active"));
+}
+
+#[test]
+fn test_format_repositories_and_integrations_empty() {
+    let output = format_repositories_and_integrations("acme", &[], &[]);
+    assert!(output.contains("No repositories linked"));
+    assert!(output.contains("No integrations installed."));
+    assert!(output.contains("- ❌ Commit tracking and suspect commits: no active integration\n"));
+    assert!(!output.contains("not active"));
+}
@@ -0,0 +1,44 @@
+use sentry_mcp::api_client::Team;
+use sentry_mcp::tools::list_teams::{filter_teams, format_teams_output};
+
+fn make_teams() -> Vec<Team> {
+    serde_json::from_value(serde_json::json!([
+        {"id": "3", "slug": "payments", "name": "Payments Squad", "memberCount": 4,
+         "isMember": true, "projects": [{"id": "1", "slug": "billing"}, {"id": "2", "slug": "checkout"}]},
+        {"id": "1", "slug": "infra", "name": "Infrastructure", "projects": []}
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_filter_teams_sorts_by_slug() {
+    let teams = make_teams();
+    let filtered = filter_teams(&teams, None);
+    let slugs: Vec<_> = filtered.iter().map(|t| t.slug.as_str()).collect();
+    assert_eq!(slugs, vec!["infra", "payments"]);
+}
+
+#[test]
+fn test_filter_teams_matches_name_case_insensitive() {
+    let teams = make_teams();
+    let filtered = filter_teams(&teams, Some("SQUAD"));
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(filtered[0].slug, "payments");
+}
+
+#[test]
+fn test_format_teams_output_table() {
+    let teams = make_teams();
+    let output = format_teams_output("acme", &filter_teams(&teams, None));
+    assert!(output.contains("**Organization:** acme"));
+    assert!(output.contains("**Found:** 2 teams"));
+    assert!(output.contains("| infra | Infrastructure | 1 | - | - |"));
+    assert!(output.contains("| payments (member) | Payments Squad | 3 | 4 | billing, checkout |"));
+    assert!(output.contains("team:<id>"));
+}
+
+#[test]
+fn test_format_teams_output_empty() {
+    let output = format_teams_output("acme", &[]);
+    assert!(output.contains("No teams found."));
+}
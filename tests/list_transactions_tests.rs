@@ -0,0 +1,32 @@
+use sentry_mcp::api_client::DiscoverResult;
+use sentry_mcp::tools::list_transactions::{format_transactions_output, sort_field};
+use serde_json::json;
+
+#[test]
+fn test_sort_field() {
+    assert_eq!(sort_field(None).unwrap(), "-p95(transaction.duration)");
+    assert_eq!(sort_field(Some("Volume")).unwrap(), "-tpm()");
+    assert_eq!(sort_field(Some("failing")).unwrap(), "-failure_rate()");
+    let err = sort_field(Some("fastest")).unwrap_err();
+    assert!(err.message.contains("Invalid sort 'fastest'"));
+}
+
+#[test]
+fn test_format_transactions_empty() {
+    let output = format_transactions_output(
+        "backend",
+        "24h",
+        "-p95(transaction.duration)",
+        &DiscoverResult::default(),
+    );
+    assert!(output.contains("**Sorted by:** p95(transaction.duration)"));
+    assert!(output.contains("No transactions found."));
+}
+
+#[test]
+fn test_format_transactions_missing_values() {
+    let result: DiscoverResult =
+        serde_json::from_value(json!({"data": [{"transaction": "a|b"}]})).unwrap();
+    let output = format_transactions_output("backend", "1h", "-tpm()", &result);
+    assert!(output.contains("| a\\|b | 0.00 | 0.00ms | 0.00ms | 0.0% | 0 |"));
+}
@@ -0,0 +1,78 @@
+use sentry_mcp::logging::{JsonLayer, LogFormat, new_call_id};
+use std::io;
+use std::sync::{Arc, Mutex};
+use tracing::{info, info_span};
+use tracing_subscriber::layer::SubscriberExt;
+
+#[derive(Clone, Default)]
+struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn capture_json(f: impl FnOnce()) -> Vec<serde_json::Value> {
+    let buffer = Buffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::registry().with(JsonLayer::new(move || writer.clone()));
+    tracing::subscriber::with_default(subscriber, f);
+    let bytes = buffer.0.lock().unwrap().clone();
+    String::from_utf8(bytes)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_log_format_parse() {
+    assert_eq!(LogFormat::parse("json"), Some(LogFormat::Json));
+    assert_eq!(LogFormat::parse(" JSON "), Some(LogFormat::Json));
+    assert_eq!(LogFormat::parse("text"), Some(LogFormat::Text));
+    assert_eq!(LogFormat::parse(""), Some(LogFormat::Text));
+    assert_eq!(LogFormat::parse("xml"), None);
+}
+
+#[test]
+fn test_new_call_id_is_short_hex() {
+    let id = new_call_id();
+    assert_eq!(id.len(), 16);
+    assert!(id.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_ne!(id, new_call_id());
+}
+
+#[test]
+fn test_json_layer_inlines_span_fields() {
+    let lines = capture_json(|| {
+        let span = info_span!("tool_call", tool = "list_issues", call_id = "abc123");
+        let _guard = span.enter();
+        info!(status = 200, "GET {}", "https://sentry.io/api/0/");
+    });
+    assert_eq!(lines.len(), 1);
+    let line = &lines[0];
+    assert_eq!(line["level"], "INFO");
+    assert_eq!(line["target"], "logging_tests");
+    assert_eq!(line["tool"], "list_issues");
+    assert_eq!(line["call_id"], "abc123");
+    assert_eq!(line["status"], 200);
+    assert_eq!(line["message"], "GET https://sentry.io/api/0/");
+    assert!(line["timestamp"].as_str().unwrap().ends_with('Z'));
+}
+
+#[test]
+fn test_json_layer_records_late_span_fields() {
+    let lines = capture_json(|| {
+        let span = info_span!("tool_call", call_id = tracing::field::Empty);
+        span.record("call_id", "late");
+        span.in_scope(|| info!("inside"));
+        info!("outside");
+    });
+    assert_eq!(lines[0]["call_id"], "late");
+    assert!(lines[1].get("call_id").is_none());
+}
@@ -0,0 +1,73 @@
+use sentry_mcp::api_client::{Issue, MergeResult};
+use sentry_mcp::tools::merge_issues::{
+    MergeIssuesInput, check_merge, format_merge_output, validate_merge_ids,
+};
+use serde_json::json;
+
+fn issue(id: &str, count: u64, project: &str) -> Issue {
+    serde_json::from_value(json!({
+        "id": id,
+        "shortId": format!("{}-{}", project.to_uppercase(), id),
+        "title": format!("Error {}", id),
+        "culprit": null,
+        "status": "unresolved",
+        "platform": "python",
+        "project": {"id": project, "name": project, "slug": project},
+        "count": count.to_string(),
+        "userCount": 1,
+        "permalink": null
+    }))
+    .unwrap()
+}
+
+fn input(primary: &str, ids: &[&str]) -> MergeIssuesInput {
+    MergeIssuesInput {
+        organization_slug: "org".to_string(),
+        primary_issue_id: primary.to_string(),
+        issue_ids: ids.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_validate_merge_ids_dedupes_and_trims() {
+    let ids = validate_merge_ids(&input("API-1", &[" API-2 ", "API-2", "", "API-3"])).unwrap();
+    assert_eq!(ids, vec!["API-2", "API-3"]);
+}
+
+#[test]
+fn test_validate_merge_ids_rejects_primary_and_empty() {
+    assert!(validate_merge_ids(&input("API-1", &["api-1"])).is_err());
+    assert!(validate_merge_ids(&input("API-1", &[])).is_err());
+    assert!(validate_merge_ids(&input(" ", &["API-2"])).is_err());
+}
+
+#[test]
+fn test_check_merge_requires_same_project() {
+    let err = check_merge(&issue("1", 10, "api"), &[issue("2", 1, "web")]).unwrap_err();
+    assert!(err.message.contains("same project"));
+}
+
+#[test]
+fn test_check_merge_primary_must_have_most_events() {
+    assert!(check_merge(&issue("1", 10, "api"), &[issue("2", 9, "api")]).is_ok());
+    let err = check_merge(&issue("1", 10, "api"), &[issue("2", 11, "api")]).unwrap_err();
+    assert!(
+        err.message
+            .contains("API-2 (11 events) would be kept instead of API-1")
+    );
+    // Ties are broken in favour of the newer (higher) ID.
+    assert!(check_merge(&issue("1", 10, "api"), &[issue("2", 10, "api")]).is_err());
+    assert!(check_merge(&issue("2", 10, "api"), &[issue("1", 10, "api")]).is_ok());
+}
+
+#[test]
+fn test_format_merge_output_notes_unexpected_parent() {
+    let result = MergeResult {
+        parent: "2".to_string(),
+        children: vec!["1".to_string()],
+    };
+    let output = format_merge_output(&issue("1", 10, "api"), &[issue("2", 3, "api")], &result);
+    assert!(output.contains("**Primary:** API-1 - Error 1"));
+    assert!(output.contains("**Note:** Sentry kept issue 2 as the primary"));
+    assert!(output.contains("- API-2 - Error 2 (3 events)"));
+}
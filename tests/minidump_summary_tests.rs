@@ -0,0 +1,195 @@
+use sentry_mcp::api_client::EventAttachment;
+use sentry_mcp::minidump::{self, exception_name};
+use sentry_mcp::tools::minidump_summary::{format_minidump_summary, select_attachment};
+
+/// Builds a little-endian minidump with system info, exception (+ amd64 context) and modules.
+struct DumpBuilder {
+    data: Vec<u8>,
+}
+
+impl DumpBuilder {
+    fn put_u16(&mut self, at: usize, v: u16) {
+        self.data[at..at + 2].copy_from_slice(&v.to_le_bytes());
+    }
+    fn put_u32(&mut self, at: usize, v: u32) {
+        self.data[at..at + 4].copy_from_slice(&v.to_le_bytes());
+    }
+    fn put_u64(&mut self, at: usize, v: u64) {
+        self.data[at..at + 8].copy_from_slice(&v.to_le_bytes());
+    }
+    fn alloc(&mut self, len: usize) -> usize {
+        let at = self.data.len();
+        self.data.resize(at + len, 0);
+        at
+    }
+    fn string(&mut self, s: &str) -> u32 {
+        let units: Vec<u16> = s.encode_utf16().collect();
+        let at = self.alloc(4 + units.len() * 2);
+        self.put_u32(at, (units.len() * 2) as u32);
+        for (i, u) in units.iter().enumerate() {
+            self.put_u16(at + 4 + i * 2, *u);
+        }
+        at as u32
+    }
+}
+
+fn build_dump(platform_id: u32, code: u32, rip: u64, modules: &[(u64, u32, &str)]) -> Vec<u8> {
+    let mut b = DumpBuilder { data: vec![] };
+    let header = b.alloc(32);
+    b.put_u32(header, 0x504d_444d);
+    b.put_u32(header + 8, 3);
+    b.put_u32(header + 20, 1_700_000_000);
+    let dir = b.alloc(36);
+    b.put_u32(header + 12, dir as u32);
+
+    let sys = b.alloc(56);
+    b.put_u16(sys, 9);
+    b.data[sys + 6] = 8;
+    b.put_u32(sys + 8, 10);
+    b.put_u32(sys + 16, 19045);
+    b.put_u32(sys + 20, platform_id);
+    let csd = b.string("Service Pack 1");
+    b.put_u32(sys + 24, csd);
+    b.put_u32(dir, 7);
+    b.put_u32(dir + 4, 56);
+    b.put_u32(dir + 8, sys as u32);
+
+    let exc = b.alloc(168);
+    b.put_u32(exc, 4242);
+    b.put_u32(exc + 8, code);
+    b.put_u64(exc + 24, 0xdead);
+    let ctx = b.alloc(0x4d0);
+    b.put_u64(ctx + 0xf8, rip);
+    b.put_u32(exc + 160, 0x4d0);
+    b.put_u32(exc + 164, ctx as u32);
+    b.put_u32(dir + 12, 6);
+    b.put_u32(dir + 16, 168);
+    b.put_u32(dir + 20, exc as u32);
+
+    let list = b.alloc(4 + modules.len() * 108);
+    b.put_u32(list, modules.len() as u32);
+    for (i, (base, size, name)) in modules.iter().enumerate() {
+        let at = list + 4 + i * 108;
+        let name_rva = b.string(name);
+        b.put_u64(at, *base);
+        b.put_u32(at + 8, *size);
+        b.put_u32(at + 20, name_rva);
+    }
+    b.put_u32(dir + 24, 4);
+    b.put_u32(dir + 28, (4 + modules.len() * 108) as u32);
+    b.put_u32(dir + 32, list as u32);
+    b.data
+}
+
+fn sample_dump() -> Vec<u8> {
+    build_dump(
+        2,
+        0xc000_0005,
+        0x7ff6_0000_1234,
+        &[
+            (0x7ff6_0000_0000, 0x10000, "C:\\app\\game.exe"),
+            (
+                0x7ffa_0000_0000,
+                0x20000,
+                "C:\\Windows\\System32\\ntdll.dll",
+            ),
+        ],
+    )
+}
+
+fn attachment(id: &str, kind: &str) -> EventAttachment {
+    EventAttachment {
+        id: id.to_string(),
+        name: format!("{}.dmp", id),
+        attachment_type: kind.to_string(),
+        size: 2048,
+        mimetype: None,
+    }
+}
+
+#[test]
+fn test_parse_minidump_streams() {
+    let dump = minidump::parse(&sample_dump()).unwrap();
+    assert_eq!(dump.timestamp, 1_700_000_000);
+    let info = dump.system_info.as_ref().unwrap();
+    assert_eq!(info.processor_architecture, 9);
+    assert_eq!(info.processor_count, 8);
+    assert_eq!(info.build_number, 19045);
+    assert_eq!(info.csd_version.as_deref(), Some("Service Pack 1"));
+    let exception = dump.exception.as_ref().unwrap();
+    assert_eq!(exception.thread_id, 4242);
+    assert_eq!(exception.code, 0xc000_0005);
+    assert_eq!(exception.address, 0xdead);
+    assert_eq!(exception.instruction_pointer, Some(0x7ff6_0000_1234));
+    assert_eq!(dump.modules.len(), 2);
+    assert_eq!(dump.modules[1].name, "C:\\Windows\\System32\\ntdll.dll");
+}
+
+#[test]
+fn test_crash_address_resolves_module() {
+    let dump = minidump::parse(&sample_dump()).unwrap();
+    let address = dump.crash_address().unwrap();
+    assert_eq!(address, 0x7ff6_0000_1234);
+    assert_eq!(dump.module_at(address).unwrap().name, "C:\\app\\game.exe");
+    assert!(dump.module_at(0x1).is_none());
+}
+
+#[test]
+fn test_parse_rejects_non_minidump() {
+    assert!(minidump::parse(b"not a dump at all, definitely not").is_err());
+    assert!(minidump::parse(b"MDMP").is_err());
+}
+
+#[test]
+fn test_parse_truncated_stream_errors() {
+    let mut data = sample_dump();
+    data.truncate(200);
+    assert!(minidump::parse(&data).is_err());
+}
+
+#[test]
+fn test_exception_name_by_platform() {
+    assert_eq!(
+        exception_name(2, 0xc000_0005),
+        Some("EXCEPTION_ACCESS_VIOLATION")
+    );
+    assert_eq!(exception_name(0x8201, 11), Some("SIGSEGV"));
+    assert_eq!(exception_name(0x8101, 1), Some("EXC_BAD_ACCESS"));
+    assert_eq!(exception_name(0x8201, 99), None);
+}
+
+#[test]
+fn test_select_attachment() {
+    let attachments = vec![
+        attachment("1", "event.attachment"),
+        attachment("2", "event.minidump"),
+    ];
+    assert_eq!(select_attachment(&attachments, None).unwrap().id, "2");
+    assert_eq!(select_attachment(&attachments, Some("1")).unwrap().id, "1");
+    assert!(select_attachment(&attachments, Some("9")).is_none());
+    assert!(select_attachment(&attachments[..1], None).is_none());
+}
+
+#[test]
+fn test_format_minidump_summary() {
+    let dump = minidump::parse(&sample_dump()).unwrap();
+    let output = format_minidump_summary(&attachment("2", "event.minidump"), &dump, 1);
+    assert!(output.contains("**Attachment:** 2.dmp (2048 bytes)"));
+    assert!(output.contains("**OS:** Windows 10.0.19045 (Service Pack 1)"));
+    assert!(output.contains("**CPU:** amd64 (8 cores)"));
+    assert!(output.contains("**Reason:** EXCEPTION_ACCESS_VIOLATION (0xc0000005)"));
+    assert!(output.contains("**Crashing Thread:** 4242"));
+    assert!(output.contains("**Crashing Module:** C:\\app\\game.exe +0x1234"));
+    assert!(output.contains("## Modules (2)"));
+    assert!(output.contains("*1 more modules not shown.*"));
+}
+
+#[test]
+fn test_format_minidump_summary_unknown_module() {
+    let data = build_dump(0x8201, 11, 0x42, &[(0x1000, 0x10, "/usr/lib/libc.so.6")]);
+    let dump = minidump::parse(&data).unwrap();
+    let output = format_minidump_summary(&attachment("3", "event.minidump"), &dump, 0);
+    assert!(output.contains("**Reason:** SIGSEGV (0xb)"));
+    assert!(output.contains("**Crashing Module:** not in any loaded module"));
+    assert!(!output.contains("## Modules"));
+}
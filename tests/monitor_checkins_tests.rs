@@ -0,0 +1,65 @@
+use sentry_mcp::api_client::MonitorCheckin;
+use sentry_mcp::tools::monitor_checkins::{count_by_status, format_checkins_output, is_failure};
+use serde_json::json;
+
+fn make_checkins() -> Vec<MonitorCheckin> {
+    serde_json::from_value(json!([
+        {"id": "c4", "status": "missed", "environment": "production",
+         "dateCreated": "2024-05-04T02:00:00Z", "expectedTime": "2024-05-04T02:00:00Z"},
+        {"id": "c3", "status": "error", "duration": 1200, "environment": "production",
+         "dateCreated": "2024-05-03T02:00:05Z"},
+        {"id": "c2", "status": "ok", "duration": 90000, "environment": "production",
+         "dateCreated": "2024-05-02T02:00:03Z"},
+        {"id": "c1", "status": "ok", "duration": 85000, "environment": "production",
+         "dateCreated": "2024-05-01T02:00:02Z"}
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_is_failure() {
+    let checkins = make_checkins();
+    assert!(is_failure(&checkins[0]));
+    assert!(is_failure(&checkins[1]));
+    assert!(!is_failure(&checkins[2]));
+}
+
+#[test]
+fn test_count_by_status_orders_ok_first() {
+    let checkins = make_checkins();
+    assert_eq!(
+        count_by_status(&checkins),
+        vec![("ok", 2), ("error", 1), ("missed", 1)]
+    );
+}
+
+#[test]
+fn test_format_checkins_output() {
+    let checkins = make_checkins();
+    let output = format_checkins_output("nightly-sync", Some("production"), &checkins);
+    assert!(output.contains("# Monitor Check-ins"));
+    assert!(output.contains("**Environment:** production"));
+    assert!(output.contains("**Check-ins:** 4"));
+    assert!(output.contains("**By Status:** ok 2, error 1, missed 1"));
+    assert!(output.contains("**Last OK:** 2024-05-02T02:00:03Z"));
+    assert!(
+        output.contains(
+            "| 2024-05-04T02:00:00Z | **missed** |  | production | 2024-05-04T02:00:00Z |"
+        )
+    );
+    assert!(output.contains("| 2024-05-02T02:00:03Z | ok | 90.00s | production |  |"));
+}
+
+#[test]
+fn test_format_checkins_output_without_ok() {
+    let checkins: Vec<MonitorCheckin> = make_checkins().into_iter().take(2).collect();
+    let output = format_checkins_output("nightly-sync", None, &checkins);
+    assert!(output.contains("**Last OK:** none in these check-ins"));
+    assert!(!output.contains("**Environment:**"));
+}
+
+#[test]
+fn test_format_checkins_output_empty() {
+    let output = format_checkins_output("nightly-sync", None, &[]);
+    assert!(output.contains("No check-ins recorded for this monitor."));
+}
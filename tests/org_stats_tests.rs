@@ -0,0 +1,70 @@
+use sentry_mcp::api_client::OrgStats;
+use sentry_mcp::tools::org_stats::{format_org_stats_output, outcome_matrix};
+use serde_json::json;
+
+fn group(outcome: &str, category: &str, quantity: u64) -> serde_json::Value {
+    json!({
+        "by": {"outcome": outcome, "reason": "none", "category": category},
+        "totals": {"sum(quantity)": quantity}
+    })
+}
+
+fn make_stats() -> OrgStats {
+    serde_json::from_value(json!({
+        "intervals": ["2024-05-01T00:00:00Z"],
+        "groups": [
+            group("rate_limited", "error", 30),
+            group("accepted", "error", 60),
+            group("filtered", "error", 10),
+            group("accepted", "transaction", 500),
+            group("client_discard", "transaction", 500),
+            group("accepted", "replay", 7)
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_outcome_matrix_orders_known_outcomes() {
+    let matrix = outcome_matrix(&make_stats());
+    assert_eq!(
+        matrix.outcomes,
+        vec!["accepted", "filtered", "rate_limited", "client_discard"]
+    );
+    assert_eq!(matrix.get("error", "rate_limited"), 30.0);
+    assert_eq!(matrix.get("replay", "filtered"), 0.0);
+}
+
+#[test]
+fn test_outcome_matrix_sums_duplicate_groups() {
+    let stats: OrgStats = serde_json::from_value(json!({
+        "groups": [group("invalid", "error", 2), group("invalid", "error", 3),
+                   group("zzz_new", "error", 1)]
+    }))
+    .unwrap();
+    let matrix = outcome_matrix(&stats);
+    assert_eq!(matrix.get("error", "invalid"), 5.0);
+    assert_eq!(matrix.outcomes, vec!["invalid", "zzz_new"]);
+}
+
+#[test]
+fn test_format_org_stats_table() {
+    let output = format_org_stats_output("acme", "30d", &[], &make_stats());
+    assert!(output.contains("# Organization Stats: acme"));
+    assert!(!output.contains("**Projects:**"));
+    assert!(output.contains(
+        "| Category | accepted | filtered | rate_limited | client_discard | Total | Not Accepted |\n|---|---|---|---|---|---|---|\n"
+    ));
+    assert!(output.contains("| error | 60 | 10 | 30 | 0 | 100 | 40.0% |"));
+    assert!(output.contains("| replay | 7 | 0 | 0 | 0 | 7 | 0.0% |"));
+    assert!(output.contains("| transaction | 500 | 0 | 0 | 500 | 1000 | 50.0% |"));
+    assert!(output.contains("| **Total** | 567 | 10 | 30 | 500 | 1107 | 48.8% |"));
+}
+
+#[test]
+fn test_format_org_stats_lists_projects_and_handles_empty() {
+    let projects = vec!["web".to_string(), "api".to_string()];
+    let output = format_org_stats_output("acme", "7d", &projects, &OrgStats::default());
+    assert!(output.contains("**Projects:** web, api"));
+    assert!(output.contains("No usage data for this period."));
+}
@@ -0,0 +1,91 @@
+use rmcp::model::{CallToolResult, Content};
+use sentry_mcp::output_style::{OutputStyle, ascii};
+
+const REPORT: &str = "# Triage Queue: web
+
+**Period:** 14d
+See [the issue](https://sentry.io/issues/1/) or `get_issue_details`.
+
+| Issue | Title | Users |
+|---|---|---|
+| WEB-1 | TypeError \\| null | 12 |
+| WEB-22 | Timeout | - |
+
+
+## Frame
+─────────────────────
+  → 42 │return user['id']
+```
+✓ [http.server] GET /
+def show(user):
+    return **kwargs
+```
+- ✅ Code owners: GitHub
+";
+
+#[test]
+fn test_parse() {
+    assert_eq!(OutputStyle::parse(""), Some(OutputStyle::Markdown));
+    assert_eq!(OutputStyle::parse("rich"), Some(OutputStyle::Markdown));
+    assert_eq!(OutputStyle::parse("TEXT"), Some(OutputStyle::Plain));
+    assert_eq!(OutputStyle::parse("minimal"), Some(OutputStyle::Minimal));
+    assert_eq!(OutputStyle::parse("html"), None);
+}
+
+#[test]
+fn test_markdown_is_unchanged() {
+    assert_eq!(OutputStyle::Markdown.render(REPORT), REPORT);
+}
+
+#[test]
+fn test_plain() {
+    let output = OutputStyle::Plain.render(REPORT);
+    assert!(output.starts_with("Triage Queue: web\n=================\n\nPeriod: 14d\n"));
+    assert!(output.contains("See the issue (https://sentry.io/issues/1/) or get_issue_details.\n"));
+    assert!(output.contains(
+        "Issue   Title             Users\nWEB-1   TypeError | null  12\nWEB-22  Timeout           -\n"
+    ));
+    assert!(output.contains("Frame\n-----\n---------------------\n  -> 42 |return user['id']\n"));
+    assert!(
+        output
+            .contains("    ok [http.server] GET /\n    def show(user):\n        return **kwargs\n")
+    );
+    assert!(output.ends_with("- [ok] Code owners: GitHub\n"));
+    assert!(output.is_ascii());
+}
+
+#[test]
+fn test_minimal() {
+    let output = OutputStyle::Minimal.render(REPORT);
+    assert!(output.starts_with("Triage Queue: web\n\nPeriod: 14d\n"));
+    assert!(output.contains("Issue | Title | Users\nWEB-1 | TypeError | null | 12\n"));
+    assert!(output.contains("| 12\nWEB-22 | Timeout | -\n\nFrame\n  -> 42 |return"));
+    assert!(
+        output.contains("\nok [http.server] GET /\ndef show(user):\n    return **kwargs\n- [ok]")
+    );
+    assert!(!output.contains("\n\n\n"));
+}
+
+#[test]
+fn test_apply_rewrites_text_contents() {
+    let mut result = CallToolResult::success(vec![Content::text("**Status:** ✓")]);
+    OutputStyle::Plain.apply(&mut result, false);
+    assert_eq!(result.content[0].as_text().unwrap().text, "Status: ok");
+}
+
+#[test]
+fn test_ascii_keeps_markdown() {
+    assert_eq!(
+        ascii("**Status:** ✗ failed\n  → 42 │x = 1\n```\n✓ ×3\n```\n"),
+        "**Status:** x failed\n  -> 42 |x = 1\n```\nok x3\n```\n"
+    );
+}
+
+#[test]
+fn test_apply_ascii_only() {
+    let mut result = CallToolResult::success(vec![Content::text("**Status:** ✓")]);
+    OutputStyle::Markdown.apply(&mut result, false);
+    assert_eq!(result.content[0].as_text().unwrap().text, "**Status:** ✓");
+    OutputStyle::Markdown.apply(&mut result, true);
+    assert_eq!(result.content[0].as_text().unwrap().text, "**Status:** ok");
+}
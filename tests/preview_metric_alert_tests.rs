@@ -0,0 +1,165 @@
+use sentry_mcp::tools::preview_metric_alert::{
+    AlertCondition, Direction, Firing, PreviewMetricAlertInput, format_condition,
+    format_preview_output, percent_change, percentile, simulate, validate_windows,
+};
+
+fn make_input(delta: Option<u32>) -> PreviewMetricAlertInput {
+    PreviewMetricAlertInput {
+        organization_slug: "test-org".to_string(),
+        project_slug: "backend".to_string(),
+        aggregate: None,
+        query: Some("level:error".to_string()),
+        environment: None,
+        time_window_minutes: 60,
+        threshold: 100.0,
+        direction: None,
+        resolve_threshold: None,
+        comparison_delta_minutes: delta,
+    }
+}
+
+fn above(threshold: f64, resolve: Option<f64>) -> AlertCondition {
+    AlertCondition {
+        direction: Direction::Above,
+        threshold,
+        resolve_threshold: resolve,
+    }
+}
+
+fn hourly(values: &[f64]) -> Vec<(i64, f64)> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (1_700_000_000 + i as i64 * 3600, *v))
+        .collect()
+}
+
+#[test]
+fn test_direction_parse() {
+    assert_eq!(Direction::parse("Above"), Some(Direction::Above));
+    assert_eq!(Direction::parse(" below "), Some(Direction::Below));
+    assert_eq!(Direction::parse("equal"), None);
+}
+
+#[test]
+fn test_validate_windows() {
+    assert!(validate_windows(5, None).is_ok());
+    assert!(validate_windows(60, Some(1440)).is_ok());
+    assert!(validate_windows(7, None).is_err());
+    assert!(validate_windows(60, Some(30)).is_err());
+    // 15 minutes is not a whole number of hourly windows.
+    assert!(validate_windows(60, Some(15)).is_err());
+}
+
+#[test]
+fn test_simulate_counts_separate_firings() {
+    let series = hourly(&[10.0, 150.0, 200.0, 20.0, 30.0, 120.0, 5.0]);
+    let firings = simulate(&series, &above(100.0, None));
+    assert_eq!(firings.len(), 2);
+    assert_eq!(
+        firings[0],
+        Firing {
+            started: series[1].0,
+            resolved: Some(series[3].0),
+            peak: 200.0,
+        }
+    );
+    assert_eq!(firings[1].started, series[5].0);
+    assert_eq!(firings[1].resolved, Some(series[6].0));
+}
+
+#[test]
+fn test_simulate_resolve_threshold_keeps_alert_open() {
+    // Dipping to 80 does not resolve an alert that resolves below 50.
+    let series = hourly(&[150.0, 80.0, 150.0, 40.0]);
+    let firings = simulate(&series, &above(100.0, Some(50.0)));
+    assert_eq!(firings.len(), 1);
+    assert_eq!(firings[0].resolved, Some(series[3].0));
+}
+
+#[test]
+fn test_simulate_below_and_still_firing() {
+    let condition = AlertCondition {
+        direction: Direction::Below,
+        threshold: 10.0,
+        resolve_threshold: None,
+    };
+    let series = hourly(&[50.0, 5.0, 2.0]);
+    let firings = simulate(&series, &condition);
+    assert_eq!(firings.len(), 1);
+    assert_eq!(firings[0].peak, 2.0);
+    assert_eq!(firings[0].resolved, None);
+}
+
+#[test]
+fn test_percent_change_skips_baseline_and_zero() {
+    let series = hourly(&[10.0, 0.0, 20.0, 5.0]);
+    let changes = percent_change(&series, 2);
+    // 20 vs 10 is +100%; 5 vs 0 is undefined and skipped.
+    assert_eq!(changes, vec![(series[2].0, 100.0)]);
+}
+
+#[test]
+fn test_percentile() {
+    let values = [1.0, 2.0, 3.0, 4.0, 100.0];
+    assert_eq!(percentile(&values, 50.0), Some(3.0));
+    assert_eq!(percentile(&values, 100.0), Some(100.0));
+    assert_eq!(percentile(&[], 50.0), None);
+}
+
+#[test]
+fn test_format_condition() {
+    assert_eq!(
+        format_condition(&above(100.0, Some(50.0)), None),
+        "above 100 (resolves below 50)"
+    );
+    assert_eq!(
+        format_condition(&above(50.0, None), Some(1440)),
+        "above 50% change compared to 1d earlier"
+    );
+}
+
+#[test]
+fn test_format_preview_output_with_firings() {
+    let input = make_input(None);
+    let condition = above(100.0, None);
+    let series = hourly(&[10.0, 150.0, 20.0]);
+    let firings = simulate(&series, &condition);
+    let output = format_preview_output(&input, &condition, &series, &firings);
+    assert!(output.contains("# Metric Alert Preview"));
+    assert!(output.contains("**Metric:** `count()` over 1h"));
+    assert!(output.contains("**Filter:** `level:error`"));
+    assert!(output.contains("**Period:** last 7 days (3 windows evaluated)"));
+    assert!(output.contains("**Observed:** p50 20, p90 150, p99 150, max 150"));
+    assert!(output.contains("**Would have fired:** 1 times"));
+    assert!(output.contains("| 2023-11-14 23:13 UTC | 2023-11-15 00:13 UTC | 1h | 150 |"));
+}
+
+#[test]
+fn test_format_preview_output_comparison_values() {
+    let input = make_input(Some(60));
+    let condition = above(50.0, None);
+    let series = hourly(&[10.0, 80.0]);
+    let firings = simulate(&series, &condition);
+    let output = format_preview_output(&input, &condition, &series, &firings);
+    assert!(output.contains("**Condition:** above 50% change compared to 1h earlier"));
+    assert!(output.contains("| still firing |  | +80.0% |"));
+}
+
+#[test]
+fn test_format_preview_output_never_fires() {
+    let input = make_input(None);
+    let condition = above(1000.0, None);
+    let series = hourly(&[10.0, 20.0]);
+    let output = format_preview_output(&input, &condition, &series, &[]);
+    assert!(output.contains("**Would have fired:** 0 times"));
+    assert!(output.contains("would not have fired"));
+    assert!(!output.contains("## Firings"));
+}
+
+#[test]
+fn test_format_preview_output_no_data() {
+    let input = make_input(None);
+    let output = format_preview_output(&input, &above(1.0, None), &[], &[]);
+    assert!(output.contains("No data in the last 7 days"));
+}
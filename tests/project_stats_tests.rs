@@ -0,0 +1,97 @@
+use sentry_mcp::api_client::OrgStats;
+use sentry_mcp::tools::project_stats::{
+    CategoryTotals, OutcomeClass, format_project_stats_output, summarize,
+};
+use serde_json::json;
+
+fn group(outcome: &str, reason: &str, category: &str, series: &[u64]) -> serde_json::Value {
+    json!({
+        "by": {"outcome": outcome, "reason": reason, "category": category},
+        "totals": {"sum(quantity)": series.iter().sum::<u64>()},
+        "series": {"sum(quantity)": series}
+    })
+}
+
+fn make_stats() -> OrgStats {
+    serde_json::from_value(json!({
+        "intervals": ["2024-05-01T00:00:00Z", "2024-05-02T00:00:00Z", "2024-05-03T00:00:00Z"],
+        "groups": [
+            group("accepted", "none", "error", &[10, 20, 30]),
+            group("filtered", "browser-extensions", "error", &[1, 1, 0]),
+            group("rate_limited", "usage_exceeded", "error", &[0, 0, 40]),
+            group("invalid", "too_large", "error", &[0, 2, 0]),
+            group("accepted", "none", "transaction", &[100, 100, 100]),
+            group("client_discard", "sample_rate", "transaction", &[0, 0, 0])
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_outcome_class() {
+    assert_eq!(OutcomeClass::of("accepted"), OutcomeClass::Accepted);
+    assert_eq!(OutcomeClass::of("filtered"), OutcomeClass::Filtered);
+    assert_eq!(OutcomeClass::of("rate_limited"), OutcomeClass::Dropped);
+    assert_eq!(OutcomeClass::of("client_discard"), OutcomeClass::Dropped);
+}
+
+#[test]
+fn test_summarize_totals_by_category() {
+    let summary = summarize(&make_stats());
+    assert_eq!(
+        summary.categories["error"],
+        CategoryTotals {
+            accepted: 60.0,
+            filtered: 2.0,
+            dropped: 42.0
+        }
+    );
+    assert_eq!(summary.categories["transaction"].total(), 300.0);
+}
+
+#[test]
+fn test_summarize_skips_empty_reasons() {
+    let summary = summarize(&make_stats());
+    assert_eq!(summary.reasons.len(), 3);
+    let key = (
+        "rate_limited".to_string(),
+        "usage_exceeded".to_string(),
+        "error".to_string(),
+    );
+    assert_eq!(summary.reasons[&key], 40.0);
+}
+
+#[test]
+fn test_summarize_sums_series_per_class() {
+    let summary = summarize(&make_stats());
+    assert_eq!(
+        summary.series[&OutcomeClass::Accepted],
+        vec![110.0, 120.0, 130.0]
+    );
+    assert_eq!(summary.series[&OutcomeClass::Dropped], vec![0.0, 2.0, 40.0]);
+}
+
+#[test]
+fn test_format_project_stats_output() {
+    let output = format_project_stats_output("backend", "3d", "1d", &make_stats());
+    assert!(output.contains("# Project Stats: backend"));
+    assert!(output.contains("**Period:** 3d (1d intervals)"));
+    assert!(output.contains("| error | 60 | 2 | 42 | 104 | 40.4% |"));
+    assert!(output.contains("| transaction | 300 | 0 | 0 | 300 | 0.0% |"));
+    let reasons = output.find("## Filtered and Dropped by Reason").unwrap();
+    let rate_limited = output
+        .find("- rate_limited / usage_exceeded (error): 40")
+        .unwrap();
+    let filtered = output
+        .find("- filtered / browser-extensions (error): 2")
+        .unwrap();
+    assert!(reasons < rate_limited && rate_limited < filtered);
+    assert!(output.contains("dropped  ▁▁█"));
+    assert!(output.contains("2024-05-01T00:00:00Z → 2024-05-03T00:00:00Z"));
+}
+
+#[test]
+fn test_format_project_stats_empty() {
+    let output = format_project_stats_output("backend", "30d", "1d", &OrgStats::default());
+    assert!(output.contains("No usage data for this period."));
+}
@@ -0,0 +1,40 @@
+use chrono::{TimeZone, Utc};
+use sentry_mcp::tools::project_throughput::{
+    WindowCounts, format_change, format_throughput_output,
+};
+
+#[test]
+fn test_format_change() {
+    assert_eq!(format_change(150.0, 120.0), "+25.0%");
+    assert_eq!(format_change(30.0, 120.0), "-75.0%");
+    assert_eq!(format_change(5.0, 0.0), "new");
+    assert_eq!(format_change(0.0, 0.0), "-");
+}
+
+#[test]
+fn test_window_counts_per_minute() {
+    let counts = WindowCounts {
+        last: 90.0,
+        previous: 30.0,
+    };
+    assert_eq!(counts.last_per_minute(), 1.5);
+    assert_eq!(counts.previous_per_minute(), 0.5);
+}
+
+#[test]
+fn test_format_throughput_output() {
+    let end = Utc.with_ymd_and_hms(2024, 5, 1, 12, 30, 0).unwrap();
+    let errors = WindowCounts {
+        last: 90.0,
+        previous: 30.0,
+    };
+    let transactions = WindowCounts {
+        last: 6000.0,
+        previous: 6000.0,
+    };
+    let output = format_throughput_output("backend", end, &errors, &transactions);
+    assert!(output.contains("# Throughput: backend"));
+    assert!(output.contains("ending 2024-05-01 12:30 UTC"));
+    assert!(output.contains("| Errors/min | 1.50 | 0.50 | +200.0% |"));
+    assert!(output.contains("| Transactions/min | 100.00 | 100.00 | +0.0% |"));
+}
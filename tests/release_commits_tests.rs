@@ -0,0 +1,53 @@
+use sentry_mcp::api_client::Commit;
+use sentry_mcp::tools::release_commits::{format_release_commits, group_by_repository};
+use serde_json::json;
+
+fn make_commits() -> Vec<Commit> {
+    serde_json::from_value(json!([
+        {
+            "id": "1111111abcdef", "message": "Fix checkout total\n\nLonger body",
+            "dateCreated": "2024-05-01T10:00:00Z",
+            "author": {"name": "Ana", "email": "ana@example.com"},
+            "repository": {"name": "acme/shop", "url": "https://github.com/acme/shop"}
+        },
+        {
+            "id": "2222222abcdef", "message": "Bump deps",
+            "author": {"email": "bot@example.com"},
+            "repository": {"name": "acme/infra"},
+            "pullRequest": {"id": "42", "externalUrl": "https://github.com/acme/infra/pull/42"}
+        },
+        {"id": "3333333abcdef", "message": "Refactor | cleanup", "repository": {"name": "acme/shop"}}
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_group_by_repository_keeps_first_seen_order() {
+    let commits = make_commits();
+    let groups = group_by_repository(&commits);
+    let names: Vec<_> = groups.iter().map(|(n, c)| (n.as_str(), c.len())).collect();
+    assert_eq!(names, vec![("acme/shop", 2), ("acme/infra", 1)]);
+}
+
+#[test]
+fn test_format_release_commits_table() {
+    let commits = make_commits();
+    let output = format_release_commits("shop@2.0.0", &commits, 3);
+    assert!(output.contains("**Release:** shop@2.0.0"));
+    assert!(output.contains("**Commits:** 3 in 2 repositories\n"));
+    assert!(output.contains("## acme/shop"));
+    assert!(output.contains(
+        "| [1111111](https://github.com/acme/shop/commit/1111111abcdef) | Ana | 2024-05-01T10:00:00Z | Fix checkout total | - |"
+    ));
+    assert!(output.contains(
+        "| 2222222 | bot@example.com | - | Bump deps | [#42](https://github.com/acme/infra/pull/42) |"
+    ));
+    assert!(output.contains("Refactor \\| cleanup"));
+    assert!(!output.contains("Longer body"));
+}
+
+#[test]
+fn test_format_release_commits_empty() {
+    let output = format_release_commits("v1", &[], 0);
+    assert!(output.contains("No commits are associated with this release"));
+}
@@ -0,0 +1,49 @@
+use sentry_mcp::api_client::Deploy;
+use sentry_mcp::tools::release_deploys::{format_deploys_output, sort_deploys};
+use serde_json::json;
+
+fn make_deploys() -> Vec<Deploy> {
+    serde_json::from_value(json!([
+        {"id": "1", "environment": "staging", "dateStarted": "2024-05-01T08:00:00Z",
+         "dateFinished": "2024-05-01T08:00:40Z"},
+        {"id": "2", "environment": "production", "dateFinished": "2024-05-02T12:00:00+00:00",
+         "name": "prod rollout", "url": "https://ci.example.com/run/2"},
+        {"id": "3", "environment": "production", "dateStarted": "2024-05-01T09:00:00Z"}
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_sort_deploys_most_recent_first() {
+    let deploys = make_deploys();
+    let ids: Vec<_> = sort_deploys(&deploys, None)
+        .iter()
+        .map(|d| d.id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["2", "3", "1"]);
+}
+
+#[test]
+fn test_sort_deploys_environment_filter() {
+    let deploys = make_deploys();
+    let selected = sort_deploys(&deploys, Some("staging"));
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected[0].id, "1");
+}
+
+#[test]
+fn test_format_deploys_output_table() {
+    let deploys = make_deploys();
+    let output = format_deploys_output("api@1.0.0", &sort_deploys(&deploys, None));
+    assert!(output.contains("**Release:** api@1.0.0"));
+    assert!(output.contains("**Deploys:** 3"));
+    assert!(output.contains("[prod rollout](https://ci.example.com/run/2)"));
+    assert!(output.contains("| staging | 2024-05-01T08:00:00Z | 2024-05-01T08:00:40Z | 40s | - |"));
+    assert!(output.contains("| production | 2024-05-01T09:00:00Z | - | - | - |"));
+}
+
+#[test]
+fn test_format_deploys_output_empty() {
+    let output = format_deploys_output("api@1.0.0", &[]);
+    assert!(output.contains("No deploys recorded for this release."));
+}
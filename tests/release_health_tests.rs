@@ -0,0 +1,114 @@
+use sentry_mcp::api_client::{Issue, SessionStats};
+use sentry_mcp::tools::release_health::{
+    ReleaseHealth, SessionTotals, Verdict, adoption, format_release_health, session_totals, verdict,
+};
+use serde_json::json;
+
+fn make_issue(id: &str) -> Issue {
+    serde_json::from_value(json!({
+        "id": id,
+        "shortId": format!("BACK-{}", id),
+        "title": format!("Issue {}", id),
+        "status": "unresolved",
+        "project": {"id": "1", "name": "Backend", "slug": "backend"},
+        "count": "10",
+        "userCount": 2
+    }))
+    .unwrap()
+}
+
+fn totals(sessions: f64, users: f64, crash_free: Option<f64>) -> SessionTotals {
+    SessionTotals {
+        sessions,
+        users,
+        crash_free_sessions: crash_free,
+        crash_free_users: crash_free,
+    }
+}
+
+fn health(release: Option<f64>, project: Option<f64>, regressed: usize) -> ReleaseHealth {
+    ReleaseHealth {
+        release: totals(250.0, 40.0, release),
+        project: totals(1000.0, 200.0, project),
+        new_issues: vec![],
+        regressed: (0..regressed).map(|i| make_issue(&i.to_string())).collect(),
+    }
+}
+
+#[test]
+fn test_session_totals() {
+    let stats: SessionStats = serde_json::from_value(json!({
+        "groups": [{
+            "by": {},
+            "totals": {
+                "sum(session)": 500,
+                "count_unique(user)": 80,
+                "crash_free_rate(session)": 0.99,
+                "crash_free_rate(user)": null
+            }
+        }]
+    }))
+    .unwrap();
+    assert_eq!(
+        session_totals(&stats),
+        SessionTotals {
+            sessions: 500.0,
+            users: 80.0,
+            crash_free_sessions: Some(0.99),
+            crash_free_users: None,
+        }
+    );
+    assert_eq!(
+        session_totals(&SessionStats::default()),
+        SessionTotals::default()
+    );
+}
+
+#[test]
+fn test_adoption() {
+    assert_eq!(adoption(250.0, 1000.0), Some(0.25));
+    assert_eq!(adoption(10.0, 0.0), None);
+    assert_eq!(adoption(12.0, 10.0), Some(1.0));
+}
+
+#[test]
+fn test_verdict() {
+    assert_eq!(
+        verdict(&health(Some(0.995), Some(0.99), 0)),
+        Verdict::Healthy
+    );
+    assert_eq!(verdict(&health(Some(0.99), Some(0.99), 1)), Verdict::Watch);
+    assert_eq!(verdict(&health(Some(0.98), Some(0.99), 0)), Verdict::Watch);
+    assert_eq!(
+        verdict(&health(Some(0.95), Some(0.99), 0)),
+        Verdict::Unhealthy
+    );
+    assert_eq!(verdict(&health(Some(0.95), None, 0)), Verdict::Healthy);
+    assert_eq!(verdict(&health(None, Some(0.99), 0)), Verdict::NoData);
+}
+
+#[test]
+fn test_format_release_health() {
+    let mut health = health(Some(0.98), Some(0.99), 1);
+    health.new_issues = (0..7).map(|i| make_issue(&format!("n{}", i))).collect();
+    let output = format_release_health("backend@1.2.0", "backend", "7d", &health);
+    assert!(output.contains("# Release Health: backend@1.2.0"));
+    assert!(output.contains("**Verdict:** Needs attention"));
+    assert!(output.contains("| Sessions | 250 | 1000 |"));
+    assert!(output.contains("| Adoption (sessions) | 25.00% | - |"));
+    assert!(output.contains("| Adoption (users) | 20.00% | - |"));
+    assert!(output.contains("| Crash-free sessions | 98.00% | 99.00% |"));
+    assert!(output.contains("## New Issues (7)"));
+    assert!(output.contains("| BACK-n4 | Issue n4 | 2 | 10 |"));
+    assert!(!output.contains("BACK-n5"));
+    assert!(output.contains("\nAnd 2 more.\n"));
+    assert!(output.contains("## Regressed Issues (1)"));
+}
+
+#[test]
+fn test_format_release_health_without_sessions() {
+    let output = format_release_health("1.0", "backend", "24h", &health(None, None, 0));
+    assert!(output.contains("**Verdict:** No session data"));
+    assert!(output.contains("| Crash-free users | - | - |"));
+    assert!(output.contains("## Regressed Issues (0)\n\nNone.\n"));
+}
@@ -0,0 +1,67 @@
+use sentry_mcp::api_client::{CodeMapping, Event};
+use sentry_mcp::tools::repo_paths::{map_path, normalize_frame_paths};
+use serde_json::json;
+
+fn mappings(pairs: &[(&str, &str)]) -> Vec<CodeMapping> {
+    pairs
+        .iter()
+        .enumerate()
+        .map(|(i, (stack_root, source_root))| {
+            serde_json::from_value(json!({
+                "id": i.to_string(),
+                "stackRoot": stack_root,
+                "sourceRoot": source_root
+            }))
+            .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_map_path_replaces_stack_root() {
+    let m = mappings(&[("/usr/src/app/", "backend/")]);
+    assert_eq!(
+        map_path("/usr/src/app/api/views.py", &m).as_deref(),
+        Some("backend/api/views.py")
+    );
+    assert_eq!(map_path("/usr/lib/python3.12/json.py", &m), None);
+}
+
+#[test]
+fn test_map_path_prefers_longest_stack_root() {
+    let m = mappings(&[("/app/", ""), ("/app/vendor/sdk/", "libs/sdk")]);
+    assert_eq!(
+        map_path("/app/vendor/sdk/client.py", &m).as_deref(),
+        Some("libs/sdk/client.py")
+    );
+    assert_eq!(map_path("/app/main.py", &m).as_deref(), Some("main.py"));
+}
+
+#[test]
+fn test_map_path_ignores_empty_roots_and_bare_prefix() {
+    let m = mappings(&[("", "src/"), ("/app/", "src/")]);
+    assert_eq!(map_path("main.py", &m), None);
+    assert_eq!(map_path("/app/", &m), None);
+}
+
+#[test]
+fn test_normalize_frame_paths_prefers_abs_path() {
+    let mut event: Event = serde_json::from_value(json!({
+        "id": "1",
+        "eventID": "abc",
+        "entries": [{
+            "type": "exception",
+            "data": {"values": [{"stacktrace": {"frames": [
+                {"filename": "api/views.py", "absPath": "/usr/src/app/api/views.py"},
+                {"filename": "json/__init__.py", "absPath": "/usr/lib/python3.12/json/__init__.py"}
+            ]}}]}
+        }]
+    }))
+    .unwrap();
+    let m = mappings(&[("/usr/src/app/", "backend/")]);
+    assert_eq!(normalize_frame_paths(&mut event, &m), 1);
+    let frames = &event.entries[0].data["values"][0]["stacktrace"]["frames"];
+    assert_eq!(frames[0]["filename"], "backend/api/views.py");
+    assert_eq!(frames[0]["absPath"], "/usr/src/app/api/views.py");
+    assert_eq!(frames[1]["filename"], "json/__init__.py");
+}
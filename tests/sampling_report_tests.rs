@@ -0,0 +1,86 @@
+use sentry_mcp::api_client::{OrgStats, ProjectSampling};
+use sentry_mcp::tools::sampling_report::{
+    format_sampling_report, sampling_rows, spike_protection_hits,
+};
+use serde_json::json;
+
+fn stats(groups: serde_json::Value) -> OrgStats {
+    serde_json::from_value(json!({
+        "intervals": ["2024-05-01T00:00:00Z", "2024-05-01T01:00:00Z", "2024-05-01T02:00:00Z"],
+        "groups": groups
+    }))
+    .unwrap()
+}
+
+fn group(outcome: &str, reason: &str, category: &str, series: [f64; 3]) -> serde_json::Value {
+    json!({
+        "by": {"outcome": outcome, "reason": reason, "category": category},
+        "totals": {"sum(quantity)": series.iter().sum::<f64>()},
+        "series": {"sum(quantity)": series}
+    })
+}
+
+#[test]
+fn test_spike_protection_hits_window_and_order() {
+    let stats = stats(json!([
+        group("rate_limited", "smart_rate_limit", "error", [0.0, 5.0, 0.0]),
+        group(
+            "rate_limited",
+            "smart_rate_limit",
+            "transaction",
+            [10.0, 0.0, 20.0]
+        ),
+        group("rate_limited", "usage_exceeded", "error", [100.0, 0.0, 0.0]),
+        group("accepted", "none", "error", [1.0, 1.0, 1.0])
+    ]));
+    let hits = spike_protection_hits(&stats);
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].category, "transaction");
+    assert_eq!(hits[0].dropped, 30.0);
+    assert_eq!(hits[0].first.as_deref(), Some("2024-05-01T00:00:00Z"));
+    assert_eq!(hits[0].last.as_deref(), Some("2024-05-01T02:00:00Z"));
+    assert_eq!(hits[1].category, "error");
+    assert_eq!(hits[1].first, hits[1].last);
+}
+
+#[test]
+fn test_sampling_rows_client_and_dynamic_rates() {
+    let stats = stats(json!([
+        group("accepted", "none", "transaction", [20.0, 0.0, 0.0]),
+        group("filtered", "Sampled:12", "transaction", [60.0, 0.0, 0.0]),
+        group(
+            "client_discard",
+            "sample_rate",
+            "transaction",
+            [320.0, 0.0, 0.0]
+        ),
+        group(
+            "client_discard",
+            "queue_overflow",
+            "transaction",
+            [7.0, 0.0, 0.0]
+        ),
+        group("accepted", "none", "error", [5.0, 0.0, 0.0])
+    ]));
+    let rows = sampling_rows(&stats);
+    assert_eq!(rows.len(), 1);
+    let row = &rows[0];
+    assert_eq!(row.category, "transaction");
+    assert_eq!(row.received, 80.0);
+    assert_eq!(row.client_rate(), Some(0.2));
+    assert_eq!(row.dynamic_rate(), Some(0.25));
+}
+
+#[test]
+fn test_format_sampling_report_quiet_project() {
+    let settings: ProjectSampling = serde_json::from_value(json!({
+        "options": {"quotas:spike-protection-disabled": true}
+    }))
+    .unwrap();
+    let stats = stats(json!([group("accepted", "none", "error", [1.0, 2.0, 3.0])]));
+    let output = format_sampling_report("backend", "24h", &settings, &stats);
+    assert!(output.contains("**Spike protection:** disabled for this project"));
+    assert!(output.contains("Spike protection did not drop any events in this period."));
+    assert!(output.contains("No client or dynamic sampling observed"));
+    assert!(!output.contains("Active dynamic sampling biases"));
+}
@@ -1,5 +1,7 @@
 use sentry_mcp::api_client::{Event, EventEntry, EventTag};
-use sentry_mcp::tools::search_issue_events::format_events_output;
+use sentry_mcp::tools::search_issue_events::{
+    format_event_count, format_events_output, issue_events_query,
+};
 use serde_json::json;
 
 fn make_event(
@@ -26,6 +28,7 @@ fn make_event(
                 value: v.to_string(),
             })
             .collect(),
+        release: serde_json::Value::Null,
     }
 }
 
@@ -228,6 +231,7 @@ fn test_format_events_all_optional_fields_missing() {
         contexts: json!({}),
         context: json!({}),
         tags: vec![],
+        release: serde_json::Value::Null,
     };
     let output = format_events_output("P-1", None, &[event]);
     assert!(output.contains("## Event 1 - evt-minimal"));
@@ -265,6 +269,7 @@ fn test_format_events_no_date_created() {
         contexts: json!({}),
         context: json!({}),
         tags: vec![],
+        release: serde_json::Value::Null,
     };
     let output = format_events_output("P-1", None, &[event]);
     assert!(!output.contains("**Date:**"));
@@ -301,3 +306,45 @@ fn test_format_events_large_event_count() {
     assert!(output.contains("## Event 1 - evt-0"));
     assert!(output.contains("## Event 100 - evt-99"));
 }
+
+#[test]
+fn test_format_events_output_within_budget() {
+    let message = "x".repeat(2000);
+    let events: Vec<Event> = (0..40)
+        .map(|i| {
+            make_event(
+                &format!("evt-{}", i),
+                "2024-01-01",
+                None,
+                Some(&message),
+                vec![],
+                vec![],
+            )
+        })
+        .collect();
+    let output = format_events_output("P-1", None, &events);
+    assert!(output.contains("**Found:** 40 events"));
+    assert!(output.contains("## Event 1 - evt-0"));
+    assert!(!output.contains("## Event 40 - evt-39"));
+    assert!(output.ends_with("more events omitted to fit ~8000 tokens._\n"));
+}
+
+#[test]
+fn test_issue_events_query() {
+    assert_eq!(issue_events_query("123", None), "issue.id:123");
+    assert_eq!(issue_events_query("BACK-12", Some("  ")), "issue:BACK-12");
+    assert_eq!(
+        issue_events_query("BACK-12", Some("os.name:Linux")),
+        "issue:BACK-12 os.name:Linux"
+    );
+}
+
+#[test]
+fn test_format_event_count() {
+    let output = format_event_count("BACK-12", Some("os.name:Linux"), 7);
+    assert!(output.contains("# Issue Event Count"));
+    assert!(output.contains("**Query:** os.name:Linux"));
+    assert!(output.contains("**Period:** 90d"));
+    assert!(output.contains("**Count:** 7\n"));
+    assert!(!format_event_count("BACK-12", None, 0).contains("**Query:**"));
+}
@@ -0,0 +1,125 @@
+use sentry_mcp::tools::search_query::{
+    QueryTarget, closest, edit_distance, push_query_warnings, validate_query,
+};
+
+#[test]
+fn test_edit_distance() {
+    assert_eq!(edit_distance("level", "level"), 0);
+    assert_eq!(edit_distance("levl", "level"), 1);
+    assert_eq!(edit_distance("unresolve", "unresolved"), 1);
+    assert_eq!(edit_distance("", "abc"), 3);
+}
+
+#[test]
+fn test_closest() {
+    let keys = ["level", "release", "firstSeen"];
+    assert_eq!(closest("levle", keys), Some("level"));
+    assert_eq!(closest("firstseen", keys), Some("firstSeen"));
+    assert_eq!(closest("customer_id", keys), None);
+    assert_eq!(closest("os", ["is"]), Some("is"));
+    assert_eq!(closest("url", ["is"]), None);
+}
+
+#[test]
+fn test_valid_queries() {
+    for query in [
+        "is:unresolved level:error",
+        "!is:resolved assigned:me TypeError",
+        "level:[error,fatal] firstSeen:-24h times_seen:>100",
+        "release:\"backend@1.2.0\" message:\"a: b\"",
+        "(browser.name:Chrome OR browser.name:Firefox) customer_id:42",
+        "tags[os]:Linux user.email:*@example.com",
+        "TypeError: cannot read property",
+        "",
+    ] {
+        assert_eq!(
+            validate_query(query, QueryTarget::Issues),
+            Ok(vec![]),
+            "{}",
+            query
+        );
+    }
+    assert_eq!(
+        validate_query("server_name:web-1 id:abc", QueryTarget::Events),
+        Ok(vec![])
+    );
+}
+
+#[test]
+fn test_unknown_key_suggestion() {
+    let warnings = validate_query("lvel:error", QueryTarget::Issues)
+        .unwrap()
+        .join("\n");
+    assert!(warnings.contains("Unknown key `lvel`: did you mean `level:error`?"));
+    assert!(warnings.contains("`tags[lvel]:error`"));
+    let warnings = validate_query("firstseen:-24h", QueryTarget::Issues)
+        .unwrap()
+        .join("\n");
+    assert!(warnings.contains("did you mean `firstSeen:-24h`?"));
+}
+
+#[test]
+fn test_alias_suggestion() {
+    let warnings = validate_query("status:unresolved assignee:me", QueryTarget::Issues)
+        .unwrap()
+        .join("\n");
+    assert!(warnings.contains("Unknown key `status`: did you mean `is:unresolved`?"));
+    assert!(warnings.contains("Unknown key `assignee`: did you mean `assigned:me`?"));
+}
+
+#[test]
+fn test_unknown_values() {
+    let warnings = validate_query("is:unresolve level:[error,fatl]", QueryTarget::Issues)
+        .unwrap()
+        .join("\n");
+    assert!(warnings.contains("Unknown `is:` value `unresolve`: did you mean `is:unresolved`?"));
+    assert!(warnings.contains("Unknown `level:` value `fatl`: did you mean `level:fatal`?"));
+    let warnings = validate_query("is:open", QueryTarget::Issues)
+        .unwrap()
+        .join("\n");
+    assert!(warnings.contains("expected one of unresolved, resolved"));
+}
+
+#[test]
+fn test_missing_value() {
+    let warnings = validate_query("release: level:error", QueryTarget::Issues)
+        .unwrap()
+        .join("\n");
+    assert!(warnings.contains("`release:` has no value"));
+}
+
+#[test]
+fn test_issue_keys_in_event_search() {
+    let warnings = validate_query("is:unresolved environment:prod", QueryTarget::Events)
+        .unwrap()
+        .join("\n");
+    assert!(warnings.contains("`is:` filters issues, not events"));
+}
+
+#[test]
+fn test_unbalanced_syntax() {
+    let err = validate_query("message:\"oops", QueryTarget::Issues).unwrap_err();
+    assert!(err.contains("Unclosed '\"'"));
+    let err = validate_query("(level:error OR level:fatal", QueryTarget::Issues).unwrap_err();
+    assert!(err.contains("Unclosed '('"));
+    let err = validate_query("level:error)", QueryTarget::Issues).unwrap_err();
+    assert!(err.contains("Unmatched ')'"));
+}
+
+#[test]
+fn test_custom_tags_and_new_values_are_not_rejected() {
+    let warnings = validate_query("env:prod is:reprocessing", QueryTarget::Issues).unwrap();
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings[0].contains("did you mean `environment:prod`?"));
+    assert!(warnings[1].contains("Unknown `is:` value `reprocessing`"));
+}
+
+#[test]
+fn test_push_query_warnings() {
+    let mut output = String::from("# Issues\n");
+    push_query_warnings(&mut output, &[]);
+    assert_eq!(output, "# Issues\n");
+    push_query_warnings(&mut output, &["Unknown key `lvel`".to_string()]);
+    assert!(output.contains("## Query Warnings"));
+    assert!(output.contains("- Unknown key `lvel`\n"));
+}
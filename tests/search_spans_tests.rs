@@ -0,0 +1,115 @@
+use sentry_mcp::api_client::DiscoverResult;
+use sentry_mcp::tools::search_spans::{
+    SearchSpansInput, SpanSearchMode, build_spans_query, format_spans_output, group_statements,
+    parse_mode, short_description, spans_sort,
+};
+
+fn input() -> SearchSpansInput {
+    SearchSpansInput {
+        organization_slug: "acme".to_string(),
+        op: None,
+        description: None,
+        min_duration_ms: None,
+        query: None,
+        project_slugs: None,
+        stats_period: None,
+        sort: None,
+        limit: None,
+        mode: None,
+    }
+}
+
+#[test]
+fn test_build_spans_query_empty() {
+    assert_eq!(build_spans_query(&input()), "");
+}
+
+#[test]
+fn test_build_spans_query_escapes_and_appends_query() {
+    let mut filters = input();
+    filters.description = Some(r#"WHERE "name" = "#.to_string());
+    filters.min_duration_ms = Some(0.0);
+    filters.query = Some(" span.status:internal_error ".to_string());
+    assert_eq!(
+        build_spans_query(&filters),
+        r#"span.description:"*WHERE \"name\" =*" span.status:internal_error"#
+    );
+}
+
+#[test]
+fn test_spans_sort() {
+    assert_eq!(spans_sort(None).unwrap(), "-span.duration");
+    assert_eq!(spans_sort(Some("Newest")).unwrap(), "-timestamp");
+    assert!(spans_sort(Some("fastest")).is_err());
+}
+
+#[test]
+fn test_short_description() {
+    assert_eq!(
+        short_description("query GetUser { user { id } viewer { id } }"),
+        "query GetUser { user, viewer }"
+    );
+    let long = format!("SELECT {}", "col, ".repeat(50));
+    let short = short_description(&long);
+    assert!(short.ends_with('…'));
+    assert_eq!(short.chars().count(), 121);
+    assert_eq!(short_description("a |\n b"), "a \\| b");
+}
+
+fn db_spans() -> DiscoverResult {
+    serde_json::from_value(serde_json::json!({
+        "data": [
+            {"span.op": "db", "span.description": "SELECT * FROM orders WHERE id = 42",
+             "span.duration": 300.0, "transaction": "GET /orders", "project": "api", "trace": "t1"},
+            {"span.op": "db", "span.description": "SELECT * FROM orders WHERE id = 7",
+             "span.duration": 900.0, "transaction": "GET /orders", "project": "api", "trace": "t2"},
+            {"span.op": "db", "span.description": "UPDATE users SET seen = now()",
+             "span.duration": 1000.0, "transaction": "POST /login", "project": "api", "trace": "t3"}
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_parse_mode() {
+    assert_eq!(parse_mode(None).unwrap(), SpanSearchMode::List);
+    assert_eq!(
+        parse_mode(Some("Explain")).unwrap(),
+        SpanSearchMode::Explain
+    );
+    assert!(parse_mode(Some("table")).is_err());
+}
+
+#[test]
+fn test_group_statements() {
+    let result = db_spans();
+    let groups = group_statements(&result.data);
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].statement, "SELECT * FROM orders WHERE id = %s");
+    assert_eq!(groups[0].count, 2);
+    assert_eq!(groups[0].total_ms, 1200.0);
+    assert_eq!(groups[0].max_ms, 900.0);
+    assert_eq!(groups[0].slowest["trace"], "t2");
+}
+
+#[test]
+fn test_format_spans_output_grouped() {
+    let output = format_spans_output(
+        "span.op:\"db*\"",
+        "24h",
+        SpanSearchMode::Grouped,
+        &db_spans(),
+    );
+    assert!(output.contains("| SELECT * FROM orders WHERE id = %s | 2 | 1.20s | 900.00ms | t2 |"));
+    assert!(output.contains("| UPDATE users SET seen = now() | 1 | 1.00s | 1.00s | t3 |"));
+}
+
+#[test]
+fn test_format_spans_output_explain() {
+    let output = format_spans_output("", "24h", SpanSearchMode::Explain, &db_spans());
+    let update = output.find("## 1. 1.00s (api, POST /login)").unwrap();
+    let select = output.find("## 2. 900.00ms (api, GET /orders)").unwrap();
+    assert!(update < select);
+    assert!(output.contains("```sql\nSELECT * FROM orders WHERE id = 7\n```"));
+    assert!(!output.contains("id = 42"));
+}
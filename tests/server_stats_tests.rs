@@ -0,0 +1,36 @@
+use sentry_mcp::tools::server_stats::{ServerStats, format_server_stats, format_uptime};
+use std::time::Duration;
+
+#[test]
+fn test_format_uptime() {
+    assert_eq!(format_uptime(Duration::from_secs(75)), "1m 15s");
+    assert_eq!(
+        format_uptime(Duration::from_secs(2 * 3600 + 5 * 60)),
+        "2h 5m"
+    );
+    assert_eq!(
+        format_uptime(Duration::from_secs(3 * 86_400 + 4 * 3600)),
+        "3d 4h"
+    );
+}
+
+#[test]
+fn test_format_server_stats_shows_session() {
+    let stats = ServerStats {
+        version: "0.3.0",
+        user_agent: "sentry-mcp/0.3.0",
+        session_id: "abc123",
+        api_url: "https://sentry.io/api/0",
+        uptime: Duration::from_secs(42),
+        writes_enabled: false,
+        destructive_enabled: false,
+        tool_count: 20,
+    };
+    let output = format_server_stats(&stats);
+    assert!(output.contains("**Session ID:** abc123"));
+    assert!(output.contains("**User Agent:** sentry-mcp/0.3.0"));
+    assert!(output.contains("**Uptime:** 0m 42s"));
+    assert!(output.contains("**Write Tools:** disabled"));
+    assert!(output.contains("**Destructive Tools:** disabled"));
+    assert!(output.contains("**Registered Tools:** 20"));
+}
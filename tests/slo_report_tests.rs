@@ -0,0 +1,99 @@
+use sentry_mcp::api_client::DiscoverResult;
+use sentry_mcp::tools::slo_report::{
+    SloBudget, SloMetric, aggregate, build_query, format_slo_report,
+};
+use serde_json::json;
+
+#[test]
+fn test_slo_metric_parse() {
+    assert_eq!(
+        SloMetric::parse("failed_transactions"),
+        Some(SloMetric::FailedTransactions)
+    );
+    assert_eq!(SloMetric::parse("Errors"), Some(SloMetric::Errors));
+    assert_eq!(SloMetric::parse("latency"), None);
+}
+
+#[test]
+fn test_budget_within_target() {
+    let budget = SloBudget::compute(10_000.0, 4.0, 99.9);
+    assert!((budget.error_rate - 0.0004).abs() < 1e-12);
+    assert!((budget.allowed - 10.0).abs() < 1e-9);
+    assert!((budget.remaining - 6.0).abs() < 1e-9);
+    assert!((budget.remaining_fraction().unwrap() - 0.6).abs() < 1e-9);
+    assert!((budget.burn_rate - 0.4).abs() < 1e-9);
+}
+
+#[test]
+fn test_budget_exhausted() {
+    let budget = SloBudget::compute(1000.0, 30.0, 99.0);
+    assert!((budget.remaining + 20.0).abs() < 1e-9);
+    assert!(budget.remaining_fraction().unwrap() < 0.0);
+    assert!((budget.burn_rate - 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_budget_no_traffic() {
+    let budget = SloBudget::compute(0.0, 0.0, 99.9);
+    assert_eq!(budget.error_rate, 0.0);
+    assert_eq!(budget.remaining_fraction(), None);
+}
+
+#[test]
+fn test_build_query_escapes_transaction() {
+    assert_eq!(build_query("error", None), "event.type:error");
+    assert_eq!(
+        build_query("transaction", Some("GET \"/a\"")),
+        "event.type:transaction transaction:\"GET \\\"/a\\\"\""
+    );
+}
+
+#[test]
+fn test_aggregate_reads_first_row() {
+    let result: DiscoverResult =
+        serde_json::from_value(json!({"data": [{"count()": 12, "failure_count()": 1.0}]})).unwrap();
+    assert_eq!(aggregate(&result, "count()"), 12.0);
+    assert_eq!(aggregate(&result, "failure_count()"), 1.0);
+    assert_eq!(aggregate(&result, "missing"), 0.0);
+    assert_eq!(aggregate(&DiscoverResult::default(), "count()"), 0.0);
+}
+
+#[test]
+fn test_format_slo_report_violated() {
+    let budget = SloBudget::compute(1000.0, 30.0, 99.0);
+    let output = format_slo_report("backend", "7d", SloMetric::Errors, 99.0, &budget);
+    assert!(output.contains("**Metric:** Error events / total transactions"));
+    assert!(output.contains("**Error Rate:** 3.000% (success 97.000%)"));
+    assert!(output.contains("exhausted — over by 20 failures (200.0%)"));
+    assert!(output.contains("SLO violated"));
+}
+
+#[test]
+fn test_format_slo_report_burning_fast() {
+    let budget = SloBudget {
+        remaining: 1.0,
+        burn_rate: 1.5,
+        ..SloBudget::compute(1000.0, 9.0, 99.0)
+    };
+    let output = format_slo_report(
+        "backend",
+        "24h",
+        SloMetric::FailedTransactions,
+        99.0,
+        &budget,
+    );
+    assert!(output.contains("Burning budget faster"));
+}
+
+#[test]
+fn test_format_slo_report_no_traffic() {
+    let budget = SloBudget::compute(0.0, 0.0, 99.9);
+    let output = format_slo_report(
+        "backend",
+        "7d",
+        SloMetric::FailedTransactions,
+        99.9,
+        &budget,
+    );
+    assert!(output.contains("No transactions recorded in this window."));
+}
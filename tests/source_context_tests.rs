@@ -0,0 +1,95 @@
+use sentry_mcp::api_client::Event;
+use sentry_mcp::tools::source_context::{frames_missing_context, raw_source_url, source_context};
+use serde_json::json;
+
+#[test]
+fn test_raw_source_url_github() {
+    assert_eq!(
+        raw_source_url("https://github.com/acme/api/blob/4f2c/src/main.rs#L12").as_deref(),
+        Some("https://raw.githubusercontent.com/acme/api/4f2c/src/main.rs")
+    );
+}
+
+#[test]
+fn test_raw_source_url_gitlab_and_bitbucket() {
+    assert_eq!(
+        raw_source_url("https://gitlab.com/acme/api/-/blob/main/app.py?ref_type=heads").as_deref(),
+        Some("https://gitlab.com/acme/api/-/raw/main/app.py")
+    );
+    assert_eq!(
+        raw_source_url("https://bitbucket.org/acme/api/src/main/app.py#lines-3").as_deref(),
+        Some("https://bitbucket.org/acme/api/raw/main/app.py")
+    );
+}
+
+#[test]
+fn test_raw_source_url_unknown_host() {
+    assert_eq!(raw_source_url("https://example.com/acme/api/app.py"), None);
+    assert_eq!(
+        raw_source_url("https://github.com/acme/api/tree/main"),
+        None
+    );
+}
+
+#[test]
+fn test_source_context_window() {
+    let text = "a\nb\nc\nd\ne\nf\ng\nh";
+    let context = source_context(text, 2, 3);
+    assert_eq!(
+        context,
+        vec![
+            json!([1, "a"]),
+            json!([2, "b"]),
+            json!([3, "c"]),
+            json!([4, "d"]),
+            json!([5, "e"])
+        ]
+    );
+    assert_eq!(source_context(text, 8, 3).len(), 4);
+}
+
+#[test]
+fn test_source_context_out_of_range() {
+    assert!(source_context("a\nb", 5, 3).is_empty());
+    assert!(source_context("a\nb", 0, 3).is_empty());
+    assert!(source_context("a\nb", -1, 3).is_empty());
+}
+
+fn make_event(frames: serde_json::Value) -> Event {
+    serde_json::from_value(json!({
+        "id": "1",
+        "eventID": "1",
+        "entries": [
+            {"type": "message", "data": {"formatted": "hi"}},
+            {"type": "exception", "data": {"values": [{"stacktrace": {"frames": frames}}]}}
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_frames_missing_context_innermost_in_app_first() {
+    let event = make_event(json!([
+        {"filename": "a.py", "lineNo": 1, "inApp": true},
+        {"filename": "lib.py", "lineNo": 2, "inApp": false},
+        {"filename": "b.py", "lineNo": 3, "inApp": true, "context": [[3, "x"]]},
+        {"filename": "c.py", "lineNo": 4, "inApp": true, "context": []},
+        {"filename": "d.py", "inApp": true}
+    ]));
+    assert_eq!(
+        frames_missing_context(&event, &[]),
+        vec![(1, 0, 3), (1, 0, 0)]
+    );
+}
+
+#[test]
+fn test_frames_missing_context_respects_in_app_patterns() {
+    let event = make_event(json!([
+        {"filename": "vendor/acme/x.py", "lineNo": 7, "inApp": false}
+    ]));
+    assert!(frames_missing_context(&event, &[]).is_empty());
+    assert_eq!(
+        frames_missing_context(&event, &["vendor/acme/".to_string()]),
+        vec![(1, 0, 0)]
+    );
+}
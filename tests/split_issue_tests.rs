@@ -0,0 +1,50 @@
+use sentry_mcp::api_client::IssueHash;
+use sentry_mcp::tools::split_issue::{format_split_output, validate_split};
+use serde_json::json;
+
+fn hash(id: &str, exc: Option<&str>, function: &str) -> IssueHash {
+    let latest_event = exc.map(|exc| {
+        json!({"id": "e", "eventID": "e", "entries": [{"type": "exception", "data": {"values": [{
+            "type": exc,
+            "stacktrace": {"frames": [
+                {"function": "main", "module": "app", "inApp": true},
+                {"function": function, "module": "app.views", "inApp": true}
+            ]}
+        }]}}]})
+    });
+    serde_json::from_value(json!({"id": id, "latestEvent": latest_event})).unwrap()
+}
+
+fn hashes() -> Vec<IssueHash> {
+    vec![
+        hash("h1", Some("TypeError"), "cart"),
+        hash("h2", None, ""),
+        hash("h3", Some("KeyError"), "checkout"),
+        hash("h4", Some("KeyError"), "checkout"),
+    ]
+}
+
+#[test]
+fn test_validate_split() {
+    let hashes = hashes();
+    assert_eq!(
+        validate_split(
+            &["h1".to_string(), " h1".to_string(), "".to_string()],
+            &hashes
+        )
+        .unwrap(),
+        vec!["h1"]
+    );
+    assert!(validate_split(&["nope".to_string()], &hashes).is_err());
+    assert!(validate_split(&[], &hashes).is_err());
+    let all: Vec<String> = hashes.iter().map(|h| h.id.clone()).collect();
+    let err = validate_split(&all, &hashes).unwrap_err();
+    assert!(err.message.contains("At least one hash must stay"));
+}
+
+#[test]
+fn test_format_split_output() {
+    let output = format_split_output("PROJ-1", &["h1".to_string()]);
+    assert!(output.contains("Split 1 grouping hashes out of PROJ-1 into a new issue"));
+    assert!(output.contains("- `h1`"));
+}
@@ -0,0 +1,37 @@
+use sentry_mcp::tools::sql::normalize_sql;
+
+#[test]
+fn test_strips_literals() {
+    assert_eq!(
+        normalize_sql("SELECT * FROM users WHERE name = 'O''Brien' AND age > 30"),
+        "SELECT * FROM users WHERE name = %s AND age > %s"
+    );
+}
+
+#[test]
+fn test_keeps_identifiers_with_digits() {
+    assert_eq!(
+        normalize_sql(r#"SELECT t1.col2, "v2" FROM t1 LIMIT 10 OFFSET 2.5"#),
+        r#"SELECT t1.col2, "v2" FROM t1 LIMIT %s OFFSET %s"#
+    );
+}
+
+#[test]
+fn test_bind_parameters_and_lists() {
+    assert_eq!(
+        normalize_sql("SELECT id FROM orders\n  WHERE id IN ($1, $2, $3) AND state = ?"),
+        "SELECT id FROM orders WHERE id IN (%s) AND state = %s"
+    );
+    assert_eq!(
+        normalize_sql("DELETE FROM t WHERE id IN (%s, %s)"),
+        "DELETE FROM t WHERE id IN (%s)"
+    );
+}
+
+#[test]
+fn test_values_rows() {
+    assert_eq!(
+        normalize_sql("INSERT INTO t (a, b) VALUES (1, 'x'), (2, 'y'), (3, 'z')"),
+        "INSERT INTO t (a, b) VALUES (%s), ..."
+    );
+}
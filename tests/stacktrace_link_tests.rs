@@ -0,0 +1,112 @@
+use sentry_mcp::api_client::Event;
+use sentry_mcp::tools::source_context::release_commit;
+use sentry_mcp::tools::stacktrace_link::{
+    FrameLink, format_frame_links, line_anchor, link_error_hint, matching_frames, relevant_frames,
+};
+use serde_json::json;
+
+fn make_event() -> Event {
+    serde_json::from_value(json!({
+        "id": "e1",
+        "eventID": "e1",
+        "release": {"version": "1.0", "lastCommit": {"id": "deadbeef"}},
+        "entries": [{
+            "type": "exception",
+            "data": {"values": [
+                {"type": "KeyError", "stacktrace": {"frames": [
+                    {"filename": "app/main.py", "lineNo": 3, "inApp": true},
+                    {"filename": "app/views.py", "lineNo": 42, "inApp": true},
+                    {"filename": "lib/requests.py", "lineNo": 9, "inApp": false}
+                ]}},
+                {"type": "ValueError", "stacktrace": {"frames": [
+                    {"filename": "lib/json.py", "lineNo": 1, "inApp": false}
+                ]}},
+                {"type": "TypeError", "stacktrace": {"frames": [
+                    {"filename": "app/views.py", "lineNo": 7, "inApp": true}
+                ]}}
+            ]}
+        }]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_line_anchor() {
+    assert_eq!(
+        line_anchor("https://github.com/acme/api/blob/main/app.py", Some(12)),
+        "https://github.com/acme/api/blob/main/app.py#L12"
+    );
+    assert_eq!(
+        line_anchor("https://bitbucket.org/acme/api/src/main/app.py", Some(12)),
+        "https://bitbucket.org/acme/api/src/main/app.py#lines-12"
+    );
+    assert_eq!(line_anchor("https://x/app.py", None), "https://x/app.py");
+    assert_eq!(line_anchor("https://x/app.py", Some(0)), "https://x/app.py");
+}
+
+#[test]
+fn test_link_error_hint() {
+    assert_eq!(
+        link_error_hint("stack_root_mismatch"),
+        "no code mapping's stack root matches this path"
+    );
+    assert_eq!(link_error_hint("something_new"), "something_new");
+}
+
+#[test]
+fn test_relevant_frames_innermost_in_app_per_exception() {
+    let event = make_event();
+    assert_eq!(relevant_frames(&event, &[]), [(0, 0, 1), (0, 2, 0)]);
+    assert_eq!(
+        relevant_frames(&event, &["lib/".to_string()]),
+        [(0, 0, 2), (0, 1, 0), (0, 2, 0)]
+    );
+}
+
+#[test]
+fn test_matching_frames() {
+    let event = make_event();
+    assert_eq!(
+        matching_frames(&event, "views.py", None),
+        [(0, 0, 1), (0, 2, 0)]
+    );
+    assert_eq!(matching_frames(&event, "views.py", Some(7)), [(0, 2, 0)]);
+    assert!(matching_frames(&event, "missing.py", None).is_empty());
+}
+
+#[test]
+fn test_release_commit() {
+    let mut event = make_event();
+    assert_eq!(release_commit(&event), Some("deadbeef"));
+    event.release = json!({"version": "1.0", "lastCommit": null});
+    assert_eq!(release_commit(&event), None);
+}
+
+#[test]
+fn test_format_frame_links() {
+    let links = vec![
+        FrameLink {
+            location: "app/views.py:42".to_string(),
+            function: Some("index".to_string()),
+            url: Some("https://github.com/acme/api/blob/deadbeef/app/views.py#L42".to_string()),
+            error: None,
+        },
+        FrameLink {
+            location: "vendor/x.py:1".to_string(),
+            function: None,
+            url: None,
+            error: Some("stack_root_mismatch".to_string()),
+        },
+    ];
+    let output = format_frame_links("API-1", "e1", None, &links);
+    assert!(output.contains("**Commit:** unknown"));
+    assert!(output.contains(
+        "- `app/views.py:42` in `index`: https://github.com/acme/api/blob/deadbeef/app/views.py#L42\n"
+    ));
+    assert!(output.contains(
+        "- `vendor/x.py:1`: not linked, no code mapping's stack root matches this path\n"
+    ));
+    let output = format_frame_links("API-1", "e1", Some("deadbeef"), &[]);
+    assert!(output.contains("**Commit:** deadbeef\n"));
+    assert!(output.contains("No matching frames"));
+}
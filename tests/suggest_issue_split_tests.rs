@@ -0,0 +1,76 @@
+use sentry_mcp::api_client::IssueHash;
+use sentry_mcp::tools::suggest_issue_split::{format_split_suggestion, group_hashes};
+use serde_json::json;
+
+fn hash(id: &str, exc: Option<&str>, function: &str) -> IssueHash {
+    let latest_event = exc.map(|exc| {
+        json!({"id": "e", "eventID": "e", "entries": [{"type": "exception", "data": {"values": [{
+            "type": exc,
+            "stacktrace": {"frames": [
+                {"function": "main", "module": "app", "inApp": true},
+                {"function": function, "module": "app.views", "inApp": true}
+            ]}
+        }]}}]})
+    });
+    serde_json::from_value(json!({"id": id, "latestEvent": latest_event})).unwrap()
+}
+
+fn hashes() -> Vec<IssueHash> {
+    vec![
+        hash("h1", Some("TypeError"), "cart"),
+        hash("h2", None, ""),
+        hash("h3", Some("KeyError"), "checkout"),
+        hash("h4", Some("KeyError"), "checkout"),
+    ]
+}
+
+#[test]
+fn test_group_hashes_largest_path_first() {
+    let hashes = hashes();
+    let groups = group_hashes(&hashes, 3, &[]);
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[0].hashes, vec!["h3", "h4"]);
+    assert_eq!(
+        groups[0].path.as_ref().unwrap().frames,
+        vec!["checkout (app.views)", "main (app)"]
+    );
+    assert_eq!(groups[1].hashes, vec!["h1"]);
+    assert!(groups[2].path.is_none());
+}
+
+#[test]
+fn test_group_hashes_depth_limits_frames() {
+    let hashes = vec![
+        hash("h1", Some("KeyError"), "checkout"),
+        hash("h2", Some("KeyError"), "checkout"),
+    ];
+    let groups = group_hashes(&hashes, 1, &[]);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(
+        groups[0].path.as_ref().unwrap().frames,
+        vec!["checkout (app.views)"]
+    );
+}
+
+#[test]
+fn test_format_split_suggestion() {
+    let hashes = hashes();
+    let groups = group_hashes(&hashes, 3, &[]);
+    let output = format_split_suggestion("PROJ-1", &hashes, &groups);
+    assert!(output.contains("**Hashes:** 4\n**Failure paths:** 2"));
+    assert!(output.contains(
+        "## Path 1: KeyError (keep)\n\n**Hashes:** `h3`, `h4`\n- `checkout (app.views)`"
+    ));
+    assert!(output.contains("## Path 2: TypeError (split out)"));
+    assert!(output.contains("## Path 3: (no stored event) (unclassified)"));
+    assert!(output.contains("- `hashes: [\"h1\"]`"));
+    assert!(!output.contains("\"h2\""));
+}
+
+#[test]
+fn test_format_split_suggestion_single_path() {
+    let hashes = vec![hash("h1", Some("KeyError"), "checkout")];
+    let groups = group_hashes(&hashes, 3, &[]);
+    let output = format_split_suggestion("PROJ-1", &hashes, &groups);
+    assert!(output.contains("no split recommended"));
+}
@@ -0,0 +1,125 @@
+use sentry_mcp::tools::token_scope_advisor::{
+    format_advice_output, implied_scopes, parse_endpoint, required_scopes, satisfying_scope,
+};
+
+fn required(endpoint: &str) -> sentry_mcp::tools::token_scope_advisor::RequiredScopes {
+    let (method, segments) = parse_endpoint(endpoint, None).unwrap();
+    required_scopes(&method, &segments).unwrap()
+}
+
+fn scopes(values: &[&str]) -> Vec<String> {
+    values.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn test_parse_endpoint_strips_host_prefix_and_query() {
+    let (method, segments) = parse_endpoint(
+        "delete https://sentry.example.com/api/0/projects/acme/web/?x=1",
+        None,
+    )
+    .unwrap();
+    assert_eq!(method, "DELETE");
+    assert_eq!(segments, vec!["projects", "acme", "web"]);
+}
+
+#[test]
+fn test_parse_endpoint_method_parameter() {
+    let (method, segments) = parse_endpoint("/organizations/acme/members/", Some("post")).unwrap();
+    assert_eq!(method, "POST");
+    assert_eq!(segments, vec!["organizations", "acme", "members"]);
+    let (method, _) = parse_endpoint("/organizations/acme/", None).unwrap();
+    assert_eq!(method, "GET");
+    assert!(parse_endpoint("/organizations/acme/", Some("TRACE")).is_err());
+}
+
+#[test]
+fn test_required_scopes_by_resource() {
+    assert_eq!(
+        required("GET /api/0/organizations/acme/issues/1/").any_of[0],
+        "event:read"
+    );
+    assert_eq!(
+        required("PUT /organizations/acme/issues/1/").any_of[0],
+        "event:write"
+    );
+    assert_eq!(
+        required("POST /organizations/acme/members/").any_of[0],
+        "member:invite"
+    );
+    assert_eq!(
+        required("POST /projects/acme/web/rules/").any_of[0],
+        "alerts:write"
+    );
+    assert_eq!(
+        required("POST /organizations/acme/releases/").any_of[0],
+        "project:releases"
+    );
+    assert_eq!(
+        required("GET /projects/acme/web/events/abc/").any_of[0],
+        "event:read"
+    );
+    // Discover queries are organization reads, not event reads.
+    assert_eq!(
+        required("GET /organizations/acme/events/").any_of[0],
+        "org:read"
+    );
+    assert_eq!(
+        required("DELETE /projects/acme/web/").any_of[0],
+        "project:admin"
+    );
+}
+
+#[test]
+fn test_required_scopes_unknown_root() {
+    let (method, segments) = parse_endpoint("/internal/health/", None).unwrap();
+    assert!(required_scopes(&method, &segments).is_none());
+    assert!(required_scopes("GET", &[]).is_none());
+}
+
+#[test]
+fn test_implied_scopes() {
+    assert_eq!(
+        implied_scopes("project:admin"),
+        vec!["project:admin", "project:write", "project:read"]
+    );
+    assert!(implied_scopes("member:write").contains(&"member:invite".to_string()));
+    assert_eq!(implied_scopes("project:releases"), vec!["project:releases"]);
+}
+
+#[test]
+fn test_satisfying_scope_uses_hierarchy() {
+    let needed = required("GET /organizations/acme/issues/1/");
+    assert_eq!(
+        satisfying_scope(&needed, &scopes(&["org:read", "event:admin"])),
+        Some("event:admin")
+    );
+    assert_eq!(satisfying_scope(&needed, &scopes(&["org:read"])), None);
+}
+
+#[test]
+fn test_format_advice_output_missing() {
+    let needed = required("POST /organizations/acme/members/");
+    let output = format_advice_output(&needed, &scopes(&["org:read", "member:read"]));
+    assert!(output.contains("**Resource:** organization members"));
+    assert!(
+        output.contains("**Requires one of:** `member:invite`, `member:write`, `member:admin`")
+    );
+    assert!(output.contains("**Missing:** `member:invite`"));
+    assert!(output.contains("```\nmember:invite member:read org:read\n```"));
+}
+
+#[test]
+fn test_format_advice_output_already_granted() {
+    let needed = required("GET /projects/acme/web/");
+    let output = format_advice_output(&needed, &scopes(&["project:write"]));
+    assert!(output.contains("`project:write` scope already covers this endpoint"));
+    assert!(!output.contains("**Missing:**"));
+}
+
+#[test]
+fn test_format_advice_output_no_scopes() {
+    let needed = required("GET /organizations/acme/");
+    let output = format_advice_output(&needed, &[]);
+    assert!(output.contains("**Token scopes:** none"));
+    assert!(output.contains("```\norg:read\n```"));
+}
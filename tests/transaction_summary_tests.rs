@@ -0,0 +1,36 @@
+use sentry_mcp::api_client::DiscoverResult;
+use sentry_mcp::tools::transaction_summary::format_transaction_summary;
+use serde_json::json;
+
+fn result(rows: serde_json::Value) -> DiscoverResult {
+    serde_json::from_value(json!({ "data": rows })).unwrap()
+}
+
+#[test]
+fn test_format_summary_percentiles() {
+    let aggregates = result(json!([{
+        "count()": 500, "tpm()": 0.35, "apdex()": 0.8, "failure_rate()": 0.02,
+        "p50(transaction.duration)": 120.0, "p75(transaction.duration)": 240.0,
+        "p95(transaction.duration)": 900.0, "p99(transaction.duration)": 2100.0,
+        "max(transaction.duration)": 9000.0
+    }]));
+    let output = format_transaction_summary("GET /", "7d", &aggregates, &result(json!([])));
+    assert!(output.contains("**Events:** 500"));
+    assert!(output.contains("**Failure Rate:** 2.0%"));
+    assert!(output.contains(
+        "| p50 | p75 | p95 | p99 | max |\n|---|---|---|---|---|\n| 120.00ms | 240.00ms | 900.00ms | 2.10s | 9.00s |"
+    ));
+    assert!(output.contains("## Slowest Events\n\nNo events found."));
+}
+
+#[test]
+fn test_format_summary_without_events() {
+    let output = format_transaction_summary(
+        "GET /missing",
+        "24h",
+        &result(json!([{"count()": 0}])),
+        &result(json!([])),
+    );
+    assert!(output.contains("No events for this transaction in the period."));
+    assert!(!output.contains("## Duration"));
+}
@@ -0,0 +1,55 @@
+use sentry_mcp::api_client::Issue;
+use sentry_mcp::tools::triage_queue::{format_triage_queue, merge_queue};
+use serde_json::json;
+
+fn make_issue(id: &str, users: i64, count: &str, priority: Option<&str>) -> Issue {
+    serde_json::from_value(json!({
+        "id": id,
+        "shortId": format!("BACK-{}", id),
+        "title": format!("Issue {}", id),
+        "status": "unresolved",
+        "level": "error",
+        "priority": priority,
+        "project": {"id": "1", "name": "Backend", "slug": "backend"},
+        "count": count,
+        "userCount": users,
+        "lastSeen": "2024-01-02T00:00:00Z"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_merge_queue_dedups_and_sorts_by_users() {
+    let high = vec![
+        make_issue("1", 3, "900", Some("high")),
+        make_issue("2", 50, "10", Some("high")),
+    ];
+    let errors = vec![
+        make_issue("2", 50, "10", Some("high")),
+        make_issue("3", 3, "1000", Some("medium")),
+        make_issue("4", 0, "5", None),
+    ];
+    let queue = merge_queue(vec![high, errors], 25);
+    let ids: Vec<&str> = queue.iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, ["2", "3", "1", "4"]);
+    assert_eq!(merge_queue(vec![queue.clone()], 2).len(), 2);
+}
+
+#[test]
+fn test_format_triage_queue() {
+    let issues = vec![make_issue("7", 12, "340", Some("high"))];
+    let output = format_triage_queue("backend", "14d", &issues);
+    assert!(output.contains("# Triage Queue: backend"));
+    assert!(output.contains("**Found:** 1 issues"));
+    assert!(
+        output.contains("| BACK-7 | Issue 7 | high | error | 12 | 340 | 2024-01-02T00:00:00Z |\n")
+    );
+    assert!(output.contains("update_issue"));
+}
+
+#[test]
+fn test_format_triage_queue_empty() {
+    let output = format_triage_queue("backend", "24h", &[]);
+    assert!(output.contains("The queue is clear."));
+    assert!(!output.contains("| Issue |"));
+}
@@ -0,0 +1,270 @@
+use sentry_mcp::api_client::{Issue, Project};
+use sentry_mcp::tools::update_issue::{UpdateIssueInput, build_update, format_update_output};
+use serde_json::json;
+
+fn input(status: &str) -> UpdateIssueInput {
+    UpdateIssueInput {
+        organization_slug: "org".to_string(),
+        issue_id: "PROJ-1".to_string(),
+        status: Some(status.to_string()),
+        in_release: None,
+        in_next_release: None,
+        in_commit: None,
+        repository: None,
+        ignore_duration: None,
+        ignore_count: None,
+        ignore_window: None,
+        ignore_user_count: None,
+        ignore_user_window: None,
+        ignore_until_escalating: None,
+        assigned_to: None,
+    }
+}
+
+#[test]
+fn test_build_update_plain_resolve() {
+    let update = build_update(&input("Resolved")).unwrap();
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"status": "resolved"})
+    );
+}
+
+#[test]
+fn test_build_update_reopen() {
+    let update = build_update(&input("unresolved")).unwrap();
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"status": "unresolved"})
+    );
+}
+
+#[test]
+fn test_build_update_in_release() {
+    let mut input = input("resolved");
+    input.in_release = Some("1.2.3".to_string());
+    let update = build_update(&input).unwrap();
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"status": "resolved", "statusDetails": {"inRelease": "1.2.3"}})
+    );
+}
+
+#[test]
+fn test_build_update_in_next_release() {
+    let mut input = input("resolved");
+    input.in_next_release = Some(true);
+    let update = build_update(&input).unwrap();
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"status": "resolved", "statusDetails": {"inNextRelease": true}})
+    );
+}
+
+#[test]
+fn test_build_update_in_commit() {
+    let mut input = input("resolved");
+    input.in_commit = Some("abc123".to_string());
+    input.repository = Some("acme/backend".to_string());
+    let update = build_update(&input).unwrap();
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({
+            "status": "resolved",
+            "statusDetails": {"inCommit": {"commit": "abc123", "repository": "acme/backend"}}
+        })
+    );
+}
+
+#[test]
+fn test_build_update_in_commit_requires_repository() {
+    let mut input = input("resolved");
+    input.in_commit = Some("abc123".to_string());
+    assert!(build_update(&input).is_err());
+}
+
+#[test]
+fn test_build_update_rejects_multiple_targets() {
+    let mut input = input("resolved");
+    input.in_release = Some("1.2.3".to_string());
+    input.in_next_release = Some(true);
+    assert!(build_update(&input).is_err());
+}
+
+#[test]
+fn test_build_update_rejects_target_when_reopening() {
+    let mut input = input("unresolved");
+    input.in_release = Some("1.2.3".to_string());
+    assert!(build_update(&input).is_err());
+}
+
+#[test]
+fn test_build_update_rejects_unknown_status() {
+    assert!(build_update(&input("fixed")).is_err());
+}
+
+#[test]
+fn test_format_update_output_with_resolution() {
+    let issue = Issue {
+        id: "1".to_string(),
+        short_id: "PROJ-1".to_string(),
+        title: "Boom".to_string(),
+        culprit: None,
+        status: "resolved".to_string(),
+        status_details: json!({"inRelease": "1.2.3"}),
+        substatus: None,
+        level: None,
+        priority: None,
+        platform: None,
+        project: Project {
+            id: "1".to_string(),
+            name: "p".to_string(),
+            slug: "p".to_string(),
+        },
+        first_seen: None,
+        last_seen: None,
+        count: "1".to_string(),
+        user_count: 1,
+        permalink: None,
+        metadata: json!({}),
+        tags: vec![],
+        issue_type: None,
+        issue_category: None,
+        assigned_to: None,
+        stats: json!({}),
+        forecast: None,
+        is_bookmarked: None,
+        is_subscribed: None,
+        annotations: vec![],
+    };
+    let output = format_update_output(&issue);
+    assert!(output.contains("# Issue Updated"));
+    assert!(output.contains("**Status:** resolved"));
+    assert!(output.contains("**Resolved In:** release 1.2.3"));
+}
+
+#[test]
+fn test_build_update_ignore_for_duration() {
+    let mut input = input("ignored");
+    input.ignore_duration = Some(60);
+    let update = build_update(&input).unwrap();
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"status": "ignored", "statusDetails": {"ignoreDuration": 60}})
+    );
+}
+
+#[test]
+fn test_build_update_ignore_count_with_window() {
+    let mut input = input("ignored");
+    input.ignore_count = Some(100);
+    input.ignore_window = Some(60);
+    let update = build_update(&input).unwrap();
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"status": "ignored", "statusDetails": {"ignoreCount": 100, "ignoreWindow": 60}})
+    );
+}
+
+#[test]
+fn test_build_update_ignore_until_escalating() {
+    let mut input = input("ignored");
+    input.ignore_until_escalating = Some(true);
+    let update = build_update(&input).unwrap();
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"status": "ignored", "substatus": "archived_until_escalating"})
+    );
+}
+
+#[test]
+fn test_build_update_ignore_forever() {
+    let update = build_update(&input("ignored")).unwrap();
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"status": "ignored"})
+    );
+}
+
+#[test]
+fn test_build_update_rejects_ignore_options_without_ignored() {
+    let mut input = input("resolved");
+    input.ignore_duration = Some(30);
+    assert!(build_update(&input).is_err());
+}
+
+#[test]
+fn test_build_update_rejects_conflicting_ignore_options() {
+    let mut input = input("ignored");
+    input.ignore_duration = Some(30);
+    input.ignore_user_count = Some(5);
+    assert!(build_update(&input).is_err());
+}
+
+#[test]
+fn test_build_update_rejects_window_without_count() {
+    let mut input = input("ignored");
+    input.ignore_user_window = Some(60);
+    assert!(build_update(&input).is_err());
+}
+
+#[test]
+fn test_build_update_rejects_non_positive_ignore_values() {
+    let mut input = input("ignored");
+    input.ignore_count = Some(0);
+    assert!(build_update(&input).is_err());
+}
+
+#[test]
+fn test_build_update_assign_only() {
+    let mut input = input("resolved");
+    input.status = None;
+    input.assigned_to = Some(" team:42 ".to_string());
+    let update = build_update(&input).unwrap();
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"assignedTo": "team:42"})
+    );
+}
+
+#[test]
+fn test_build_update_unassign_with_reopen() {
+    let mut input = input("unresolved");
+    input.assigned_to = Some(String::new());
+    let update = build_update(&input).unwrap();
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"status": "unresolved", "assignedTo": ""})
+    );
+}
+
+#[test]
+fn test_build_update_requires_status_or_assignee() {
+    let mut input = input("resolved");
+    input.status = None;
+    assert!(build_update(&input).is_err());
+}
+
+#[test]
+fn test_format_update_output_ignored_and_assigned() {
+    let issue: Issue = serde_json::from_value(json!({
+        "id": "1",
+        "shortId": "PROJ-1",
+        "title": "Boom",
+        "status": "ignored",
+        "substatus": "archived_until_condition_met",
+        "statusDetails": {"ignoreCount": 100, "ignoreWindow": 60},
+        "assignedTo": {"type": "team", "id": "4", "name": "backend"},
+        "permalink": "https://sentry.io/issues/1/",
+        "project": {"id": "1", "name": "p", "slug": "p"},
+        "count": "1",
+        "userCount": 1
+    }))
+    .unwrap();
+    let output = format_update_output(&issue);
+    assert!(output.contains("**Status:** ignored"));
+    assert!(output.contains("**Substatus:** archived_until_condition_met"));
+    assert!(output.contains("**Ignored:** until 100 more events within 60 minutes"));
+    assert!(output.contains("**Assigned To:** #backend"));
+    assert!(output.contains("**URL:** https://sentry.io/issues/1/"));
+}
@@ -0,0 +1,130 @@
+use sentry_mcp::api_client::{UptimeCheck, UptimeMonitor};
+use sentry_mcp::tools::uptime_monitors::{
+    Downtime, MonitorChecks, correlation_query, downtime_windows, format_uptime_output,
+    select_monitors, success_rate,
+};
+use serde_json::json;
+
+fn checks(value: serde_json::Value) -> Vec<UptimeCheck> {
+    serde_json::from_value(value).unwrap()
+}
+
+fn monitors() -> Vec<UptimeMonitor> {
+    serde_json::from_value(json!([
+        {"id": 7, "name": "API health", "url": "https://api.example.com/health",
+         "method": "HEAD", "projectSlug": "backend", "environment": "production",
+         "uptimeStatus": 1, "status": "active", "intervalSeconds": 60},
+        {"id": "8", "name": "Docs", "url": "https://docs.example.com",
+         "projectSlug": "docs", "uptimeStatus": 2, "status": "disabled"}
+    ]))
+    .unwrap()
+}
+
+/// Newest first, like the API returns them.
+fn outage_checks() -> Vec<UptimeCheck> {
+    checks(json!([
+        {"timestamp": "2024-05-01T10:40:00Z", "checkStatus": "failure", "httpStatusCode": 502},
+        {"timestamp": "2024-05-01T10:30:00Z", "checkStatus": "success", "httpStatusCode": 200},
+        {"timestamp": "2024-05-01T10:20:00Z", "checkStatus": "missed_window"},
+        {"timestamp": "2024-05-01T10:10:00Z", "checkStatus": "failure",
+         "checkStatusReason": "timeout"},
+        {"timestamp": "2024-05-01T10:05:00Z", "checkStatus": "failure",
+         "checkStatusReason": "timeout"},
+        {"timestamp": "2024-05-01T10:00:00Z", "checkStatus": "success", "httpStatusCode": 200}
+    ]))
+}
+
+#[test]
+fn test_downtime_windows() {
+    let windows = downtime_windows(&outage_checks());
+    assert_eq!(
+        windows,
+        vec![
+            Downtime {
+                start: "2024-05-01T10:05:00Z".to_string(),
+                end: Some("2024-05-01T10:30:00Z".to_string()),
+                failures: 2,
+                reasons: vec!["timeout".to_string()],
+            },
+            Downtime {
+                start: "2024-05-01T10:40:00Z".to_string(),
+                end: None,
+                failures: 1,
+                reasons: vec!["HTTP 502".to_string()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_downtime_windows_all_up() {
+    let up = checks(json!([
+        {"timestamp": "2024-05-01T10:00:00Z", "checkStatus": "success"}
+    ]));
+    assert!(downtime_windows(&up).is_empty());
+}
+
+#[test]
+fn test_success_rate_ignores_missed_windows() {
+    let rate = success_rate(&outage_checks()).unwrap();
+    assert!((rate - 40.0).abs() < 1e-9);
+    assert_eq!(success_rate(&[]), None);
+}
+
+#[test]
+fn test_correlation_query() {
+    let windows = downtime_windows(&outage_checks());
+    assert_eq!(
+        correlation_query(&windows[0]),
+        "lastSeen:>=2024-05-01T10:05:00Z firstSeen:<=2024-05-01T10:30:00Z"
+    );
+    assert_eq!(
+        correlation_query(&windows[1]),
+        "lastSeen:>=2024-05-01T10:40:00Z"
+    );
+}
+
+#[test]
+fn test_select_monitors() {
+    let monitors = monitors();
+    assert_eq!(select_monitors(&monitors, None, None).len(), 2);
+    assert_eq!(select_monitors(&monitors, Some("docs"), None)[0].id, "8");
+    assert_eq!(select_monitors(&monitors, None, Some("7"))[0].id, "7");
+    assert!(select_monitors(&monitors, Some("docs"), Some("7")).is_empty());
+}
+
+#[test]
+fn test_format_uptime_output() {
+    let monitors = monitors();
+    let entries: Vec<MonitorChecks> =
+        vec![(&monitors[0], Some(outage_checks())), (&monitors[1], None)];
+    let output = format_uptime_output(&entries, false);
+    assert!(output.contains("**Monitors:** 2"));
+    assert!(output.contains("**Down:** 0"));
+    assert!(output.contains("## API health (ID: 7)"));
+    assert!(output.contains("- **URL:** HEAD https://api.example.com/health"));
+    assert!(output.contains("- **Status:** up"));
+    assert!(output.contains("- **Interval:** 1m"));
+    assert!(output.contains("- **Recent Checks:** 6, 40.0% successful"));
+    assert!(output.contains("| 2024-05-01T10:05:00Z | 2024-05-01T10:30:00Z | 25m | 2 | timeout |"));
+    assert!(output.contains("| 2024-05-01T10:40:00Z | still down |  | 1 | HTTP 502 |"));
+    assert!(output.contains("- `lastSeen:>=2024-05-01T10:40:00Z`"));
+    assert!(output.contains("- **Status:** disabled"));
+    assert!(!output.contains("### Checks"));
+}
+
+#[test]
+fn test_format_uptime_output_with_checks() {
+    let monitors = monitors();
+    let entries: Vec<MonitorChecks> = vec![(&monitors[0], Some(outage_checks()))];
+    let output = format_uptime_output(&entries, true);
+    assert!(output.contains("### Checks"));
+    assert!(output.contains("| 2024-05-01T10:10:00Z | failure (timeout) |"));
+    assert!(output.contains("| 2024-05-01T10:40:00Z | failure | 502 |"));
+}
+
+#[test]
+fn test_format_uptime_output_empty() {
+    let output = format_uptime_output(&[], false);
+    assert!(output.contains("No uptime monitors found."));
+}
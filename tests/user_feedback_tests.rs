@@ -0,0 +1,56 @@
+use sentry_mcp::api_client::UserFeedback;
+use sentry_mcp::tools::user_feedback::{filter_by_issue, format_feedback_output, reporter};
+use serde_json::json;
+
+fn make_feedback() -> Vec<UserFeedback> {
+    serde_json::from_value(json!([
+        {"id": "3", "eventID": "e3", "name": "Jane", "email": "jane@example.com",
+         "comments": "I clicked pay twice.\n\nThen the page went blank.",
+         "dateCreated": "2024-05-02T10:00:00Z",
+         "issue": {"id": "42", "shortId": "WEB-42", "title": "TypeError: x is undefined"}},
+        {"id": "2", "email": "bob@example.com", "comments": "Search hangs",
+         "issue": {"id": "43", "shortId": "WEB-43"}},
+        {"id": "1", "name": "", "comments": "  "}
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_reporter() {
+    let feedback = make_feedback();
+    assert_eq!(reporter(&feedback[0]), "Jane <jane@example.com>");
+    assert_eq!(reporter(&feedback[1]), "bob@example.com");
+    assert_eq!(reporter(&feedback[2]), "Anonymous");
+}
+
+#[test]
+fn test_filter_by_issue() {
+    let feedback = make_feedback();
+    assert_eq!(filter_by_issue(&feedback, None).len(), 3);
+    let by_short_id = filter_by_issue(&feedback, Some("web-43"));
+    assert_eq!(by_short_id.len(), 1);
+    assert_eq!(by_short_id[0].id, "2");
+    assert_eq!(filter_by_issue(&feedback, Some(" 42 "))[0].id, "3");
+    assert!(filter_by_issue(&feedback, Some("WEB-1")).is_empty());
+}
+
+#[test]
+fn test_format_feedback_output() {
+    let feedback = make_feedback();
+    let selected: Vec<&UserFeedback> = feedback.iter().collect();
+    let output = format_feedback_output("web", &selected);
+    assert!(output.contains("# User Feedback for web"));
+    assert!(output.contains("**Found:** 3 reports"));
+    assert!(output.contains("### Jane <jane@example.com> (2024-05-02T10:00:00Z)"));
+    assert!(output.contains("**Issue:** WEB-42 - TypeError: x is undefined"));
+    assert!(output.contains("**Issue:** WEB-43\n"));
+    assert!(output.contains("**Event:** e3"));
+    assert!(output.contains("> I clicked pay twice.\n>\n> Then the page went blank.\n"));
+    assert!(output.contains("### Anonymous\n\n> (no comment)"));
+}
+
+#[test]
+fn test_format_feedback_output_empty() {
+    let output = format_feedback_output("WEB-42", &[]);
+    assert!(output.contains("No user feedback found."));
+}
@@ -0,0 +1,136 @@
+use sentry_mcp::api_client::{Issue, Release, SessionStats};
+use sentry_mcp::tools::weekly_report::{
+    DailyRate, WeeklyReport, daily_rates, format_weekly_report, notable_releases, split_weeks,
+    weighted_rate,
+};
+use serde_json::json;
+
+fn day(date: &str, sessions: f64, crash_free: Option<f64>) -> DailyRate {
+    DailyRate {
+        date: date.to_string(),
+        sessions,
+        crash_free,
+    }
+}
+
+fn release(version: &str, new_groups: Option<i64>) -> Release {
+    serde_json::from_value(json!({
+        "version": version,
+        "dateCreated": "2024-05-03T12:00:00Z",
+        "newGroups": new_groups
+    }))
+    .unwrap()
+}
+
+fn issue(id: &str, users: i64) -> Issue {
+    serde_json::from_value(json!({
+        "id": id,
+        "shortId": format!("WEB-{}", id),
+        "title": "TypeError | undefined",
+        "status": "unresolved",
+        "project": {"id": "1", "name": "Web", "slug": "web"},
+        "count": "40",
+        "userCount": users
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_daily_rates() {
+    let stats: SessionStats = serde_json::from_value(json!({
+        "intervals": ["2024-05-01T00:00:00Z", "2024-05-02T00:00:00Z"],
+        "groups": [{
+            "series": {
+                "sum(session)": [10, 0],
+                "crash_free_rate(session)": [0.9, null]
+            }
+        }]
+    }))
+    .unwrap();
+    assert_eq!(
+        daily_rates(&stats),
+        vec![
+            day("2024-05-01", 10.0, Some(0.9)),
+            day("2024-05-02", 0.0, None)
+        ]
+    );
+    assert!(daily_rates(&SessionStats::default()).is_empty());
+}
+
+#[test]
+fn test_weighted_rate() {
+    let days = [
+        day("a", 100.0, Some(1.0)),
+        day("b", 300.0, Some(0.9)),
+        day("c", 0.0, None),
+    ];
+    assert!((weighted_rate(&days).unwrap() - 0.925).abs() < 1e-9);
+    assert_eq!(weighted_rate(&[day("c", 0.0, None)]), None);
+}
+
+#[test]
+fn test_split_weeks() {
+    let days: Vec<DailyRate> = (0..15)
+        .map(|i| day(&i.to_string(), 1.0, Some(1.0)))
+        .collect();
+    let (previous, current) = split_weeks(&days);
+    assert_eq!(previous.len(), 8);
+    assert_eq!(current.len(), 7);
+    assert_eq!(current[0].date, "8");
+    let (previous, current) = split_weeks(&days[..3]);
+    assert!(previous.is_empty());
+    assert_eq!(current.len(), 3);
+}
+
+#[test]
+fn test_notable_releases() {
+    let releases = notable_releases(vec![
+        release("1.0", Some(1)),
+        release("1.1", None),
+        release("1.2", Some(4)),
+    ]);
+    let versions: Vec<&str> = releases.iter().map(|r| r.version.as_str()).collect();
+    assert_eq!(versions, ["1.2", "1.0", "1.1"]);
+}
+
+#[test]
+fn test_format_weekly_report() {
+    let mut days: Vec<DailyRate> = (1..=7)
+        .map(|d| day(&format!("2024-04-{:02}", d), 100.0, Some(0.99)))
+        .collect();
+    days.extend((1..=7).map(|d| day(&format!("2024-05-{:02}", d), 100.0, Some(0.98))));
+    let report = WeeklyReport {
+        new_issues: (0..100).map(|i| issue(&i.to_string(), 1)).collect(),
+        resolved: vec![issue("7", 1)],
+        top_issues: vec![issue("3", 12)],
+        days,
+        releases: vec![release("2.0", Some(3))],
+    };
+    let output = format_weekly_report("web", "2024-05-01", "2024-05-08", &report);
+    assert!(output.contains("**Period:** 2024-05-01 to 2024-05-08"));
+    assert!(output.contains("- **New issues:** 100+\n"));
+    assert!(output.contains("- **Resolved issues:** 1\n"));
+    assert!(
+        output.contains("- **Crash-free sessions:** 98.00% (previous week 99.00%, -1.00 pts)\n")
+    );
+    assert!(output.contains("| WEB-3 | TypeError \\| undefined | 12 | 40 |"));
+    assert!(output.contains("| 2024-05-07 | 100 | 98.00% |"));
+    assert!(!output.contains("| 2024-04-07 |"));
+    assert!(output.contains("- **2.0** created 2024-05-03T12:00:00Z, 3 new issues"));
+}
+
+#[test]
+fn test_format_weekly_report_without_data() {
+    let report = WeeklyReport {
+        new_issues: vec![],
+        resolved: vec![],
+        top_issues: vec![],
+        days: vec![],
+        releases: vec![],
+    };
+    let output = format_weekly_report("web", "2024-05-01", "2024-05-08", &report);
+    assert!(output.contains("- **Crash-free sessions:** no session data\n"));
+    assert!(output.contains("No unresolved issues this week."));
+    assert!(output.contains("No session data; release health needs"));
+    assert!(output.contains("No releases this week."));
+}
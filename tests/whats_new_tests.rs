@@ -0,0 +1,126 @@
+use chrono::{TimeZone, Utc};
+use sentry_mcp::api_client::{Issue, Project, Release};
+use sentry_mcp::tools::whats_new::{
+    WhatsNew, format_whats_new_output, parse_since, sentry_timestamp,
+};
+
+fn make_issue(short_id: &str, title: &str) -> Issue {
+    Issue {
+        id: "1".to_string(),
+        short_id: short_id.to_string(),
+        title: title.to_string(),
+        culprit: None,
+        status: "unresolved".to_string(),
+        status_details: serde_json::json!({}),
+        substatus: None,
+        level: Some("error".to_string()),
+        priority: None,
+        platform: None,
+        project: Project {
+            id: "1".to_string(),
+            name: "backend".to_string(),
+            slug: "backend".to_string(),
+        },
+        first_seen: None,
+        last_seen: Some("2024-01-02T00:00:00Z".to_string()),
+        count: "42".to_string(),
+        user_count: 7,
+        permalink: None,
+        metadata: serde_json::json!({}),
+        tags: vec![],
+        issue_type: None,
+        issue_category: None,
+        assigned_to: None,
+        stats: serde_json::json!({}),
+        forecast: None,
+        is_bookmarked: None,
+        is_subscribed: None,
+        annotations: vec![],
+    }
+}
+
+fn empty() -> WhatsNew {
+    WhatsNew {
+        new_issues: vec![],
+        regressions: vec![],
+        escalating: vec![],
+        resolved: vec![],
+        releases: vec![],
+    }
+}
+
+#[test]
+fn test_parse_since_relative() {
+    let now = Utc.with_ymd_and_hms(2024, 1, 10, 12, 0, 0).unwrap();
+    assert_eq!(
+        parse_since("24h", now),
+        Some(Utc.with_ymd_and_hms(2024, 1, 9, 12, 0, 0).unwrap())
+    );
+    assert_eq!(
+        parse_since("1w", now),
+        Some(Utc.with_ymd_and_hms(2024, 1, 3, 12, 0, 0).unwrap())
+    );
+    assert_eq!(
+        parse_since("30m", now),
+        Some(Utc.with_ymd_and_hms(2024, 1, 10, 11, 30, 0).unwrap())
+    );
+}
+
+#[test]
+fn test_parse_since_absolute() {
+    let now = Utc::now();
+    let expected = Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap();
+    assert_eq!(parse_since("2024-01-15T09:00:00Z", now), Some(expected));
+    assert_eq!(
+        parse_since("2024-01-15T10:00:00+01:00", now),
+        Some(expected)
+    );
+    assert_eq!(parse_since("2024-01-15T09:00:00", now), Some(expected));
+    assert_eq!(
+        parse_since("2024-01-15", now),
+        Some(Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap())
+    );
+}
+
+#[test]
+fn test_parse_since_invalid() {
+    assert!(parse_since("yesterday", Utc::now()).is_none());
+    assert!(parse_since("", Utc::now()).is_none());
+}
+
+#[test]
+fn test_sentry_timestamp() {
+    let dt = Utc.with_ymd_and_hms(2024, 1, 15, 9, 5, 3).unwrap();
+    assert_eq!(sentry_timestamp(&dt), "2024-01-15T09:05:03");
+}
+
+#[test]
+fn test_format_whats_new_empty() {
+    let output = format_whats_new_output("backend", "2024-01-01T00:00:00+00:00", &empty());
+    assert!(output.contains("# What's New in backend"));
+    assert!(output.contains("**Since:** 2024-01-01T00:00:00+00:00"));
+    assert!(output.contains("## New Issues (0)"));
+    assert!(output.contains("## New Releases (0)"));
+    assert!(output.contains("None."));
+}
+
+#[test]
+fn test_format_whats_new_sections() {
+    let mut whats_new = empty();
+    whats_new.new_issues = vec![make_issue("BACK-1", "KeyError: 'id'")];
+    whats_new.regressions = vec![make_issue("BACK-2", "Timeout")];
+    whats_new.releases = vec![Release {
+        version: "backend@1.2.3+abc".to_string(),
+        short_version: Some("1.2.3".to_string()),
+        date_created: Some("2024-01-02T00:00:00Z".to_string()),
+        date_released: None,
+        new_groups: Some(3),
+    }];
+    let output = format_whats_new_output("backend", "24h", &whats_new);
+    assert!(output.contains("## New Issues (1)"));
+    assert!(output.contains("- **BACK-1** KeyError: 'id' (42 events, 7 users"));
+    assert!(output.contains("## Regressed, Active Since (1)"));
+    assert!(output.contains("in that state now"));
+    assert!(output.contains("**BACK-2** Timeout"));
+    assert!(output.contains("- **1.2.3** created 2024-01-02T00:00:00Z (3 new issues)"));
+}